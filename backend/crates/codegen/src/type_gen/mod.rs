@@ -1,2 +1,3 @@
 pub mod operation_definitions;
 pub mod rust_types;
+pub mod search_parameters;
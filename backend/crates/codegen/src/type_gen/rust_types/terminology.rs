@@ -250,6 +250,24 @@ fn generate_enum_variants(value_set: ValueSet) -> Option<TokenStream> {
                     fn typename(&self) -> &'static str {
                         "FHIRCode"
                     }
+
+                    // Codes can be built from a FHIRCode holding one of the value set codes.
+                    fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+                        let value = match value.downcast::<Self>() {
+                            Ok(value) => return Ok(*value),
+                            Err(value) => value,
+                        };
+                        let code = value.downcast::<FHIRCode>()?;
+                        match code.value.clone().map(#terminology_enum_name::try_from) {
+                            Some(Ok(value)) => Ok(value),
+                            _ => Err(code),
+                        }
+                    }
+
+                    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+                        *self = Self::from_any(value)?;
+                        Ok(())
+                    }
                 }
             });
         }
@@ -448,7 +466,7 @@ pub async fn generate(
         tokens: quote! {
             #![allow(non_camel_case_types)]
             /// DO NOT EDIT THIS FILE. It is auto-generated by the FHIR Rust code generator.
-            use self::super::types::Element;
+            use self::super::types::{Element, FHIRCode};
             use std::any::Any;
             use haste_reflect::MetaValue;
             use haste_fhir_serialization_json::derive::{FHIRJSONSerialize, FHIRJSONDeserialize};
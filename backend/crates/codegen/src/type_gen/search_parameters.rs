@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use crate::utilities::load;
+use haste_fhir_model::r4::generated::{
+    resources::{Resource, SearchParameter},
+    terminology::SearchParamType,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use walkdir::WalkDir;
+
+/// Parameters on these bases apply to every resource type.
+static SHARED_BASE_TYPES: &[&str] = &["Resource", "DomainResource"];
+
+static KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn get_search_parameters(resource: Resource) -> Vec<SearchParameter> {
+    match resource {
+        Resource::Bundle(bundle) => bundle
+            .entry
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|e| e.resource)
+            .filter_map(|resource| match *resource {
+                Resource::SearchParameter(search_parameter) => Some(search_parameter),
+                _ => None,
+            })
+            .collect(),
+        Resource::SearchParameter(search_parameter) => vec![search_parameter],
+        _ => vec![],
+    }
+}
+
+/// `_lastUpdated` -> `_last_updated`, `value-quantity` -> `value_quantity`, `type` -> `type_`.
+/// The leading underscore is kept so shared parameters don't collide with resource parameters (`_type` and `type`).
+fn method_name(code: &str) -> String {
+    let mut name = String::new();
+    let mut previous_lowercase = false;
+    for c in code.chars() {
+        if c == '-' {
+            name.push('_');
+            previous_lowercase = false;
+        } else if c.is_uppercase() {
+            if previous_lowercase {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+            previous_lowercase = false;
+        } else {
+            name.push(c);
+            previous_lowercase = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+
+    if KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+fn parameter_type(search_parameter: &SearchParameter) -> Option<TokenStream> {
+    match search_parameter.type_.as_ref() {
+        SearchParamType::Number(_) => Some(quote! { NumberParameter }),
+        SearchParamType::Date(_) => Some(quote! { DateParameter }),
+        SearchParamType::String(_) => Some(quote! { StringParameter }),
+        SearchParamType::Token(_) => Some(quote! { TokenParameter }),
+        SearchParamType::Reference(_) => Some(quote! { ReferenceParameter }),
+        SearchParamType::Composite(_) => Some(quote! { CompositeParameter }),
+        SearchParamType::Quantity(_) => Some(quote! { QuantityParameter }),
+        SearchParamType::Uri(_) => Some(quote! { UriParameter }),
+        SearchParamType::Special(_) => Some(quote! { SpecialParameter }),
+        SearchParamType::Null(_) => None,
+    }
+}
+
+/// Parameters shared by multiple resources list a description per resource,
+/// `* [Patient](patient.html): A patient identifier`.
+fn description(search_parameter: &SearchParameter, base: &str) -> String {
+    let description = search_parameter
+        .description
+        .value
+        .clone()
+        .unwrap_or_default();
+    let resource_prefix = format!("* [{}](", base);
+
+    description
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix(&resource_prefix)
+                .and_then(|line| line.split_once("): "))
+                .map(|(_, description)| description.trim().to_string())
+        })
+        .unwrap_or_else(|| {
+            description
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default()
+                .trim()
+                .to_string()
+        })
+}
+
+/// `resource` is the query's resource type, `R` for the shared parameters.
+fn generate_parameter_method(
+    search_parameter: &SearchParameter,
+    base: &str,
+    resource: &TokenStream,
+) -> TokenStream {
+    let code = search_parameter.code.value.clone().unwrap_or_default();
+    let Some(parameter_type) = parameter_type(search_parameter) else {
+        return quote! {};
+    };
+    let method_ident = format_ident!("{}", method_name(&code));
+    let description = description(search_parameter, base);
+
+    quote! {
+        #[doc = #description]
+        pub fn #method_ident(self) -> #parameter_type<#resource> {
+            #parameter_type::new(self, #code)
+        }
+    }
+}
+
+/// Generates the typed search query methods for every resource type with search parameters.
+/// Parameters on `Resource` and `DomainResource` are generated once for all resource types.
+pub fn generate_search_parameters_from_files(file_paths: &Vec<String>) -> Result<String, String> {
+    let mut parameters_by_base: BTreeMap<String, BTreeMap<String, SearchParameter>> =
+        BTreeMap::new();
+
+    for dir_path in file_paths {
+        let walker = WalkDir::new(dir_path).into_iter();
+        for entry in walker
+            .filter_map(|e| e.ok())
+            .filter(|e| e.metadata().unwrap().is_file())
+        {
+            let resource = load::load_from_file(entry.path())?;
+            for search_parameter in get_search_parameters(resource) {
+                let code = search_parameter
+                    .code
+                    .value
+                    .clone()
+                    .ok_or_else(|| "Search parameter must have a code.".to_string())?;
+
+                for base in search_parameter.base.iter() {
+                    let base: Option<String> = base.as_ref().into();
+                    if let Some(base) = base {
+                        parameters_by_base
+                            .entry(base)
+                            .or_default()
+                            .insert(code.clone(), search_parameter.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let shared_resource = quote! { R };
+    let shared_methods = SHARED_BASE_TYPES
+        .iter()
+        .filter_map(|base| parameters_by_base.get(*base).map(|p| (base, p)))
+        .flat_map(|(base, parameters)| {
+            parameters.values().map(|search_parameter| {
+                generate_parameter_method(search_parameter, base, &shared_resource)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let resource_impls = parameters_by_base
+        .iter()
+        .filter(|(base, _)| !SHARED_BASE_TYPES.contains(&base.as_str()))
+        .map(|(base, parameters)| {
+            let resource_ident = format_ident!("{}", base);
+            let resource = quote! { #resource_ident };
+            let methods = parameters.values().map(|search_parameter| {
+                generate_parameter_method(search_parameter, base, &resource)
+            });
+
+            quote! {
+                impl Search for #resource_ident {
+                    fn resource_type() -> ResourceType {
+                        ResourceType::#resource_ident
+                    }
+                }
+
+                impl SearchQuery<#resource_ident> {
+                    #(#methods)*
+                }
+            }
+        });
+
+    let generated_code = quote! {
+        use super::{
+            CompositeParameter, DateParameter, NumberParameter, QuantityParameter, ReferenceParameter,
+            Search, SearchQuery, SpecialParameter, StringParameter, TokenParameter, UriParameter,
+        };
+        use haste_fhir_model::r4::generated::resources::*;
+
+        impl<R: Search> SearchQuery<R> {
+            #(#shared_methods)*
+        }
+
+        #(#resource_impls)*
+    };
+
+    Ok(generated_code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_name() {
+        assert_eq!(method_name("_lastUpdated"), "_last_updated");
+        assert_eq!(method_name("value-quantity"), "value_quantity");
+        assert_eq!(method_name("address-postalcode"), "address_postalcode");
+        assert_eq!(method_name("type"), "type_");
+        assert_eq!(method_name("_haste-health-author"), "_haste_health_author");
+    }
+}
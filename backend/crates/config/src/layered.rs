@@ -0,0 +1,416 @@
+use crate::Config;
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+static CONFIG_FILE_VARIABLE: &str = "HASTE_CONFIG_FILE";
+static SECRETS_DIR_VARIABLE: &str = "HASTE_SECRETS_DIR";
+static RELOAD_INTERVAL_VARIABLE: &str = "HASTE_CONFIG_RELOAD_SECONDS";
+static DEFAULT_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum LayeredConfigError {
+    #[error(
+        code = "invalid",
+        diagnostic = "Failed to read config file '{arg0}': '{arg1}'."
+    )]
+    FailedToReadFile(String, std::io::Error),
+    #[error(
+        code = "invalid",
+        diagnostic = "Unsupported config file '{arg0}' expected a .toml, .yaml or .yml extension."
+    )]
+    UnsupportedFileFormat(String),
+    #[error(code = "invalid", diagnostic = "Invalid TOML config file: '{arg0}'.")]
+    InvalidTOML(#[from] toml::de::Error),
+    #[error(code = "invalid", diagnostic = "Invalid YAML config file: '{arg0}'.")]
+    InvalidYAML(#[from] serde_yaml::Error),
+    #[error(
+        code = "invalid",
+        diagnostic = "Config file must contain a table of keys at the root."
+    )]
+    InvalidFileRoot,
+    #[error(
+        code = "invalid",
+        diagnostic = "Missing required configuration keys: '{arg0}'."
+    )]
+    MissingRequiredKeys(String),
+    #[error(code = "invalid", diagnostic = "Config key '{arg0}' is not set.")]
+    KeyNotSet(String),
+}
+
+/// Options for [`LayeredConfig`].
+/// Paths that are not set explicitly are read from HASTE_CONFIG_FILE and HASTE_SECRETS_DIR.
+#[derive(Default)]
+pub struct LayeredConfigOptions {
+    file: Option<PathBuf>,
+    secrets_dir: Option<PathBuf>,
+    required: Vec<String>,
+    reloadable: HashSet<String>,
+    reload_interval: Option<Duration>,
+}
+
+impl LayeredConfigOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn secrets_dir(mut self, secrets_dir: impl Into<PathBuf>) -> Self {
+        self.secrets_dir = Some(secrets_dir.into());
+        self
+    }
+
+    /// Keys that must resolve from some layer otherwise loading fails.
+    pub fn required<Key: Into<String>>(mut self, keys: impl IntoIterator<Item = Key>) -> Self {
+        self.required.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Keys that are safe to change while running and are picked up on reload.
+    /// All other keys keep the value they had at startup.
+    pub fn reloadable<Key: Into<String>>(mut self, keys: impl IntoIterator<Item = Key>) -> Self {
+        self.reloadable.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn reload_interval(mut self, interval: Duration) -> Self {
+        self.reload_interval = Some(interval);
+        self
+    }
+}
+
+/// Nested tables are flattened into upper snake case keys
+/// IE [elasticsearch] url = "" becomes ELASTICSEARCH_URL.
+fn flatten_toml(prefix: Option<&str>, table: &toml::Table, values: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = match prefix {
+            Some(prefix) => format!("{}_{}", prefix, key.to_uppercase()),
+            None => key.to_uppercase(),
+        };
+        match value {
+            toml::Value::Table(table) => flatten_toml(Some(&key), table, values),
+            toml::Value::String(value) => {
+                values.insert(key, value.clone());
+            }
+            value => {
+                values.insert(key, value.to_string());
+            }
+        }
+    }
+}
+
+fn flatten_yaml(
+    prefix: Option<&str>,
+    mapping: &serde_yaml::Mapping,
+    values: &mut HashMap<String, String>,
+) {
+    for (key, value) in mapping {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let key = match prefix {
+            Some(prefix) => format!("{}_{}", prefix, key.to_uppercase()),
+            None => key.to_uppercase(),
+        };
+        match value {
+            serde_yaml::Value::Mapping(mapping) => flatten_yaml(Some(&key), mapping, values),
+            serde_yaml::Value::String(value) => {
+                values.insert(key, value.clone());
+            }
+            serde_yaml::Value::Bool(value) => {
+                values.insert(key, value.to_string());
+            }
+            serde_yaml::Value::Number(value) => {
+                values.insert(key, value.to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<HashMap<String, String>, LayeredConfigError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| LayeredConfigError::FailedToReadFile(path.display().to_string(), e))?;
+    let mut values = HashMap::new();
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => {
+            let table = contents.parse::<toml::Table>()?;
+            flatten_toml(None, &table, &mut values);
+        }
+        Some("yaml") | Some("yml") => {
+            let serde_yaml::Value::Mapping(mapping) =
+                serde_yaml::from_str::<serde_yaml::Value>(&contents)?
+            else {
+                return Err(LayeredConfigError::InvalidFileRoot);
+            };
+            flatten_yaml(None, &mapping, &mut values);
+        }
+        _ => {
+            return Err(LayeredConfigError::UnsupportedFileFormat(
+                path.display().to_string(),
+            ));
+        }
+    }
+
+    Ok(values)
+}
+
+/// Each file in the directory is a key with the file contents as the value.
+/// Hidden entries are skipped which covers the ..data links Kubernetes creates for mounted secrets.
+fn read_secrets_dir(path: &Path) -> Result<HashMap<String, String>, LayeredConfigError> {
+    let entries = std::fs::read_dir(path)
+        .map_err(|e| LayeredConfigError::FailedToReadFile(path.display().to_string(), e))?;
+    let mut values = HashMap::new();
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') || !entry.path().is_file() {
+            continue;
+        }
+
+        let value = std::fs::read_to_string(entry.path()).map_err(|e| {
+            LayeredConfigError::FailedToReadFile(entry.path().display().to_string(), e)
+        })?;
+        values.insert(name, value.trim_end_matches(['\r', '\n']).to_string());
+    }
+
+    Ok(values)
+}
+
+struct Sources {
+    file: Option<PathBuf>,
+    secrets_dir: Option<PathBuf>,
+}
+
+impl Sources {
+    /// Secrets take precedence over the config file.
+    fn load(&self) -> Result<HashMap<String, String>, LayeredConfigError> {
+        let mut values = match self.file.as_ref() {
+            Some(file) => read_file(file)?,
+            None => HashMap::new(),
+        };
+
+        if let Some(secrets_dir) = self.secrets_dir.as_ref() {
+            values.extend(read_secrets_dir(secrets_dir)?);
+        }
+
+        Ok(values)
+    }
+}
+
+/// Merges a TOML/YAML config file, a directory of secret files and the environment.
+/// Precedence from highest to lowest is environment, secrets directory then config file.
+pub struct LayeredConfig {
+    values: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl LayeredConfig {
+    pub fn new(options: LayeredConfigOptions) -> Result<Self, OperationOutcomeError> {
+        if let Err(e) = dotenvy::dotenv() {
+            tracing::warn!(
+                "Failed to load .env file: '{:?}' will use config file, secrets and environment variables.",
+                e
+            )
+        }
+
+        let sources = Sources {
+            file: options
+                .file
+                .or_else(|| std::env::var(CONFIG_FILE_VARIABLE).ok().map(PathBuf::from)),
+            secrets_dir: options
+                .secrets_dir
+                .or_else(|| std::env::var(SECRETS_DIR_VARIABLE).ok().map(PathBuf::from)),
+        };
+        let reload_interval = options.reload_interval.unwrap_or_else(|| {
+            std::env::var(RELOAD_INTERVAL_VARIABLE)
+                .ok()
+                .and_then(|seconds| seconds.parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_RELOAD_INTERVAL)
+        });
+
+        let config = LayeredConfig {
+            values: Arc::new(RwLock::new(sources.load()?)),
+        };
+
+        let missing = options
+            .required
+            .iter()
+            .filter(|key| config.resolve(key).is_none())
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(LayeredConfigError::MissingRequiredKeys(missing.join(", ")).into());
+        }
+
+        if !options.reloadable.is_empty()
+            && (sources.file.is_some() || sources.secrets_dir.is_some())
+        {
+            spawn_reloader(
+                sources,
+                options.reloadable,
+                reload_interval,
+                config.values.clone(),
+            );
+        }
+
+        Ok(config)
+    }
+
+    fn resolve(&self, key: &str) -> Option<String> {
+        std::env::var(key).ok().or_else(|| {
+            self.values
+                .read()
+                .ok()
+                .and_then(|values| values.get(key).cloned())
+        })
+    }
+}
+
+/// Applies changes to reloadable keys from the file and secrets directory.
+/// Changes to any other key are logged and ignored until restart.
+fn reload(
+    sources: &Sources,
+    reloadable: &HashSet<String>,
+    values: &RwLock<HashMap<String, String>>,
+) -> Result<(), LayeredConfigError> {
+    let latest = sources.load()?;
+    let Ok(mut values) = values.write() else {
+        return Ok(());
+    };
+
+    let keys = latest
+        .keys()
+        .chain(values.keys())
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    for key in keys {
+        let next = latest.get(&key);
+        if values.get(&key) == next {
+            continue;
+        }
+
+        if !reloadable.contains(&key) {
+            tracing::warn!(
+                "Config key '{}' changed but is not reloadable, restart to apply.",
+                key
+            );
+            continue;
+        }
+
+        tracing::info!("Reloaded config key '{}'.", key);
+        match next {
+            Some(next) => values.insert(key, next.clone()),
+            None => values.remove(&key),
+        };
+    }
+
+    Ok(())
+}
+
+fn spawn_reloader(
+    sources: Sources,
+    reloadable: HashSet<String>,
+    interval: Duration,
+    values: Arc<RwLock<HashMap<String, String>>>,
+) {
+    std::thread::spawn(move || {
+        loop {
+            std::thread::sleep(interval);
+            if let Err(e) = reload(&sources, &reloadable, &values) {
+                tracing::error!("Failed to reload configuration: {:?}", e);
+            }
+        }
+    });
+}
+
+impl<Key: Into<String>> Config<Key> for LayeredConfig {
+    fn get(&self, key: Key) -> Result<String, OperationOutcomeError> {
+        let key_string = key.into();
+        self.resolve(&key_string)
+            .ok_or_else(|| LayeredConfigError::KeyNotSet(key_string).into())
+    }
+    fn set(&self, key: Key, value: String) -> Result<(), OperationOutcomeError> {
+        // Environment has the highest precedence so this overrides any file or secret value.
+        unsafe {
+            std::env::set_var(key.into(), value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_toml() {
+        let table = r#"
+            API_URI = "http://localhost:3000"
+            max_request_body_size = 1024
+
+            [elasticsearch]
+            url = "http://elasticsearch:9200"
+        "#
+        .parse::<toml::Table>()
+        .unwrap();
+        let mut values = HashMap::new();
+        flatten_toml(None, &table, &mut values);
+
+        assert_eq!(
+            values.get("API_URI"),
+            Some(&"http://localhost:3000".to_string())
+        );
+        assert_eq!(
+            values.get("MAX_REQUEST_BODY_SIZE"),
+            Some(&"1024".to_string())
+        );
+        assert_eq!(
+            values.get("ELASTICSEARCH_URL"),
+            Some(&"http://elasticsearch:9200".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reload_only_applies_reloadable_keys() {
+        let dir = std::env::temp_dir().join(format!("haste-config-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("HASTE_TEST_RELOADABLE"), "new\n").unwrap();
+        std::fs::write(dir.join("HASTE_TEST_FIXED"), "new\n").unwrap();
+
+        let sources = Sources {
+            file: None,
+            secrets_dir: Some(dir.clone()),
+        };
+        let values = RwLock::new(HashMap::from([
+            ("HASTE_TEST_RELOADABLE".to_string(), "old".to_string()),
+            ("HASTE_TEST_FIXED".to_string(), "old".to_string()),
+        ]));
+
+        reload(
+            &sources,
+            &HashSet::from(["HASTE_TEST_RELOADABLE".to_string()]),
+            &values,
+        )
+        .unwrap();
+
+        let values = values.read().unwrap();
+        assert_eq!(
+            values.get("HASTE_TEST_RELOADABLE"),
+            Some(&"new".to_string())
+        );
+        assert_eq!(values.get("HASTE_TEST_FIXED"), Some(&"old".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,29 +1,48 @@
 use crate::environment::EnvironmentConfig;
-use haste_fhir_operation_error::OperationOutcomeError;
-use std::sync::Arc;
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use std::{str::FromStr, sync::Arc};
 
 mod environment;
+mod layered;
+
+pub use layered::{LayeredConfig, LayeredConfigOptions};
 
 pub trait Config<Key: Into<String>>: Send + Sync {
     fn get(&self, name: Key) -> Result<String, OperationOutcomeError>;
     fn set(&self, name: Key, value: String) -> Result<(), OperationOutcomeError>;
 }
 
+#[derive(OperationOutcomeError, Debug)]
+pub enum ConfigTypeError {
+    #[error(
+        code = "invalid",
+        diagnostic = "Unknown config type '{arg0}' expected 'environment' or 'layered'."
+    )]
+    UnknownConfigType(String),
+}
+
 pub enum ConfigType {
     Environment,
+    Layered(LayeredConfigOptions),
 }
 
-impl From<&str> for ConfigType {
-    fn from(value: &str) -> Self {
+impl FromStr for ConfigType {
+    type Err = OperationOutcomeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "environment" => ConfigType::Environment,
-            _ => panic!("Unknown config type"),
+            "environment" => Ok(ConfigType::Environment),
+            "layered" => Ok(ConfigType::Layered(LayeredConfigOptions::new())),
+            _ => Err(ConfigTypeError::UnknownConfigType(value.to_string()).into()),
         }
     }
 }
 
-pub fn get_config<Key: Into<String>>(config_type: ConfigType) -> Arc<dyn Config<Key>> {
+pub fn get_config<Key: Into<String>>(
+    config_type: ConfigType,
+) -> Result<Arc<dyn Config<Key>>, OperationOutcomeError> {
     match config_type {
-        ConfigType::Environment => Arc::new(EnvironmentConfig::new().unwrap()),
+        ConfigType::Environment => Ok(Arc::new(EnvironmentConfig::new()?)),
+        ConfigType::Layered(options) => Ok(Arc::new(LayeredConfig::new(options)?)),
     }
 }
@@ -3,7 +3,7 @@ use crate::request::{
 };
 use axum::response::IntoResponse;
 use haste_fhir_model::r4::generated::{
-    resources::Resource,
+    resources::{Bundle, Resource},
     terminology::IssueType,
     types::{FHIRId, FHIRInstant},
 };
@@ -76,6 +76,16 @@ fn add_headers(response: &FHIRResponse) -> HeaderMap {
     header
 }
 
+/// Bundles can hold many entries, so they're serialized an entry at a time as the body is sent.
+fn bundle_body(mut bundle: Bundle) -> axum::body::Body {
+    let entries = bundle.entry.take().unwrap_or_default();
+    axum::body::Body::from_stream(
+        // Unwrap should be safe here.
+        haste_fhir_serialization_json::stream::to_array_field_stream(&bundle, "entry", entries)
+            .unwrap(),
+    )
+}
+
 impl IntoResponse for FHIRResponse {
     fn into_response(self) -> axum::response::Response {
         let header = add_headers(&self);
@@ -127,52 +137,26 @@ impl IntoResponse for FHIRResponse {
             )
                 .into_response(),
             FHIRResponse::History(history_response) => match history_response {
-                HistoryResponse::Instance(response) => (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.bundle).unwrap(),
-                )
-                    .into_response(),
-                HistoryResponse::Type(response) => (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.bundle).unwrap(),
-                )
-                    .into_response(),
-                HistoryResponse::System(response) => (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.bundle).unwrap(),
-                )
-                    .into_response(),
+                HistoryResponse::Instance(response) => {
+                    (StatusCode::OK, header, bundle_body(response.bundle)).into_response()
+                }
+                HistoryResponse::Type(response) => {
+                    (StatusCode::OK, header, bundle_body(response.bundle)).into_response()
+                }
+                HistoryResponse::System(response) => {
+                    (StatusCode::OK, header, bundle_body(response.bundle)).into_response()
+                }
             },
             FHIRResponse::Search(search_response) => match search_response {
-                SearchResponse::Type(response) => (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.bundle).unwrap(),
-                )
-                    .into_response(),
-                SearchResponse::System(response) => (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.bundle).unwrap(),
-                )
-                    .into_response(),
+                SearchResponse::Type(response) => {
+                    (StatusCode::OK, header, bundle_body(response.bundle)).into_response()
+                }
+                SearchResponse::System(response) => {
+                    (StatusCode::OK, header, bundle_body(response.bundle)).into_response()
+                }
             },
             FHIRResponse::Batch(response) => {
-                (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&response.resource).unwrap(),
-                )
-                    .into_response()
+                (StatusCode::OK, header, bundle_body(response.resource)).into_response()
             }
             FHIRResponse::Invoke(invoke_response) => match invoke_response {
                 InvokeResponse::Instance(invoke_response) => {
@@ -221,16 +205,12 @@ impl IntoResponse for FHIRResponse {
             )
                 .into_response(),
 
-            FHIRResponse::Transaction(fhirtransaction_response) => {
-                (
-                    StatusCode::OK,
-                    header,
-                    // Unwrap should be safe here.
-                    haste_fhir_serialization_json::to_string(&fhirtransaction_response.resource)
-                        .unwrap(),
-                )
-                    .into_response()
-            }
+            FHIRResponse::Transaction(fhirtransaction_response) => (
+                StatusCode::OK,
+                header,
+                bundle_body(fhirtransaction_response.resource),
+            )
+                .into_response(),
         }
     }
 }
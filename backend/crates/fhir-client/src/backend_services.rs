@@ -0,0 +1,190 @@
+//! Access tokens for SMART Backend Services clients, authenticating with a signed
+//! client assertion (private_key_jwt) instead of a client secret.
+use crate::http::AccessTokenProvider;
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub static JWT_BEARER_CLIENT_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Lifetime of a client assertion, the maximum servers are required to accept.
+static ASSERTION_LIFETIME: u64 = 60 * 5;
+/// Tokens are renewed this many seconds before they expire.
+static EXPIRY_MARGIN: u64 = 30;
+
+#[derive(Debug, OperationOutcomeError)]
+pub enum BackendServicesError {
+    #[error(code = "exception", diagnostic = "Reqwest failed.")]
+    ReqwestError(#[from] reqwest::Error),
+    #[fatal(
+        code = "not-supported",
+        diagnostic = "Unsupported client assertion algorithm '{arg0}'."
+    )]
+    UnsupportedAlgorithm(String),
+    #[fatal(
+        code = "invalid",
+        diagnostic = "Invalid private key for client assertion."
+    )]
+    InvalidKey,
+    #[error(code = "exception", diagnostic = "Failed to sign client assertion.")]
+    SignError,
+    #[error(
+        code = "forbidden",
+        diagnostic = "Token request failed with HTTP status '{arg0}'."
+    )]
+    TokenRequestFailed(u16),
+    #[error(
+        code = "exception",
+        diagnostic = "No access_token field in token response."
+    )]
+    InvalidTokenResponse,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: u64,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+pub struct BackendServicesAuth {
+    client: reqwest::Client,
+    token_endpoint: String,
+    client_id: String,
+    scope: String,
+    algorithm: Algorithm,
+    key: EncodingKey,
+    kid: Option<String>,
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl BackendServicesAuth {
+    /// `private_key_pem` is the PEM encoded RSA or EC private key whose public key is registered
+    /// in the client application's JWKS.
+    pub fn new(
+        token_endpoint: &str,
+        client_id: &str,
+        scope: &str,
+        algorithm: Algorithm,
+        private_key_pem: &[u8],
+    ) -> Result<Self, OperationOutcomeError> {
+        let key = match algorithm {
+            Algorithm::RS256
+            | Algorithm::RS384
+            | Algorithm::RS512
+            | Algorithm::PS256
+            | Algorithm::PS384
+            | Algorithm::PS512 => EncodingKey::from_rsa_pem(private_key_pem),
+            Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(private_key_pem),
+            _ => {
+                return Err(
+                    BackendServicesError::UnsupportedAlgorithm(format!("{:?}", algorithm)).into(),
+                );
+            }
+        }
+        .map_err(|_| BackendServicesError::InvalidKey)?;
+
+        Ok(BackendServicesAuth {
+            client: reqwest::Client::new(),
+            token_endpoint: token_endpoint.to_string(),
+            client_id: client_id.to_string(),
+            scope: scope.to_string(),
+            algorithm,
+            key,
+            kid: None,
+            token: Mutex::new(None),
+        })
+    }
+
+    /// Key id of the signing key, used by the server to select the key from the JWKS.
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Signs a single use client assertion for the token endpoint.
+    pub fn client_assertion(&self) -> Result<String, OperationOutcomeError> {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.kid.clone();
+
+        let claims = serde_json::json!({
+            "iss": self.client_id,
+            "sub": self.client_id,
+            "aud": self.token_endpoint,
+            "exp": now() + ASSERTION_LIFETIME,
+            "jti": nanoid::nanoid!(),
+        });
+
+        jsonwebtoken::encode(&header, &claims, &self.key)
+            .map_err(|_| BackendServicesError::SignError.into())
+    }
+
+    /// Returns the cached access token or requests a new one with a fresh client assertion.
+    pub async fn access_token(&self) -> Result<String, OperationOutcomeError> {
+        if let Some(token) = self.token.lock().unwrap().as_ref()
+            && token.expires_at > now() + EXPIRY_MARGIN
+        {
+            return Ok(token.access_token.clone());
+        }
+
+        let client_assertion = self.client_assertion()?;
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("scope", self.scope.as_str()),
+            ("client_assertion_type", JWT_BEARER_CLIENT_ASSERTION_TYPE),
+            ("client_assertion", client_assertion.as_str()),
+        ];
+
+        let res = self
+            .client
+            .post(&self.token_endpoint)
+            .form(&params)
+            .send()
+            .await
+            .map_err(BackendServicesError::from)?;
+
+        if !res.status().is_success() {
+            return Err(BackendServicesError::TokenRequestFailed(res.status().as_u16()).into());
+        }
+
+        let token_response = res
+            .json::<serde_json::Value>()
+            .await
+            .map_err(BackendServicesError::from)?;
+
+        let access_token = token_response
+            .get("access_token")
+            .and_then(|v| v.as_str())
+            .ok_or(BackendServicesError::InvalidTokenResponse)?
+            .to_string();
+        let expires_in = token_response
+            .get("expires_in")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(ASSERTION_LIFETIME);
+
+        *self.token.lock().unwrap() = Some(CachedToken {
+            access_token: access_token.clone(),
+            expires_at: now() + expires_in,
+        });
+
+        Ok(access_token)
+    }
+
+    /// Token provider to pass to [`crate::http::FHIRHttpState::new`].
+    pub fn into_token_provider(self) -> AccessTokenProvider {
+        let auth = Arc::new(self);
+        Arc::new(move || {
+            let auth = auth.clone();
+            Box::pin(async move { auth.access_token().await })
+        })
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     FHIRClient,
     middleware::{Context, Middleware, MiddlewareChain, Next},
     request::{
-        self, DeleteRequest, DeleteResponse, FHIRCreateResponse, FHIRPatchResponse,
+        self, DeleteRequest, DeleteResponse, FHIRCreateResponse, FHIRPatch, FHIRPatchResponse,
         FHIRReadResponse, FHIRRequest, FHIRResponse, HistoryRequest, HistoryResponse,
         InvocationRequest, InvokeResponse, Operation, SearchRequest, SearchResponse, UpdateRequest,
     },
@@ -18,36 +18,25 @@ use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcome
 use haste_jwt::VersionId;
 use http::HeaderValue;
 use reqwest::Url;
-use std::{pin::Pin, sync::Arc};
+use std::{fmt::Display, pin::Pin, sync::Arc, time::Duration};
+
+/// Returns a bearer token to attach to every request.
+pub type AccessTokenProvider = Arc<
+    dyn Fn() -> Pin<Box<dyn Future<Output = Result<String, OperationOutcomeError>> + Send + Sync>>
+        + Sync
+        + Send,
+>;
 
 pub struct FHIRHttpState {
     client: reqwest::Client,
     api_url: Url,
-    get_access_token: Option<
-        Arc<
-            dyn Fn() -> Pin<
-                    Box<dyn Future<Output = Result<String, OperationOutcomeError>> + Send + Sync>,
-                > + Sync
-                + Send,
-        >,
-    >,
+    get_access_token: Option<AccessTokenProvider>,
 }
 
 impl FHIRHttpState {
     pub fn new(
         api_url: &str,
-        get_access_token: Option<
-            Arc<
-                dyn Fn() -> Pin<
-                        Box<
-                            dyn Future<Output = Result<String, OperationOutcomeError>>
-                                + Send
-                                + Sync,
-                        >,
-                    > + Sync
-                    + Send,
-            >,
-        >,
+        get_access_token: Option<AccessTokenProvider>,
     ) -> Result<Self, OperationOutcomeError> {
         let url =
             Url::parse(api_url).map_err(|_| FHIRHTTPError::UrlParseError(api_url.to_string()))?;
@@ -86,6 +75,32 @@ pub enum FHIRHTTPError {
     JSONSerializeError(#[from] serde_json::Error),
 }
 
+/// Status of a failed HTTP response, set as the source of the returned [`OperationOutcomeError`]
+/// so middleware can decide whether the request is worth retrying.
+#[derive(Debug)]
+pub struct HTTPStatusError {
+    pub status: u16,
+    /// Delay requested by the server with a `Retry-After` header (delta-seconds form).
+    pub retry_after: Option<Duration>,
+}
+
+impl Display for HTTPStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP returned error '{}'.", self.status)
+    }
+}
+
+impl std::error::Error for HTTPStatusError {}
+
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
 fn fhir_parameter_to_query_parameters(http_url: &mut reqwest::Url, parameters: &ParsedParameters) {
     let mut query_parameters = http_url.query_pairs_mut();
     for parameter in parameters.parameters() {
@@ -168,14 +183,23 @@ async fn fhir_request_to_http_request(
                 ))
                 .map_err(|_e| FHIRHTTPError::UrlParseError("Patch request".to_string()))?;
 
-            let patch_body =
-                serde_json::to_string(&patch_request.patch).map_err(FHIRHTTPError::from)?;
+            let (content_type, patch_body) = match &patch_request.patch {
+                FHIRPatch::JSONPatch(patch) => (
+                    "application/json-patch+json",
+                    serde_json::to_string(patch).map_err(FHIRHTTPError::from)?,
+                ),
+                FHIRPatch::FHIRPathPatch(parameters) => (
+                    "application/fhir+json",
+                    haste_fhir_serialization_json::to_string(parameters)
+                        .map_err(FHIRHTTPError::from)?,
+                ),
+            };
 
             let request = state
                 .client
                 .patch(patch_request_url)
                 .header("Accept", "application/fhir+json")
-                .header("Content-Type", "application/fhir+json, application/json")
+                .header("Content-Type", content_type)
                 .body(patch_body)
                 .build()
                 .map_err(FHIRHTTPError::from)?;
@@ -329,7 +353,7 @@ async fn fhir_request_to_http_request(
         },
         FHIRRequest::Delete(delete_request) => match delete_request {
             DeleteRequest::Instance(fhirdelete_instance_request) => {
-                let delete_request_url = state
+                let mut delete_request_url = state
                     .api_url
                     .join(&format!(
                         "{}/{}/{}",
@@ -341,6 +365,11 @@ async fn fhir_request_to_http_request(
                         FHIRHTTPError::UrlParseError("DeleteInstance request".to_string())
                     })?;
 
+                fhir_parameter_to_query_parameters(
+                    &mut delete_request_url,
+                    &fhirdelete_instance_request.parameters,
+                );
+
                 let request = state
                     .client
                     .delete(delete_request_url)
@@ -1010,9 +1039,28 @@ impl<CTX: Send + 'static>
                 .await
                 .map_err(FHIRHTTPError::ReqwestError)?;
 
+            let status = response.status();
+            let retry_after = retry_after(&response);
+
             let mut next_context = context;
-            let fhir_response =
-                http_response_to_fhir_response(&next_context.request, response).await?;
+            let fhir_response = http_response_to_fhir_response(&next_context.request, response)
+                .await
+                .map_err(|error| {
+                    if status.is_success() {
+                        error
+                    } else {
+                        OperationOutcomeError::new(
+                            Some(
+                                HTTPStatusError {
+                                    status: status.as_u16(),
+                                    retry_after,
+                                }
+                                .into(),
+                            ),
+                            error.outcome().clone(),
+                        )
+                    }
+                })?;
             next_context.response = Some(fhir_response);
 
             Ok(next_context)
@@ -1022,7 +1070,26 @@ impl<CTX: Send + 'static>
 
 impl<CTX: 'static + Send + Sync> FHIRHttpClient<CTX> {
     pub fn new(state: FHIRHttpState) -> Self {
-        let middleware = Middleware::new(vec![Box::new(HTTPMiddleware::new())]);
+        Self::with_middleware(state, vec![])
+    }
+
+    /// Runs `middleware` in order before the request is sent, e.g. [`crate::retry::RetryMiddleware`].
+    pub fn with_middleware(
+        state: FHIRHttpState,
+        mut middleware: Vec<
+            Box<
+                dyn MiddlewareChain<
+                        Arc<FHIRHttpState>,
+                        CTX,
+                        FHIRRequest,
+                        FHIRResponse,
+                        OperationOutcomeError,
+                    >,
+            >,
+        >,
+    ) -> Self {
+        middleware.push(Box::new(HTTPMiddleware::new()));
+        let middleware = Middleware::new(middleware);
         FHIRHttpClient {
             state: Arc::new(state),
             middleware,
@@ -1191,7 +1258,7 @@ impl<CTX: 'static + Send + Sync> FHIRClient<CTX, OperationOutcomeError> for FHIR
         ctx: CTX,
         resource_type: ResourceType,
         id: String,
-        patch: json_patch::Patch,
+        patch: FHIRPatch,
     ) -> Result<Resource, OperationOutcomeError> {
         let res = self
             .middleware
@@ -1273,7 +1340,11 @@ impl<CTX: 'static + Send + Sync> FHIRClient<CTX, OperationOutcomeError> for FHIR
                 self.state.clone(),
                 ctx,
                 FHIRRequest::Delete(DeleteRequest::Instance(
-                    request::FHIRDeleteInstanceRequest { resource_type, id },
+                    request::FHIRDeleteInstanceRequest {
+                        resource_type,
+                        id,
+                        parameters: ParsedParameters::new(vec![]),
+                    },
                 )),
             )
             .await?;
@@ -2,19 +2,24 @@ use haste_fhir_model::r4::generated::resources::{
     Bundle, CapabilityStatement, Parameters, Resource, ResourceType,
 };
 use haste_fhir_operation_error::OperationOutcomeError;
-use json_patch::Patch;
 
 use crate::{
-    request::{FHIRRequest, FHIRResponse},
+    request::{FHIRPatch, FHIRRequest, FHIRResponse},
     url::ParsedParameters,
 };
 
 #[cfg(feature = "axum")]
 pub mod axum;
 #[cfg(feature = "http")]
+pub mod backend_services;
+#[cfg(feature = "http")]
 pub mod http;
 pub mod middleware;
+pub mod pagination;
 pub mod request;
+#[cfg(feature = "http")]
+pub mod retry;
+pub mod search;
 pub mod url;
 
 pub trait FHIRClient<CTX, Error>: Send + Sync {
@@ -69,7 +74,7 @@ pub trait FHIRClient<CTX, Error>: Send + Sync {
         ctx: CTX,
         resource_type: ResourceType,
         id: String,
-        patches: Patch,
+        patch: FHIRPatch,
     ) -> impl Future<Output = Result<Resource, Error>> + Send;
 
     fn read(
@@ -0,0 +1,172 @@
+use std::collections::VecDeque;
+
+use futures::{Stream, stream};
+use haste_fhir_model::r4::generated::resources::{Bundle, Resource, ResourceType};
+
+use crate::{
+    FHIRClient,
+    url::{ParseError, ParsedParameters},
+};
+
+/// The interaction a paginated stream walks through.
+#[derive(Debug, Clone)]
+pub enum PaginatedRequest {
+    SearchSystem,
+    SearchType(ResourceType),
+    HistorySystem,
+    HistoryType(ResourceType),
+    HistoryInstance(ResourceType, String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PaginationOptions {
+    /// Stop the stream after this many resources have been yielded.
+    pub max_items: Option<usize>,
+}
+
+impl PaginationOptions {
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+struct PageState<CTX> {
+    ctx: CTX,
+    request: PaginatedRequest,
+    next: Option<ParsedParameters>,
+    buffer: VecDeque<Resource>,
+    remaining: Option<usize>,
+}
+
+async fn fetch_page<CTX, Error, Client: FHIRClient<CTX, Error>>(
+    client: &Client,
+    ctx: CTX,
+    request: &PaginatedRequest,
+    parameters: ParsedParameters,
+) -> Result<Bundle, Error> {
+    match request {
+        PaginatedRequest::SearchSystem => client.search_system(ctx, parameters).await,
+        PaginatedRequest::SearchType(resource_type) => {
+            client
+                .search_type(ctx, resource_type.clone(), parameters)
+                .await
+        }
+        PaginatedRequest::HistorySystem => client.history_system(ctx, parameters).await,
+        PaginatedRequest::HistoryType(resource_type) => {
+            client
+                .history_type(ctx, resource_type.clone(), parameters)
+                .await
+        }
+        PaginatedRequest::HistoryInstance(resource_type, id) => {
+            client
+                .history_instance(ctx, resource_type.clone(), id.clone(), parameters)
+                .await
+        }
+    }
+}
+
+/// Parses the query of the bundle's `next` link, if there is one.
+fn next_parameters(bundle: &Bundle) -> Result<Option<ParsedParameters>, ParseError> {
+    let next_url = bundle
+        .link
+        .iter()
+        .flatten()
+        .find(|link| link.relation.value.as_deref() == Some("next"))
+        .and_then(|link| link.url.value.as_deref());
+
+    match next_url {
+        Some(url) => {
+            let query = url.split_once('?').map(|(_, query)| query).unwrap_or("");
+            Ok(Some(ParsedParameters::try_from(query)?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Streams every resource of a search or history interaction, following the bundle `next` links.
+/// A page is only requested once the resources of the previous page have been consumed.
+/// The stream ends after the first error.
+pub fn paginate<'a, CTX, Error, Client>(
+    client: &'a Client,
+    ctx: CTX,
+    request: PaginatedRequest,
+    parameters: ParsedParameters,
+    options: PaginationOptions,
+) -> impl Stream<Item = Result<Resource, Error>> + Send + 'a
+where
+    CTX: Clone + Send + Sync + 'a,
+    Error: From<ParseError> + Send + 'a,
+    Client: FHIRClient<CTX, Error>,
+{
+    let state = PageState {
+        ctx,
+        request,
+        next: Some(parameters),
+        buffer: VecDeque::new(),
+        remaining: options.max_items,
+    };
+
+    stream::unfold(Some(state), move |state| async move {
+        let mut state = state?;
+        loop {
+            if state.remaining == Some(0) {
+                return None;
+            }
+
+            if let Some(resource) = state.buffer.pop_front() {
+                state.remaining = state.remaining.map(|remaining| remaining - 1);
+                return Some((Ok(resource), Some(state)));
+            }
+
+            let parameters = state.next.take()?;
+            let bundle =
+                match fetch_page(client, state.ctx.clone(), &state.request, parameters).await {
+                    Ok(bundle) => bundle,
+                    Err(error) => return Some((Err(error), None)),
+                };
+
+            state.next = match next_parameters(&bundle) {
+                Ok(next) => next,
+                Err(error) => return Some((Err(error.into()), None)),
+            };
+            state.buffer.extend(
+                bundle
+                    .entry
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| entry.resource.map(|resource| *resource)),
+            );
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_parameters() {
+        let bundle = haste_fhir_serialization_json::from_str::<Bundle>(
+            r#"{
+                "resourceType": "Bundle",
+                "type": "searchset",
+                "link": [
+                    { "relation": "self", "url": "https://example.com/Patient?_count=2" },
+                    { "relation": "next", "url": "https://example.com/Patient?_count=2&_offset=2" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let next = next_parameters(&bundle).unwrap().unwrap();
+        assert!(next.get("_offset").is_some());
+        assert!(next.get("_count").is_some());
+
+        let last = haste_fhir_serialization_json::from_str::<Bundle>(
+            r#"{ "resourceType": "Bundle", "type": "searchset" }"#,
+        )
+        .unwrap();
+        assert!(next_parameters(&last).unwrap().is_none());
+    }
+}
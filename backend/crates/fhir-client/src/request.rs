@@ -7,88 +7,111 @@ use thiserror::Error;
 
 use crate::url::ParsedParameters;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRCreateRequest {
     pub resource_type: ResourceType,
     pub resource: Resource,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRReadRequest {
     pub resource_type: ResourceType,
     pub id: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRVersionReadRequest {
     pub resource_type: ResourceType,
     pub id: String,
     pub version_id: VersionId,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRUpdateInstanceRequest {
     pub resource_type: ResourceType,
     pub id: String,
     pub resource: Resource,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRConditionalUpdateRequest {
     pub resource_type: ResourceType,
     pub parameters: ParsedParameters,
     pub resource: Resource,
 }
 
-#[derive(Debug)]
+/// Patch formats accepted by the patch interaction.
+#[derive(Debug, Clone)]
+pub enum FHIRPatch {
+    /// JSON Patch (RFC 6902) sent as `application/json-patch+json`.
+    JSONPatch(Patch),
+    /// FHIRPath Patch, a Parameters resource sent as `application/fhir+json`.
+    FHIRPathPatch(Parameters),
+}
+
+impl From<Patch> for FHIRPatch {
+    fn from(patch: Patch) -> Self {
+        FHIRPatch::JSONPatch(patch)
+    }
+}
+
+impl From<Parameters> for FHIRPatch {
+    fn from(parameters: Parameters) -> Self {
+        FHIRPatch::FHIRPathPatch(parameters)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct FHIRPatchRequest {
     pub resource_type: ResourceType,
     pub id: String,
-    pub patch: Patch,
+    pub patch: FHIRPatch,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRHistoryInstanceRequest {
     pub resource_type: ResourceType,
     pub id: String,
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRHistoryTypeRequest {
     pub resource_type: ResourceType,
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRHistorySystemRequest {
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRDeleteInstanceRequest {
     pub resource_type: ResourceType,
     pub id: String,
+    /// Control parameters IE _cascade.
+    pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRDeleteTypeRequest {
     pub resource_type: ResourceType,
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRDeleteSystemRequest {
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRSearchTypeRequest {
     pub resource_type: ResourceType,
     pub parameters: ParsedParameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRSearchSystemRequest {
     pub parameters: ParsedParameters,
 }
@@ -99,7 +122,7 @@ pub enum OperationParseError {
     Invalid,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Operation(String);
 impl Operation {
     pub fn new(name: &str) -> Result<Self, OperationParseError> {
@@ -111,7 +134,7 @@ impl Operation {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRInvokeInstanceRequest {
     pub operation: Operation,
     pub resource_type: ResourceType,
@@ -119,63 +142,63 @@ pub struct FHIRInvokeInstanceRequest {
     pub parameters: Parameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRInvokeTypeRequest {
     pub operation: Operation,
     pub resource_type: ResourceType,
     pub parameters: Parameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRInvokeSystemRequest {
     pub operation: Operation,
     pub parameters: Parameters,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRBatchRequest {
     pub resource: Bundle,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FHIRTransactionRequest {
     pub resource: Bundle,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum InvocationRequest {
     Instance(FHIRInvokeInstanceRequest),
     Type(FHIRInvokeTypeRequest),
     System(FHIRInvokeSystemRequest),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum HistoryRequest {
     Instance(FHIRHistoryInstanceRequest),
     Type(FHIRHistoryTypeRequest),
     System(FHIRHistorySystemRequest),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum SearchRequest {
     Type(FHIRSearchTypeRequest),
     System(FHIRSearchSystemRequest),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DeleteRequest {
     Instance(FHIRDeleteInstanceRequest),
     Type(FHIRDeleteTypeRequest),
     System(FHIRDeleteSystemRequest),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum UpdateRequest {
     Instance(FHIRUpdateInstanceRequest),
     Conditional(FHIRConditionalUpdateRequest),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum FHIRRequest {
     Create(FHIRCreateRequest),
 
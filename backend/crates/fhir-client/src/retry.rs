@@ -0,0 +1,495 @@
+//! Retries transient failures with exponential backoff and jitter.
+//! Requests that are not idempotent (create, patch, operations, batch and transaction) are only
+//! retried when the server did not process them: `429`, `503` and connection failures.
+use crate::{
+    http::HTTPStatusError,
+    middleware::{Context, MiddlewareChain, MiddlewareOutput, Next},
+    request::{FHIRRequest, FHIRResponse},
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use rand::Rng;
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, OperationOutcomeError)]
+pub enum RetryError {
+    #[error(
+        code = "timeout",
+        diagnostic = "Request attempt timed out after {arg0} ms."
+    )]
+    AttemptTimeout(u128),
+    #[error(code = "timeout", diagnostic = "Request deadline exceeded.")]
+    DeadlineExceeded,
+    #[error(
+        code = "transient",
+        diagnostic = "Circuit breaker is open, the request was not sent."
+    )]
+    CircuitOpen,
+}
+
+/// Stop sending requests after `failure_threshold` consecutive transient failures.
+/// After `reset_after` a single trial request is let through, closing the circuit if it succeeds.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerPolicy {
+    pub failure_threshold: u32,
+    pub reset_after: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Timeout for a single attempt.
+    pub attempt_timeout: Option<Duration>,
+    /// Time budget for the request including all retries and backoff.
+    pub deadline: Option<Duration>,
+    pub circuit_breaker: Option<CircuitBreakerPolicy>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            attempt_timeout: Some(Duration::from_secs(30)),
+            deadline: None,
+            circuit_breaker: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn with_attempt_timeout(mut self, attempt_timeout: Duration) -> Self {
+        self.attempt_timeout = Some(attempt_timeout);
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, reset_after: Duration) -> Self {
+        self.circuit_breaker = Some(CircuitBreakerPolicy {
+            failure_threshold,
+            reset_after,
+        });
+        self
+    }
+
+    /// Exponential backoff with equal jitter, between half and the full backoff for the retry.
+    fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+        let millis = backoff.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(millis / 2..=millis))
+    }
+}
+
+#[derive(Default)]
+struct CircuitState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+enum Failure {
+    /// The request may succeed if sent again.
+    Transient {
+        retry_after: Option<Duration>,
+        /// The server may have processed the request, so only idempotent requests are retried.
+        idempotent_only: bool,
+    },
+    Permanent,
+}
+
+fn is_idempotent(request: &FHIRRequest) -> bool {
+    match request {
+        FHIRRequest::Read(_)
+        | FHIRRequest::VersionRead(_)
+        | FHIRRequest::Update(_)
+        | FHIRRequest::Delete(_)
+        | FHIRRequest::Capabilities
+        | FHIRRequest::Search(_)
+        | FHIRRequest::History(_) => true,
+        FHIRRequest::Create(_)
+        | FHIRRequest::Patch(_)
+        | FHIRRequest::Invocation(_)
+        | FHIRRequest::Batch(_)
+        | FHIRRequest::Transaction(_) => false,
+    }
+}
+
+fn classify(error: &OperationOutcomeError) -> Failure {
+    let Some(source) = error.source() else {
+        return Failure::Permanent;
+    };
+
+    if let Some(status_error) = source.downcast_ref::<HTTPStatusError>() {
+        return match status_error.status {
+            429 | 503 => Failure::Transient {
+                retry_after: status_error.retry_after,
+                idempotent_only: false,
+            },
+            408 | 502 | 504 => Failure::Transient {
+                retry_after: status_error.retry_after,
+                idempotent_only: true,
+            },
+            _ => Failure::Permanent,
+        };
+    }
+
+    if let Some(reqwest_error) = source.downcast_ref::<reqwest::Error>() {
+        if reqwest_error.is_connect() {
+            return Failure::Transient {
+                retry_after: None,
+                idempotent_only: false,
+            };
+        }
+        if reqwest_error.is_timeout() || reqwest_error.is_request() || reqwest_error.is_body() {
+            return Failure::Transient {
+                retry_after: None,
+                idempotent_only: true,
+            };
+        }
+    }
+
+    Failure::Permanent
+}
+
+pub struct RetryMiddleware {
+    policy: RetryPolicy,
+    circuit: Arc<Mutex<CircuitState>>,
+}
+
+impl RetryMiddleware {
+    pub fn new(policy: RetryPolicy) -> Self {
+        RetryMiddleware {
+            policy,
+            circuit: Arc::new(Mutex::new(CircuitState::default())),
+        }
+    }
+}
+
+fn circuit_allows(policy: &RetryPolicy, circuit: &Mutex<CircuitState>) -> bool {
+    let Some(breaker) = policy.circuit_breaker.as_ref() else {
+        return true;
+    };
+    let mut circuit = circuit.lock().unwrap();
+    match circuit.open_until {
+        Some(open_until) if Instant::now() < open_until => false,
+        // Half open, block other requests while the trial request is in flight.
+        Some(_) => {
+            circuit.open_until = Some(Instant::now() + breaker.reset_after);
+            true
+        }
+        None => true,
+    }
+}
+
+fn record_attempt(policy: &RetryPolicy, circuit: &Mutex<CircuitState>, transient_failure: bool) {
+    let Some(breaker) = policy.circuit_breaker.as_ref() else {
+        return;
+    };
+    let mut circuit = circuit.lock().unwrap();
+    if transient_failure {
+        circuit.consecutive_failures += 1;
+        if circuit.consecutive_failures >= breaker.failure_threshold {
+            circuit.open_until = Some(Instant::now() + breaker.reset_after);
+        }
+    } else {
+        circuit.consecutive_failures = 0;
+        circuit.open_until = None;
+    }
+}
+
+impl<State: Clone + Send + Sync + 'static, CTX: Clone + Send + Sync + 'static>
+    MiddlewareChain<State, CTX, FHIRRequest, FHIRResponse, OperationOutcomeError>
+    for RetryMiddleware
+{
+    fn call(
+        &self,
+        state: State,
+        context: Context<CTX, FHIRRequest, FHIRResponse>,
+        next: Option<
+            Arc<Next<State, Context<CTX, FHIRRequest, FHIRResponse>, OperationOutcomeError>>,
+        >,
+    ) -> MiddlewareOutput<Context<CTX, FHIRRequest, FHIRResponse>, OperationOutcomeError> {
+        let policy = self.policy.clone();
+        let circuit = self.circuit.clone();
+
+        Box::pin(async move {
+            let Some(next) = next else {
+                return Ok(context);
+            };
+
+            let idempotent = is_idempotent(&context.request);
+            let deadline = policy.deadline.map(|deadline| Instant::now() + deadline);
+            let mut retries = 0;
+
+            loop {
+                if !circuit_allows(&policy, &circuit) {
+                    return Err(RetryError::CircuitOpen.into());
+                }
+
+                let remaining =
+                    deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+                if remaining == Some(Duration::ZERO) {
+                    return Err(RetryError::DeadlineExceeded.into());
+                }
+                let attempt_timeout = match (policy.attempt_timeout, remaining) {
+                    (Some(attempt_timeout), Some(remaining)) => {
+                        Some(attempt_timeout.min(remaining))
+                    }
+                    (attempt_timeout, remaining) => attempt_timeout.or(remaining),
+                };
+
+                let attempt = next(
+                    state.clone(),
+                    Context {
+                        ctx: context.ctx.clone(),
+                        request: context.request.clone(),
+                        response: None,
+                    },
+                );
+
+                let (error, failure) = match attempt_timeout {
+                    Some(attempt_timeout) => {
+                        match tokio::time::timeout(attempt_timeout, attempt).await {
+                            Ok(Ok(result)) => {
+                                record_attempt(&policy, &circuit, false);
+                                return Ok(result);
+                            }
+                            Ok(Err(error)) => {
+                                let failure = classify(&error);
+                                (error, failure)
+                            }
+                            Err(_) => (
+                                RetryError::AttemptTimeout(attempt_timeout.as_millis()).into(),
+                                Failure::Transient {
+                                    retry_after: None,
+                                    idempotent_only: true,
+                                },
+                            ),
+                        }
+                    }
+                    None => match attempt.await {
+                        Ok(result) => {
+                            record_attempt(&policy, &circuit, false);
+                            return Ok(result);
+                        }
+                        Err(error) => {
+                            let failure = classify(&error);
+                            (error, failure)
+                        }
+                    },
+                };
+
+                let retry_after = match failure {
+                    // The server handled the request so it is reachable.
+                    Failure::Permanent => {
+                        record_attempt(&policy, &circuit, false);
+                        return Err(error);
+                    }
+                    Failure::Transient {
+                        retry_after,
+                        idempotent_only,
+                    } => {
+                        record_attempt(&policy, &circuit, true);
+                        if idempotent_only && !idempotent {
+                            return Err(error);
+                        }
+                        retry_after
+                    }
+                };
+
+                if retries >= policy.max_retries {
+                    return Err(error);
+                }
+
+                let delay = retry_after.unwrap_or_else(|| policy.backoff(retries));
+                if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) {
+                    return Err(error);
+                }
+
+                tokio::time::sleep(delay).await;
+                retries += 1;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        middleware::Middleware,
+        request::{FHIRCapabilitiesResponse, FHIRCreateRequest},
+    };
+    use haste_fhir_model::r4::generated::{
+        resources::{CapabilityStatement, Patient, Resource, ResourceType},
+        terminology::IssueType,
+    };
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Fails with the given status until `failures` attempts have been made.
+    struct Flaky {
+        status: u16,
+        failures: u32,
+        attempts: Arc<AtomicU32>,
+    }
+
+    impl MiddlewareChain<(), (), FHIRRequest, FHIRResponse, OperationOutcomeError> for Flaky {
+        fn call(
+            &self,
+            _state: (),
+            mut context: Context<(), FHIRRequest, FHIRResponse>,
+            _next: Option<
+                Arc<Next<(), Context<(), FHIRRequest, FHIRResponse>, OperationOutcomeError>>,
+            >,
+        ) -> MiddlewareOutput<Context<(), FHIRRequest, FHIRResponse>, OperationOutcomeError>
+        {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            let status = self.status;
+            let failures = self.failures;
+            Box::pin(async move {
+                if attempt < failures {
+                    let error = OperationOutcomeError::error(
+                        IssueType::Exception(None),
+                        "Unavailable".to_string(),
+                    );
+                    return Err(OperationOutcomeError::new(
+                        Some(
+                            HTTPStatusError {
+                                status,
+                                retry_after: None,
+                            }
+                            .into(),
+                        ),
+                        error.outcome().clone(),
+                    ));
+                }
+                context.response = Some(FHIRResponse::Capabilities(FHIRCapabilitiesResponse {
+                    capabilities: CapabilityStatement::default(),
+                }));
+                Ok(context)
+            })
+        }
+    }
+
+    fn client(
+        policy: RetryPolicy,
+        status: u16,
+        failures: u32,
+    ) -> (
+        Middleware<(), (), FHIRRequest, FHIRResponse, OperationOutcomeError>,
+        Arc<AtomicU32>,
+    ) {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let middleware = Middleware::new(vec![
+            Box::new(RetryMiddleware::new(policy)),
+            Box::new(Flaky {
+                status,
+                failures,
+                attempts: attempts.clone(),
+            }),
+        ]);
+        (middleware, attempts)
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(2))
+    }
+
+    fn create_request() -> FHIRRequest {
+        FHIRRequest::Create(FHIRCreateRequest {
+            resource_type: ResourceType::Patient,
+            resource: Resource::Patient(Patient::default()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failures() {
+        let (middleware, attempts) = client(policy(), 503, 2);
+        let result = middleware.call((), (), FHIRRequest::Capabilities).await;
+        assert!(result.unwrap().response.is_some());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let (middleware, attempts) = client(policy().with_max_retries(1), 503, 5);
+        assert!(
+            middleware
+                .call((), (), FHIRRequest::Capabilities)
+                .await
+                .is_err()
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        let (middleware, attempts) = client(policy(), 404, 5);
+        assert!(
+            middleware
+                .call((), (), FHIRRequest::Capabilities)
+                .await
+                .is_err()
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_requests() {
+        let (middleware, attempts) = client(policy(), 502, 1);
+        assert!(middleware.call((), (), create_request()).await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Rate limited requests were not processed so they are safe to send again.
+        let (middleware, attempts) = client(policy(), 429, 1);
+        assert!(middleware.call((), (), create_request()).await.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let (middleware, attempts) = client(
+            policy()
+                .with_max_retries(0)
+                .with_circuit_breaker(2, Duration::from_secs(60)),
+            503,
+            10,
+        );
+        assert!(
+            middleware
+                .call((), (), FHIRRequest::Capabilities)
+                .await
+                .is_err()
+        );
+        assert!(
+            middleware
+                .call((), (), FHIRRequest::Capabilities)
+                .await
+                .is_err()
+        );
+        assert!(
+            middleware
+                .call((), (), FHIRRequest::Capabilities)
+                .await
+                .is_err()
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+}
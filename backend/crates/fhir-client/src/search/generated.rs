@@ -0,0 +1,7865 @@
+use super::{
+    CompositeParameter, DateParameter, NumberParameter, QuantityParameter, ReferenceParameter,
+    Search, SearchQuery, SpecialParameter, StringParameter, TokenParameter, UriParameter,
+};
+use haste_fhir_model::r4::generated::resources::*;
+impl<R: Search> SearchQuery<R> {
+    #[doc = "Search on the entire content of the resource"]
+    pub fn _content(self) -> StringParameter<R> {
+        StringParameter::new(self, "_content")
+    }
+    #[doc = "The author of the resource"]
+    pub fn _haste_health_author(self) -> ReferenceParameter<R> {
+        ReferenceParameter::new(self, "_haste-health-author")
+    }
+    #[doc = "Logical id of this artifact"]
+    pub fn _id(self) -> TokenParameter<R> {
+        TokenParameter::new(self, "_id")
+    }
+    #[doc = "When the resource version last changed"]
+    pub fn _last_updated(self) -> DateParameter<R> {
+        DateParameter::new(self, "_lastUpdated")
+    }
+    #[doc = "Profiles this resource claims to conform to"]
+    pub fn _profile(self) -> UriParameter<R> {
+        UriParameter::new(self, "_profile")
+    }
+    #[doc = "A custom search profile that describes a specific defined query operation"]
+    pub fn _query(self) -> TokenParameter<R> {
+        TokenParameter::new(self, "_query")
+    }
+    #[doc = "Security Labels applied to this resource"]
+    pub fn _security(self) -> TokenParameter<R> {
+        TokenParameter::new(self, "_security")
+    }
+    #[doc = "Identifies where the resource comes from"]
+    pub fn _source(self) -> UriParameter<R> {
+        UriParameter::new(self, "_source")
+    }
+    #[doc = "Tags applied to this resource"]
+    pub fn _tag(self) -> TokenParameter<R> {
+        TokenParameter::new(self, "_tag")
+    }
+    #[doc = "Derived from the R4B Definition"]
+    pub fn _type(self) -> TokenParameter<R> {
+        TokenParameter::new(self, "_type")
+    }
+    #[doc = "Search on the narrative of the resource"]
+    pub fn _text(self) -> StringParameter<R> {
+        StringParameter::new(self, "_text")
+    }
+}
+impl Search for AccessPolicyV2 {
+    fn resource_type() -> ResourceType {
+        ResourceType::AccessPolicyV2
+    }
+}
+impl SearchQuery<AccessPolicyV2> {
+    #[doc = "An access policies engine."]
+    pub fn engine(self) -> TokenParameter<AccessPolicyV2> {
+        TokenParameter::new(self, "engine")
+    }
+    #[doc = "What/Who is associated with a given access policy."]
+    pub fn link(self) -> ReferenceParameter<AccessPolicyV2> {
+        ReferenceParameter::new(self, "link")
+    }
+    #[doc = "An access policies name."]
+    pub fn name(self) -> StringParameter<AccessPolicyV2> {
+        StringParameter::new(self, "name")
+    }
+}
+impl Search for Account {
+    fn resource_type() -> ResourceType {
+        ResourceType::Account
+    }
+}
+impl SearchQuery<Account> {
+    #[doc = "Account number"]
+    pub fn identifier(self) -> TokenParameter<Account> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Human-readable label"]
+    pub fn name(self) -> StringParameter<Account> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Entity managing the Account"]
+    pub fn owner(self) -> ReferenceParameter<Account> {
+        ReferenceParameter::new(self, "owner")
+    }
+    #[doc = "The entity that caused the expenses"]
+    pub fn patient(self) -> ReferenceParameter<Account> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Transaction window"]
+    pub fn period(self) -> DateParameter<Account> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "active | inactive | entered-in-error | on-hold | unknown"]
+    pub fn status(self) -> TokenParameter<Account> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The entity that caused the expenses"]
+    pub fn subject(self) -> ReferenceParameter<Account> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "E.g. patient, expense, depreciation"]
+    pub fn type_(self) -> TokenParameter<Account> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for ActivityDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::ActivityDefinition
+    }
+}
+impl SearchQuery<ActivityDefinition> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<ActivityDefinition> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the activity definition"]
+    pub fn context(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the activity definition"]
+    pub fn context_quantity(self) -> QuantityParameter<ActivityDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the activity definition"]
+    pub fn context_type(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the activity definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ActivityDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the activity definition"]
+    pub fn context_type_value(self) -> CompositeParameter<ActivityDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The activity definition publication date"]
+    pub fn date(self) -> DateParameter<ActivityDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<ActivityDefinition> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<ActivityDefinition> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the activity definition"]
+    pub fn description(self) -> StringParameter<ActivityDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the activity definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<ActivityDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the activity definition"]
+    pub fn identifier(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the activity definition"]
+    pub fn jurisdiction(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the activity definition"]
+    pub fn name(self) -> StringParameter<ActivityDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<ActivityDefinition> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the activity definition"]
+    pub fn publisher(self) -> StringParameter<ActivityDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the activity definition"]
+    pub fn status(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<ActivityDefinition> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the activity definition"]
+    pub fn title(self) -> StringParameter<ActivityDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the module"]
+    pub fn topic(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the activity definition"]
+    pub fn url(self) -> UriParameter<ActivityDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the activity definition"]
+    pub fn version(self) -> TokenParameter<ActivityDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for AdverseEvent {
+    fn resource_type() -> ResourceType {
+        ResourceType::AdverseEvent
+    }
+}
+impl SearchQuery<AdverseEvent> {
+    #[doc = "actual | potential"]
+    pub fn actuality(self) -> TokenParameter<AdverseEvent> {
+        TokenParameter::new(self, "actuality")
+    }
+    #[doc = "product-problem | product-quality | product-use-error | wrong-dose | incorrect-prescribing-information | wrong-technique | wrong-route-of-administration | wrong-rate | wrong-duration | wrong-time | expired-drug | medical-device-use-error | problem-different-manufacturer | unsafe-physical-environment"]
+    pub fn category(self) -> TokenParameter<AdverseEvent> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "When the event occurred"]
+    pub fn date(self) -> DateParameter<AdverseEvent> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Type of the event itself in relation to the subject"]
+    pub fn event(self) -> TokenParameter<AdverseEvent> {
+        TokenParameter::new(self, "event")
+    }
+    #[doc = "Location where adverse event occurred"]
+    pub fn location(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Who recorded the adverse event"]
+    pub fn recorder(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "recorder")
+    }
+    #[doc = "Effect on the subject due to this event"]
+    pub fn resultingcondition(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "resultingcondition")
+    }
+    #[doc = "Seriousness of the event"]
+    pub fn seriousness(self) -> TokenParameter<AdverseEvent> {
+        TokenParameter::new(self, "seriousness")
+    }
+    #[doc = "mild | moderate | severe"]
+    pub fn severity(self) -> TokenParameter<AdverseEvent> {
+        TokenParameter::new(self, "severity")
+    }
+    #[doc = "AdverseEvent.study"]
+    pub fn study(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "study")
+    }
+    #[doc = "Subject impacted by event"]
+    pub fn subject(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Refers to the specific entity that caused the adverse event"]
+    pub fn substance(self) -> ReferenceParameter<AdverseEvent> {
+        ReferenceParameter::new(self, "substance")
+    }
+}
+impl Search for AllergyIntolerance {
+    fn resource_type() -> ResourceType {
+        ResourceType::AllergyIntolerance
+    }
+}
+impl SearchQuery<AllergyIntolerance> {
+    #[doc = "Source of the information about the allergy"]
+    pub fn asserter(self) -> ReferenceParameter<AllergyIntolerance> {
+        ReferenceParameter::new(self, "asserter")
+    }
+    #[doc = "food | medication | environment | biologic"]
+    pub fn category(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "active | inactive | resolved"]
+    pub fn clinical_status(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "clinical-status")
+    }
+    #[doc = "Code that identifies the allergy or intolerance"]
+    pub fn code(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "low | high | unable-to-assess"]
+    pub fn criticality(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "criticality")
+    }
+    #[doc = "Date first version of the resource instance was recorded"]
+    pub fn date(self) -> DateParameter<AllergyIntolerance> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "External ids for this item"]
+    pub fn identifier(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Date(/time) of last known occurrence of a reaction"]
+    pub fn last_date(self) -> DateParameter<AllergyIntolerance> {
+        DateParameter::new(self, "last-date")
+    }
+    #[doc = "Clinical symptoms/signs associated with the Event"]
+    pub fn manifestation(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "manifestation")
+    }
+    #[doc = "Date(/time) when manifestations showed"]
+    pub fn onset(self) -> DateParameter<AllergyIntolerance> {
+        DateParameter::new(self, "onset")
+    }
+    #[doc = "Who the sensitivity is for"]
+    pub fn patient(self) -> ReferenceParameter<AllergyIntolerance> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who recorded the sensitivity"]
+    pub fn recorder(self) -> ReferenceParameter<AllergyIntolerance> {
+        ReferenceParameter::new(self, "recorder")
+    }
+    #[doc = "How the subject was exposed to the substance"]
+    pub fn route(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "route")
+    }
+    #[doc = "mild | moderate | severe (of event as a whole)"]
+    pub fn severity(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "severity")
+    }
+    #[doc = "allergy | intolerance - Underlying mechanism (if known)"]
+    pub fn type_(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "unconfirmed | confirmed | refuted | entered-in-error"]
+    pub fn verification_status(self) -> TokenParameter<AllergyIntolerance> {
+        TokenParameter::new(self, "verification-status")
+    }
+}
+impl Search for Appointment {
+    fn resource_type() -> ResourceType {
+        ResourceType::Appointment
+    }
+}
+impl SearchQuery<Appointment> {
+    #[doc = "Any one of the individuals participating in the appointment"]
+    pub fn actor(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "actor")
+    }
+    #[doc = "The style of appointment or patient that has been booked in the slot (not service type)"]
+    pub fn appointment_type(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "appointment-type")
+    }
+    #[doc = "The service request this appointment is allocated to assess"]
+    pub fn based_on(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Appointment date/time."]
+    pub fn date(self) -> DateParameter<Appointment> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "An Identifier of the Appointment"]
+    pub fn identifier(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "This location is listed in the participants of the appointment"]
+    pub fn location(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "The Participation status of the subject, or other participant on the appointment. Can be used to locate participants that have not responded to meeting requests."]
+    pub fn part_status(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "part-status")
+    }
+    #[doc = "One of the individuals of the appointment is this patient"]
+    pub fn patient(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "One of the individuals of the appointment is this practitioner"]
+    pub fn practitioner(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "practitioner")
+    }
+    #[doc = "Coded reason this appointment is scheduled"]
+    pub fn reason_code(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "reason-code")
+    }
+    #[doc = "Reason the appointment is to take place (resource)"]
+    pub fn reason_reference(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "reason-reference")
+    }
+    #[doc = "A broad categorization of the service that is to be performed during this appointment"]
+    pub fn service_category(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "service-category")
+    }
+    #[doc = "The specific service that is to be performed during this appointment"]
+    pub fn service_type(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "service-type")
+    }
+    #[doc = "The slots that this appointment is filling"]
+    pub fn slot(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "slot")
+    }
+    #[doc = "The specialty of a practitioner that would be required to perform the service requested in this appointment"]
+    pub fn specialty(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "specialty")
+    }
+    #[doc = "The overall status of the appointment"]
+    pub fn status(self) -> TokenParameter<Appointment> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Additional information to support the appointment"]
+    pub fn supporting_info(self) -> ReferenceParameter<Appointment> {
+        ReferenceParameter::new(self, "supporting-info")
+    }
+}
+impl Search for AppointmentResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::AppointmentResponse
+    }
+}
+impl SearchQuery<AppointmentResponse> {
+    #[doc = "The Person, Location/HealthcareService or Device that this appointment response replies for"]
+    pub fn actor(self) -> ReferenceParameter<AppointmentResponse> {
+        ReferenceParameter::new(self, "actor")
+    }
+    #[doc = "The appointment that the response is attached to"]
+    pub fn appointment(self) -> ReferenceParameter<AppointmentResponse> {
+        ReferenceParameter::new(self, "appointment")
+    }
+    #[doc = "An Identifier in this appointment response"]
+    pub fn identifier(self) -> TokenParameter<AppointmentResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "This Response is for this Location"]
+    pub fn location(self) -> ReferenceParameter<AppointmentResponse> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "The participants acceptance status for this appointment"]
+    pub fn part_status(self) -> TokenParameter<AppointmentResponse> {
+        TokenParameter::new(self, "part-status")
+    }
+    #[doc = "This Response is for this Patient"]
+    pub fn patient(self) -> ReferenceParameter<AppointmentResponse> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "This Response is for this Practitioner"]
+    pub fn practitioner(self) -> ReferenceParameter<AppointmentResponse> {
+        ReferenceParameter::new(self, "practitioner")
+    }
+}
+impl Search for AuditEvent {
+    fn resource_type() -> ResourceType {
+        ResourceType::AuditEvent
+    }
+}
+impl SearchQuery<AuditEvent> {
+    #[doc = "Type of action performed during the event"]
+    pub fn action(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "action")
+    }
+    #[doc = "Identifier for the network access point of the user device"]
+    pub fn address(self) -> StringParameter<AuditEvent> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "Identifier of who"]
+    pub fn agent(self) -> ReferenceParameter<AuditEvent> {
+        ReferenceParameter::new(self, "agent")
+    }
+    #[doc = "Human friendly name for the agent"]
+    pub fn agent_name(self) -> StringParameter<AuditEvent> {
+        StringParameter::new(self, "agent-name")
+    }
+    #[doc = "Agent role in the event"]
+    pub fn agent_role(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "agent-role")
+    }
+    #[doc = "Alternative User identity"]
+    pub fn altid(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "altid")
+    }
+    #[doc = "Time when the event was recorded"]
+    pub fn date(self) -> DateParameter<AuditEvent> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Specific instance of resource"]
+    pub fn entity(self) -> ReferenceParameter<AuditEvent> {
+        ReferenceParameter::new(self, "entity")
+    }
+    #[doc = "Descriptor for entity"]
+    pub fn entity_name(self) -> StringParameter<AuditEvent> {
+        StringParameter::new(self, "entity-name")
+    }
+    #[doc = "What role the entity played"]
+    pub fn entity_role(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "entity-role")
+    }
+    #[doc = "Type of entity involved"]
+    pub fn entity_type(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "entity-type")
+    }
+    #[doc = "Whether the event succeeded or failed"]
+    pub fn outcome(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "outcome")
+    }
+    #[doc = "Identifier of who"]
+    pub fn patient(self) -> ReferenceParameter<AuditEvent> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Policy that authorized event"]
+    pub fn policy(self) -> UriParameter<AuditEvent> {
+        UriParameter::new(self, "policy")
+    }
+    #[doc = "Logical source location within the enterprise"]
+    pub fn site(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "site")
+    }
+    #[doc = "The identity of source detecting the event"]
+    pub fn source(self) -> ReferenceParameter<AuditEvent> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "More specific type/id for the event"]
+    pub fn subtype(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "subtype")
+    }
+    #[doc = "Type/identifier of event"]
+    pub fn type_(self) -> TokenParameter<AuditEvent> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for Basic {
+    fn resource_type() -> ResourceType {
+        ResourceType::Basic
+    }
+}
+impl SearchQuery<Basic> {
+    #[doc = "Who created"]
+    pub fn author(self) -> ReferenceParameter<Basic> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Kind of Resource"]
+    pub fn code(self) -> TokenParameter<Basic> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "When created"]
+    pub fn created(self) -> DateParameter<Basic> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<Basic> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Identifies the focus of this resource"]
+    pub fn patient(self) -> ReferenceParameter<Basic> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Identifies the focus of this resource"]
+    pub fn subject(self) -> ReferenceParameter<Basic> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for BodyStructure {
+    fn resource_type() -> ResourceType {
+        ResourceType::BodyStructure
+    }
+}
+impl SearchQuery<BodyStructure> {
+    #[doc = "Bodystructure identifier"]
+    pub fn identifier(self) -> TokenParameter<BodyStructure> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Body site"]
+    pub fn location(self) -> TokenParameter<BodyStructure> {
+        TokenParameter::new(self, "location")
+    }
+    #[doc = "Kind of Structure"]
+    pub fn morphology(self) -> TokenParameter<BodyStructure> {
+        TokenParameter::new(self, "morphology")
+    }
+    #[doc = "Who this is about"]
+    pub fn patient(self) -> ReferenceParameter<BodyStructure> {
+        ReferenceParameter::new(self, "patient")
+    }
+}
+impl Search for Bundle {
+    fn resource_type() -> ResourceType {
+        ResourceType::Bundle
+    }
+}
+impl SearchQuery<Bundle> {
+    #[doc = "The first resource in the bundle, if the bundle type is \"document\" - this is a composition, and this parameter provides access to search its contents"]
+    pub fn composition(self) -> ReferenceParameter<Bundle> {
+        ReferenceParameter::new(self, "composition")
+    }
+    #[doc = "Persistent identifier for the bundle"]
+    pub fn identifier(self) -> TokenParameter<Bundle> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The first resource in the bundle, if the bundle type is \"message\" - this is a message header, and this parameter provides access to search its contents"]
+    pub fn message(self) -> ReferenceParameter<Bundle> {
+        ReferenceParameter::new(self, "message")
+    }
+    #[doc = "When the bundle was assembled"]
+    pub fn timestamp(self) -> DateParameter<Bundle> {
+        DateParameter::new(self, "timestamp")
+    }
+    #[doc = "document | message | transaction | transaction-response | batch | batch-response | history | searchset | collection"]
+    pub fn type_(self) -> TokenParameter<Bundle> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for CapabilityStatement {
+    fn resource_type() -> ResourceType {
+        ResourceType::CapabilityStatement
+    }
+}
+impl SearchQuery<CapabilityStatement> {
+    #[doc = "A use context assigned to the capability statement"]
+    pub fn context(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the capability statement"]
+    pub fn context_quantity(self) -> QuantityParameter<CapabilityStatement> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the capability statement"]
+    pub fn context_type(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the capability statement"]
+    pub fn context_type_quantity(self) -> CompositeParameter<CapabilityStatement> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the capability statement"]
+    pub fn context_type_value(self) -> CompositeParameter<CapabilityStatement> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The capability statement publication date"]
+    pub fn date(self) -> DateParameter<CapabilityStatement> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the capability statement"]
+    pub fn description(self) -> StringParameter<CapabilityStatement> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The version of FHIR"]
+    pub fn fhirversion(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "fhirversion")
+    }
+    #[doc = "formats supported (xml | json | ttl | mime type)"]
+    pub fn format(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "format")
+    }
+    #[doc = "Implementation guides supported"]
+    pub fn guide(self) -> ReferenceParameter<CapabilityStatement> {
+        ReferenceParameter::new(self, "guide")
+    }
+    #[doc = "Intended jurisdiction for the capability statement"]
+    pub fn jurisdiction(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Mode - restful (server/client) or messaging (sender/receiver)"]
+    pub fn mode(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "mode")
+    }
+    #[doc = "Computationally friendly name of the capability statement"]
+    pub fn name(self) -> StringParameter<CapabilityStatement> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the capability statement"]
+    pub fn publisher(self) -> StringParameter<CapabilityStatement> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "Name of a resource mentioned in a capability statement"]
+    pub fn resource(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "resource")
+    }
+    #[doc = "A profile id invoked in a capability statement"]
+    pub fn resource_profile(self) -> ReferenceParameter<CapabilityStatement> {
+        ReferenceParameter::new(self, "resource-profile")
+    }
+    #[doc = "OAuth | SMART-on-FHIR | NTLM | Basic | Kerberos | Certificates"]
+    pub fn security_service(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "security-service")
+    }
+    #[doc = "Part of the name of a software application"]
+    pub fn software(self) -> StringParameter<CapabilityStatement> {
+        StringParameter::new(self, "software")
+    }
+    #[doc = "The current status of the capability statement"]
+    pub fn status(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Profiles for use cases supported"]
+    pub fn supported_profile(self) -> ReferenceParameter<CapabilityStatement> {
+        ReferenceParameter::new(self, "supported-profile")
+    }
+    #[doc = "The human-friendly name of the capability statement"]
+    pub fn title(self) -> StringParameter<CapabilityStatement> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the capability statement"]
+    pub fn url(self) -> UriParameter<CapabilityStatement> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the capability statement"]
+    pub fn version(self) -> TokenParameter<CapabilityStatement> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for CarePlan {
+    fn resource_type() -> ResourceType {
+        ResourceType::CarePlan
+    }
+}
+impl SearchQuery<CarePlan> {
+    #[doc = "Detail type of activity"]
+    pub fn activity_code(self) -> TokenParameter<CarePlan> {
+        TokenParameter::new(self, "activity-code")
+    }
+    #[doc = "Specified date occurs within period specified by CarePlan.activity.detail.scheduled[x]"]
+    pub fn activity_date(self) -> DateParameter<CarePlan> {
+        DateParameter::new(self, "activity-date")
+    }
+    #[doc = "Activity details defined in specific resource"]
+    pub fn activity_reference(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "activity-reference")
+    }
+    #[doc = "Fulfills CarePlan"]
+    pub fn based_on(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Who's involved in plan?"]
+    pub fn care_team(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "care-team")
+    }
+    #[doc = "Type of plan"]
+    pub fn category(self) -> TokenParameter<CarePlan> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Health issues this plan addresses"]
+    pub fn condition(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "condition")
+    }
+    #[doc = "Time period plan covers"]
+    pub fn date(self) -> DateParameter<CarePlan> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Desired outcome of plan"]
+    pub fn goal(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "goal")
+    }
+    #[doc = "External Ids for this plan"]
+    pub fn identifier(self) -> TokenParameter<CarePlan> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<CarePlan> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "proposal | plan | order | option"]
+    pub fn intent(self) -> TokenParameter<CarePlan> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "Part of referenced CarePlan"]
+    pub fn part_of(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Who the care plan is for"]
+    pub fn patient(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Matches if the practitioner is listed as a performer in any of the \"simple\" activities.  (For performers of the detailed activities, chain through the activitydetail search parameter.)"]
+    pub fn performer(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "CarePlan replaced by this CarePlan"]
+    pub fn replaces(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "replaces")
+    }
+    #[doc = "draft | active | on-hold | revoked | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<CarePlan> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who the care plan is for"]
+    pub fn subject(self) -> ReferenceParameter<CarePlan> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for CareTeam {
+    fn resource_type() -> ResourceType {
+        ResourceType::CareTeam
+    }
+}
+impl SearchQuery<CareTeam> {
+    #[doc = "Type of team"]
+    pub fn category(self) -> TokenParameter<CareTeam> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Time period team covers"]
+    pub fn date(self) -> DateParameter<CareTeam> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<CareTeam> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "External Ids for this team"]
+    pub fn identifier(self) -> TokenParameter<CareTeam> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Who is involved"]
+    pub fn participant(self) -> ReferenceParameter<CareTeam> {
+        ReferenceParameter::new(self, "participant")
+    }
+    #[doc = "Who care team is for"]
+    pub fn patient(self) -> ReferenceParameter<CareTeam> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "proposed | active | suspended | inactive | entered-in-error"]
+    pub fn status(self) -> TokenParameter<CareTeam> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who care team is for"]
+    pub fn subject(self) -> ReferenceParameter<CareTeam> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for ChargeItem {
+    fn resource_type() -> ResourceType {
+        ResourceType::ChargeItem
+    }
+}
+impl SearchQuery<ChargeItem> {
+    #[doc = "Account to place this charge"]
+    pub fn account(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "account")
+    }
+    #[doc = "A code that identifies the charge, like a billing code"]
+    pub fn code(self) -> TokenParameter<ChargeItem> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Encounter / Episode associated with event"]
+    pub fn context(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "context")
+    }
+    #[doc = "Date the charge item was entered"]
+    pub fn entered_date(self) -> DateParameter<ChargeItem> {
+        DateParameter::new(self, "entered-date")
+    }
+    #[doc = "Individual who was entering"]
+    pub fn enterer(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "enterer")
+    }
+    #[doc = "Factor overriding the associated rules"]
+    pub fn factor_override(self) -> NumberParameter<ChargeItem> {
+        NumberParameter::new(self, "factor-override")
+    }
+    #[doc = "Business Identifier for item"]
+    pub fn identifier(self) -> TokenParameter<ChargeItem> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "When the charged service was applied"]
+    pub fn occurrence(self) -> DateParameter<ChargeItem> {
+        DateParameter::new(self, "occurrence")
+    }
+    #[doc = "Individual service was done for/to"]
+    pub fn patient(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Individual who was performing"]
+    pub fn performer_actor(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "performer-actor")
+    }
+    #[doc = "What type of performance was done"]
+    pub fn performer_function(self) -> TokenParameter<ChargeItem> {
+        TokenParameter::new(self, "performer-function")
+    }
+    #[doc = "Organization providing the charged service"]
+    pub fn performing_organization(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "performing-organization")
+    }
+    #[doc = "Price overriding the associated rules"]
+    pub fn price_override(self) -> QuantityParameter<ChargeItem> {
+        QuantityParameter::new(self, "price-override")
+    }
+    #[doc = "Quantity of which the charge item has been serviced"]
+    pub fn quantity(self) -> QuantityParameter<ChargeItem> {
+        QuantityParameter::new(self, "quantity")
+    }
+    #[doc = "Organization requesting the charged service"]
+    pub fn requesting_organization(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "requesting-organization")
+    }
+    #[doc = "Which rendered service is being charged?"]
+    pub fn service(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "service")
+    }
+    #[doc = "Individual service was done for/to"]
+    pub fn subject(self) -> ReferenceParameter<ChargeItem> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for ChargeItemDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::ChargeItemDefinition
+    }
+}
+impl SearchQuery<ChargeItemDefinition> {
+    #[doc = "A use context assigned to the charge item definition"]
+    pub fn context(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the charge item definition"]
+    pub fn context_quantity(self) -> QuantityParameter<ChargeItemDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the charge item definition"]
+    pub fn context_type(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the charge item definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ChargeItemDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the charge item definition"]
+    pub fn context_type_value(self) -> CompositeParameter<ChargeItemDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The charge item definition publication date"]
+    pub fn date(self) -> DateParameter<ChargeItemDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the charge item definition"]
+    pub fn description(self) -> StringParameter<ChargeItemDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the charge item definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<ChargeItemDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the charge item definition"]
+    pub fn identifier(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the charge item definition"]
+    pub fn jurisdiction(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Name of the publisher of the charge item definition"]
+    pub fn publisher(self) -> StringParameter<ChargeItemDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the charge item definition"]
+    pub fn status(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the charge item definition"]
+    pub fn title(self) -> StringParameter<ChargeItemDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the charge item definition"]
+    pub fn url(self) -> UriParameter<ChargeItemDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the charge item definition"]
+    pub fn version(self) -> TokenParameter<ChargeItemDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Claim {
+    fn resource_type() -> ResourceType {
+        ResourceType::Claim
+    }
+}
+impl SearchQuery<Claim> {
+    #[doc = "Member of the CareTeam"]
+    pub fn care_team(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "care-team")
+    }
+    #[doc = "The creation date for the Claim"]
+    pub fn created(self) -> DateParameter<Claim> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "UDI associated with a line item, detail product or service"]
+    pub fn detail_udi(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "detail-udi")
+    }
+    #[doc = "Encounters associated with a billed line item"]
+    pub fn encounter(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The party responsible for the entry of the Claim"]
+    pub fn enterer(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "enterer")
+    }
+    #[doc = "Facility where the products or services have been or will be provided"]
+    pub fn facility(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "facility")
+    }
+    #[doc = "The primary identifier of the financial resource"]
+    pub fn identifier(self) -> TokenParameter<Claim> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The target payor/insurer for the Claim"]
+    pub fn insurer(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "insurer")
+    }
+    #[doc = "UDI associated with a line item product or service"]
+    pub fn item_udi(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "item-udi")
+    }
+    #[doc = "Patient receiving the products or services"]
+    pub fn patient(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The party receiving any payment for the Claim"]
+    pub fn payee(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "payee")
+    }
+    #[doc = "Processing priority requested"]
+    pub fn priority(self) -> TokenParameter<Claim> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "UDI associated with a procedure"]
+    pub fn procedure_udi(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "procedure-udi")
+    }
+    #[doc = "Provider responsible for the Claim"]
+    pub fn provider(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "provider")
+    }
+    #[doc = "The status of the Claim instance."]
+    pub fn status(self) -> TokenParameter<Claim> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "UDI associated with a line item, detail, subdetail product or service"]
+    pub fn subdetail_udi(self) -> ReferenceParameter<Claim> {
+        ReferenceParameter::new(self, "subdetail-udi")
+    }
+    #[doc = "The kind of financial resource"]
+    pub fn use_(self) -> TokenParameter<Claim> {
+        TokenParameter::new(self, "use")
+    }
+}
+impl Search for ClaimResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::ClaimResponse
+    }
+}
+impl SearchQuery<ClaimResponse> {
+    #[doc = "The creation date"]
+    pub fn created(self) -> DateParameter<ClaimResponse> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "The contents of the disposition message"]
+    pub fn disposition(self) -> StringParameter<ClaimResponse> {
+        StringParameter::new(self, "disposition")
+    }
+    #[doc = "The identity of the ClaimResponse"]
+    pub fn identifier(self) -> TokenParameter<ClaimResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The organization which generated this resource"]
+    pub fn insurer(self) -> ReferenceParameter<ClaimResponse> {
+        ReferenceParameter::new(self, "insurer")
+    }
+    #[doc = "The processing outcome"]
+    pub fn outcome(self) -> TokenParameter<ClaimResponse> {
+        TokenParameter::new(self, "outcome")
+    }
+    #[doc = "The subject of care"]
+    pub fn patient(self) -> ReferenceParameter<ClaimResponse> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The expected payment date"]
+    pub fn payment_date(self) -> DateParameter<ClaimResponse> {
+        DateParameter::new(self, "payment-date")
+    }
+    #[doc = "The claim reference"]
+    pub fn request(self) -> ReferenceParameter<ClaimResponse> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "The Provider of the claim"]
+    pub fn requestor(self) -> ReferenceParameter<ClaimResponse> {
+        ReferenceParameter::new(self, "requestor")
+    }
+    #[doc = "The status of the ClaimResponse"]
+    pub fn status(self) -> TokenParameter<ClaimResponse> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The type of claim"]
+    pub fn use_(self) -> TokenParameter<ClaimResponse> {
+        TokenParameter::new(self, "use")
+    }
+}
+impl Search for ClientApplication {
+    fn resource_type() -> ResourceType {
+        ResourceType::ClientApplication
+    }
+}
+impl SearchQuery<ClientApplication> {
+    #[doc = "ClientApplication grant type."]
+    pub fn grant_type(self) -> TokenParameter<ClientApplication> {
+        TokenParameter::new(self, "grantType")
+    }
+    #[doc = "ClientApplication name"]
+    pub fn name(self) -> StringParameter<ClientApplication> {
+        StringParameter::new(self, "name")
+    }
+}
+impl Search for ClinicalImpression {
+    fn resource_type() -> ResourceType {
+        ResourceType::ClinicalImpression
+    }
+}
+impl SearchQuery<ClinicalImpression> {
+    #[doc = "The clinician performing the assessment"]
+    pub fn assessor(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "assessor")
+    }
+    #[doc = "When the assessment was documented"]
+    pub fn date(self) -> DateParameter<ClinicalImpression> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "What was found"]
+    pub fn finding_code(self) -> TokenParameter<ClinicalImpression> {
+        TokenParameter::new(self, "finding-code")
+    }
+    #[doc = "What was found"]
+    pub fn finding_ref(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "finding-ref")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<ClinicalImpression> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Record of a specific investigation"]
+    pub fn investigation(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "investigation")
+    }
+    #[doc = "Patient or group assessed"]
+    pub fn patient(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Reference to last assessment"]
+    pub fn previous(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "previous")
+    }
+    #[doc = "Relevant impressions of patient state"]
+    pub fn problem(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "problem")
+    }
+    #[doc = "in-progress | completed | entered-in-error"]
+    pub fn status(self) -> TokenParameter<ClinicalImpression> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Patient or group assessed"]
+    pub fn subject(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Information supporting the clinical impression"]
+    pub fn supporting_info(self) -> ReferenceParameter<ClinicalImpression> {
+        ReferenceParameter::new(self, "supporting-info")
+    }
+}
+impl Search for CodeSystem {
+    fn resource_type() -> ResourceType {
+        ResourceType::CodeSystem
+    }
+}
+impl SearchQuery<CodeSystem> {
+    #[doc = "A code defined in the code system"]
+    pub fn code(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "not-present | example | fragment | complete | supplement"]
+    pub fn content_mode(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "content-mode")
+    }
+    #[doc = "A use context assigned to the code system"]
+    pub fn context(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the code system"]
+    pub fn context_quantity(self) -> QuantityParameter<CodeSystem> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the code system"]
+    pub fn context_type(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the code system"]
+    pub fn context_type_quantity(self) -> CompositeParameter<CodeSystem> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the code system"]
+    pub fn context_type_value(self) -> CompositeParameter<CodeSystem> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The code system publication date"]
+    pub fn date(self) -> DateParameter<CodeSystem> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the code system"]
+    pub fn description(self) -> StringParameter<CodeSystem> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "External identifier for the code system"]
+    pub fn identifier(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the code system"]
+    pub fn jurisdiction(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "A language in which a designation is provided"]
+    pub fn language(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "language")
+    }
+    #[doc = "Computationally friendly name of the code system"]
+    pub fn name(self) -> StringParameter<CodeSystem> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the code system"]
+    pub fn publisher(self) -> StringParameter<CodeSystem> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the code system"]
+    pub fn status(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Find code system supplements for the referenced code system"]
+    pub fn supplements(self) -> ReferenceParameter<CodeSystem> {
+        ReferenceParameter::new(self, "supplements")
+    }
+    #[doc = "The system for any codes defined by this code system (same as 'url')"]
+    pub fn system(self) -> UriParameter<CodeSystem> {
+        UriParameter::new(self, "system")
+    }
+    #[doc = "The human-friendly name of the code system"]
+    pub fn title(self) -> StringParameter<CodeSystem> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the code system"]
+    pub fn url(self) -> UriParameter<CodeSystem> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the code system"]
+    pub fn version(self) -> TokenParameter<CodeSystem> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Communication {
+    fn resource_type() -> ResourceType {
+        ResourceType::Communication
+    }
+}
+impl SearchQuery<Communication> {
+    #[doc = "Request fulfilled by this communication"]
+    pub fn based_on(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Message category"]
+    pub fn category(self) -> TokenParameter<Communication> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Unique identifier"]
+    pub fn identifier(self) -> TokenParameter<Communication> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<Communication> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "A channel of communication"]
+    pub fn medium(self) -> TokenParameter<Communication> {
+        TokenParameter::new(self, "medium")
+    }
+    #[doc = "Part of this action"]
+    pub fn part_of(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Focus of message"]
+    pub fn patient(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "When received"]
+    pub fn received(self) -> DateParameter<Communication> {
+        DateParameter::new(self, "received")
+    }
+    #[doc = "Message recipient"]
+    pub fn recipient(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "recipient")
+    }
+    #[doc = "Message sender"]
+    pub fn sender(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "sender")
+    }
+    #[doc = "When sent"]
+    pub fn sent(self) -> DateParameter<Communication> {
+        DateParameter::new(self, "sent")
+    }
+    #[doc = "preparation | in-progress | not-done | on-hold | stopped | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<Communication> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Focus of message"]
+    pub fn subject(self) -> ReferenceParameter<Communication> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for CommunicationRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::CommunicationRequest
+    }
+}
+impl SearchQuery<CommunicationRequest> {
+    #[doc = "When request transitioned to being actionable"]
+    pub fn authored(self) -> DateParameter<CommunicationRequest> {
+        DateParameter::new(self, "authored")
+    }
+    #[doc = "Fulfills plan or proposal"]
+    pub fn based_on(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Message category"]
+    pub fn category(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Composite request this is part of"]
+    pub fn group_identifier(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "group-identifier")
+    }
+    #[doc = "Unique identifier"]
+    pub fn identifier(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A channel of communication"]
+    pub fn medium(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "medium")
+    }
+    #[doc = "When scheduled"]
+    pub fn occurrence(self) -> DateParameter<CommunicationRequest> {
+        DateParameter::new(self, "occurrence")
+    }
+    #[doc = "Focus of message"]
+    pub fn patient(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "routine | urgent | asap | stat"]
+    pub fn priority(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "Message recipient"]
+    pub fn recipient(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "recipient")
+    }
+    #[doc = "Request(s) replaced by this request"]
+    pub fn replaces(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "replaces")
+    }
+    #[doc = "Who/what is requesting service"]
+    pub fn requester(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "Message sender"]
+    pub fn sender(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "sender")
+    }
+    #[doc = "draft | active | on-hold | revoked | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<CommunicationRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Focus of message"]
+    pub fn subject(self) -> ReferenceParameter<CommunicationRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for CompartmentDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::CompartmentDefinition
+    }
+}
+impl SearchQuery<CompartmentDefinition> {
+    #[doc = "Patient | Encounter | RelatedPerson | Practitioner | Device"]
+    pub fn code(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "A use context assigned to the compartment definition"]
+    pub fn context(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the compartment definition"]
+    pub fn context_quantity(self) -> QuantityParameter<CompartmentDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the compartment definition"]
+    pub fn context_type(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the compartment definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<CompartmentDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the compartment definition"]
+    pub fn context_type_value(self) -> CompositeParameter<CompartmentDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The compartment definition publication date"]
+    pub fn date(self) -> DateParameter<CompartmentDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the compartment definition"]
+    pub fn description(self) -> StringParameter<CompartmentDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Computationally friendly name of the compartment definition"]
+    pub fn name(self) -> StringParameter<CompartmentDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the compartment definition"]
+    pub fn publisher(self) -> StringParameter<CompartmentDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "Name of resource type"]
+    pub fn resource(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "resource")
+    }
+    #[doc = "The current status of the compartment definition"]
+    pub fn status(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The uri that identifies the compartment definition"]
+    pub fn url(self) -> UriParameter<CompartmentDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the compartment definition"]
+    pub fn version(self) -> TokenParameter<CompartmentDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Composition {
+    fn resource_type() -> ResourceType {
+        ResourceType::Composition
+    }
+}
+impl SearchQuery<Composition> {
+    #[doc = "Who attested the composition"]
+    pub fn attester(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "attester")
+    }
+    #[doc = "Who and/or what authored the composition"]
+    pub fn author(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Categorization of Composition"]
+    pub fn category(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "As defined by affinity domain"]
+    pub fn confidentiality(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "confidentiality")
+    }
+    #[doc = "Code(s) that apply to the event being documented"]
+    pub fn context(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "Composition editing time"]
+    pub fn date(self) -> DateParameter<Composition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Context of the Composition"]
+    pub fn encounter(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "A reference to data that supports this section"]
+    pub fn entry(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "entry")
+    }
+    #[doc = "Version-independent identifier for the Composition"]
+    pub fn identifier(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Who and/or what the composition is about"]
+    pub fn patient(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The period covered by the documentation"]
+    pub fn period(self) -> DateParameter<Composition> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "Target of the relationship"]
+    pub fn related_id(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "related-id")
+    }
+    #[doc = "Target of the relationship"]
+    pub fn related_ref(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "related-ref")
+    }
+    #[doc = "Classification of section (recommended)"]
+    pub fn section(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "section")
+    }
+    #[doc = "preliminary | final | amended | entered-in-error"]
+    pub fn status(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who and/or what the composition is about"]
+    pub fn subject(self) -> ReferenceParameter<Composition> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Human Readable name/title"]
+    pub fn title(self) -> StringParameter<Composition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Kind of composition (LOINC if possible)"]
+    pub fn type_(self) -> TokenParameter<Composition> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for ConceptMap {
+    fn resource_type() -> ResourceType {
+        ResourceType::ConceptMap
+    }
+}
+impl SearchQuery<ConceptMap> {
+    #[doc = "A use context assigned to the concept map"]
+    pub fn context(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the concept map"]
+    pub fn context_quantity(self) -> QuantityParameter<ConceptMap> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the concept map"]
+    pub fn context_type(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the concept map"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ConceptMap> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the concept map"]
+    pub fn context_type_value(self) -> CompositeParameter<ConceptMap> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The concept map publication date"]
+    pub fn date(self) -> DateParameter<ConceptMap> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Reference to property mapping depends on"]
+    pub fn dependson(self) -> UriParameter<ConceptMap> {
+        UriParameter::new(self, "dependson")
+    }
+    #[doc = "The description of the concept map"]
+    pub fn description(self) -> StringParameter<ConceptMap> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "External identifier for the concept map"]
+    pub fn identifier(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the concept map"]
+    pub fn jurisdiction(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the concept map"]
+    pub fn name(self) -> StringParameter<ConceptMap> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "canonical reference to an additional ConceptMap to use for mapping if the source concept is unmapped"]
+    pub fn other(self) -> ReferenceParameter<ConceptMap> {
+        ReferenceParameter::new(self, "other")
+    }
+    #[doc = "Reference to property mapping depends on"]
+    pub fn product(self) -> UriParameter<ConceptMap> {
+        UriParameter::new(self, "product")
+    }
+    #[doc = "Name of the publisher of the concept map"]
+    pub fn publisher(self) -> StringParameter<ConceptMap> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The source value set that contains the concepts that are being mapped"]
+    pub fn source(self) -> ReferenceParameter<ConceptMap> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "Identifies element being mapped"]
+    pub fn source_code(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "source-code")
+    }
+    #[doc = "Source system where concepts to be mapped are defined"]
+    pub fn source_system(self) -> UriParameter<ConceptMap> {
+        UriParameter::new(self, "source-system")
+    }
+    #[doc = "The source value set that contains the concepts that are being mapped"]
+    pub fn source_uri(self) -> ReferenceParameter<ConceptMap> {
+        ReferenceParameter::new(self, "source-uri")
+    }
+    #[doc = "The current status of the concept map"]
+    pub fn status(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The target value set which provides context for the mappings"]
+    pub fn target(self) -> ReferenceParameter<ConceptMap> {
+        ReferenceParameter::new(self, "target")
+    }
+    #[doc = "Code that identifies the target element"]
+    pub fn target_code(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "target-code")
+    }
+    #[doc = "Target system that the concepts are to be mapped to"]
+    pub fn target_system(self) -> UriParameter<ConceptMap> {
+        UriParameter::new(self, "target-system")
+    }
+    #[doc = "The target value set which provides context for the mappings"]
+    pub fn target_uri(self) -> ReferenceParameter<ConceptMap> {
+        ReferenceParameter::new(self, "target-uri")
+    }
+    #[doc = "The human-friendly name of the concept map"]
+    pub fn title(self) -> StringParameter<ConceptMap> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the concept map"]
+    pub fn url(self) -> UriParameter<ConceptMap> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the concept map"]
+    pub fn version(self) -> TokenParameter<ConceptMap> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Condition {
+    fn resource_type() -> ResourceType {
+        ResourceType::Condition
+    }
+}
+impl SearchQuery<Condition> {
+    #[doc = "Abatement as age or age range"]
+    pub fn abatement_age(self) -> QuantityParameter<Condition> {
+        QuantityParameter::new(self, "abatement-age")
+    }
+    #[doc = "Date-related abatements (dateTime and period)"]
+    pub fn abatement_date(self) -> DateParameter<Condition> {
+        DateParameter::new(self, "abatement-date")
+    }
+    #[doc = "Abatement as a string"]
+    pub fn abatement_string(self) -> StringParameter<Condition> {
+        StringParameter::new(self, "abatement-string")
+    }
+    #[doc = "Person who asserts this condition"]
+    pub fn asserter(self) -> ReferenceParameter<Condition> {
+        ReferenceParameter::new(self, "asserter")
+    }
+    #[doc = "Anatomical location, if relevant"]
+    pub fn body_site(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "body-site")
+    }
+    #[doc = "The category of the condition"]
+    pub fn category(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "The clinical status of the condition"]
+    pub fn clinical_status(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "clinical-status")
+    }
+    #[doc = "Code for the condition"]
+    pub fn code(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<Condition> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Manifestation/symptom"]
+    pub fn evidence(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "evidence")
+    }
+    #[doc = "Supporting information found elsewhere"]
+    pub fn evidence_detail(self) -> ReferenceParameter<Condition> {
+        ReferenceParameter::new(self, "evidence-detail")
+    }
+    #[doc = "A unique identifier of the condition record"]
+    pub fn identifier(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Onsets as age or age range"]
+    pub fn onset_age(self) -> QuantityParameter<Condition> {
+        QuantityParameter::new(self, "onset-age")
+    }
+    #[doc = "Date related onsets (dateTime and Period)"]
+    pub fn onset_date(self) -> DateParameter<Condition> {
+        DateParameter::new(self, "onset-date")
+    }
+    #[doc = "Onsets as a string"]
+    pub fn onset_info(self) -> StringParameter<Condition> {
+        StringParameter::new(self, "onset-info")
+    }
+    #[doc = "Who has the condition?"]
+    pub fn patient(self) -> ReferenceParameter<Condition> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Date record was first recorded"]
+    pub fn recorded_date(self) -> DateParameter<Condition> {
+        DateParameter::new(self, "recorded-date")
+    }
+    #[doc = "The severity of the condition"]
+    pub fn severity(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "severity")
+    }
+    #[doc = "Simple summary (disease specific)"]
+    pub fn stage(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "stage")
+    }
+    #[doc = "Who has the condition?"]
+    pub fn subject(self) -> ReferenceParameter<Condition> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "unconfirmed | provisional | differential | confirmed | refuted | entered-in-error"]
+    pub fn verification_status(self) -> TokenParameter<Condition> {
+        TokenParameter::new(self, "verification-status")
+    }
+}
+impl Search for Consent {
+    fn resource_type() -> ResourceType {
+        ResourceType::Consent
+    }
+}
+impl SearchQuery<Consent> {
+    #[doc = "Actions controlled by this rule"]
+    pub fn action(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "action")
+    }
+    #[doc = "Resource for the actor (or group, by role)"]
+    pub fn actor(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "actor")
+    }
+    #[doc = "Classification of the consent statement - for indexing/retrieval"]
+    pub fn category(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Who is agreeing to the policy and rules"]
+    pub fn consentor(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "consentor")
+    }
+    #[doc = "The actual data reference"]
+    pub fn data(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "data")
+    }
+    #[doc = "When this Consent was created or indexed"]
+    pub fn date(self) -> DateParameter<Consent> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Identifier for this record (external references)"]
+    pub fn identifier(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Custodian of the consent"]
+    pub fn organization(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "Who the consent applies to"]
+    pub fn patient(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Timeframe for this rule"]
+    pub fn period(self) -> DateParameter<Consent> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "Context of activities covered by this rule"]
+    pub fn purpose(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "purpose")
+    }
+    #[doc = "Which of the four areas this resource covers (extensible)"]
+    pub fn scope(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "scope")
+    }
+    #[doc = "Security Labels that define affected resources"]
+    pub fn security_label(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "security-label")
+    }
+    #[doc = "Search by reference to a Consent, DocumentReference, Contract  or QuestionnaireResponse"]
+    pub fn source_reference(self) -> ReferenceParameter<Consent> {
+        ReferenceParameter::new(self, "source-reference")
+    }
+    #[doc = "draft | proposed | active | rejected | inactive | entered-in-error"]
+    pub fn status(self) -> TokenParameter<Consent> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for Contract {
+    fn resource_type() -> ResourceType {
+        ResourceType::Contract
+    }
+}
+impl SearchQuery<Contract> {
+    #[doc = "The authority of the contract"]
+    pub fn authority(self) -> ReferenceParameter<Contract> {
+        ReferenceParameter::new(self, "authority")
+    }
+    #[doc = "The domain of the contract"]
+    pub fn domain(self) -> ReferenceParameter<Contract> {
+        ReferenceParameter::new(self, "domain")
+    }
+    #[doc = "The identity of the contract"]
+    pub fn identifier(self) -> TokenParameter<Contract> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A source definition of the contract"]
+    pub fn instantiates(self) -> UriParameter<Contract> {
+        UriParameter::new(self, "instantiates")
+    }
+    #[doc = "The date/time the contract was issued"]
+    pub fn issued(self) -> DateParameter<Contract> {
+        DateParameter::new(self, "issued")
+    }
+    #[doc = "The identity of the subject of the contract (if a patient)"]
+    pub fn patient(self) -> ReferenceParameter<Contract> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Contract Signatory Party"]
+    pub fn signer(self) -> ReferenceParameter<Contract> {
+        ReferenceParameter::new(self, "signer")
+    }
+    #[doc = "The status of the contract"]
+    pub fn status(self) -> TokenParameter<Contract> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of the subject of the contract"]
+    pub fn subject(self) -> ReferenceParameter<Contract> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "The basal contract definition"]
+    pub fn url(self) -> UriParameter<Contract> {
+        UriParameter::new(self, "url")
+    }
+}
+impl Search for Coverage {
+    fn resource_type() -> ResourceType {
+        ResourceType::Coverage
+    }
+}
+impl SearchQuery<Coverage> {
+    #[doc = "Covered party"]
+    pub fn beneficiary(self) -> ReferenceParameter<Coverage> {
+        ReferenceParameter::new(self, "beneficiary")
+    }
+    #[doc = "Coverage class (eg. plan, group)"]
+    pub fn class_type(self) -> TokenParameter<Coverage> {
+        TokenParameter::new(self, "class-type")
+    }
+    #[doc = "Value of the class (eg. Plan number, group number)"]
+    pub fn class_value(self) -> StringParameter<Coverage> {
+        StringParameter::new(self, "class-value")
+    }
+    #[doc = "Dependent number"]
+    pub fn dependent(self) -> StringParameter<Coverage> {
+        StringParameter::new(self, "dependent")
+    }
+    #[doc = "The primary identifier of the insured and the coverage"]
+    pub fn identifier(self) -> TokenParameter<Coverage> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Retrieve coverages for a patient"]
+    pub fn patient(self) -> ReferenceParameter<Coverage> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The identity of the insurer or party paying for services"]
+    pub fn payor(self) -> ReferenceParameter<Coverage> {
+        ReferenceParameter::new(self, "payor")
+    }
+    #[doc = "Reference to the policyholder"]
+    pub fn policy_holder(self) -> ReferenceParameter<Coverage> {
+        ReferenceParameter::new(self, "policy-holder")
+    }
+    #[doc = "The status of the Coverage"]
+    pub fn status(self) -> TokenParameter<Coverage> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Reference to the subscriber"]
+    pub fn subscriber(self) -> ReferenceParameter<Coverage> {
+        ReferenceParameter::new(self, "subscriber")
+    }
+    #[doc = "The kind of coverage (health plan, auto, Workers Compensation)"]
+    pub fn type_(self) -> TokenParameter<Coverage> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for CoverageEligibilityRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::CoverageEligibilityRequest
+    }
+}
+impl SearchQuery<CoverageEligibilityRequest> {
+    #[doc = "The creation date for the EOB"]
+    pub fn created(self) -> DateParameter<CoverageEligibilityRequest> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "The party who is responsible for the request"]
+    pub fn enterer(self) -> ReferenceParameter<CoverageEligibilityRequest> {
+        ReferenceParameter::new(self, "enterer")
+    }
+    #[doc = "Facility responsible for the goods and services"]
+    pub fn facility(self) -> ReferenceParameter<CoverageEligibilityRequest> {
+        ReferenceParameter::new(self, "facility")
+    }
+    #[doc = "The business identifier of the Eligibility"]
+    pub fn identifier(self) -> TokenParameter<CoverageEligibilityRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The reference to the patient"]
+    pub fn patient(self) -> ReferenceParameter<CoverageEligibilityRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The reference to the provider"]
+    pub fn provider(self) -> ReferenceParameter<CoverageEligibilityRequest> {
+        ReferenceParameter::new(self, "provider")
+    }
+    #[doc = "The status of the EligibilityRequest"]
+    pub fn status(self) -> TokenParameter<CoverageEligibilityRequest> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for CoverageEligibilityResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::CoverageEligibilityResponse
+    }
+}
+impl SearchQuery<CoverageEligibilityResponse> {
+    #[doc = "The creation date"]
+    pub fn created(self) -> DateParameter<CoverageEligibilityResponse> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "The contents of the disposition message"]
+    pub fn disposition(self) -> StringParameter<CoverageEligibilityResponse> {
+        StringParameter::new(self, "disposition")
+    }
+    #[doc = "The business identifier"]
+    pub fn identifier(self) -> TokenParameter<CoverageEligibilityResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The organization which generated this resource"]
+    pub fn insurer(self) -> ReferenceParameter<CoverageEligibilityResponse> {
+        ReferenceParameter::new(self, "insurer")
+    }
+    #[doc = "The processing outcome"]
+    pub fn outcome(self) -> TokenParameter<CoverageEligibilityResponse> {
+        TokenParameter::new(self, "outcome")
+    }
+    #[doc = "The reference to the patient"]
+    pub fn patient(self) -> ReferenceParameter<CoverageEligibilityResponse> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The EligibilityRequest reference"]
+    pub fn request(self) -> ReferenceParameter<CoverageEligibilityResponse> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "The EligibilityRequest provider"]
+    pub fn requestor(self) -> ReferenceParameter<CoverageEligibilityResponse> {
+        ReferenceParameter::new(self, "requestor")
+    }
+    #[doc = "The EligibilityRequest status"]
+    pub fn status(self) -> TokenParameter<CoverageEligibilityResponse> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for DetectedIssue {
+    fn resource_type() -> ResourceType {
+        ResourceType::DetectedIssue
+    }
+}
+impl SearchQuery<DetectedIssue> {
+    #[doc = "The provider or device that identified the issue"]
+    pub fn author(self) -> ReferenceParameter<DetectedIssue> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Issue Category, e.g. drug-drug, duplicate therapy, etc."]
+    pub fn code(self) -> TokenParameter<DetectedIssue> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "When identified"]
+    pub fn identified(self) -> DateParameter<DetectedIssue> {
+        DateParameter::new(self, "identified")
+    }
+    #[doc = "Unique id for the detected issue"]
+    pub fn identifier(self) -> TokenParameter<DetectedIssue> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Problem resource"]
+    pub fn implicated(self) -> ReferenceParameter<DetectedIssue> {
+        ReferenceParameter::new(self, "implicated")
+    }
+    #[doc = "Associated patient"]
+    pub fn patient(self) -> ReferenceParameter<DetectedIssue> {
+        ReferenceParameter::new(self, "patient")
+    }
+}
+impl Search for Device {
+    fn resource_type() -> ResourceType {
+        ResourceType::Device
+    }
+}
+impl SearchQuery<Device> {
+    #[doc = "A server defined search that may match any of the string fields in Device.deviceName or Device.type."]
+    pub fn device_name(self) -> StringParameter<Device> {
+        StringParameter::new(self, "device-name")
+    }
+    #[doc = "Instance id from manufacturer, owner, and others"]
+    pub fn identifier(self) -> TokenParameter<Device> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A location, where the resource is found"]
+    pub fn location(self) -> ReferenceParameter<Device> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "The manufacturer of the device"]
+    pub fn manufacturer(self) -> StringParameter<Device> {
+        StringParameter::new(self, "manufacturer")
+    }
+    #[doc = "The model of the device"]
+    pub fn model(self) -> StringParameter<Device> {
+        StringParameter::new(self, "model")
+    }
+    #[doc = "The organization responsible for the device"]
+    pub fn organization(self) -> ReferenceParameter<Device> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "Patient information, if the resource is affixed to a person"]
+    pub fn patient(self) -> ReferenceParameter<Device> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "active | inactive | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<Device> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The type of the device"]
+    pub fn type_(self) -> TokenParameter<Device> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "UDI Barcode (RFID or other technology) string in *HRF* format."]
+    pub fn udi_carrier(self) -> StringParameter<Device> {
+        StringParameter::new(self, "udi-carrier")
+    }
+    #[doc = "The udi Device Identifier (DI)"]
+    pub fn udi_di(self) -> StringParameter<Device> {
+        StringParameter::new(self, "udi-di")
+    }
+    #[doc = "Network address to contact device"]
+    pub fn url(self) -> UriParameter<Device> {
+        UriParameter::new(self, "url")
+    }
+}
+impl Search for DeviceDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::DeviceDefinition
+    }
+}
+impl SearchQuery<DeviceDefinition> {
+    #[doc = "The identifier of the component"]
+    pub fn identifier(self) -> TokenParameter<DeviceDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The parent DeviceDefinition resource"]
+    pub fn parent(self) -> ReferenceParameter<DeviceDefinition> {
+        ReferenceParameter::new(self, "parent")
+    }
+    #[doc = "The device component type"]
+    pub fn type_(self) -> TokenParameter<DeviceDefinition> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for DeviceMetric {
+    fn resource_type() -> ResourceType {
+        ResourceType::DeviceMetric
+    }
+}
+impl SearchQuery<DeviceMetric> {
+    #[doc = "The category of the metric"]
+    pub fn category(self) -> TokenParameter<DeviceMetric> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "The identifier of the metric"]
+    pub fn identifier(self) -> TokenParameter<DeviceMetric> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The parent DeviceMetric resource"]
+    pub fn parent(self) -> ReferenceParameter<DeviceMetric> {
+        ReferenceParameter::new(self, "parent")
+    }
+    #[doc = "The device resource"]
+    pub fn source(self) -> ReferenceParameter<DeviceMetric> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "The component type"]
+    pub fn type_(self) -> TokenParameter<DeviceMetric> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for DeviceRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::DeviceRequest
+    }
+}
+impl SearchQuery<DeviceRequest> {
+    #[doc = "When the request transitioned to being actionable"]
+    pub fn authored_on(self) -> DateParameter<DeviceRequest> {
+        DateParameter::new(self, "authored-on")
+    }
+    #[doc = "Plan/proposal/order fulfilled by this request"]
+    pub fn based_on(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Code for what is being requested/ordered"]
+    pub fn code(self) -> TokenParameter<DeviceRequest> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Reference to resource that is being requested/ordered"]
+    pub fn device(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "device")
+    }
+    #[doc = "Encounter during which request was created"]
+    pub fn encounter(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "When service should occur"]
+    pub fn event_date(self) -> DateParameter<DeviceRequest> {
+        DateParameter::new(self, "event-date")
+    }
+    #[doc = "Composite request this is part of"]
+    pub fn group_identifier(self) -> TokenParameter<DeviceRequest> {
+        TokenParameter::new(self, "group-identifier")
+    }
+    #[doc = "Business identifier for request/order"]
+    pub fn identifier(self) -> TokenParameter<DeviceRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<DeviceRequest> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "Associated insurance coverage"]
+    pub fn insurance(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "insurance")
+    }
+    #[doc = "proposal | plan | original-order |reflex-order"]
+    pub fn intent(self) -> TokenParameter<DeviceRequest> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "Individual the service is ordered for"]
+    pub fn patient(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Desired performer for service"]
+    pub fn performer(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Request takes the place of referenced completed or terminated requests"]
+    pub fn prior_request(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "prior-request")
+    }
+    #[doc = "Who/what is requesting service"]
+    pub fn requester(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "entered-in-error | draft | active |suspended | completed"]
+    pub fn status(self) -> TokenParameter<DeviceRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Individual the service is ordered for"]
+    pub fn subject(self) -> ReferenceParameter<DeviceRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for DeviceUseStatement {
+    fn resource_type() -> ResourceType {
+        ResourceType::DeviceUseStatement
+    }
+}
+impl SearchQuery<DeviceUseStatement> {
+    #[doc = "Search by device"]
+    pub fn device(self) -> ReferenceParameter<DeviceUseStatement> {
+        ReferenceParameter::new(self, "device")
+    }
+    #[doc = "Search by identifier"]
+    pub fn identifier(self) -> TokenParameter<DeviceUseStatement> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Search by subject - a patient"]
+    pub fn patient(self) -> ReferenceParameter<DeviceUseStatement> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Search by subject"]
+    pub fn subject(self) -> ReferenceParameter<DeviceUseStatement> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for DiagnosticReport {
+    fn resource_type() -> ResourceType {
+        ResourceType::DiagnosticReport
+    }
+}
+impl SearchQuery<DiagnosticReport> {
+    #[doc = "Reference to the service request."]
+    pub fn based_on(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Which diagnostic discipline/department created the report"]
+    pub fn category(self) -> TokenParameter<DiagnosticReport> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "The code for the report, as opposed to codes for the atomic results, which are the names on the observation resource referred to from the result"]
+    pub fn code(self) -> TokenParameter<DiagnosticReport> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "A coded conclusion (interpretation/impression) on the report"]
+    pub fn conclusion(self) -> TokenParameter<DiagnosticReport> {
+        TokenParameter::new(self, "conclusion")
+    }
+    #[doc = "The clinically relevant time of the report"]
+    pub fn date(self) -> DateParameter<DiagnosticReport> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The Encounter when the order was made"]
+    pub fn encounter(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "An identifier for the report"]
+    pub fn identifier(self) -> TokenParameter<DiagnosticReport> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "When the report was issued"]
+    pub fn issued(self) -> DateParameter<DiagnosticReport> {
+        DateParameter::new(self, "issued")
+    }
+    #[doc = "A reference to the image source."]
+    pub fn media(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "media")
+    }
+    #[doc = "The subject of the report if a patient"]
+    pub fn patient(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who is responsible for the report"]
+    pub fn performer(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Link to an atomic result (observation resource)"]
+    pub fn result(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "result")
+    }
+    #[doc = "Who was the source of the report"]
+    pub fn results_interpreter(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "results-interpreter")
+    }
+    #[doc = "The specimen details"]
+    pub fn specimen(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "specimen")
+    }
+    #[doc = "The status of the report"]
+    pub fn status(self) -> TokenParameter<DiagnosticReport> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject of the report"]
+    pub fn subject(self) -> ReferenceParameter<DiagnosticReport> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for DocumentManifest {
+    fn resource_type() -> ResourceType {
+        ResourceType::DocumentManifest
+    }
+}
+impl SearchQuery<DocumentManifest> {
+    #[doc = "Who and/or what authored the DocumentManifest"]
+    pub fn author(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "When this document manifest created"]
+    pub fn created(self) -> DateParameter<DocumentManifest> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "Human-readable description (title)"]
+    pub fn description(self) -> StringParameter<DocumentManifest> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Unique Identifier for the set of documents"]
+    pub fn identifier(self) -> TokenParameter<DocumentManifest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Items in manifest"]
+    pub fn item(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "item")
+    }
+    #[doc = "The subject of the set of documents"]
+    pub fn patient(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Intended to get notified about this set of documents"]
+    pub fn recipient(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "recipient")
+    }
+    #[doc = "Identifiers of things that are related"]
+    pub fn related_id(self) -> TokenParameter<DocumentManifest> {
+        TokenParameter::new(self, "related-id")
+    }
+    #[doc = "Related Resource"]
+    pub fn related_ref(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "related-ref")
+    }
+    #[doc = "The source system/application/software"]
+    pub fn source(self) -> UriParameter<DocumentManifest> {
+        UriParameter::new(self, "source")
+    }
+    #[doc = "current | superseded | entered-in-error"]
+    pub fn status(self) -> TokenParameter<DocumentManifest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject of the set of documents"]
+    pub fn subject(self) -> ReferenceParameter<DocumentManifest> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Kind of document set"]
+    pub fn type_(self) -> TokenParameter<DocumentManifest> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for DocumentReference {
+    fn resource_type() -> ResourceType {
+        ResourceType::DocumentReference
+    }
+}
+impl SearchQuery<DocumentReference> {
+    #[doc = "Who/what authenticated the document"]
+    pub fn authenticator(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "authenticator")
+    }
+    #[doc = "Who and/or what authored the document"]
+    pub fn author(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Categorization of document"]
+    pub fn category(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Mime type of the content, with charset etc."]
+    pub fn contenttype(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "contenttype")
+    }
+    #[doc = "Organization which maintains the document"]
+    pub fn custodian(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "custodian")
+    }
+    #[doc = "When this document reference was created"]
+    pub fn date(self) -> DateParameter<DocumentReference> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Human-readable description"]
+    pub fn description(self) -> StringParameter<DocumentReference> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Context of the document  content"]
+    pub fn encounter(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Main clinical acts documented"]
+    pub fn event(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "event")
+    }
+    #[doc = "Kind of facility where patient was seen"]
+    pub fn facility(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "facility")
+    }
+    #[doc = "Format/content rules for the document"]
+    pub fn format(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "format")
+    }
+    #[doc = "Master Version Specific Identifier"]
+    pub fn identifier(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Human language of the content (BCP-47)"]
+    pub fn language(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "language")
+    }
+    #[doc = "Uri where the data can be found"]
+    pub fn location(self) -> UriParameter<DocumentReference> {
+        UriParameter::new(self, "location")
+    }
+    #[doc = "Who/what is the subject of the document"]
+    pub fn patient(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Time of service that is being documented"]
+    pub fn period(self) -> DateParameter<DocumentReference> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "Related identifiers or resources"]
+    pub fn related(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "related")
+    }
+    #[doc = "Target of the relationship"]
+    pub fn relatesto(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "relatesto")
+    }
+    #[doc = "replaces | transforms | signs | appends"]
+    pub fn relation(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "relation")
+    }
+    #[doc = "Combination of relation and relatesTo"]
+    pub fn relationship(self) -> CompositeParameter<DocumentReference> {
+        CompositeParameter::new(self, "relationship")
+    }
+    #[doc = "Document security-tags"]
+    pub fn security_label(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "security-label")
+    }
+    #[doc = "Additional details about where the content was created (e.g. clinical specialty)"]
+    pub fn setting(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "setting")
+    }
+    #[doc = "current | superseded | entered-in-error"]
+    pub fn status(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who/what is the subject of the document"]
+    pub fn subject(self) -> ReferenceParameter<DocumentReference> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Kind of document (LOINC if possible)"]
+    pub fn type_(self) -> TokenParameter<DocumentReference> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for EffectEvidenceSynthesis {
+    fn resource_type() -> ResourceType {
+        ResourceType::EffectEvidenceSynthesis
+    }
+}
+impl SearchQuery<EffectEvidenceSynthesis> {
+    #[doc = "A use context assigned to the effect evidence synthesis"]
+    pub fn context(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the effect evidence synthesis"]
+    pub fn context_quantity(self) -> QuantityParameter<EffectEvidenceSynthesis> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the effect evidence synthesis"]
+    pub fn context_type(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the effect evidence synthesis"]
+    pub fn context_type_quantity(self) -> CompositeParameter<EffectEvidenceSynthesis> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the effect evidence synthesis"]
+    pub fn context_type_value(self) -> CompositeParameter<EffectEvidenceSynthesis> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The effect evidence synthesis publication date"]
+    pub fn date(self) -> DateParameter<EffectEvidenceSynthesis> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the effect evidence synthesis"]
+    pub fn description(self) -> StringParameter<EffectEvidenceSynthesis> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the effect evidence synthesis is intended to be in use"]
+    pub fn effective(self) -> DateParameter<EffectEvidenceSynthesis> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the effect evidence synthesis"]
+    pub fn identifier(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the effect evidence synthesis"]
+    pub fn jurisdiction(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the effect evidence synthesis"]
+    pub fn name(self) -> StringParameter<EffectEvidenceSynthesis> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the effect evidence synthesis"]
+    pub fn publisher(self) -> StringParameter<EffectEvidenceSynthesis> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the effect evidence synthesis"]
+    pub fn status(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the effect evidence synthesis"]
+    pub fn title(self) -> StringParameter<EffectEvidenceSynthesis> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the effect evidence synthesis"]
+    pub fn url(self) -> UriParameter<EffectEvidenceSynthesis> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the effect evidence synthesis"]
+    pub fn version(self) -> TokenParameter<EffectEvidenceSynthesis> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Encounter {
+    fn resource_type() -> ResourceType {
+        ResourceType::Encounter
+    }
+}
+impl SearchQuery<Encounter> {
+    #[doc = "The set of accounts that may be used for billing for this Encounter"]
+    pub fn account(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "account")
+    }
+    #[doc = "The appointment that scheduled this encounter"]
+    pub fn appointment(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "appointment")
+    }
+    #[doc = "The ServiceRequest that initiated this encounter"]
+    pub fn based_on(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Classification of patient encounter"]
+    pub fn class(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "class")
+    }
+    #[doc = "A date within the period the Encounter lasted"]
+    pub fn date(self) -> DateParameter<Encounter> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The diagnosis or procedure relevant to the encounter"]
+    pub fn diagnosis(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "diagnosis")
+    }
+    #[doc = "Episode(s) of care that this encounter should be recorded against"]
+    pub fn episode_of_care(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "episode-of-care")
+    }
+    #[doc = "Identifier(s) by which this encounter is known"]
+    pub fn identifier(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Length of encounter in days"]
+    pub fn length(self) -> QuantityParameter<Encounter> {
+        QuantityParameter::new(self, "length")
+    }
+    #[doc = "Location the encounter takes place"]
+    pub fn location(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Time period during which the patient was present at the location"]
+    pub fn location_period(self) -> DateParameter<Encounter> {
+        DateParameter::new(self, "location-period")
+    }
+    #[doc = "Another Encounter this encounter is part of"]
+    pub fn part_of(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Persons involved in the encounter other than the patient"]
+    pub fn participant(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "participant")
+    }
+    #[doc = "Role of participant in encounter"]
+    pub fn participant_type(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "participant-type")
+    }
+    #[doc = "The patient or group present at the encounter"]
+    pub fn patient(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Persons involved in the encounter other than the patient"]
+    pub fn practitioner(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "practitioner")
+    }
+    #[doc = "Coded reason the encounter takes place"]
+    pub fn reason_code(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "reason-code")
+    }
+    #[doc = "Reason the encounter takes place (reference)"]
+    pub fn reason_reference(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "reason-reference")
+    }
+    #[doc = "The organization (facility) responsible for this encounter"]
+    pub fn service_provider(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "service-provider")
+    }
+    #[doc = "Wheelchair, translator, stretcher, etc."]
+    pub fn special_arrangement(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "special-arrangement")
+    }
+    #[doc = "planned | arrived | triaged | in-progress | onleave | finished | cancelled +"]
+    pub fn status(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The patient or group present at the encounter"]
+    pub fn subject(self) -> ReferenceParameter<Encounter> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Specific type of encounter"]
+    pub fn type_(self) -> TokenParameter<Encounter> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for Endpoint {
+    fn resource_type() -> ResourceType {
+        ResourceType::Endpoint
+    }
+}
+impl SearchQuery<Endpoint> {
+    #[doc = "Protocol/Profile/Standard to be used with this endpoint connection"]
+    pub fn connection_type(self) -> TokenParameter<Endpoint> {
+        TokenParameter::new(self, "connection-type")
+    }
+    #[doc = "Identifies this endpoint across multiple systems"]
+    pub fn identifier(self) -> TokenParameter<Endpoint> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A name that this endpoint can be identified by"]
+    pub fn name(self) -> StringParameter<Endpoint> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "The organization that is managing the endpoint"]
+    pub fn organization(self) -> ReferenceParameter<Endpoint> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "The type of content that may be used at this endpoint (e.g. XDS Discharge summaries)"]
+    pub fn payload_type(self) -> TokenParameter<Endpoint> {
+        TokenParameter::new(self, "payload-type")
+    }
+    #[doc = "The current status of the Endpoint (usually expected to be active)"]
+    pub fn status(self) -> TokenParameter<Endpoint> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for EnrollmentRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::EnrollmentRequest
+    }
+}
+impl SearchQuery<EnrollmentRequest> {
+    #[doc = "The business identifier of the Enrollment"]
+    pub fn identifier(self) -> TokenParameter<EnrollmentRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The party to be enrolled"]
+    pub fn patient(self) -> ReferenceParameter<EnrollmentRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The status of the enrollment"]
+    pub fn status(self) -> TokenParameter<EnrollmentRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The party to be enrolled"]
+    pub fn subject(self) -> ReferenceParameter<EnrollmentRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for EnrollmentResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::EnrollmentResponse
+    }
+}
+impl SearchQuery<EnrollmentResponse> {
+    #[doc = "The business identifier of the EnrollmentResponse"]
+    pub fn identifier(self) -> TokenParameter<EnrollmentResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The reference to the claim"]
+    pub fn request(self) -> ReferenceParameter<EnrollmentResponse> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "The status of the enrollment response"]
+    pub fn status(self) -> TokenParameter<EnrollmentResponse> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for EpisodeOfCare {
+    fn resource_type() -> ResourceType {
+        ResourceType::EpisodeOfCare
+    }
+}
+impl SearchQuery<EpisodeOfCare> {
+    #[doc = "Care manager/care coordinator for the patient"]
+    pub fn care_manager(self) -> ReferenceParameter<EpisodeOfCare> {
+        ReferenceParameter::new(self, "care-manager")
+    }
+    #[doc = "Conditions/problems/diagnoses this episode of care is for"]
+    pub fn condition(self) -> ReferenceParameter<EpisodeOfCare> {
+        ReferenceParameter::new(self, "condition")
+    }
+    #[doc = "The provided date search value falls within the episode of care's period"]
+    pub fn date(self) -> DateParameter<EpisodeOfCare> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business Identifier(s) relevant for this EpisodeOfCare"]
+    pub fn identifier(self) -> TokenParameter<EpisodeOfCare> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Incoming Referral Request"]
+    pub fn incoming_referral(self) -> ReferenceParameter<EpisodeOfCare> {
+        ReferenceParameter::new(self, "incoming-referral")
+    }
+    #[doc = "The organization that has assumed the specific responsibilities of this EpisodeOfCare"]
+    pub fn organization(self) -> ReferenceParameter<EpisodeOfCare> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "The patient who is the focus of this episode of care"]
+    pub fn patient(self) -> ReferenceParameter<EpisodeOfCare> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The current status of the Episode of Care as provided (does not check the status history collection)"]
+    pub fn status(self) -> TokenParameter<EpisodeOfCare> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Type/class  - e.g. specialist referral, disease management"]
+    pub fn type_(self) -> TokenParameter<EpisodeOfCare> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for EventDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::EventDefinition
+    }
+}
+impl SearchQuery<EventDefinition> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<EventDefinition> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the event definition"]
+    pub fn context(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the event definition"]
+    pub fn context_quantity(self) -> QuantityParameter<EventDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the event definition"]
+    pub fn context_type(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the event definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<EventDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the event definition"]
+    pub fn context_type_value(self) -> CompositeParameter<EventDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The event definition publication date"]
+    pub fn date(self) -> DateParameter<EventDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<EventDefinition> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<EventDefinition> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the event definition"]
+    pub fn description(self) -> StringParameter<EventDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the event definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<EventDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the event definition"]
+    pub fn identifier(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the event definition"]
+    pub fn jurisdiction(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the event definition"]
+    pub fn name(self) -> StringParameter<EventDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<EventDefinition> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the event definition"]
+    pub fn publisher(self) -> StringParameter<EventDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the event definition"]
+    pub fn status(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<EventDefinition> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the event definition"]
+    pub fn title(self) -> StringParameter<EventDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the module"]
+    pub fn topic(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the event definition"]
+    pub fn url(self) -> UriParameter<EventDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the event definition"]
+    pub fn version(self) -> TokenParameter<EventDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Evidence {
+    fn resource_type() -> ResourceType {
+        ResourceType::Evidence
+    }
+}
+impl SearchQuery<Evidence> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<Evidence> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the evidence"]
+    pub fn context(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the evidence"]
+    pub fn context_quantity(self) -> QuantityParameter<Evidence> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the evidence"]
+    pub fn context_type(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the evidence"]
+    pub fn context_type_quantity(self) -> CompositeParameter<Evidence> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the evidence"]
+    pub fn context_type_value(self) -> CompositeParameter<Evidence> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The evidence publication date"]
+    pub fn date(self) -> DateParameter<Evidence> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<Evidence> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<Evidence> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the evidence"]
+    pub fn description(self) -> StringParameter<Evidence> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the evidence is intended to be in use"]
+    pub fn effective(self) -> DateParameter<Evidence> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the evidence"]
+    pub fn identifier(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the evidence"]
+    pub fn jurisdiction(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the evidence"]
+    pub fn name(self) -> StringParameter<Evidence> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<Evidence> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the evidence"]
+    pub fn publisher(self) -> StringParameter<Evidence> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the evidence"]
+    pub fn status(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<Evidence> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the evidence"]
+    pub fn title(self) -> StringParameter<Evidence> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the Evidence"]
+    pub fn topic(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the evidence"]
+    pub fn url(self) -> UriParameter<Evidence> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the evidence"]
+    pub fn version(self) -> TokenParameter<Evidence> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for EvidenceVariable {
+    fn resource_type() -> ResourceType {
+        ResourceType::EvidenceVariable
+    }
+}
+impl SearchQuery<EvidenceVariable> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<EvidenceVariable> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the evidence variable"]
+    pub fn context(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the evidence variable"]
+    pub fn context_quantity(self) -> QuantityParameter<EvidenceVariable> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the evidence variable"]
+    pub fn context_type(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the evidence variable"]
+    pub fn context_type_quantity(self) -> CompositeParameter<EvidenceVariable> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the evidence variable"]
+    pub fn context_type_value(self) -> CompositeParameter<EvidenceVariable> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The evidence variable publication date"]
+    pub fn date(self) -> DateParameter<EvidenceVariable> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<EvidenceVariable> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<EvidenceVariable> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the evidence variable"]
+    pub fn description(self) -> StringParameter<EvidenceVariable> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the evidence variable is intended to be in use"]
+    pub fn effective(self) -> DateParameter<EvidenceVariable> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the evidence variable"]
+    pub fn identifier(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the evidence variable"]
+    pub fn jurisdiction(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the evidence variable"]
+    pub fn name(self) -> StringParameter<EvidenceVariable> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<EvidenceVariable> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the evidence variable"]
+    pub fn publisher(self) -> StringParameter<EvidenceVariable> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the evidence variable"]
+    pub fn status(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<EvidenceVariable> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the evidence variable"]
+    pub fn title(self) -> StringParameter<EvidenceVariable> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the EvidenceVariable"]
+    pub fn topic(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the evidence variable"]
+    pub fn url(self) -> UriParameter<EvidenceVariable> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the evidence variable"]
+    pub fn version(self) -> TokenParameter<EvidenceVariable> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for ExampleScenario {
+    fn resource_type() -> ResourceType {
+        ResourceType::ExampleScenario
+    }
+}
+impl SearchQuery<ExampleScenario> {
+    #[doc = "A use context assigned to the example scenario"]
+    pub fn context(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the example scenario"]
+    pub fn context_quantity(self) -> QuantityParameter<ExampleScenario> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the example scenario"]
+    pub fn context_type(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the example scenario"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ExampleScenario> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the example scenario"]
+    pub fn context_type_value(self) -> CompositeParameter<ExampleScenario> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The example scenario publication date"]
+    pub fn date(self) -> DateParameter<ExampleScenario> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "External identifier for the example scenario"]
+    pub fn identifier(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the example scenario"]
+    pub fn jurisdiction(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the example scenario"]
+    pub fn name(self) -> StringParameter<ExampleScenario> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the example scenario"]
+    pub fn publisher(self) -> StringParameter<ExampleScenario> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the example scenario"]
+    pub fn status(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The uri that identifies the example scenario"]
+    pub fn url(self) -> UriParameter<ExampleScenario> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the example scenario"]
+    pub fn version(self) -> TokenParameter<ExampleScenario> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for ExplanationOfBenefit {
+    fn resource_type() -> ResourceType {
+        ResourceType::ExplanationOfBenefit
+    }
+}
+impl SearchQuery<ExplanationOfBenefit> {
+    #[doc = "Member of the CareTeam"]
+    pub fn care_team(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "care-team")
+    }
+    #[doc = "The reference to the claim"]
+    pub fn claim(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "claim")
+    }
+    #[doc = "The plan under which the claim was adjudicated"]
+    pub fn coverage(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "coverage")
+    }
+    #[doc = "The creation date for the EOB"]
+    pub fn created(self) -> DateParameter<ExplanationOfBenefit> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "UDI associated with a line item detail product or service"]
+    pub fn detail_udi(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "detail-udi")
+    }
+    #[doc = "The contents of the disposition message"]
+    pub fn disposition(self) -> StringParameter<ExplanationOfBenefit> {
+        StringParameter::new(self, "disposition")
+    }
+    #[doc = "Encounters associated with a billed line item"]
+    pub fn encounter(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The party responsible for the entry of the Claim"]
+    pub fn enterer(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "enterer")
+    }
+    #[doc = "Facility responsible for the goods and services"]
+    pub fn facility(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "facility")
+    }
+    #[doc = "The business identifier of the Explanation of Benefit"]
+    pub fn identifier(self) -> TokenParameter<ExplanationOfBenefit> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "UDI associated with a line item product or service"]
+    pub fn item_udi(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "item-udi")
+    }
+    #[doc = "The reference to the patient"]
+    pub fn patient(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The party receiving any payment for the Claim"]
+    pub fn payee(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "payee")
+    }
+    #[doc = "UDI associated with a procedure"]
+    pub fn procedure_udi(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "procedure-udi")
+    }
+    #[doc = "The reference to the provider"]
+    pub fn provider(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "provider")
+    }
+    #[doc = "Status of the instance"]
+    pub fn status(self) -> TokenParameter<ExplanationOfBenefit> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "UDI associated with a line item detail subdetail product or service"]
+    pub fn subdetail_udi(self) -> ReferenceParameter<ExplanationOfBenefit> {
+        ReferenceParameter::new(self, "subdetail-udi")
+    }
+}
+impl Search for FamilyMemberHistory {
+    fn resource_type() -> ResourceType {
+        ResourceType::FamilyMemberHistory
+    }
+}
+impl SearchQuery<FamilyMemberHistory> {
+    #[doc = "A search by a condition code"]
+    pub fn code(self) -> TokenParameter<FamilyMemberHistory> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "When history was recorded or last updated"]
+    pub fn date(self) -> DateParameter<FamilyMemberHistory> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "A search by a record identifier"]
+    pub fn identifier(self) -> TokenParameter<FamilyMemberHistory> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<FamilyMemberHistory> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<FamilyMemberHistory> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "The identity of a subject to list family member history items for"]
+    pub fn patient(self) -> ReferenceParameter<FamilyMemberHistory> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "A search by a relationship type"]
+    pub fn relationship(self) -> TokenParameter<FamilyMemberHistory> {
+        TokenParameter::new(self, "relationship")
+    }
+    #[doc = "A search by a sex code of a family member"]
+    pub fn sex(self) -> TokenParameter<FamilyMemberHistory> {
+        TokenParameter::new(self, "sex")
+    }
+    #[doc = "partial | completed | entered-in-error | health-unknown"]
+    pub fn status(self) -> TokenParameter<FamilyMemberHistory> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for Flag {
+    fn resource_type() -> ResourceType {
+        ResourceType::Flag
+    }
+}
+impl SearchQuery<Flag> {
+    #[doc = "Flag creator"]
+    pub fn author(self) -> ReferenceParameter<Flag> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Time period when flag is active"]
+    pub fn date(self) -> DateParameter<Flag> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Alert relevant during encounter"]
+    pub fn encounter(self) -> ReferenceParameter<Flag> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<Flag> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The identity of a subject to list flags for"]
+    pub fn patient(self) -> ReferenceParameter<Flag> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The identity of a subject to list flags for"]
+    pub fn subject(self) -> ReferenceParameter<Flag> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Goal {
+    fn resource_type() -> ResourceType {
+        ResourceType::Goal
+    }
+}
+impl SearchQuery<Goal> {
+    #[doc = "in-progress | improving | worsening | no-change | achieved | sustaining | not-achieved | no-progress | not-attainable"]
+    pub fn achievement_status(self) -> TokenParameter<Goal> {
+        TokenParameter::new(self, "achievement-status")
+    }
+    #[doc = "E.g. Treatment, dietary, behavioral, etc."]
+    pub fn category(self) -> TokenParameter<Goal> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "External Ids for this goal"]
+    pub fn identifier(self) -> TokenParameter<Goal> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "proposed | planned | accepted | active | on-hold | completed | cancelled | entered-in-error | rejected"]
+    pub fn lifecycle_status(self) -> TokenParameter<Goal> {
+        TokenParameter::new(self, "lifecycle-status")
+    }
+    #[doc = "Who this goal is intended for"]
+    pub fn patient(self) -> ReferenceParameter<Goal> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "When goal pursuit begins"]
+    pub fn start_date(self) -> DateParameter<Goal> {
+        DateParameter::new(self, "start-date")
+    }
+    #[doc = "Who this goal is intended for"]
+    pub fn subject(self) -> ReferenceParameter<Goal> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Reach goal on or before"]
+    pub fn target_date(self) -> DateParameter<Goal> {
+        DateParameter::new(self, "target-date")
+    }
+}
+impl Search for GraphDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::GraphDefinition
+    }
+}
+impl SearchQuery<GraphDefinition> {
+    #[doc = "A use context assigned to the graph definition"]
+    pub fn context(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the graph definition"]
+    pub fn context_quantity(self) -> QuantityParameter<GraphDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the graph definition"]
+    pub fn context_type(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the graph definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<GraphDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the graph definition"]
+    pub fn context_type_value(self) -> CompositeParameter<GraphDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The graph definition publication date"]
+    pub fn date(self) -> DateParameter<GraphDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the graph definition"]
+    pub fn description(self) -> StringParameter<GraphDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Intended jurisdiction for the graph definition"]
+    pub fn jurisdiction(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the graph definition"]
+    pub fn name(self) -> StringParameter<GraphDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the graph definition"]
+    pub fn publisher(self) -> StringParameter<GraphDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "Type of resource at which the graph starts"]
+    pub fn start(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "start")
+    }
+    #[doc = "The current status of the graph definition"]
+    pub fn status(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The uri that identifies the graph definition"]
+    pub fn url(self) -> UriParameter<GraphDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the graph definition"]
+    pub fn version(self) -> TokenParameter<GraphDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Group {
+    fn resource_type() -> ResourceType {
+        ResourceType::Group
+    }
+}
+impl SearchQuery<Group> {
+    #[doc = "Descriptive or actual"]
+    pub fn actual(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "actual")
+    }
+    #[doc = "Kind of characteristic"]
+    pub fn characteristic(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "characteristic")
+    }
+    #[doc = "A composite of both characteristic and value"]
+    pub fn characteristic_value(self) -> CompositeParameter<Group> {
+        CompositeParameter::new(self, "characteristic-value")
+    }
+    #[doc = "The kind of resources contained"]
+    pub fn code(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Group includes or excludes"]
+    pub fn exclude(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "exclude")
+    }
+    #[doc = "Unique id"]
+    pub fn identifier(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Entity that is the custodian of the Group's definition"]
+    pub fn managing_entity(self) -> ReferenceParameter<Group> {
+        ReferenceParameter::new(self, "managing-entity")
+    }
+    #[doc = "Reference to the group member"]
+    pub fn member(self) -> ReferenceParameter<Group> {
+        ReferenceParameter::new(self, "member")
+    }
+    #[doc = "The type of resources the group contains"]
+    pub fn type_(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "Value held by characteristic"]
+    pub fn value(self) -> TokenParameter<Group> {
+        TokenParameter::new(self, "value")
+    }
+}
+impl Search for GuidanceResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::GuidanceResponse
+    }
+}
+impl SearchQuery<GuidanceResponse> {
+    #[doc = "The identifier of the guidance response"]
+    pub fn identifier(self) -> TokenParameter<GuidanceResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The identity of a patient to search for guidance response results"]
+    pub fn patient(self) -> ReferenceParameter<GuidanceResponse> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The identifier of the request associated with the response"]
+    pub fn request(self) -> TokenParameter<GuidanceResponse> {
+        TokenParameter::new(self, "request")
+    }
+    #[doc = "The subject that the guidance response is about"]
+    pub fn subject(self) -> ReferenceParameter<GuidanceResponse> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for HealthcareService {
+    fn resource_type() -> ResourceType {
+        ResourceType::HealthcareService
+    }
+}
+impl SearchQuery<HealthcareService> {
+    #[doc = "The Healthcare Service is currently marked as active"]
+    pub fn active(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "One of the HealthcareService's characteristics"]
+    pub fn characteristic(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "characteristic")
+    }
+    #[doc = "Location(s) service is intended for/available to"]
+    pub fn coverage_area(self) -> ReferenceParameter<HealthcareService> {
+        ReferenceParameter::new(self, "coverage-area")
+    }
+    #[doc = "Technical endpoints providing access to electronic services operated for the healthcare service"]
+    pub fn endpoint(self) -> ReferenceParameter<HealthcareService> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "External identifiers for this item"]
+    pub fn identifier(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The location of the Healthcare Service"]
+    pub fn location(self) -> ReferenceParameter<HealthcareService> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "A portion of the Healthcare service name"]
+    pub fn name(self) -> StringParameter<HealthcareService> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "The organization that provides this Healthcare Service"]
+    pub fn organization(self) -> ReferenceParameter<HealthcareService> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "One of the Programs supported by this HealthcareService"]
+    pub fn program(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "program")
+    }
+    #[doc = "Service Category of the Healthcare Service"]
+    pub fn service_category(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "service-category")
+    }
+    #[doc = "The type of service provided by this healthcare service"]
+    pub fn service_type(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "service-type")
+    }
+    #[doc = "The specialty of the service provided by this healthcare service"]
+    pub fn specialty(self) -> TokenParameter<HealthcareService> {
+        TokenParameter::new(self, "specialty")
+    }
+}
+impl Search for IdentityProvider {
+    fn resource_type() -> ResourceType {
+        ResourceType::IdentityProvider
+    }
+}
+impl SearchQuery<IdentityProvider> {
+    #[doc = "An identity providers accessType."]
+    pub fn access_type(self) -> TokenParameter<IdentityProvider> {
+        TokenParameter::new(self, "accessType")
+    }
+    #[doc = "An identity providers name."]
+    pub fn name(self) -> StringParameter<IdentityProvider> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "An identity providers status."]
+    pub fn status(self) -> TokenParameter<IdentityProvider> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for ImagingStudy {
+    fn resource_type() -> ResourceType {
+        ResourceType::ImagingStudy
+    }
+}
+impl SearchQuery<ImagingStudy> {
+    #[doc = "The order for the image"]
+    pub fn basedon(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "basedon")
+    }
+    #[doc = "The body site studied"]
+    pub fn bodysite(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "bodysite")
+    }
+    #[doc = "The type of the instance"]
+    pub fn dicom_class(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "dicom-class")
+    }
+    #[doc = "The context of the study"]
+    pub fn encounter(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The endpoint for the study or series"]
+    pub fn endpoint(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "Identifiers for the Study, such as DICOM Study Instance UID and Accession number"]
+    pub fn identifier(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "SOP Instance UID for an instance"]
+    pub fn instance(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "instance")
+    }
+    #[doc = "Who interpreted the images"]
+    pub fn interpreter(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "interpreter")
+    }
+    #[doc = "The modality of the series"]
+    pub fn modality(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "modality")
+    }
+    #[doc = "Who the study is about"]
+    pub fn patient(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The person who performed the study"]
+    pub fn performer(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "The reason for the study"]
+    pub fn reason(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "reason")
+    }
+    #[doc = "The referring physician"]
+    pub fn referrer(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "referrer")
+    }
+    #[doc = "DICOM Series Instance UID for a series"]
+    pub fn series(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "series")
+    }
+    #[doc = "When the study was started"]
+    pub fn started(self) -> DateParameter<ImagingStudy> {
+        DateParameter::new(self, "started")
+    }
+    #[doc = "The status of the study"]
+    pub fn status(self) -> TokenParameter<ImagingStudy> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who the study is about"]
+    pub fn subject(self) -> ReferenceParameter<ImagingStudy> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Immunization {
+    fn resource_type() -> ResourceType {
+        ResourceType::Immunization
+    }
+}
+impl SearchQuery<Immunization> {
+    #[doc = "Vaccination  (non)-Administration Date"]
+    pub fn date(self) -> DateParameter<Immunization> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The service delivery location or facility in which the vaccine was / was to be administered"]
+    pub fn location(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Vaccine Lot Number"]
+    pub fn lot_number(self) -> StringParameter<Immunization> {
+        StringParameter::new(self, "lot-number")
+    }
+    #[doc = "Vaccine Manufacturer"]
+    pub fn manufacturer(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "manufacturer")
+    }
+    #[doc = "The patient for the vaccination record"]
+    pub fn patient(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The practitioner or organization who played a role in the vaccination"]
+    pub fn performer(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Additional information on reaction"]
+    pub fn reaction(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "reaction")
+    }
+    #[doc = "When reaction started"]
+    pub fn reaction_date(self) -> DateParameter<Immunization> {
+        DateParameter::new(self, "reaction-date")
+    }
+    #[doc = "Reason why the vaccine was administered"]
+    pub fn reason_code(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "reason-code")
+    }
+    #[doc = "Why immunization occurred"]
+    pub fn reason_reference(self) -> ReferenceParameter<Immunization> {
+        ReferenceParameter::new(self, "reason-reference")
+    }
+    #[doc = "The series being followed by the provider"]
+    pub fn series(self) -> StringParameter<Immunization> {
+        StringParameter::new(self, "series")
+    }
+    #[doc = "Immunization event status"]
+    pub fn status(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Reason why the vaccine was not administered"]
+    pub fn status_reason(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "status-reason")
+    }
+    #[doc = "The target disease the dose is being administered against"]
+    pub fn target_disease(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "target-disease")
+    }
+    #[doc = "Vaccine Product Administered"]
+    pub fn vaccine_code(self) -> TokenParameter<Immunization> {
+        TokenParameter::new(self, "vaccine-code")
+    }
+}
+impl Search for ImmunizationEvaluation {
+    fn resource_type() -> ResourceType {
+        ResourceType::ImmunizationEvaluation
+    }
+}
+impl SearchQuery<ImmunizationEvaluation> {
+    #[doc = "Date the evaluation was generated"]
+    pub fn date(self) -> DateParameter<ImmunizationEvaluation> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The status of the dose relative to published recommendations"]
+    pub fn dose_status(self) -> TokenParameter<ImmunizationEvaluation> {
+        TokenParameter::new(self, "dose-status")
+    }
+    #[doc = "ID of the evaluation"]
+    pub fn identifier(self) -> TokenParameter<ImmunizationEvaluation> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The vaccine administration event being evaluated"]
+    pub fn immunization_event(self) -> ReferenceParameter<ImmunizationEvaluation> {
+        ReferenceParameter::new(self, "immunization-event")
+    }
+    #[doc = "The patient being evaluated"]
+    pub fn patient(self) -> ReferenceParameter<ImmunizationEvaluation> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Immunization evaluation status"]
+    pub fn status(self) -> TokenParameter<ImmunizationEvaluation> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The vaccine preventable disease being evaluated against"]
+    pub fn target_disease(self) -> TokenParameter<ImmunizationEvaluation> {
+        TokenParameter::new(self, "target-disease")
+    }
+}
+impl Search for ImmunizationRecommendation {
+    fn resource_type() -> ResourceType {
+        ResourceType::ImmunizationRecommendation
+    }
+}
+impl SearchQuery<ImmunizationRecommendation> {
+    #[doc = "Date recommendation(s) created"]
+    pub fn date(self) -> DateParameter<ImmunizationRecommendation> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<ImmunizationRecommendation> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Patient observations supporting recommendation"]
+    pub fn information(self) -> ReferenceParameter<ImmunizationRecommendation> {
+        ReferenceParameter::new(self, "information")
+    }
+    #[doc = "Who this profile is for"]
+    pub fn patient(self) -> ReferenceParameter<ImmunizationRecommendation> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Vaccine recommendation status"]
+    pub fn status(self) -> TokenParameter<ImmunizationRecommendation> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Past immunizations supporting recommendation"]
+    pub fn support(self) -> ReferenceParameter<ImmunizationRecommendation> {
+        ReferenceParameter::new(self, "support")
+    }
+    #[doc = "Disease to be immunized against"]
+    pub fn target_disease(self) -> TokenParameter<ImmunizationRecommendation> {
+        TokenParameter::new(self, "target-disease")
+    }
+    #[doc = "Vaccine  or vaccine group recommendation applies to"]
+    pub fn vaccine_type(self) -> TokenParameter<ImmunizationRecommendation> {
+        TokenParameter::new(self, "vaccine-type")
+    }
+}
+impl Search for ImplementationGuide {
+    fn resource_type() -> ResourceType {
+        ResourceType::ImplementationGuide
+    }
+}
+impl SearchQuery<ImplementationGuide> {
+    #[doc = "A use context assigned to the implementation guide"]
+    pub fn context(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the implementation guide"]
+    pub fn context_quantity(self) -> QuantityParameter<ImplementationGuide> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the implementation guide"]
+    pub fn context_type(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the implementation guide"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ImplementationGuide> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the implementation guide"]
+    pub fn context_type_value(self) -> CompositeParameter<ImplementationGuide> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The implementation guide publication date"]
+    pub fn date(self) -> DateParameter<ImplementationGuide> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Identity of the IG that this depends on"]
+    pub fn depends_on(self) -> ReferenceParameter<ImplementationGuide> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "The description of the implementation guide"]
+    pub fn description(self) -> StringParameter<ImplementationGuide> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "For testing purposes, not real usage"]
+    pub fn experimental(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "experimental")
+    }
+    #[doc = "Profile that all resources must conform to"]
+    pub fn global(self) -> ReferenceParameter<ImplementationGuide> {
+        ReferenceParameter::new(self, "global")
+    }
+    #[doc = "Intended jurisdiction for the implementation guide"]
+    pub fn jurisdiction(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the implementation guide"]
+    pub fn name(self) -> StringParameter<ImplementationGuide> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the implementation guide"]
+    pub fn publisher(self) -> StringParameter<ImplementationGuide> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "Location of the resource"]
+    pub fn resource(self) -> ReferenceParameter<ImplementationGuide> {
+        ReferenceParameter::new(self, "resource")
+    }
+    #[doc = "The current status of the implementation guide"]
+    pub fn status(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the implementation guide"]
+    pub fn title(self) -> StringParameter<ImplementationGuide> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the implementation guide"]
+    pub fn url(self) -> UriParameter<ImplementationGuide> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the implementation guide"]
+    pub fn version(self) -> TokenParameter<ImplementationGuide> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for InsurancePlan {
+    fn resource_type() -> ResourceType {
+        ResourceType::InsurancePlan
+    }
+}
+impl SearchQuery<InsurancePlan> {
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postal code specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<InsurancePlan> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "Product administrator"]
+    pub fn administered_by(self) -> ReferenceParameter<InsurancePlan> {
+        ReferenceParameter::new(self, "administered-by")
+    }
+    #[doc = "Technical endpoint"]
+    pub fn endpoint(self) -> ReferenceParameter<InsurancePlan> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "Any identifier for the organization (not the accreditation issuer's identifier)"]
+    pub fn identifier(self) -> TokenParameter<InsurancePlan> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A portion of the organization's name or alias"]
+    pub fn name(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "An organization of which this organization forms a part"]
+    pub fn owned_by(self) -> ReferenceParameter<InsurancePlan> {
+        ReferenceParameter::new(self, "owned-by")
+    }
+    #[doc = "A portion of the organization's name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<InsurancePlan> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "Is the Organization record active"]
+    pub fn status(self) -> TokenParameter<InsurancePlan> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "A code for the type of organization"]
+    pub fn type_(self) -> TokenParameter<InsurancePlan> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for Invoice {
+    fn resource_type() -> ResourceType {
+        ResourceType::Invoice
+    }
+}
+impl SearchQuery<Invoice> {
+    #[doc = "Account that is being balanced"]
+    pub fn account(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "account")
+    }
+    #[doc = "Invoice date / posting date"]
+    pub fn date(self) -> DateParameter<Invoice> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business Identifier for item"]
+    pub fn identifier(self) -> TokenParameter<Invoice> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Issuing Organization of Invoice"]
+    pub fn issuer(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "issuer")
+    }
+    #[doc = "Individual who was involved"]
+    pub fn participant(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "participant")
+    }
+    #[doc = "Type of involvement in creation of this Invoice"]
+    pub fn participant_role(self) -> TokenParameter<Invoice> {
+        TokenParameter::new(self, "participant-role")
+    }
+    #[doc = "Recipient(s) of goods and services"]
+    pub fn patient(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Recipient of this invoice"]
+    pub fn recipient(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "recipient")
+    }
+    #[doc = "draft | issued | balanced | cancelled | entered-in-error"]
+    pub fn status(self) -> TokenParameter<Invoice> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Recipient(s) of goods and services"]
+    pub fn subject(self) -> ReferenceParameter<Invoice> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Gross total of this Invoice"]
+    pub fn totalgross(self) -> QuantityParameter<Invoice> {
+        QuantityParameter::new(self, "totalgross")
+    }
+    #[doc = "Net total of this Invoice"]
+    pub fn totalnet(self) -> QuantityParameter<Invoice> {
+        QuantityParameter::new(self, "totalnet")
+    }
+    #[doc = "Type of Invoice"]
+    pub fn type_(self) -> TokenParameter<Invoice> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for Library {
+    fn resource_type() -> ResourceType {
+        ResourceType::Library
+    }
+}
+impl SearchQuery<Library> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<Library> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "The type of content in the library (e.g. text/cql)"]
+    pub fn content_type(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "content-type")
+    }
+    #[doc = "A use context assigned to the library"]
+    pub fn context(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the library"]
+    pub fn context_quantity(self) -> QuantityParameter<Library> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the library"]
+    pub fn context_type(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the library"]
+    pub fn context_type_quantity(self) -> CompositeParameter<Library> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the library"]
+    pub fn context_type_value(self) -> CompositeParameter<Library> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The library publication date"]
+    pub fn date(self) -> DateParameter<Library> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<Library> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<Library> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the library"]
+    pub fn description(self) -> StringParameter<Library> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the library is intended to be in use"]
+    pub fn effective(self) -> DateParameter<Library> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the library"]
+    pub fn identifier(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the library"]
+    pub fn jurisdiction(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the library"]
+    pub fn name(self) -> StringParameter<Library> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<Library> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the library"]
+    pub fn publisher(self) -> StringParameter<Library> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the library"]
+    pub fn status(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<Library> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the library"]
+    pub fn title(self) -> StringParameter<Library> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the module"]
+    pub fn topic(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The type of the library (e.g. logic-library, model-definition, asset-collection, module-definition)"]
+    pub fn type_(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The uri that identifies the library"]
+    pub fn url(self) -> UriParameter<Library> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the library"]
+    pub fn version(self) -> TokenParameter<Library> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Linkage {
+    fn resource_type() -> ResourceType {
+        ResourceType::Linkage
+    }
+}
+impl SearchQuery<Linkage> {
+    #[doc = "Author of the Linkage"]
+    pub fn author(self) -> ReferenceParameter<Linkage> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "Matches on any item in the Linkage"]
+    pub fn item(self) -> ReferenceParameter<Linkage> {
+        ReferenceParameter::new(self, "item")
+    }
+    #[doc = "Matches on any item in the Linkage with a type of 'source'"]
+    pub fn source(self) -> ReferenceParameter<Linkage> {
+        ReferenceParameter::new(self, "source")
+    }
+}
+impl Search for List {
+    fn resource_type() -> ResourceType {
+        ResourceType::List
+    }
+}
+impl SearchQuery<List> {
+    #[doc = "What the purpose of this list is"]
+    pub fn code(self) -> TokenParameter<List> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "When the list was prepared"]
+    pub fn date(self) -> DateParameter<List> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Why list is empty"]
+    pub fn empty_reason(self) -> TokenParameter<List> {
+        TokenParameter::new(self, "empty-reason")
+    }
+    #[doc = "Context in which list created"]
+    pub fn encounter(self) -> ReferenceParameter<List> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Business identifier"]
+    pub fn identifier(self) -> TokenParameter<List> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Actual entry"]
+    pub fn item(self) -> ReferenceParameter<List> {
+        ReferenceParameter::new(self, "item")
+    }
+    #[doc = "The annotation  - text content (as markdown)"]
+    pub fn notes(self) -> StringParameter<List> {
+        StringParameter::new(self, "notes")
+    }
+    #[doc = "If all resources have the same subject"]
+    pub fn patient(self) -> ReferenceParameter<List> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who and/or what defined the list contents (aka Author)"]
+    pub fn source(self) -> ReferenceParameter<List> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "current | retired | entered-in-error"]
+    pub fn status(self) -> TokenParameter<List> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "If all resources have the same subject"]
+    pub fn subject(self) -> ReferenceParameter<List> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Descriptive name for the list"]
+    pub fn title(self) -> StringParameter<List> {
+        StringParameter::new(self, "title")
+    }
+}
+impl Search for Location {
+    fn resource_type() -> ResourceType {
+        ResourceType::Location
+    }
+}
+impl SearchQuery<Location> {
+    #[doc = "A (part of the) address of the location"]
+    pub fn address(self) -> StringParameter<Location> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<Location> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<Location> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postal code specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<Location> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<Location> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<Location> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "Technical endpoints providing access to services operated for the location"]
+    pub fn endpoint(self) -> ReferenceParameter<Location> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "An identifier for the location"]
+    pub fn identifier(self) -> TokenParameter<Location> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A portion of the location's name or alias"]
+    pub fn name(self) -> StringParameter<Location> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Search for locations where the location.position is near to, or within a specified distance of, the provided coordinates expressed as [latitude]|[longitude]|[distance]|[units] (using the WGS84 datum, see notes)."]
+    pub fn near(self) -> SpecialParameter<Location> {
+        SpecialParameter::new(self, "near")
+    }
+    #[doc = "Searches for locations (typically bed/room) that have an operational status (e.g. contaminated, housekeeping)"]
+    pub fn operational_status(self) -> TokenParameter<Location> {
+        TokenParameter::new(self, "operational-status")
+    }
+    #[doc = "Searches for locations that are managed by the provided organization"]
+    pub fn organization(self) -> ReferenceParameter<Location> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "A location of which this location is a part"]
+    pub fn partof(self) -> ReferenceParameter<Location> {
+        ReferenceParameter::new(self, "partof")
+    }
+    #[doc = "Searches for locations with a specific kind of status"]
+    pub fn status(self) -> TokenParameter<Location> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "A code for the type of location"]
+    pub fn type_(self) -> TokenParameter<Location> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for Measure {
+    fn resource_type() -> ResourceType {
+        ResourceType::Measure
+    }
+}
+impl SearchQuery<Measure> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<Measure> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the measure"]
+    pub fn context(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the measure"]
+    pub fn context_quantity(self) -> QuantityParameter<Measure> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the measure"]
+    pub fn context_type(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the measure"]
+    pub fn context_type_quantity(self) -> CompositeParameter<Measure> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the measure"]
+    pub fn context_type_value(self) -> CompositeParameter<Measure> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The measure publication date"]
+    pub fn date(self) -> DateParameter<Measure> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<Measure> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<Measure> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the measure"]
+    pub fn description(self) -> StringParameter<Measure> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the measure is intended to be in use"]
+    pub fn effective(self) -> DateParameter<Measure> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the measure"]
+    pub fn identifier(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the measure"]
+    pub fn jurisdiction(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the measure"]
+    pub fn name(self) -> StringParameter<Measure> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<Measure> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the measure"]
+    pub fn publisher(self) -> StringParameter<Measure> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the measure"]
+    pub fn status(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<Measure> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the measure"]
+    pub fn title(self) -> StringParameter<Measure> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the measure"]
+    pub fn topic(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the measure"]
+    pub fn url(self) -> UriParameter<Measure> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the measure"]
+    pub fn version(self) -> TokenParameter<Measure> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for MeasureReport {
+    fn resource_type() -> ResourceType {
+        ResourceType::MeasureReport
+    }
+}
+impl SearchQuery<MeasureReport> {
+    #[doc = "The date of the measure report"]
+    pub fn date(self) -> DateParameter<MeasureReport> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "An evaluated resource referenced by the measure report"]
+    pub fn evaluated_resource(self) -> ReferenceParameter<MeasureReport> {
+        ReferenceParameter::new(self, "evaluated-resource")
+    }
+    #[doc = "External identifier of the measure report to be returned"]
+    pub fn identifier(self) -> TokenParameter<MeasureReport> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The measure to return measure report results for"]
+    pub fn measure(self) -> ReferenceParameter<MeasureReport> {
+        ReferenceParameter::new(self, "measure")
+    }
+    #[doc = "The identity of a patient to search for individual measure report results for"]
+    pub fn patient(self) -> ReferenceParameter<MeasureReport> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The period of the measure report"]
+    pub fn period(self) -> DateParameter<MeasureReport> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "The reporter to return measure report results for"]
+    pub fn reporter(self) -> ReferenceParameter<MeasureReport> {
+        ReferenceParameter::new(self, "reporter")
+    }
+    #[doc = "The status of the measure report"]
+    pub fn status(self) -> TokenParameter<MeasureReport> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of a subject to search for individual measure report results for"]
+    pub fn subject(self) -> ReferenceParameter<MeasureReport> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Media {
+    fn resource_type() -> ResourceType {
+        ResourceType::Media
+    }
+}
+impl SearchQuery<Media> {
+    #[doc = "Procedure that caused this media to be created"]
+    pub fn based_on(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "When Media was collected"]
+    pub fn created(self) -> DateParameter<Media> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "Observing Device"]
+    pub fn device(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "device")
+    }
+    #[doc = "Encounter associated with media"]
+    pub fn encounter(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Identifier(s) for the image"]
+    pub fn identifier(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The type of acquisition equipment/process"]
+    pub fn modality(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "modality")
+    }
+    #[doc = "The person who generated the image"]
+    pub fn operator(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "operator")
+    }
+    #[doc = "Who/What this Media is a record of"]
+    pub fn patient(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Observed body part"]
+    pub fn site(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "site")
+    }
+    #[doc = "preparation | in-progress | not-done | on-hold | stopped | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Who/What this Media is a record of"]
+    pub fn subject(self) -> ReferenceParameter<Media> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Classification of media as image, video, or audio"]
+    pub fn type_(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "Imaging view, e.g. Lateral or Antero-posterior"]
+    pub fn view(self) -> TokenParameter<Media> {
+        TokenParameter::new(self, "view")
+    }
+}
+impl Search for Medication {
+    fn resource_type() -> ResourceType {
+        ResourceType::Medication
+    }
+}
+impl SearchQuery<Medication> {
+    #[doc = "Returns medications for a specific code"]
+    pub fn code(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Returns medications in a batch with this expiration date"]
+    pub fn expiration_date(self) -> DateParameter<Medication> {
+        DateParameter::new(self, "expiration-date")
+    }
+    #[doc = "Returns medications for a specific dose form"]
+    pub fn form(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "form")
+    }
+    #[doc = "Returns medications with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Returns medications for this ingredient reference"]
+    pub fn ingredient(self) -> ReferenceParameter<Medication> {
+        ReferenceParameter::new(self, "ingredient")
+    }
+    #[doc = "Returns medications for this ingredient code"]
+    pub fn ingredient_code(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "ingredient-code")
+    }
+    #[doc = "Returns medications in a batch with this lot number"]
+    pub fn lot_number(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "lot-number")
+    }
+    #[doc = "Returns medications made or sold for this manufacturer"]
+    pub fn manufacturer(self) -> ReferenceParameter<Medication> {
+        ReferenceParameter::new(self, "manufacturer")
+    }
+    #[doc = "Returns medications for this status"]
+    pub fn status(self) -> TokenParameter<Medication> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for MedicationAdministration {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicationAdministration
+    }
+}
+impl SearchQuery<MedicationAdministration> {
+    #[doc = "Return administrations of this medication code"]
+    pub fn code(self) -> TokenParameter<MedicationAdministration> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Return administrations that share this encounter or episode of care"]
+    pub fn context(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "context")
+    }
+    #[doc = "Return administrations with this administration device identity"]
+    pub fn device(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "device")
+    }
+    #[doc = "Date administration happened (or did not happen)"]
+    pub fn effective_time(self) -> DateParameter<MedicationAdministration> {
+        DateParameter::new(self, "effective-time")
+    }
+    #[doc = "Return administrations with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<MedicationAdministration> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Return administrations of this medication resource"]
+    pub fn medication(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "medication")
+    }
+    #[doc = "The identity of a patient to list administrations  for"]
+    pub fn patient(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The identity of the individual who administered the medication"]
+    pub fn performer(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Reasons for administering the medication"]
+    pub fn reason_given(self) -> TokenParameter<MedicationAdministration> {
+        TokenParameter::new(self, "reason-given")
+    }
+    #[doc = "Reasons for not administering the medication"]
+    pub fn reason_not_given(self) -> TokenParameter<MedicationAdministration> {
+        TokenParameter::new(self, "reason-not-given")
+    }
+    #[doc = "The identity of a request to list administrations from"]
+    pub fn request(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "MedicationAdministration event status (for example one of active/paused/completed/nullified)"]
+    pub fn status(self) -> TokenParameter<MedicationAdministration> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of the individual or group to list administrations for"]
+    pub fn subject(self) -> ReferenceParameter<MedicationAdministration> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicationDispense {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicationDispense
+    }
+}
+impl SearchQuery<MedicationDispense> {
+    #[doc = "Returns dispenses of this medicine code"]
+    pub fn code(self) -> TokenParameter<MedicationDispense> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Returns dispenses with a specific context (episode or episode of care)"]
+    pub fn context(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "context")
+    }
+    #[doc = "Returns dispenses that should be sent to a specific destination"]
+    pub fn destination(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "destination")
+    }
+    #[doc = "Returns dispenses with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<MedicationDispense> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Returns dispenses of this medicine resource"]
+    pub fn medication(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "medication")
+    }
+    #[doc = "The identity of a patient to list dispenses  for"]
+    pub fn patient(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Returns dispenses performed by a specific individual"]
+    pub fn performer(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "The identity of a prescription to list dispenses from"]
+    pub fn prescription(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "prescription")
+    }
+    #[doc = "The identity of a receiver to list dispenses for"]
+    pub fn receiver(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "receiver")
+    }
+    #[doc = "Returns dispenses with the specified responsible party"]
+    pub fn responsibleparty(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "responsibleparty")
+    }
+    #[doc = "Returns dispenses with a specified dispense status"]
+    pub fn status(self) -> TokenParameter<MedicationDispense> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of a patient for whom to list dispenses"]
+    pub fn subject(self) -> ReferenceParameter<MedicationDispense> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Returns dispenses of a specific type"]
+    pub fn type_(self) -> TokenParameter<MedicationDispense> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "Returns dispenses handed over on this date"]
+    pub fn whenhandedover(self) -> DateParameter<MedicationDispense> {
+        DateParameter::new(self, "whenhandedover")
+    }
+    #[doc = "Returns dispenses prepared on this date"]
+    pub fn whenprepared(self) -> DateParameter<MedicationDispense> {
+        DateParameter::new(self, "whenprepared")
+    }
+}
+impl Search for MedicationKnowledge {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicationKnowledge
+    }
+}
+impl SearchQuery<MedicationKnowledge> {
+    #[doc = "Specific category assigned to the medication"]
+    pub fn classification(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "classification")
+    }
+    #[doc = "The type of category for the medication (for example, therapeutic classification, therapeutic sub-classification)"]
+    pub fn classification_type(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "classification-type")
+    }
+    #[doc = "Code that identifies this medication"]
+    pub fn code(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "powder | tablets | capsule +"]
+    pub fn doseform(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "doseform")
+    }
+    #[doc = "Medication(s) or substance(s) contained in the medication"]
+    pub fn ingredient(self) -> ReferenceParameter<MedicationKnowledge> {
+        ReferenceParameter::new(self, "ingredient")
+    }
+    #[doc = "Medication(s) or substance(s) contained in the medication"]
+    pub fn ingredient_code(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "ingredient-code")
+    }
+    #[doc = "Manufacturer of the item"]
+    pub fn manufacturer(self) -> ReferenceParameter<MedicationKnowledge> {
+        ReferenceParameter::new(self, "manufacturer")
+    }
+    #[doc = "Name of the reviewing program"]
+    pub fn monitoring_program_name(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "monitoring-program-name")
+    }
+    #[doc = "Type of program under which the medication is monitored"]
+    pub fn monitoring_program_type(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "monitoring-program-type")
+    }
+    #[doc = "Associated documentation about the medication"]
+    pub fn monograph(self) -> ReferenceParameter<MedicationKnowledge> {
+        ReferenceParameter::new(self, "monograph")
+    }
+    #[doc = "The category of medication document"]
+    pub fn monograph_type(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "monograph-type")
+    }
+    #[doc = "The source or owner for the price information"]
+    pub fn source_cost(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "source-cost")
+    }
+    #[doc = "active | inactive | entered-in-error"]
+    pub fn status(self) -> TokenParameter<MedicationKnowledge> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for MedicationRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicationRequest
+    }
+}
+impl SearchQuery<MedicationRequest> {
+    #[doc = "Return prescriptions written on this date"]
+    pub fn authoredon(self) -> DateParameter<MedicationRequest> {
+        DateParameter::new(self, "authoredon")
+    }
+    #[doc = "Returns prescriptions with different categories"]
+    pub fn category(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Return prescriptions of this medication code"]
+    pub fn code(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Returns medication request to be administered on a specific date"]
+    pub fn date(self) -> DateParameter<MedicationRequest> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Return prescriptions with this encounter identifier"]
+    pub fn encounter(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Return prescriptions with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Returns prescriptions intended to be dispensed by this Organization"]
+    pub fn intended_dispenser(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "intended-dispenser")
+    }
+    #[doc = "Returns the intended performer of the administration of the medication request"]
+    pub fn intended_performer(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "intended-performer")
+    }
+    #[doc = "Returns requests for a specific type of performer"]
+    pub fn intended_performertype(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "intended-performertype")
+    }
+    #[doc = "Returns prescriptions with different intents"]
+    pub fn intent(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "Return prescriptions for this medication reference"]
+    pub fn medication(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "medication")
+    }
+    #[doc = "Returns prescriptions for a specific patient"]
+    pub fn patient(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Returns prescriptions with different priorities"]
+    pub fn priority(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "Returns prescriptions prescribed by this prescriber"]
+    pub fn requester(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "Status of the prescription"]
+    pub fn status(self) -> TokenParameter<MedicationRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of a patient to list orders  for"]
+    pub fn subject(self) -> ReferenceParameter<MedicationRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicationStatement {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicationStatement
+    }
+}
+impl SearchQuery<MedicationStatement> {
+    #[doc = "Returns statements of this category of medicationstatement"]
+    pub fn category(self) -> TokenParameter<MedicationStatement> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "Return statements of this medication code"]
+    pub fn code(self) -> TokenParameter<MedicationStatement> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Returns statements for a specific context (episode or episode of Care)."]
+    pub fn context(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "context")
+    }
+    #[doc = "Date when patient was taking (or not taking) the medication"]
+    pub fn effective(self) -> DateParameter<MedicationStatement> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "Return statements with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<MedicationStatement> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Return statements of this medication reference"]
+    pub fn medication(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "medication")
+    }
+    #[doc = "Returns statements that are part of another event."]
+    pub fn part_of(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Returns statements for a specific patient."]
+    pub fn patient(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who or where the information in the statement came from"]
+    pub fn source(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "Return statements that match the given status"]
+    pub fn status(self) -> TokenParameter<MedicationStatement> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The identity of a patient, animal or group to list statements for"]
+    pub fn subject(self) -> ReferenceParameter<MedicationStatement> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProduct {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProduct
+    }
+}
+impl SearchQuery<MedicinalProduct> {
+    #[doc = "Business identifier for this product. Could be an MPID"]
+    pub fn identifier(self) -> TokenParameter<MedicinalProduct> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The full product name"]
+    pub fn name(self) -> StringParameter<MedicinalProduct> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Language code for this name"]
+    pub fn name_language(self) -> TokenParameter<MedicinalProduct> {
+        TokenParameter::new(self, "name-language")
+    }
+}
+impl Search for MedicinalProductAuthorization {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductAuthorization
+    }
+}
+impl SearchQuery<MedicinalProductAuthorization> {
+    #[doc = "The country in which the marketing authorization has been granted"]
+    pub fn country(self) -> TokenParameter<MedicinalProductAuthorization> {
+        TokenParameter::new(self, "country")
+    }
+    #[doc = "Marketing Authorization Holder"]
+    pub fn holder(self) -> ReferenceParameter<MedicinalProductAuthorization> {
+        ReferenceParameter::new(self, "holder")
+    }
+    #[doc = "Business identifier for the marketing authorization, as assigned by a regulator"]
+    pub fn identifier(self) -> TokenParameter<MedicinalProductAuthorization> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The status of the marketing authorization"]
+    pub fn status(self) -> TokenParameter<MedicinalProductAuthorization> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The medicinal product that is being authorized"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductAuthorization> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProductContraindication {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductContraindication
+    }
+}
+impl SearchQuery<MedicinalProductContraindication> {
+    #[doc = "The medication for which this is an contraindication"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductContraindication> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProductIndication {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductIndication
+    }
+}
+impl SearchQuery<MedicinalProductIndication> {
+    #[doc = "The medication for which this is an indication"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductIndication> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProductInteraction {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductInteraction
+    }
+}
+impl SearchQuery<MedicinalProductInteraction> {
+    #[doc = "The medication for which this is an interaction"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductInteraction> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProductPackaged {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductPackaged
+    }
+}
+impl SearchQuery<MedicinalProductPackaged> {
+    #[doc = "Unique identifier"]
+    pub fn identifier(self) -> TokenParameter<MedicinalProductPackaged> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The product with this is a pack for"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductPackaged> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for MedicinalProductPharmaceutical {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductPharmaceutical
+    }
+}
+impl SearchQuery<MedicinalProductPharmaceutical> {
+    #[doc = "An identifier for the pharmaceutical medicinal product"]
+    pub fn identifier(self) -> TokenParameter<MedicinalProductPharmaceutical> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Coded expression for the route"]
+    pub fn route(self) -> TokenParameter<MedicinalProductPharmaceutical> {
+        TokenParameter::new(self, "route")
+    }
+    #[doc = "Coded expression for the species"]
+    pub fn target_species(self) -> TokenParameter<MedicinalProductPharmaceutical> {
+        TokenParameter::new(self, "target-species")
+    }
+}
+impl Search for MedicinalProductUndesirableEffect {
+    fn resource_type() -> ResourceType {
+        ResourceType::MedicinalProductUndesirableEffect
+    }
+}
+impl SearchQuery<MedicinalProductUndesirableEffect> {
+    #[doc = "The medication for which this is an undesirable effect"]
+    pub fn subject(self) -> ReferenceParameter<MedicinalProductUndesirableEffect> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Membership {
+    fn resource_type() -> ResourceType {
+        ResourceType::Membership
+    }
+}
+impl SearchQuery<Membership> {
+    #[doc = "What/Who is associated with a given Membership."]
+    pub fn link(self) -> ReferenceParameter<Membership> {
+        ReferenceParameter::new(self, "link")
+    }
+    #[doc = "Membership user"]
+    pub fn user(self) -> ReferenceParameter<Membership> {
+        ReferenceParameter::new(self, "user")
+    }
+}
+impl Search for MessageDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::MessageDefinition
+    }
+}
+impl SearchQuery<MessageDefinition> {
+    #[doc = "The behavior associated with the message"]
+    pub fn category(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "A use context assigned to the message definition"]
+    pub fn context(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the message definition"]
+    pub fn context_quantity(self) -> QuantityParameter<MessageDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the message definition"]
+    pub fn context_type(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the message definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<MessageDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the message definition"]
+    pub fn context_type_value(self) -> CompositeParameter<MessageDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The message definition publication date"]
+    pub fn date(self) -> DateParameter<MessageDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the message definition"]
+    pub fn description(self) -> StringParameter<MessageDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The event that triggers the message or link to the event definition."]
+    pub fn event(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "event")
+    }
+    #[doc = "A resource that is a permitted focus of the message"]
+    pub fn focus(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "focus")
+    }
+    #[doc = "External identifier for the message definition"]
+    pub fn identifier(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the message definition"]
+    pub fn jurisdiction(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the message definition"]
+    pub fn name(self) -> StringParameter<MessageDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "A resource that is the parent of the definition"]
+    pub fn parent(self) -> ReferenceParameter<MessageDefinition> {
+        ReferenceParameter::new(self, "parent")
+    }
+    #[doc = "Name of the publisher of the message definition"]
+    pub fn publisher(self) -> StringParameter<MessageDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the message definition"]
+    pub fn status(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the message definition"]
+    pub fn title(self) -> StringParameter<MessageDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the message definition"]
+    pub fn url(self) -> UriParameter<MessageDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the message definition"]
+    pub fn version(self) -> TokenParameter<MessageDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for MessageHeader {
+    fn resource_type() -> ResourceType {
+        ResourceType::MessageHeader
+    }
+}
+impl SearchQuery<MessageHeader> {
+    #[doc = "The source of the decision"]
+    pub fn author(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "ok | transient-error | fatal-error"]
+    pub fn code(self) -> TokenParameter<MessageHeader> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Name of system"]
+    pub fn destination(self) -> StringParameter<MessageHeader> {
+        StringParameter::new(self, "destination")
+    }
+    #[doc = "Actual destination address or id"]
+    pub fn destination_uri(self) -> UriParameter<MessageHeader> {
+        UriParameter::new(self, "destination-uri")
+    }
+    #[doc = "The source of the data entry"]
+    pub fn enterer(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "enterer")
+    }
+    #[doc = "Code for the event this message represents or link to event definition"]
+    pub fn event(self) -> TokenParameter<MessageHeader> {
+        TokenParameter::new(self, "event")
+    }
+    #[doc = "The actual content of the message"]
+    pub fn focus(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "focus")
+    }
+    #[doc = "Intended \"real-world\" recipient for the data"]
+    pub fn receiver(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "receiver")
+    }
+    #[doc = "Id of original message"]
+    pub fn response_id(self) -> TokenParameter<MessageHeader> {
+        TokenParameter::new(self, "response-id")
+    }
+    #[doc = "Final responsibility for event"]
+    pub fn responsible(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "responsible")
+    }
+    #[doc = "Real world sender of the message"]
+    pub fn sender(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "sender")
+    }
+    #[doc = "Name of system"]
+    pub fn source(self) -> StringParameter<MessageHeader> {
+        StringParameter::new(self, "source")
+    }
+    #[doc = "Actual message source address or id"]
+    pub fn source_uri(self) -> UriParameter<MessageHeader> {
+        UriParameter::new(self, "source-uri")
+    }
+    #[doc = "Particular delivery destination within the destination"]
+    pub fn target(self) -> ReferenceParameter<MessageHeader> {
+        ReferenceParameter::new(self, "target")
+    }
+}
+impl Search for MolecularSequence {
+    fn resource_type() -> ResourceType {
+        ResourceType::MolecularSequence
+    }
+}
+impl SearchQuery<MolecularSequence> {
+    #[doc = "Chromosome number of the reference sequence"]
+    pub fn chromosome(self) -> TokenParameter<MolecularSequence> {
+        TokenParameter::new(self, "chromosome")
+    }
+    #[doc = "Search parameter by chromosome and variant coordinate. This will refer to part of a locus or part of a gene where search region will be represented in 1-based system. Since the coordinateSystem can either be 0-based or 1-based, this search query will include the result of both coordinateSystem that contains the equivalent segment of the gene or whole genome sequence. For example, a search for sequence can be represented as `chromosome-variant-coordinate=1$lt345$gt123`, this means it will search for the MolecularSequence resource with variants on chromosome 1 and with position >123 and <345, where in 1-based system resource, all strings within region 1:124-344 will be revealed, while in 0-based system resource, all strings within region 1:123-344 will be revealed. You may want to check detail about 0-based v.s. 1-based above."]
+    pub fn chromosome_variant_coordinate(self) -> CompositeParameter<MolecularSequence> {
+        CompositeParameter::new(self, "chromosome-variant-coordinate")
+    }
+    #[doc = "Search parameter by chromosome and window. This will refer to part of a locus or part of a gene where search region will be represented in 1-based system. Since the coordinateSystem can either be 0-based or 1-based, this search query will include the result of both coordinateSystem that contains the equivalent segment of the gene or whole genome sequence. For example, a search for sequence can be represented as `chromosome-window-coordinate=1$lt345$gt123`, this means it will search for the MolecularSequence resource with a window on chromosome 1 and with position >123 and <345, where in 1-based system resource, all strings within region 1:124-344 will be revealed, while in 0-based system resource, all strings within region 1:123-344 will be revealed. You may want to check detail about 0-based v.s. 1-based above."]
+    pub fn chromosome_window_coordinate(self) -> CompositeParameter<MolecularSequence> {
+        CompositeParameter::new(self, "chromosome-window-coordinate")
+    }
+    #[doc = "The unique identity for a particular sequence"]
+    pub fn identifier(self) -> TokenParameter<MolecularSequence> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The subject that the observation is about"]
+    pub fn patient(self) -> ReferenceParameter<MolecularSequence> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Reference Sequence of the sequence"]
+    pub fn referenceseqid(self) -> TokenParameter<MolecularSequence> {
+        TokenParameter::new(self, "referenceseqid")
+    }
+    #[doc = "Search parameter by reference sequence and variant coordinate. This will refer to part of a locus or part of a gene where search region will be represented in 1-based system. Since the coordinateSystem can either be 0-based or 1-based, this search query will include the result of both coordinateSystem that contains the equivalent segment of the gene or whole genome sequence. For example, a search for sequence can be represented as `referenceSeqId-variant-coordinate=NC_000001.11$lt345$gt123`, this means it will search for the MolecularSequence resource with variants on NC_000001.11 and with position >123 and <345, where in 1-based system resource, all strings within region NC_000001.11:124-344 will be revealed, while in 0-based system resource, all strings within region NC_000001.11:123-344 will be revealed. You may want to check detail about 0-based v.s. 1-based above."]
+    pub fn referenceseqid_variant_coordinate(self) -> CompositeParameter<MolecularSequence> {
+        CompositeParameter::new(self, "referenceseqid-variant-coordinate")
+    }
+    #[doc = "Search parameter by reference sequence and window. This will refer to part of a locus or part of a gene where search region will be represented in 1-based system. Since the coordinateSystem can either be 0-based or 1-based, this search query will include the result of both coordinateSystem that contains the equivalent segment of the gene or whole genome sequence. For example, a search for sequence can be represented as `referenceSeqId-window-coordinate=NC_000001.11$lt345$gt123`, this means it will search for the MolecularSequence resource with a window on NC_000001.11 and with position >123 and <345, where in 1-based system resource, all strings within region NC_000001.11:124-344 will be revealed, while in 0-based system resource, all strings within region NC_000001.11:123-344 will be revealed. You may want to check detail about 0-based v.s. 1-based above."]
+    pub fn referenceseqid_window_coordinate(self) -> CompositeParameter<MolecularSequence> {
+        CompositeParameter::new(self, "referenceseqid-window-coordinate")
+    }
+    #[doc = "Amino Acid Sequence/ DNA Sequence / RNA Sequence"]
+    pub fn type_(self) -> TokenParameter<MolecularSequence> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "End position (0-based exclusive, which menas the acid at this position will not be included, 1-based inclusive, which means the acid at this position will be included) of the variant."]
+    pub fn variant_end(self) -> NumberParameter<MolecularSequence> {
+        NumberParameter::new(self, "variant-end")
+    }
+    #[doc = "Start position (0-based inclusive, 1-based inclusive, that means the nucleic acid or amino acid at this position will be included) of the variant."]
+    pub fn variant_start(self) -> NumberParameter<MolecularSequence> {
+        NumberParameter::new(self, "variant-start")
+    }
+    #[doc = "End position (0-based exclusive, which menas the acid at this position will not be included, 1-based inclusive, which means the acid at this position will be included) of the reference sequence."]
+    pub fn window_end(self) -> NumberParameter<MolecularSequence> {
+        NumberParameter::new(self, "window-end")
+    }
+    #[doc = "Start position (0-based inclusive, 1-based inclusive, that means the nucleic acid or amino acid at this position will be included) of the reference sequence."]
+    pub fn window_start(self) -> NumberParameter<MolecularSequence> {
+        NumberParameter::new(self, "window-start")
+    }
+}
+impl Search for NamingSystem {
+    fn resource_type() -> ResourceType {
+        ResourceType::NamingSystem
+    }
+}
+impl SearchQuery<NamingSystem> {
+    #[doc = "Name of an individual to contact"]
+    pub fn contact(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "contact")
+    }
+    #[doc = "A use context assigned to the naming system"]
+    pub fn context(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the naming system"]
+    pub fn context_quantity(self) -> QuantityParameter<NamingSystem> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the naming system"]
+    pub fn context_type(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the naming system"]
+    pub fn context_type_quantity(self) -> CompositeParameter<NamingSystem> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the naming system"]
+    pub fn context_type_value(self) -> CompositeParameter<NamingSystem> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The naming system publication date"]
+    pub fn date(self) -> DateParameter<NamingSystem> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the naming system"]
+    pub fn description(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "oid | uuid | uri | other"]
+    pub fn id_type(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "id-type")
+    }
+    #[doc = "Intended jurisdiction for the naming system"]
+    pub fn jurisdiction(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "codesystem | identifier | root"]
+    pub fn kind(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "kind")
+    }
+    #[doc = "Computationally friendly name of the naming system"]
+    pub fn name(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "When is identifier valid?"]
+    pub fn period(self) -> DateParameter<NamingSystem> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "Name of the publisher of the naming system"]
+    pub fn publisher(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "Who maintains system namespace?"]
+    pub fn responsible(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "responsible")
+    }
+    #[doc = "The current status of the naming system"]
+    pub fn status(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Contact details for individual or organization"]
+    pub fn telecom(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "telecom")
+    }
+    #[doc = "e.g. driver,  provider,  patient, bank etc."]
+    pub fn type_(self) -> TokenParameter<NamingSystem> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The unique identifier"]
+    pub fn value(self) -> StringParameter<NamingSystem> {
+        StringParameter::new(self, "value")
+    }
+}
+impl Search for NutritionOrder {
+    fn resource_type() -> ResourceType {
+        ResourceType::NutritionOrder
+    }
+}
+impl SearchQuery<NutritionOrder> {
+    #[doc = "Type of module component to add to the feeding"]
+    pub fn additive(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "additive")
+    }
+    #[doc = "Return nutrition orders requested on this date"]
+    pub fn datetime(self) -> DateParameter<NutritionOrder> {
+        DateParameter::new(self, "datetime")
+    }
+    #[doc = "Return nutrition orders with this encounter identifier"]
+    pub fn encounter(self) -> ReferenceParameter<NutritionOrder> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Type of enteral or infant formula"]
+    pub fn formula(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "formula")
+    }
+    #[doc = "Return nutrition orders with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<NutritionOrder> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<NutritionOrder> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "Type of diet that can be consumed orally (i.e., take via the mouth)."]
+    pub fn oraldiet(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "oraldiet")
+    }
+    #[doc = "The identity of the person who requires the diet, formula or nutritional supplement"]
+    pub fn patient(self) -> ReferenceParameter<NutritionOrder> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The identity of the provider who placed the nutrition order"]
+    pub fn provider(self) -> ReferenceParameter<NutritionOrder> {
+        ReferenceParameter::new(self, "provider")
+    }
+    #[doc = "Status of the nutrition order."]
+    pub fn status(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Type of supplement product requested"]
+    pub fn supplement(self) -> TokenParameter<NutritionOrder> {
+        TokenParameter::new(self, "supplement")
+    }
+}
+impl Search for Observation {
+    fn resource_type() -> ResourceType {
+        ResourceType::Observation
+    }
+}
+impl SearchQuery<Observation> {
+    #[doc = "Reference to the service request."]
+    pub fn based_on(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "The classification of the type of observation"]
+    pub fn category(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "The code of the observation type"]
+    pub fn code(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Code and coded value parameter pair"]
+    pub fn code_value_concept(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "code-value-concept")
+    }
+    #[doc = "Code and date/time value parameter pair"]
+    pub fn code_value_date(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "code-value-date")
+    }
+    #[doc = "Code and quantity value parameter pair"]
+    pub fn code_value_quantity(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "code-value-quantity")
+    }
+    #[doc = "Code and string value parameter pair"]
+    pub fn code_value_string(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "code-value-string")
+    }
+    #[doc = "The code of the observation type or component type"]
+    pub fn combo_code(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "combo-code")
+    }
+    #[doc = "Code and coded value parameter pair, including in components"]
+    pub fn combo_code_value_concept(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "combo-code-value-concept")
+    }
+    #[doc = "Code and quantity value parameter pair, including in components"]
+    pub fn combo_code_value_quantity(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "combo-code-value-quantity")
+    }
+    #[doc = "The reason why the expected value in the element Observation.value[x] or Observation.component.value[x] is missing."]
+    pub fn combo_data_absent_reason(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "combo-data-absent-reason")
+    }
+    #[doc = "The value or component value of the observation, if the value is a CodeableConcept"]
+    pub fn combo_value_concept(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "combo-value-concept")
+    }
+    #[doc = "The value or component value of the observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)"]
+    pub fn combo_value_quantity(self) -> QuantityParameter<Observation> {
+        QuantityParameter::new(self, "combo-value-quantity")
+    }
+    #[doc = "The component code of the observation type"]
+    pub fn component_code(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "component-code")
+    }
+    #[doc = "Component code and component coded value parameter pair"]
+    pub fn component_code_value_concept(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "component-code-value-concept")
+    }
+    #[doc = "Component code and component quantity value parameter pair"]
+    pub fn component_code_value_quantity(self) -> CompositeParameter<Observation> {
+        CompositeParameter::new(self, "component-code-value-quantity")
+    }
+    #[doc = "The reason why the expected value in the element Observation.component.value[x] is missing."]
+    pub fn component_data_absent_reason(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "component-data-absent-reason")
+    }
+    #[doc = "The value of the component observation, if the value is a CodeableConcept"]
+    pub fn component_value_concept(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "component-value-concept")
+    }
+    #[doc = "The value of the component observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)"]
+    pub fn component_value_quantity(self) -> QuantityParameter<Observation> {
+        QuantityParameter::new(self, "component-value-quantity")
+    }
+    #[doc = "The reason why the expected value in the element Observation.value[x] is missing."]
+    pub fn data_absent_reason(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "data-absent-reason")
+    }
+    #[doc = "Obtained date/time. If the obtained element is a period, a date that falls in the period"]
+    pub fn date(self) -> DateParameter<Observation> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Related measurements the observation is made from"]
+    pub fn derived_from(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The Device that generated the observation data."]
+    pub fn device(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "device")
+    }
+    #[doc = "Encounter related to the observation"]
+    pub fn encounter(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The focus of an observation when the focus is not the patient of record."]
+    pub fn focus(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "focus")
+    }
+    #[doc = "Related resource that belongs to the Observation group"]
+    pub fn has_member(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "has-member")
+    }
+    #[doc = "The unique id for a particular observation"]
+    pub fn identifier(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The method used for the observation"]
+    pub fn method(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "method")
+    }
+    #[doc = "Part of referenced event"]
+    pub fn part_of(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "The subject that the observation is about (if patient)"]
+    pub fn patient(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who performed the observation"]
+    pub fn performer(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Specimen used for this observation"]
+    pub fn specimen(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "specimen")
+    }
+    #[doc = "The status of the observation"]
+    pub fn status(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject that the observation is about"]
+    pub fn subject(self) -> ReferenceParameter<Observation> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "The value of the observation, if the value is a CodeableConcept"]
+    pub fn value_concept(self) -> TokenParameter<Observation> {
+        TokenParameter::new(self, "value-concept")
+    }
+    #[doc = "The value of the observation, if the value is a date or period of time"]
+    pub fn value_date(self) -> DateParameter<Observation> {
+        DateParameter::new(self, "value-date")
+    }
+    #[doc = "The value of the observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)"]
+    pub fn value_quantity(self) -> QuantityParameter<Observation> {
+        QuantityParameter::new(self, "value-quantity")
+    }
+    #[doc = "The value of the observation, if the value is a string, and also searches in CodeableConcept.text"]
+    pub fn value_string(self) -> StringParameter<Observation> {
+        StringParameter::new(self, "value-string")
+    }
+}
+impl Search for OperationDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::OperationDefinition
+    }
+}
+impl SearchQuery<OperationDefinition> {
+    #[doc = "Marks this as a profile of the base"]
+    pub fn base(self) -> ReferenceParameter<OperationDefinition> {
+        ReferenceParameter::new(self, "base")
+    }
+    #[doc = "Name used to invoke the operation"]
+    pub fn code(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "A use context assigned to the operation definition"]
+    pub fn context(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the operation definition"]
+    pub fn context_quantity(self) -> QuantityParameter<OperationDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the operation definition"]
+    pub fn context_type(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the operation definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<OperationDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the operation definition"]
+    pub fn context_type_value(self) -> CompositeParameter<OperationDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The operation definition publication date"]
+    pub fn date(self) -> DateParameter<OperationDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the operation definition"]
+    pub fn description(self) -> StringParameter<OperationDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Validation information for in parameters"]
+    pub fn input_profile(self) -> ReferenceParameter<OperationDefinition> {
+        ReferenceParameter::new(self, "input-profile")
+    }
+    #[doc = "Invoke on an instance?"]
+    pub fn instance(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "instance")
+    }
+    #[doc = "Intended jurisdiction for the operation definition"]
+    pub fn jurisdiction(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "operation | query"]
+    pub fn kind(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "kind")
+    }
+    #[doc = "Computationally friendly name of the operation definition"]
+    pub fn name(self) -> StringParameter<OperationDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Validation information for out parameters"]
+    pub fn output_profile(self) -> ReferenceParameter<OperationDefinition> {
+        ReferenceParameter::new(self, "output-profile")
+    }
+    #[doc = "Name of the publisher of the operation definition"]
+    pub fn publisher(self) -> StringParameter<OperationDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the operation definition"]
+    pub fn status(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Invoke at the system level?"]
+    pub fn system(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "system")
+    }
+    #[doc = "The human-friendly name of the operation definition"]
+    pub fn title(self) -> StringParameter<OperationDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Invoke at the type level?"]
+    pub fn type_(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The uri that identifies the operation definition"]
+    pub fn url(self) -> UriParameter<OperationDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the operation definition"]
+    pub fn version(self) -> TokenParameter<OperationDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Organization {
+    fn resource_type() -> ResourceType {
+        ResourceType::Organization
+    }
+}
+impl SearchQuery<Organization> {
+    #[doc = "Is the Organization record active"]
+    pub fn active(self) -> TokenParameter<Organization> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postal code specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<Organization> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "Technical endpoints providing access to services operated for the organization"]
+    pub fn endpoint(self) -> ReferenceParameter<Organization> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "Any identifier for the organization (not the accreditation issuer's identifier)"]
+    pub fn identifier(self) -> TokenParameter<Organization> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A portion of the organization's name or alias"]
+    pub fn name(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "An organization of which this organization forms a part"]
+    pub fn partof(self) -> ReferenceParameter<Organization> {
+        ReferenceParameter::new(self, "partof")
+    }
+    #[doc = "A portion of the organization's name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<Organization> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "A code for the type of organization"]
+    pub fn type_(self) -> TokenParameter<Organization> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for OrganizationAffiliation {
+    fn resource_type() -> ResourceType {
+        ResourceType::OrganizationAffiliation
+    }
+}
+impl SearchQuery<OrganizationAffiliation> {
+    #[doc = "Whether this organization affiliation record is in active use"]
+    pub fn active(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "The period during which the participatingOrganization is affiliated with the primary organization"]
+    pub fn date(self) -> DateParameter<OrganizationAffiliation> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "Technical endpoints providing access to services operated for this role"]
+    pub fn endpoint(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "An organization affiliation's Identifier"]
+    pub fn identifier(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The location(s) at which the role occurs"]
+    pub fn location(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Health insurance provider network in which the participatingOrganization provides the role's services (if defined) at the indicated locations (if defined)"]
+    pub fn network(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "network")
+    }
+    #[doc = "The organization that provides services to the primary organization"]
+    pub fn participating_organization(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "participating-organization")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "The organization that receives the services from the participating organization"]
+    pub fn primary_organization(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "primary-organization")
+    }
+    #[doc = "Definition of the role the participatingOrganization plays"]
+    pub fn role(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "role")
+    }
+    #[doc = "Healthcare services provided through the role"]
+    pub fn service(self) -> ReferenceParameter<OrganizationAffiliation> {
+        ReferenceParameter::new(self, "service")
+    }
+    #[doc = "Specific specialty of the participatingOrganization in the context of the role"]
+    pub fn specialty(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "specialty")
+    }
+    #[doc = "The value in any kind of contact"]
+    pub fn telecom(self) -> TokenParameter<OrganizationAffiliation> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for Patient {
+    fn resource_type() -> ResourceType {
+        ResourceType::Patient
+    }
+}
+impl SearchQuery<Patient> {
+    #[doc = "Whether the patient record is active"]
+    pub fn active(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postalCode specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "The patient's date of birth"]
+    pub fn birthdate(self) -> DateParameter<Patient> {
+        DateParameter::new(self, "birthdate")
+    }
+    #[doc = "The date of death has been provided and satisfies this search value"]
+    pub fn death_date(self) -> DateParameter<Patient> {
+        DateParameter::new(self, "death-date")
+    }
+    #[doc = "This patient has been marked as deceased, or as a death date entered"]
+    pub fn deceased(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "deceased")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "A portion of the family name of the patient"]
+    pub fn family(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "family")
+    }
+    #[doc = "Gender of the patient"]
+    pub fn gender(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "gender")
+    }
+    #[doc = "Patient's nominated general practitioner, not the organization that manages the record"]
+    pub fn general_practitioner(self) -> ReferenceParameter<Patient> {
+        ReferenceParameter::new(self, "general-practitioner")
+    }
+    #[doc = "A portion of the given name of the patient"]
+    pub fn given(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "given")
+    }
+    #[doc = "A patient identifier"]
+    pub fn identifier(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Language code (irrespective of use value)"]
+    pub fn language(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "language")
+    }
+    #[doc = "All patients linked to the given patient"]
+    pub fn link(self) -> ReferenceParameter<Patient> {
+        ReferenceParameter::new(self, "link")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the HumanName, including family, give, prefix, suffix, suffix, and/or text"]
+    pub fn name(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "The organization that is the custodian of the patient record"]
+    pub fn organization(self) -> ReferenceParameter<Patient> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "A portion of either family or given name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<Patient> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "The value in any kind of telecom details of the patient"]
+    pub fn telecom(self) -> TokenParameter<Patient> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for PaymentNotice {
+    fn resource_type() -> ResourceType {
+        ResourceType::PaymentNotice
+    }
+}
+impl SearchQuery<PaymentNotice> {
+    #[doc = "Creation date fro the notice"]
+    pub fn created(self) -> DateParameter<PaymentNotice> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "The business identifier of the notice"]
+    pub fn identifier(self) -> TokenParameter<PaymentNotice> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The type of payment notice"]
+    pub fn payment_status(self) -> TokenParameter<PaymentNotice> {
+        TokenParameter::new(self, "payment-status")
+    }
+    #[doc = "The reference to the provider"]
+    pub fn provider(self) -> ReferenceParameter<PaymentNotice> {
+        ReferenceParameter::new(self, "provider")
+    }
+    #[doc = "The Claim"]
+    pub fn request(self) -> ReferenceParameter<PaymentNotice> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "The ClaimResponse"]
+    pub fn response(self) -> ReferenceParameter<PaymentNotice> {
+        ReferenceParameter::new(self, "response")
+    }
+    #[doc = "The status of the payment notice"]
+    pub fn status(self) -> TokenParameter<PaymentNotice> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for PaymentReconciliation {
+    fn resource_type() -> ResourceType {
+        ResourceType::PaymentReconciliation
+    }
+}
+impl SearchQuery<PaymentReconciliation> {
+    #[doc = "The creation date"]
+    pub fn created(self) -> DateParameter<PaymentReconciliation> {
+        DateParameter::new(self, "created")
+    }
+    #[doc = "The contents of the disposition message"]
+    pub fn disposition(self) -> StringParameter<PaymentReconciliation> {
+        StringParameter::new(self, "disposition")
+    }
+    #[doc = "The business identifier of the ExplanationOfBenefit"]
+    pub fn identifier(self) -> TokenParameter<PaymentReconciliation> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The processing outcome"]
+    pub fn outcome(self) -> TokenParameter<PaymentReconciliation> {
+        TokenParameter::new(self, "outcome")
+    }
+    #[doc = "The organization which generated this resource"]
+    pub fn payment_issuer(self) -> ReferenceParameter<PaymentReconciliation> {
+        ReferenceParameter::new(self, "payment-issuer")
+    }
+    #[doc = "The reference to the claim"]
+    pub fn request(self) -> ReferenceParameter<PaymentReconciliation> {
+        ReferenceParameter::new(self, "request")
+    }
+    #[doc = "The reference to the provider who submitted the claim"]
+    pub fn requestor(self) -> ReferenceParameter<PaymentReconciliation> {
+        ReferenceParameter::new(self, "requestor")
+    }
+    #[doc = "The status of the payment reconciliation"]
+    pub fn status(self) -> TokenParameter<PaymentReconciliation> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for Person {
+    fn resource_type() -> ResourceType {
+        ResourceType::Person
+    }
+}
+impl SearchQuery<Person> {
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<Person> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<Person> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<Person> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postal code specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<Person> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<Person> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "The person's date of birth"]
+    pub fn birthdate(self) -> DateParameter<Person> {
+        DateParameter::new(self, "birthdate")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "The gender of the person"]
+    pub fn gender(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "gender")
+    }
+    #[doc = "A person Identifier"]
+    pub fn identifier(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Any link has this Patient, Person, RelatedPerson or Practitioner reference"]
+    pub fn link(self) -> ReferenceParameter<Person> {
+        ReferenceParameter::new(self, "link")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the HumanName, including family, give, prefix, suffix, suffix, and/or text"]
+    pub fn name(self) -> StringParameter<Person> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "The organization at which this person record is being managed"]
+    pub fn organization(self) -> ReferenceParameter<Person> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "The Person links to this Patient"]
+    pub fn patient(self) -> ReferenceParameter<Person> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "A portion of name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<Person> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "The Person links to this Practitioner"]
+    pub fn practitioner(self) -> ReferenceParameter<Person> {
+        ReferenceParameter::new(self, "practitioner")
+    }
+    #[doc = "The Person links to this RelatedPerson"]
+    pub fn relatedperson(self) -> ReferenceParameter<Person> {
+        ReferenceParameter::new(self, "relatedperson")
+    }
+    #[doc = "The value in any kind of contact"]
+    pub fn telecom(self) -> TokenParameter<Person> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for PlanDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::PlanDefinition
+    }
+}
+impl SearchQuery<PlanDefinition> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the plan definition"]
+    pub fn context(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the plan definition"]
+    pub fn context_quantity(self) -> QuantityParameter<PlanDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the plan definition"]
+    pub fn context_type(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the plan definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<PlanDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the plan definition"]
+    pub fn context_type_value(self) -> CompositeParameter<PlanDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The plan definition publication date"]
+    pub fn date(self) -> DateParameter<PlanDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Activity or plan definitions used by plan definition"]
+    pub fn definition(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "definition")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the plan definition"]
+    pub fn description(self) -> StringParameter<PlanDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the plan definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<PlanDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the plan definition"]
+    pub fn identifier(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the plan definition"]
+    pub fn jurisdiction(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the plan definition"]
+    pub fn name(self) -> StringParameter<PlanDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the plan definition"]
+    pub fn publisher(self) -> StringParameter<PlanDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the plan definition"]
+    pub fn status(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<PlanDefinition> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the plan definition"]
+    pub fn title(self) -> StringParameter<PlanDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the module"]
+    pub fn topic(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The type of artifact the plan (e.g. order-set, eca-rule, protocol)"]
+    pub fn type_(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The uri that identifies the plan definition"]
+    pub fn url(self) -> UriParameter<PlanDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the plan definition"]
+    pub fn version(self) -> TokenParameter<PlanDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Practitioner {
+    fn resource_type() -> ResourceType {
+        ResourceType::Practitioner
+    }
+}
+impl SearchQuery<Practitioner> {
+    #[doc = "Whether the practitioner record is active"]
+    pub fn active(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postalCode specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "One of the languages that the practitioner can communicate with"]
+    pub fn communication(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "communication")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "A portion of the family name"]
+    pub fn family(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "family")
+    }
+    #[doc = "Gender of the practitioner"]
+    pub fn gender(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "gender")
+    }
+    #[doc = "A portion of the given name"]
+    pub fn given(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "given")
+    }
+    #[doc = "A practitioner's Identifier"]
+    pub fn identifier(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the HumanName, including family, give, prefix, suffix, suffix, and/or text"]
+    pub fn name(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "A portion of either family or given name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<Practitioner> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "The value in any kind of contact"]
+    pub fn telecom(self) -> TokenParameter<Practitioner> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for PractitionerRole {
+    fn resource_type() -> ResourceType {
+        ResourceType::PractitionerRole
+    }
+}
+impl SearchQuery<PractitionerRole> {
+    #[doc = "Whether this practitioner role record is in active use"]
+    pub fn active(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "The period during which the practitioner is authorized to perform in these role(s)"]
+    pub fn date(self) -> DateParameter<PractitionerRole> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "Technical endpoints providing access to services operated for the practitioner with this role"]
+    pub fn endpoint(self) -> ReferenceParameter<PractitionerRole> {
+        ReferenceParameter::new(self, "endpoint")
+    }
+    #[doc = "A practitioner's Identifier"]
+    pub fn identifier(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "One of the locations at which this practitioner provides care"]
+    pub fn location(self) -> ReferenceParameter<PractitionerRole> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "The identity of the organization the practitioner represents / acts on behalf of"]
+    pub fn organization(self) -> ReferenceParameter<PractitionerRole> {
+        ReferenceParameter::new(self, "organization")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "Practitioner that is able to provide the defined services for the organization"]
+    pub fn practitioner(self) -> ReferenceParameter<PractitionerRole> {
+        ReferenceParameter::new(self, "practitioner")
+    }
+    #[doc = "The practitioner can perform this role at for the organization"]
+    pub fn role(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "role")
+    }
+    #[doc = "The list of healthcare services that this worker provides for this role's Organization/Location(s)"]
+    pub fn service(self) -> ReferenceParameter<PractitionerRole> {
+        ReferenceParameter::new(self, "service")
+    }
+    #[doc = "The practitioner has this specialty at an organization"]
+    pub fn specialty(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "specialty")
+    }
+    #[doc = "The value in any kind of contact"]
+    pub fn telecom(self) -> TokenParameter<PractitionerRole> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for Procedure {
+    fn resource_type() -> ResourceType {
+        ResourceType::Procedure
+    }
+}
+impl SearchQuery<Procedure> {
+    #[doc = "A request for this procedure"]
+    pub fn based_on(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Classification of the procedure"]
+    pub fn category(self) -> TokenParameter<Procedure> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "A code to identify a  procedure"]
+    pub fn code(self) -> TokenParameter<Procedure> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "When the procedure was performed"]
+    pub fn date(self) -> DateParameter<Procedure> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Encounter created as part of"]
+    pub fn encounter(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "A unique identifier for a procedure"]
+    pub fn identifier(self) -> TokenParameter<Procedure> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<Procedure> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "Where the procedure happened"]
+    pub fn location(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Part of referenced event"]
+    pub fn part_of(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Search by subject - a patient"]
+    pub fn patient(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The reference to the practitioner"]
+    pub fn performer(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Coded reason procedure performed"]
+    pub fn reason_code(self) -> TokenParameter<Procedure> {
+        TokenParameter::new(self, "reason-code")
+    }
+    #[doc = "The justification that the procedure was performed"]
+    pub fn reason_reference(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "reason-reference")
+    }
+    #[doc = "preparation | in-progress | not-done | on-hold | stopped | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<Procedure> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Search by subject"]
+    pub fn subject(self) -> ReferenceParameter<Procedure> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Provenance {
+    fn resource_type() -> ResourceType {
+        ResourceType::Provenance
+    }
+}
+impl SearchQuery<Provenance> {
+    #[doc = "Who participated"]
+    pub fn agent(self) -> ReferenceParameter<Provenance> {
+        ReferenceParameter::new(self, "agent")
+    }
+    #[doc = "What the agents role was"]
+    pub fn agent_role(self) -> TokenParameter<Provenance> {
+        TokenParameter::new(self, "agent-role")
+    }
+    #[doc = "How the agent participated"]
+    pub fn agent_type(self) -> TokenParameter<Provenance> {
+        TokenParameter::new(self, "agent-type")
+    }
+    #[doc = "Identity of entity"]
+    pub fn entity(self) -> ReferenceParameter<Provenance> {
+        ReferenceParameter::new(self, "entity")
+    }
+    #[doc = "Where the activity occurred, if relevant"]
+    pub fn location(self) -> ReferenceParameter<Provenance> {
+        ReferenceParameter::new(self, "location")
+    }
+    #[doc = "Target Reference(s) (usually version specific)"]
+    pub fn patient(self) -> ReferenceParameter<Provenance> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "When the activity was recorded / updated"]
+    pub fn recorded(self) -> DateParameter<Provenance> {
+        DateParameter::new(self, "recorded")
+    }
+    #[doc = "Indication of the reason the entity signed the object(s)"]
+    pub fn signature_type(self) -> TokenParameter<Provenance> {
+        TokenParameter::new(self, "signature-type")
+    }
+    #[doc = "Target Reference(s) (usually version specific)"]
+    pub fn target(self) -> ReferenceParameter<Provenance> {
+        ReferenceParameter::new(self, "target")
+    }
+    #[doc = "When the activity occurred"]
+    pub fn when(self) -> DateParameter<Provenance> {
+        DateParameter::new(self, "when")
+    }
+}
+impl Search for Questionnaire {
+    fn resource_type() -> ResourceType {
+        ResourceType::Questionnaire
+    }
+}
+impl SearchQuery<Questionnaire> {
+    #[doc = "A code that corresponds to one of its items in the questionnaire"]
+    pub fn code(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "A use context assigned to the questionnaire"]
+    pub fn context(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the questionnaire"]
+    pub fn context_quantity(self) -> QuantityParameter<Questionnaire> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the questionnaire"]
+    pub fn context_type(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the questionnaire"]
+    pub fn context_type_quantity(self) -> CompositeParameter<Questionnaire> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the questionnaire"]
+    pub fn context_type_value(self) -> CompositeParameter<Questionnaire> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The questionnaire publication date"]
+    pub fn date(self) -> DateParameter<Questionnaire> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "ElementDefinition - details for the item"]
+    pub fn definition(self) -> UriParameter<Questionnaire> {
+        UriParameter::new(self, "definition")
+    }
+    #[doc = "The description of the questionnaire"]
+    pub fn description(self) -> StringParameter<Questionnaire> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the questionnaire is intended to be in use"]
+    pub fn effective(self) -> DateParameter<Questionnaire> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the questionnaire"]
+    pub fn identifier(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the questionnaire"]
+    pub fn jurisdiction(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the questionnaire"]
+    pub fn name(self) -> StringParameter<Questionnaire> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the questionnaire"]
+    pub fn publisher(self) -> StringParameter<Questionnaire> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the questionnaire"]
+    pub fn status(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Resource that can be subject of QuestionnaireResponse"]
+    pub fn subject_type(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "subject-type")
+    }
+    #[doc = "The human-friendly name of the questionnaire"]
+    pub fn title(self) -> StringParameter<Questionnaire> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the questionnaire"]
+    pub fn url(self) -> UriParameter<Questionnaire> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the questionnaire"]
+    pub fn version(self) -> TokenParameter<Questionnaire> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for QuestionnaireResponse {
+    fn resource_type() -> ResourceType {
+        ResourceType::QuestionnaireResponse
+    }
+}
+impl SearchQuery<QuestionnaireResponse> {
+    #[doc = "The author of the questionnaire response"]
+    pub fn author(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "When the questionnaire response was last changed"]
+    pub fn authored(self) -> DateParameter<QuestionnaireResponse> {
+        DateParameter::new(self, "authored")
+    }
+    #[doc = "Plan/proposal/order fulfilled by this questionnaire response"]
+    pub fn based_on(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Encounter associated with the questionnaire response"]
+    pub fn encounter(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The unique identifier for the questionnaire response"]
+    pub fn identifier(self) -> TokenParameter<QuestionnaireResponse> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Procedure or observation this questionnaire response was performed as a part of"]
+    pub fn part_of(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "The patient that is the subject of the questionnaire response"]
+    pub fn patient(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The questionnaire the answers are provided for"]
+    pub fn questionnaire(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "questionnaire")
+    }
+    #[doc = "The individual providing the information reflected in the questionnaire respose"]
+    pub fn source(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "source")
+    }
+    #[doc = "The status of the questionnaire response"]
+    pub fn status(self) -> TokenParameter<QuestionnaireResponse> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject of the questionnaire response"]
+    pub fn subject(self) -> ReferenceParameter<QuestionnaireResponse> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for RelatedPerson {
+    fn resource_type() -> ResourceType {
+        ResourceType::RelatedPerson
+    }
+}
+impl SearchQuery<RelatedPerson> {
+    #[doc = "Indicates if the related person record is active"]
+    pub fn active(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text"]
+    pub fn address(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "address")
+    }
+    #[doc = "A city specified in an address"]
+    pub fn address_city(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "address-city")
+    }
+    #[doc = "A country specified in an address"]
+    pub fn address_country(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "address-country")
+    }
+    #[doc = "A postal code specified in an address"]
+    pub fn address_postalcode(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "address-postalcode")
+    }
+    #[doc = "A state specified in an address"]
+    pub fn address_state(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "address-state")
+    }
+    #[doc = "A use code specified in an address"]
+    pub fn address_use(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "address-use")
+    }
+    #[doc = "The Related Person's date of birth"]
+    pub fn birthdate(self) -> DateParameter<RelatedPerson> {
+        DateParameter::new(self, "birthdate")
+    }
+    #[doc = "A value in an email contact"]
+    pub fn email(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "Gender of the related person"]
+    pub fn gender(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "gender")
+    }
+    #[doc = "An Identifier of the RelatedPerson"]
+    pub fn identifier(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "A server defined search that may match any of the string fields in the HumanName, including family, give, prefix, suffix, suffix, and/or text"]
+    pub fn name(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "The patient this related person is related to"]
+    pub fn patient(self) -> ReferenceParameter<RelatedPerson> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "A value in a phone contact"]
+    pub fn phone(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "phone")
+    }
+    #[doc = "A portion of name using some kind of phonetic matching algorithm"]
+    pub fn phonetic(self) -> StringParameter<RelatedPerson> {
+        StringParameter::new(self, "phonetic")
+    }
+    #[doc = "The relationship between the patient and the relatedperson"]
+    pub fn relationship(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "relationship")
+    }
+    #[doc = "The value in any kind of contact"]
+    pub fn telecom(self) -> TokenParameter<RelatedPerson> {
+        TokenParameter::new(self, "telecom")
+    }
+}
+impl Search for RequestGroup {
+    fn resource_type() -> ResourceType {
+        ResourceType::RequestGroup
+    }
+}
+impl SearchQuery<RequestGroup> {
+    #[doc = "The author of the request group"]
+    pub fn author(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "author")
+    }
+    #[doc = "The date the request group was authored"]
+    pub fn authored(self) -> DateParameter<RequestGroup> {
+        DateParameter::new(self, "authored")
+    }
+    #[doc = "The code of the request group"]
+    pub fn code(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "The encounter the request group applies to"]
+    pub fn encounter(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "The group identifier for the request group"]
+    pub fn group_identifier(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "group-identifier")
+    }
+    #[doc = "External identifiers for the request group"]
+    pub fn identifier(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The FHIR-based definition from which the request group is realized"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "The external definition from which the request group is realized"]
+    pub fn instantiates_uri(self) -> UriParameter<RequestGroup> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "The intent of the request group"]
+    pub fn intent(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "The participant in the requests in the group"]
+    pub fn participant(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "participant")
+    }
+    #[doc = "The identity of a patient to search for request groups"]
+    pub fn patient(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "The priority of the request group"]
+    pub fn priority(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "The status of the request group"]
+    pub fn status(self) -> TokenParameter<RequestGroup> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject that the request group is about"]
+    pub fn subject(self) -> ReferenceParameter<RequestGroup> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for ResearchDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::ResearchDefinition
+    }
+}
+impl SearchQuery<ResearchDefinition> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<ResearchDefinition> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the research definition"]
+    pub fn context(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the research definition"]
+    pub fn context_quantity(self) -> QuantityParameter<ResearchDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the research definition"]
+    pub fn context_type(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the research definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ResearchDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the research definition"]
+    pub fn context_type_value(self) -> CompositeParameter<ResearchDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The research definition publication date"]
+    pub fn date(self) -> DateParameter<ResearchDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<ResearchDefinition> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<ResearchDefinition> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the research definition"]
+    pub fn description(self) -> StringParameter<ResearchDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the research definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<ResearchDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the research definition"]
+    pub fn identifier(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the research definition"]
+    pub fn jurisdiction(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the research definition"]
+    pub fn name(self) -> StringParameter<ResearchDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<ResearchDefinition> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the research definition"]
+    pub fn publisher(self) -> StringParameter<ResearchDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the research definition"]
+    pub fn status(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<ResearchDefinition> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the research definition"]
+    pub fn title(self) -> StringParameter<ResearchDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the ResearchDefinition"]
+    pub fn topic(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the research definition"]
+    pub fn url(self) -> UriParameter<ResearchDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the research definition"]
+    pub fn version(self) -> TokenParameter<ResearchDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for ResearchElementDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::ResearchElementDefinition
+    }
+}
+impl SearchQuery<ResearchElementDefinition> {
+    #[doc = "What resource is being referenced"]
+    pub fn composed_of(self) -> ReferenceParameter<ResearchElementDefinition> {
+        ReferenceParameter::new(self, "composed-of")
+    }
+    #[doc = "A use context assigned to the research element definition"]
+    pub fn context(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the research element definition"]
+    pub fn context_quantity(self) -> QuantityParameter<ResearchElementDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the research element definition"]
+    pub fn context_type(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the research element definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ResearchElementDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the research element definition"]
+    pub fn context_type_value(self) -> CompositeParameter<ResearchElementDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The research element definition publication date"]
+    pub fn date(self) -> DateParameter<ResearchElementDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn depends_on(self) -> ReferenceParameter<ResearchElementDefinition> {
+        ReferenceParameter::new(self, "depends-on")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn derived_from(self) -> ReferenceParameter<ResearchElementDefinition> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the research element definition"]
+    pub fn description(self) -> StringParameter<ResearchElementDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the research element definition is intended to be in use"]
+    pub fn effective(self) -> DateParameter<ResearchElementDefinition> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the research element definition"]
+    pub fn identifier(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the research element definition"]
+    pub fn jurisdiction(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the research element definition"]
+    pub fn name(self) -> StringParameter<ResearchElementDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn predecessor(self) -> ReferenceParameter<ResearchElementDefinition> {
+        ReferenceParameter::new(self, "predecessor")
+    }
+    #[doc = "Name of the publisher of the research element definition"]
+    pub fn publisher(self) -> StringParameter<ResearchElementDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the research element definition"]
+    pub fn status(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "What resource is being referenced"]
+    pub fn successor(self) -> ReferenceParameter<ResearchElementDefinition> {
+        ReferenceParameter::new(self, "successor")
+    }
+    #[doc = "The human-friendly name of the research element definition"]
+    pub fn title(self) -> StringParameter<ResearchElementDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Topics associated with the ResearchElementDefinition"]
+    pub fn topic(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "topic")
+    }
+    #[doc = "The uri that identifies the research element definition"]
+    pub fn url(self) -> UriParameter<ResearchElementDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the research element definition"]
+    pub fn version(self) -> TokenParameter<ResearchElementDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for ResearchStudy {
+    fn resource_type() -> ResourceType {
+        ResourceType::ResearchStudy
+    }
+}
+impl SearchQuery<ResearchStudy> {
+    #[doc = "Classifications for the study"]
+    pub fn category(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "When the study began and ended"]
+    pub fn date(self) -> DateParameter<ResearchStudy> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Drugs, devices, etc. under study"]
+    pub fn focus(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "focus")
+    }
+    #[doc = "Business Identifier for study"]
+    pub fn identifier(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Used to search for the study"]
+    pub fn keyword(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "keyword")
+    }
+    #[doc = "Geographic region(s) for study"]
+    pub fn location(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "location")
+    }
+    #[doc = "Part of larger study"]
+    pub fn partof(self) -> ReferenceParameter<ResearchStudy> {
+        ReferenceParameter::new(self, "partof")
+    }
+    #[doc = "Researcher who oversees multiple aspects of the study"]
+    pub fn principalinvestigator(self) -> ReferenceParameter<ResearchStudy> {
+        ReferenceParameter::new(self, "principalinvestigator")
+    }
+    #[doc = "Steps followed in executing study"]
+    pub fn protocol(self) -> ReferenceParameter<ResearchStudy> {
+        ReferenceParameter::new(self, "protocol")
+    }
+    #[doc = "Facility where study activities are conducted"]
+    pub fn site(self) -> ReferenceParameter<ResearchStudy> {
+        ReferenceParameter::new(self, "site")
+    }
+    #[doc = "Organization that initiates and is legally responsible for the study"]
+    pub fn sponsor(self) -> ReferenceParameter<ResearchStudy> {
+        ReferenceParameter::new(self, "sponsor")
+    }
+    #[doc = "active | administratively-completed | approved | closed-to-accrual | closed-to-accrual-and-intervention | completed | disapproved | in-review | temporarily-closed-to-accrual | temporarily-closed-to-accrual-and-intervention | withdrawn"]
+    pub fn status(self) -> TokenParameter<ResearchStudy> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Name for this study"]
+    pub fn title(self) -> StringParameter<ResearchStudy> {
+        StringParameter::new(self, "title")
+    }
+}
+impl Search for ResearchSubject {
+    fn resource_type() -> ResourceType {
+        ResourceType::ResearchSubject
+    }
+}
+impl SearchQuery<ResearchSubject> {
+    #[doc = "Start and end of participation"]
+    pub fn date(self) -> DateParameter<ResearchSubject> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business Identifier for research subject in a study"]
+    pub fn identifier(self) -> TokenParameter<ResearchSubject> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Who is part of study"]
+    pub fn individual(self) -> ReferenceParameter<ResearchSubject> {
+        ReferenceParameter::new(self, "individual")
+    }
+    #[doc = "Who is part of study"]
+    pub fn patient(self) -> ReferenceParameter<ResearchSubject> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "candidate | eligible | follow-up | ineligible | not-registered | off-study | on-study | on-study-intervention | on-study-observation | pending-on-study | potential-candidate | screening | withdrawn"]
+    pub fn status(self) -> TokenParameter<ResearchSubject> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Study subject is part of"]
+    pub fn study(self) -> ReferenceParameter<ResearchSubject> {
+        ReferenceParameter::new(self, "study")
+    }
+}
+impl Search for RiskAssessment {
+    fn resource_type() -> ResourceType {
+        ResourceType::RiskAssessment
+    }
+}
+impl SearchQuery<RiskAssessment> {
+    #[doc = "Condition assessed"]
+    pub fn condition(self) -> ReferenceParameter<RiskAssessment> {
+        ReferenceParameter::new(self, "condition")
+    }
+    #[doc = "When was assessment made?"]
+    pub fn date(self) -> DateParameter<RiskAssessment> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Where was assessment performed?"]
+    pub fn encounter(self) -> ReferenceParameter<RiskAssessment> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Unique identifier for the assessment"]
+    pub fn identifier(self) -> TokenParameter<RiskAssessment> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Evaluation mechanism"]
+    pub fn method(self) -> TokenParameter<RiskAssessment> {
+        TokenParameter::new(self, "method")
+    }
+    #[doc = "Who/what does assessment apply to?"]
+    pub fn patient(self) -> ReferenceParameter<RiskAssessment> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who did assessment?"]
+    pub fn performer(self) -> ReferenceParameter<RiskAssessment> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Likelihood of specified outcome"]
+    pub fn probability(self) -> NumberParameter<RiskAssessment> {
+        NumberParameter::new(self, "probability")
+    }
+    #[doc = "Likelihood of specified outcome as a qualitative value"]
+    pub fn risk(self) -> TokenParameter<RiskAssessment> {
+        TokenParameter::new(self, "risk")
+    }
+    #[doc = "Who/what does assessment apply to?"]
+    pub fn subject(self) -> ReferenceParameter<RiskAssessment> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for RiskEvidenceSynthesis {
+    fn resource_type() -> ResourceType {
+        ResourceType::RiskEvidenceSynthesis
+    }
+}
+impl SearchQuery<RiskEvidenceSynthesis> {
+    #[doc = "A use context assigned to the risk evidence synthesis"]
+    pub fn context(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the risk evidence synthesis"]
+    pub fn context_quantity(self) -> QuantityParameter<RiskEvidenceSynthesis> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the risk evidence synthesis"]
+    pub fn context_type(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the risk evidence synthesis"]
+    pub fn context_type_quantity(self) -> CompositeParameter<RiskEvidenceSynthesis> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the risk evidence synthesis"]
+    pub fn context_type_value(self) -> CompositeParameter<RiskEvidenceSynthesis> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The risk evidence synthesis publication date"]
+    pub fn date(self) -> DateParameter<RiskEvidenceSynthesis> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the risk evidence synthesis"]
+    pub fn description(self) -> StringParameter<RiskEvidenceSynthesis> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "The time during which the risk evidence synthesis is intended to be in use"]
+    pub fn effective(self) -> DateParameter<RiskEvidenceSynthesis> {
+        DateParameter::new(self, "effective")
+    }
+    #[doc = "External identifier for the risk evidence synthesis"]
+    pub fn identifier(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the risk evidence synthesis"]
+    pub fn jurisdiction(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the risk evidence synthesis"]
+    pub fn name(self) -> StringParameter<RiskEvidenceSynthesis> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the risk evidence synthesis"]
+    pub fn publisher(self) -> StringParameter<RiskEvidenceSynthesis> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the risk evidence synthesis"]
+    pub fn status(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the risk evidence synthesis"]
+    pub fn title(self) -> StringParameter<RiskEvidenceSynthesis> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the risk evidence synthesis"]
+    pub fn url(self) -> UriParameter<RiskEvidenceSynthesis> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the risk evidence synthesis"]
+    pub fn version(self) -> TokenParameter<RiskEvidenceSynthesis> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Schedule {
+    fn resource_type() -> ResourceType {
+        ResourceType::Schedule
+    }
+}
+impl SearchQuery<Schedule> {
+    #[doc = "Is the schedule in active use"]
+    pub fn active(self) -> TokenParameter<Schedule> {
+        TokenParameter::new(self, "active")
+    }
+    #[doc = "The individual(HealthcareService, Practitioner, Location, ...) to find a Schedule for"]
+    pub fn actor(self) -> ReferenceParameter<Schedule> {
+        ReferenceParameter::new(self, "actor")
+    }
+    #[doc = "Search for Schedule resources that have a period that contains this date specified"]
+    pub fn date(self) -> DateParameter<Schedule> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "A Schedule Identifier"]
+    pub fn identifier(self) -> TokenParameter<Schedule> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "High-level category"]
+    pub fn service_category(self) -> TokenParameter<Schedule> {
+        TokenParameter::new(self, "service-category")
+    }
+    #[doc = "The type of appointments that can be booked into associated slot(s)"]
+    pub fn service_type(self) -> TokenParameter<Schedule> {
+        TokenParameter::new(self, "service-type")
+    }
+    #[doc = "Type of specialty needed"]
+    pub fn specialty(self) -> TokenParameter<Schedule> {
+        TokenParameter::new(self, "specialty")
+    }
+}
+impl Search for SearchParameter {
+    fn resource_type() -> ResourceType {
+        ResourceType::SearchParameter
+    }
+}
+impl SearchQuery<SearchParameter> {
+    #[doc = "The resource type(s) this search parameter applies to"]
+    pub fn base(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "base")
+    }
+    #[doc = "Code used in URL"]
+    pub fn code(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Defines how the part works"]
+    pub fn component(self) -> ReferenceParameter<SearchParameter> {
+        ReferenceParameter::new(self, "component")
+    }
+    #[doc = "A use context assigned to the search parameter"]
+    pub fn context(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the search parameter"]
+    pub fn context_quantity(self) -> QuantityParameter<SearchParameter> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the search parameter"]
+    pub fn context_type(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the search parameter"]
+    pub fn context_type_quantity(self) -> CompositeParameter<SearchParameter> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the search parameter"]
+    pub fn context_type_value(self) -> CompositeParameter<SearchParameter> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The search parameter publication date"]
+    pub fn date(self) -> DateParameter<SearchParameter> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Original definition for the search parameter"]
+    pub fn derived_from(self) -> ReferenceParameter<SearchParameter> {
+        ReferenceParameter::new(self, "derived-from")
+    }
+    #[doc = "The description of the search parameter"]
+    pub fn description(self) -> StringParameter<SearchParameter> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Intended jurisdiction for the search parameter"]
+    pub fn jurisdiction(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the search parameter"]
+    pub fn name(self) -> StringParameter<SearchParameter> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the search parameter"]
+    pub fn publisher(self) -> StringParameter<SearchParameter> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the search parameter"]
+    pub fn status(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Types of resource (if a resource reference)"]
+    pub fn target(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "target")
+    }
+    #[doc = "number | date | string | token | reference | composite | quantity | uri | special"]
+    pub fn type_(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The uri that identifies the search parameter"]
+    pub fn url(self) -> UriParameter<SearchParameter> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the search parameter"]
+    pub fn version(self) -> TokenParameter<SearchParameter> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for ServiceRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::ServiceRequest
+    }
+}
+impl SearchQuery<ServiceRequest> {
+    #[doc = "Date request signed"]
+    pub fn authored(self) -> DateParameter<ServiceRequest> {
+        DateParameter::new(self, "authored")
+    }
+    #[doc = "What request fulfills"]
+    pub fn based_on(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Where procedure is going to be done"]
+    pub fn body_site(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "body-site")
+    }
+    #[doc = "Classification of service"]
+    pub fn category(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "What is being requested/ordered"]
+    pub fn code(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "An encounter in which this request is made"]
+    pub fn encounter(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Identifiers assigned to this order"]
+    pub fn identifier(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Instantiates FHIR protocol or definition"]
+    pub fn instantiates_canonical(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "instantiates-canonical")
+    }
+    #[doc = "Instantiates external protocol or definition"]
+    pub fn instantiates_uri(self) -> UriParameter<ServiceRequest> {
+        UriParameter::new(self, "instantiates-uri")
+    }
+    #[doc = "proposal | plan | directive | order | original-order | reflex-order | filler-order | instance-order | option"]
+    pub fn intent(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "When service should occur"]
+    pub fn occurrence(self) -> DateParameter<ServiceRequest> {
+        DateParameter::new(self, "occurrence")
+    }
+    #[doc = "Search by subject - a patient"]
+    pub fn patient(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Requested performer"]
+    pub fn performer(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "performer")
+    }
+    #[doc = "Performer role"]
+    pub fn performer_type(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "performer-type")
+    }
+    #[doc = "routine | urgent | asap | stat"]
+    pub fn priority(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "What request replaces"]
+    pub fn replaces(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "replaces")
+    }
+    #[doc = "Who/what is requesting service"]
+    pub fn requester(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "Composite Request ID"]
+    pub fn requisition(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "requisition")
+    }
+    #[doc = "Specimen to be tested"]
+    pub fn specimen(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "specimen")
+    }
+    #[doc = "draft | active | on-hold | revoked | completed | entered-in-error | unknown"]
+    pub fn status(self) -> TokenParameter<ServiceRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Search by subject"]
+    pub fn subject(self) -> ReferenceParameter<ServiceRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for Slot {
+    fn resource_type() -> ResourceType {
+        ResourceType::Slot
+    }
+}
+impl SearchQuery<Slot> {
+    #[doc = "The style of appointment or patient that may be booked in the slot (not service type)"]
+    pub fn appointment_type(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "appointment-type")
+    }
+    #[doc = "A Slot Identifier"]
+    pub fn identifier(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The Schedule Resource that we are seeking a slot within"]
+    pub fn schedule(self) -> ReferenceParameter<Slot> {
+        ReferenceParameter::new(self, "schedule")
+    }
+    #[doc = "A broad categorization of the service that is to be performed during this appointment"]
+    pub fn service_category(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "service-category")
+    }
+    #[doc = "The type of appointments that can be booked into the slot"]
+    pub fn service_type(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "service-type")
+    }
+    #[doc = "The specialty of a practitioner that would be required to perform the service requested in this appointment"]
+    pub fn specialty(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "specialty")
+    }
+    #[doc = "Appointment date/time."]
+    pub fn start(self) -> DateParameter<Slot> {
+        DateParameter::new(self, "start")
+    }
+    #[doc = "The free/busy status of the appointment"]
+    pub fn status(self) -> TokenParameter<Slot> {
+        TokenParameter::new(self, "status")
+    }
+}
+impl Search for Specimen {
+    fn resource_type() -> ResourceType {
+        ResourceType::Specimen
+    }
+}
+impl SearchQuery<Specimen> {
+    #[doc = "The accession number associated with the specimen"]
+    pub fn accession(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "accession")
+    }
+    #[doc = "The code for the body site from where the specimen originated"]
+    pub fn bodysite(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "bodysite")
+    }
+    #[doc = "The date the specimen was collected"]
+    pub fn collected(self) -> DateParameter<Specimen> {
+        DateParameter::new(self, "collected")
+    }
+    #[doc = "Who collected the specimen"]
+    pub fn collector(self) -> ReferenceParameter<Specimen> {
+        ReferenceParameter::new(self, "collector")
+    }
+    #[doc = "The kind of specimen container"]
+    pub fn container(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "container")
+    }
+    #[doc = "The unique identifier associated with the specimen container"]
+    pub fn container_id(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "container-id")
+    }
+    #[doc = "The unique identifier associated with the specimen"]
+    pub fn identifier(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The parent of the specimen"]
+    pub fn parent(self) -> ReferenceParameter<Specimen> {
+        ReferenceParameter::new(self, "parent")
+    }
+    #[doc = "The patient the specimen comes from"]
+    pub fn patient(self) -> ReferenceParameter<Specimen> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "available | unavailable | unsatisfactory | entered-in-error"]
+    pub fn status(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The subject of the specimen"]
+    pub fn subject(self) -> ReferenceParameter<Specimen> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "The specimen type"]
+    pub fn type_(self) -> TokenParameter<Specimen> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for SpecimenDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::SpecimenDefinition
+    }
+}
+impl SearchQuery<SpecimenDefinition> {
+    #[doc = "The type of specimen conditioned in container expected by the lab"]
+    pub fn container(self) -> TokenParameter<SpecimenDefinition> {
+        TokenParameter::new(self, "container")
+    }
+    #[doc = "The unique identifier associated with the specimen"]
+    pub fn identifier(self) -> TokenParameter<SpecimenDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The type of collected specimen"]
+    pub fn type_(self) -> TokenParameter<SpecimenDefinition> {
+        TokenParameter::new(self, "type")
+    }
+}
+impl Search for StructureDefinition {
+    fn resource_type() -> ResourceType {
+        ResourceType::StructureDefinition
+    }
+}
+impl SearchQuery<StructureDefinition> {
+    #[doc = "Whether the structure is abstract"]
+    pub fn abstract_(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "abstract")
+    }
+    #[doc = "Definition that this type is constrained/specialized from"]
+    pub fn base(self) -> ReferenceParameter<StructureDefinition> {
+        ReferenceParameter::new(self, "base")
+    }
+    #[doc = "Path that identifies the base element"]
+    pub fn base_path(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "base-path")
+    }
+    #[doc = "A use context assigned to the structure definition"]
+    pub fn context(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the structure definition"]
+    pub fn context_quantity(self) -> QuantityParameter<StructureDefinition> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the structure definition"]
+    pub fn context_type(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the structure definition"]
+    pub fn context_type_quantity(self) -> CompositeParameter<StructureDefinition> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the structure definition"]
+    pub fn context_type_value(self) -> CompositeParameter<StructureDefinition> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The structure definition publication date"]
+    pub fn date(self) -> DateParameter<StructureDefinition> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "specialization | constraint - How relates to base definition"]
+    pub fn derivation(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "derivation")
+    }
+    #[doc = "The description of the structure definition"]
+    pub fn description(self) -> StringParameter<StructureDefinition> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "For testing purposes, not real usage"]
+    pub fn experimental(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "experimental")
+    }
+    #[doc = "The system is the URL for the context-type: e.g. http://hl7.org/fhir/extension-context-type#element|CodeableConcept.text"]
+    pub fn ext_context(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "ext-context")
+    }
+    #[doc = "External identifier for the structure definition"]
+    pub fn identifier(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the structure definition"]
+    pub fn jurisdiction(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "A code for the StructureDefinition"]
+    pub fn keyword(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "keyword")
+    }
+    #[doc = "primitive-type | complex-type | resource | logical"]
+    pub fn kind(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "kind")
+    }
+    #[doc = "Computationally friendly name of the structure definition"]
+    pub fn name(self) -> StringParameter<StructureDefinition> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "A path that is constrained in the StructureDefinition"]
+    pub fn path(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "path")
+    }
+    #[doc = "Name of the publisher of the structure definition"]
+    pub fn publisher(self) -> StringParameter<StructureDefinition> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the structure definition"]
+    pub fn status(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the structure definition"]
+    pub fn title(self) -> StringParameter<StructureDefinition> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "Type defined or constrained by this structure"]
+    pub fn type_(self) -> UriParameter<StructureDefinition> {
+        UriParameter::new(self, "type")
+    }
+    #[doc = "The uri that identifies the structure definition"]
+    pub fn url(self) -> UriParameter<StructureDefinition> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "A vocabulary binding reference"]
+    pub fn valueset(self) -> ReferenceParameter<StructureDefinition> {
+        ReferenceParameter::new(self, "valueset")
+    }
+    #[doc = "The business version of the structure definition"]
+    pub fn version(self) -> TokenParameter<StructureDefinition> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for StructureMap {
+    fn resource_type() -> ResourceType {
+        ResourceType::StructureMap
+    }
+}
+impl SearchQuery<StructureMap> {
+    #[doc = "A use context assigned to the structure map"]
+    pub fn context(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the structure map"]
+    pub fn context_quantity(self) -> QuantityParameter<StructureMap> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the structure map"]
+    pub fn context_type(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the structure map"]
+    pub fn context_type_quantity(self) -> CompositeParameter<StructureMap> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the structure map"]
+    pub fn context_type_value(self) -> CompositeParameter<StructureMap> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The structure map publication date"]
+    pub fn date(self) -> DateParameter<StructureMap> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the structure map"]
+    pub fn description(self) -> StringParameter<StructureMap> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "External identifier for the structure map"]
+    pub fn identifier(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the structure map"]
+    pub fn jurisdiction(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the structure map"]
+    pub fn name(self) -> StringParameter<StructureMap> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the structure map"]
+    pub fn publisher(self) -> StringParameter<StructureMap> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the structure map"]
+    pub fn status(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the structure map"]
+    pub fn title(self) -> StringParameter<StructureMap> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the structure map"]
+    pub fn url(self) -> UriParameter<StructureMap> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the structure map"]
+    pub fn version(self) -> TokenParameter<StructureMap> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for Subscription {
+    fn resource_type() -> ResourceType {
+        ResourceType::Subscription
+    }
+}
+impl SearchQuery<Subscription> {
+    #[doc = "Contact details for the subscription"]
+    pub fn contact(self) -> TokenParameter<Subscription> {
+        TokenParameter::new(self, "contact")
+    }
+    #[doc = "The search rules used to determine when to send a notification"]
+    pub fn criteria(self) -> StringParameter<Subscription> {
+        StringParameter::new(self, "criteria")
+    }
+    #[doc = "The mime-type of the notification payload"]
+    pub fn payload(self) -> TokenParameter<Subscription> {
+        TokenParameter::new(self, "payload")
+    }
+    #[doc = "The current state of the subscription"]
+    pub fn status(self) -> TokenParameter<Subscription> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The type of channel for the sent notifications"]
+    pub fn type_(self) -> TokenParameter<Subscription> {
+        TokenParameter::new(self, "type")
+    }
+    #[doc = "The uri that will receive the notifications"]
+    pub fn url(self) -> UriParameter<Subscription> {
+        UriParameter::new(self, "url")
+    }
+}
+impl Search for Substance {
+    fn resource_type() -> ResourceType {
+        ResourceType::Substance
+    }
+}
+impl SearchQuery<Substance> {
+    #[doc = "The category of the substance"]
+    pub fn category(self) -> TokenParameter<Substance> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "The code of the substance or ingredient"]
+    pub fn code(self) -> TokenParameter<Substance> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Identifier of the package/container"]
+    pub fn container_identifier(self) -> TokenParameter<Substance> {
+        TokenParameter::new(self, "container-identifier")
+    }
+    #[doc = "Expiry date of package or container of substance"]
+    pub fn expiry(self) -> DateParameter<Substance> {
+        DateParameter::new(self, "expiry")
+    }
+    #[doc = "Unique identifier for the substance"]
+    pub fn identifier(self) -> TokenParameter<Substance> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Amount of substance in the package"]
+    pub fn quantity(self) -> QuantityParameter<Substance> {
+        QuantityParameter::new(self, "quantity")
+    }
+    #[doc = "active | inactive | entered-in-error"]
+    pub fn status(self) -> TokenParameter<Substance> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "A component of the substance"]
+    pub fn substance_reference(self) -> ReferenceParameter<Substance> {
+        ReferenceParameter::new(self, "substance-reference")
+    }
+}
+impl Search for SubstanceSpecification {
+    fn resource_type() -> ResourceType {
+        ResourceType::SubstanceSpecification
+    }
+}
+impl SearchQuery<SubstanceSpecification> {
+    #[doc = "The specific code"]
+    pub fn code(self) -> TokenParameter<SubstanceSpecification> {
+        TokenParameter::new(self, "code")
+    }
+}
+impl Search for SupplyDelivery {
+    fn resource_type() -> ResourceType {
+        ResourceType::SupplyDelivery
+    }
+}
+impl SearchQuery<SupplyDelivery> {
+    #[doc = "External identifier"]
+    pub fn identifier(self) -> TokenParameter<SupplyDelivery> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Patient for whom the item is supplied"]
+    pub fn patient(self) -> ReferenceParameter<SupplyDelivery> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who collected the Supply"]
+    pub fn receiver(self) -> ReferenceParameter<SupplyDelivery> {
+        ReferenceParameter::new(self, "receiver")
+    }
+    #[doc = "in-progress | completed | abandoned | entered-in-error"]
+    pub fn status(self) -> TokenParameter<SupplyDelivery> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Dispenser"]
+    pub fn supplier(self) -> ReferenceParameter<SupplyDelivery> {
+        ReferenceParameter::new(self, "supplier")
+    }
+}
+impl Search for SupplyRequest {
+    fn resource_type() -> ResourceType {
+        ResourceType::SupplyRequest
+    }
+}
+impl SearchQuery<SupplyRequest> {
+    #[doc = "The kind of supply (central, non-stock, etc.)"]
+    pub fn category(self) -> TokenParameter<SupplyRequest> {
+        TokenParameter::new(self, "category")
+    }
+    #[doc = "When the request was made"]
+    pub fn date(self) -> DateParameter<SupplyRequest> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "Business Identifier for SupplyRequest"]
+    pub fn identifier(self) -> TokenParameter<SupplyRequest> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Individual making the request"]
+    pub fn requester(self) -> ReferenceParameter<SupplyRequest> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "draft | active | suspended +"]
+    pub fn status(self) -> TokenParameter<SupplyRequest> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The destination of the supply"]
+    pub fn subject(self) -> ReferenceParameter<SupplyRequest> {
+        ReferenceParameter::new(self, "subject")
+    }
+    #[doc = "Who is intended to fulfill the request"]
+    pub fn supplier(self) -> ReferenceParameter<SupplyRequest> {
+        ReferenceParameter::new(self, "supplier")
+    }
+}
+impl Search for Task {
+    fn resource_type() -> ResourceType {
+        ResourceType::Task
+    }
+}
+impl SearchQuery<Task> {
+    #[doc = "Search by creation date"]
+    pub fn authored_on(self) -> DateParameter<Task> {
+        DateParameter::new(self, "authored-on")
+    }
+    #[doc = "Search by requests this task is based on"]
+    pub fn based_on(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "based-on")
+    }
+    #[doc = "Search by business status"]
+    pub fn business_status(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "business-status")
+    }
+    #[doc = "Search by task code"]
+    pub fn code(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "Search by encounter"]
+    pub fn encounter(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Search by task focus"]
+    pub fn focus(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "focus")
+    }
+    #[doc = "Search by group identifier"]
+    pub fn group_identifier(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "group-identifier")
+    }
+    #[doc = "Search for a task instance by its business identifier"]
+    pub fn identifier(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Search by task intent"]
+    pub fn intent(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "intent")
+    }
+    #[doc = "Search by last modification date"]
+    pub fn modified(self) -> DateParameter<Task> {
+        DateParameter::new(self, "modified")
+    }
+    #[doc = "Search by task owner"]
+    pub fn owner(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "owner")
+    }
+    #[doc = "Search by task this task is part of"]
+    pub fn part_of(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "part-of")
+    }
+    #[doc = "Search by patient"]
+    pub fn patient(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Search by recommended type of performer (e.g., Requester, Performer, Scheduler)."]
+    pub fn performer(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "performer")
+    }
+    #[doc = "Search by period Task is/was underway"]
+    pub fn period(self) -> DateParameter<Task> {
+        DateParameter::new(self, "period")
+    }
+    #[doc = "Search by task priority"]
+    pub fn priority(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "priority")
+    }
+    #[doc = "Search by task requester"]
+    pub fn requester(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "requester")
+    }
+    #[doc = "Search by task status"]
+    pub fn status(self) -> TokenParameter<Task> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "Search by subject"]
+    pub fn subject(self) -> ReferenceParameter<Task> {
+        ReferenceParameter::new(self, "subject")
+    }
+}
+impl Search for TerminologyCapabilities {
+    fn resource_type() -> ResourceType {
+        ResourceType::TerminologyCapabilities
+    }
+}
+impl SearchQuery<TerminologyCapabilities> {
+    #[doc = "A use context assigned to the terminology capabilities"]
+    pub fn context(self) -> TokenParameter<TerminologyCapabilities> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the terminology capabilities"]
+    pub fn context_quantity(self) -> QuantityParameter<TerminologyCapabilities> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the terminology capabilities"]
+    pub fn context_type(self) -> TokenParameter<TerminologyCapabilities> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the terminology capabilities"]
+    pub fn context_type_quantity(self) -> CompositeParameter<TerminologyCapabilities> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the terminology capabilities"]
+    pub fn context_type_value(self) -> CompositeParameter<TerminologyCapabilities> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The terminology capabilities publication date"]
+    pub fn date(self) -> DateParameter<TerminologyCapabilities> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the terminology capabilities"]
+    pub fn description(self) -> StringParameter<TerminologyCapabilities> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Intended jurisdiction for the terminology capabilities"]
+    pub fn jurisdiction(self) -> TokenParameter<TerminologyCapabilities> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the terminology capabilities"]
+    pub fn name(self) -> StringParameter<TerminologyCapabilities> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the terminology capabilities"]
+    pub fn publisher(self) -> StringParameter<TerminologyCapabilities> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the terminology capabilities"]
+    pub fn status(self) -> TokenParameter<TerminologyCapabilities> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the terminology capabilities"]
+    pub fn title(self) -> StringParameter<TerminologyCapabilities> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the terminology capabilities"]
+    pub fn url(self) -> UriParameter<TerminologyCapabilities> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the terminology capabilities"]
+    pub fn version(self) -> TokenParameter<TerminologyCapabilities> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for TestReport {
+    fn resource_type() -> ResourceType {
+        ResourceType::TestReport
+    }
+}
+impl SearchQuery<TestReport> {
+    #[doc = "An external identifier for the test report"]
+    pub fn identifier(self) -> TokenParameter<TestReport> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The test report generation date"]
+    pub fn issued(self) -> DateParameter<TestReport> {
+        DateParameter::new(self, "issued")
+    }
+    #[doc = "The reference to a participant in the test execution"]
+    pub fn participant(self) -> UriParameter<TestReport> {
+        UriParameter::new(self, "participant")
+    }
+    #[doc = "The result disposition of the test execution"]
+    pub fn result(self) -> TokenParameter<TestReport> {
+        TokenParameter::new(self, "result")
+    }
+    #[doc = "The name of the testing organization"]
+    pub fn tester(self) -> StringParameter<TestReport> {
+        StringParameter::new(self, "tester")
+    }
+    #[doc = "The test script executed to produce this report"]
+    pub fn testscript(self) -> ReferenceParameter<TestReport> {
+        ReferenceParameter::new(self, "testscript")
+    }
+}
+impl Search for TestScript {
+    fn resource_type() -> ResourceType {
+        ResourceType::TestScript
+    }
+}
+impl SearchQuery<TestScript> {
+    #[doc = "A use context assigned to the test script"]
+    pub fn context(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the test script"]
+    pub fn context_quantity(self) -> QuantityParameter<TestScript> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the test script"]
+    pub fn context_type(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the test script"]
+    pub fn context_type_quantity(self) -> CompositeParameter<TestScript> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the test script"]
+    pub fn context_type_value(self) -> CompositeParameter<TestScript> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The test script publication date"]
+    pub fn date(self) -> DateParameter<TestScript> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the test script"]
+    pub fn description(self) -> StringParameter<TestScript> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "External identifier for the test script"]
+    pub fn identifier(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the test script"]
+    pub fn jurisdiction(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the test script"]
+    pub fn name(self) -> StringParameter<TestScript> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the test script"]
+    pub fn publisher(self) -> StringParameter<TestScript> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "The current status of the test script"]
+    pub fn status(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "TestScript required and validated capability"]
+    pub fn testscript_capability(self) -> StringParameter<TestScript> {
+        StringParameter::new(self, "testscript-capability")
+    }
+    #[doc = "The human-friendly name of the test script"]
+    pub fn title(self) -> StringParameter<TestScript> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the test script"]
+    pub fn url(self) -> UriParameter<TestScript> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the test script"]
+    pub fn version(self) -> TokenParameter<TestScript> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for User {
+    fn resource_type() -> ResourceType {
+        ResourceType::User
+    }
+}
+impl SearchQuery<User> {
+    #[doc = "User email"]
+    pub fn email(self) -> TokenParameter<User> {
+        TokenParameter::new(self, "email")
+    }
+    #[doc = "User email verified status"]
+    pub fn email_verified(self) -> TokenParameter<User> {
+        TokenParameter::new(self, "email-verified")
+    }
+    #[doc = "User federated identityProvider"]
+    pub fn identity_provider(self) -> ReferenceParameter<User> {
+        ReferenceParameter::new(self, "identityProvider")
+    }
+    #[doc = "User name"]
+    pub fn name(self) -> StringParameter<User> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "User role"]
+    pub fn role(self) -> TokenParameter<User> {
+        TokenParameter::new(self, "role")
+    }
+}
+impl Search for ValueSet {
+    fn resource_type() -> ResourceType {
+        ResourceType::ValueSet
+    }
+}
+impl SearchQuery<ValueSet> {
+    #[doc = "This special parameter searches for codes in the value set. See additional notes on the ValueSet resource"]
+    pub fn code(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "code")
+    }
+    #[doc = "A use context assigned to the value set"]
+    pub fn context(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "context")
+    }
+    #[doc = "A quantity- or range-valued use context assigned to the value set"]
+    pub fn context_quantity(self) -> QuantityParameter<ValueSet> {
+        QuantityParameter::new(self, "context-quantity")
+    }
+    #[doc = "A type of use context assigned to the value set"]
+    pub fn context_type(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "context-type")
+    }
+    #[doc = "A use context type and quantity- or range-based value assigned to the value set"]
+    pub fn context_type_quantity(self) -> CompositeParameter<ValueSet> {
+        CompositeParameter::new(self, "context-type-quantity")
+    }
+    #[doc = "A use context type and value assigned to the value set"]
+    pub fn context_type_value(self) -> CompositeParameter<ValueSet> {
+        CompositeParameter::new(self, "context-type-value")
+    }
+    #[doc = "The value set publication date"]
+    pub fn date(self) -> DateParameter<ValueSet> {
+        DateParameter::new(self, "date")
+    }
+    #[doc = "The description of the value set"]
+    pub fn description(self) -> StringParameter<ValueSet> {
+        StringParameter::new(self, "description")
+    }
+    #[doc = "Identifies the value set expansion (business identifier)"]
+    pub fn expansion(self) -> UriParameter<ValueSet> {
+        UriParameter::new(self, "expansion")
+    }
+    #[doc = "External identifier for the value set"]
+    pub fn identifier(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "Intended jurisdiction for the value set"]
+    pub fn jurisdiction(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "jurisdiction")
+    }
+    #[doc = "Computationally friendly name of the value set"]
+    pub fn name(self) -> StringParameter<ValueSet> {
+        StringParameter::new(self, "name")
+    }
+    #[doc = "Name of the publisher of the value set"]
+    pub fn publisher(self) -> StringParameter<ValueSet> {
+        StringParameter::new(self, "publisher")
+    }
+    #[doc = "A code system included or excluded in the value set or an imported value set"]
+    pub fn reference(self) -> UriParameter<ValueSet> {
+        UriParameter::new(self, "reference")
+    }
+    #[doc = "The current status of the value set"]
+    pub fn status(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "status")
+    }
+    #[doc = "The human-friendly name of the value set"]
+    pub fn title(self) -> StringParameter<ValueSet> {
+        StringParameter::new(self, "title")
+    }
+    #[doc = "The uri that identifies the value set"]
+    pub fn url(self) -> UriParameter<ValueSet> {
+        UriParameter::new(self, "url")
+    }
+    #[doc = "The business version of the value set"]
+    pub fn version(self) -> TokenParameter<ValueSet> {
+        TokenParameter::new(self, "version")
+    }
+}
+impl Search for VerificationResult {
+    fn resource_type() -> ResourceType {
+        ResourceType::VerificationResult
+    }
+}
+impl SearchQuery<VerificationResult> {
+    #[doc = "A resource that was validated"]
+    pub fn target(self) -> ReferenceParameter<VerificationResult> {
+        ReferenceParameter::new(self, "target")
+    }
+}
+impl Search for VisionPrescription {
+    fn resource_type() -> ResourceType {
+        ResourceType::VisionPrescription
+    }
+}
+impl SearchQuery<VisionPrescription> {
+    #[doc = "Return prescriptions written on this date"]
+    pub fn datewritten(self) -> DateParameter<VisionPrescription> {
+        DateParameter::new(self, "datewritten")
+    }
+    #[doc = "Return prescriptions with this encounter identifier"]
+    pub fn encounter(self) -> ReferenceParameter<VisionPrescription> {
+        ReferenceParameter::new(self, "encounter")
+    }
+    #[doc = "Return prescriptions with this external identifier"]
+    pub fn identifier(self) -> TokenParameter<VisionPrescription> {
+        TokenParameter::new(self, "identifier")
+    }
+    #[doc = "The identity of a patient to list dispenses for"]
+    pub fn patient(self) -> ReferenceParameter<VisionPrescription> {
+        ReferenceParameter::new(self, "patient")
+    }
+    #[doc = "Who authorized the vision prescription"]
+    pub fn prescriber(self) -> ReferenceParameter<VisionPrescription> {
+        ReferenceParameter::new(self, "prescriber")
+    }
+    #[doc = "The status of the vision prescription"]
+    pub fn status(self) -> TokenParameter<VisionPrescription> {
+        TokenParameter::new(self, "status")
+    }
+}
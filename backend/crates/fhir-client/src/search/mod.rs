@@ -0,0 +1,430 @@
+//! Typed search queries, e.g. `Patient::search().family().exact("Smith").birthdate().ge(date)`.
+//! The per resource parameter methods are generated from the search parameter artifacts,
+//! see `haste-health codegen search-parameters`.
+use std::marker::PhantomData;
+
+use haste_fhir_model::r4::{
+    datetime::{Date, DateTime, Instant},
+    generated::resources::{Bundle, ResourceType},
+};
+
+use crate::{
+    FHIRClient,
+    url::{Parameter, ParsedParameter, ParsedParameters},
+};
+
+mod generated;
+
+/// Resources that can be searched with a typed [`SearchQuery`].
+pub trait Search: Sized {
+    fn resource_type() -> ResourceType;
+
+    fn search() -> SearchQuery<Self> {
+        SearchQuery::default()
+    }
+}
+
+/// Search query for the resource type `R`.
+/// Parameters only exist on the query if they are defined for `R`.
+#[derive(Debug, Clone)]
+pub struct SearchQuery<R> {
+    parameters: Vec<ParsedParameter>,
+    _resource: PhantomData<R>,
+}
+
+impl<R> Default for SearchQuery<R> {
+    fn default() -> Self {
+        SearchQuery {
+            parameters: vec![],
+            _resource: PhantomData,
+        }
+    }
+}
+
+impl<R: Search> SearchQuery<R> {
+    fn push(mut self, parameter: ParsedParameter) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    fn resource_parameter(self, name: &str, modifier: Option<&str>, value: String) -> Self {
+        self.push(ParsedParameter::Resource(Parameter {
+            name: name.to_string(),
+            value: vec![value],
+            modifier: modifier.map(|m| m.to_string()),
+            chains: None,
+        }))
+    }
+
+    fn result_parameter(self, name: &str, value: String) -> Self {
+        self.push(ParsedParameter::Result(Parameter {
+            name: name.to_string(),
+            value: vec![value],
+            modifier: None,
+            chains: None,
+        }))
+    }
+
+    pub fn resource_type(&self) -> ResourceType {
+        R::resource_type()
+    }
+
+    /// Number of resources returned per page.
+    pub fn count(self, count: usize) -> Self {
+        self.result_parameter("_count", count.to_string())
+    }
+
+    pub fn offset(self, offset: usize) -> Self {
+        self.result_parameter("_offset", offset.to_string())
+    }
+
+    /// Sort by a search parameter, prefix the parameter with `-` to sort descending.
+    pub fn sort(self, parameter: &str) -> Self {
+        self.result_parameter("_sort", parameter.to_string())
+    }
+
+    /// Include resources referenced by the results, e.g. `Observation:patient`.
+    pub fn include(self, include: &str) -> Self {
+        self.result_parameter("_include", include.to_string())
+    }
+
+    /// Include resources referencing the results, e.g. `Observation:patient`.
+    pub fn revinclude(self, revinclude: &str) -> Self {
+        self.result_parameter("_revinclude", revinclude.to_string())
+    }
+
+    pub fn total(self, total: &str) -> Self {
+        self.result_parameter("_total", total.to_string())
+    }
+
+    pub fn summary(self, summary: &str) -> Self {
+        self.result_parameter("_summary", summary.to_string())
+    }
+
+    pub fn elements(self, elements: &[&str]) -> Self {
+        self.result_parameter("_elements", elements.join(","))
+    }
+
+    pub fn into_parameters(self) -> ParsedParameters {
+        ParsedParameters::new(self.parameters)
+    }
+
+    /// Runs the query as a type level search.
+    pub async fn execute<CTX, Error, Client: FHIRClient<CTX, Error>>(
+        self,
+        client: &Client,
+        ctx: CTX,
+    ) -> Result<Bundle, Error> {
+        client
+            .search_type(ctx, R::resource_type(), self.into_parameters())
+            .await
+    }
+}
+
+/// Values accepted by date search parameters.
+pub trait DateSearchValue {
+    fn to_search_value(&self) -> String;
+}
+
+impl DateSearchValue for Date {
+    fn to_search_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl DateSearchValue for DateTime {
+    fn to_search_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl DateSearchValue for Instant {
+    fn to_search_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! search_parameter {
+    ($name:ident) => {
+        pub struct $name<R> {
+            query: SearchQuery<R>,
+            name: &'static str,
+        }
+
+        impl<R: Search> $name<R> {
+            pub(crate) fn new(query: SearchQuery<R>, name: &'static str) -> Self {
+                $name { query, name }
+            }
+
+            fn value(self, modifier: Option<&str>, value: String) -> SearchQuery<R> {
+                self.query.resource_parameter(self.name, modifier, value)
+            }
+
+            /// Matches resources that do (`true`) or do not (`false`) have a value for the parameter.
+            pub fn missing(self, missing: bool) -> SearchQuery<R> {
+                self.value(Some("missing"), missing.to_string())
+            }
+        }
+    };
+}
+
+/// Comparison prefixes, the parameter type converts the value with `search_value`.
+macro_rules! prefix_methods {
+    ($name:ident, $value:ty) => {
+        impl<R: Search> $name<R> {
+            fn prefixed(self, prefix: &str, value: $value) -> SearchQuery<R> {
+                let value = self.search_value(value);
+                self.value(None, format!("{}{}", prefix, value))
+            }
+
+            pub fn eq(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("eq", value)
+            }
+
+            pub fn ne(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("ne", value)
+            }
+
+            pub fn gt(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("gt", value)
+            }
+
+            pub fn lt(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("lt", value)
+            }
+
+            pub fn ge(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("ge", value)
+            }
+
+            pub fn le(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("le", value)
+            }
+
+            pub fn sa(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("sa", value)
+            }
+
+            pub fn eb(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("eb", value)
+            }
+
+            pub fn ap(self, value: $value) -> SearchQuery<R> {
+                self.prefixed("ap", value)
+            }
+        }
+    };
+}
+
+search_parameter!(StringParameter);
+
+impl<R: Search> StringParameter<R> {
+    /// Matches values starting with `value`, ignoring case and accents.
+    pub fn starts_with(self, value: &str) -> SearchQuery<R> {
+        self.value(None, value.to_string())
+    }
+
+    pub fn exact(self, value: &str) -> SearchQuery<R> {
+        self.value(Some("exact"), value.to_string())
+    }
+
+    pub fn contains(self, value: &str) -> SearchQuery<R> {
+        self.value(Some("contains"), value.to_string())
+    }
+}
+
+search_parameter!(TokenParameter);
+
+impl<R: Search> TokenParameter<R> {
+    /// Matches the code in any system.
+    pub fn code(self, code: &str) -> SearchQuery<R> {
+        self.value(None, code.to_string())
+    }
+
+    pub fn system_code(self, system: &str, code: &str) -> SearchQuery<R> {
+        self.value(None, format!("{}|{}", system, code))
+    }
+
+    /// Matches any code in the system.
+    pub fn system(self, system: &str) -> SearchQuery<R> {
+        self.value(None, format!("{}|", system))
+    }
+
+    pub fn not(self, code: &str) -> SearchQuery<R> {
+        self.value(Some("not"), code.to_string())
+    }
+
+    pub fn text(self, text: &str) -> SearchQuery<R> {
+        self.value(Some("text"), text.to_string())
+    }
+
+    pub fn in_value_set(self, value_set: &str) -> SearchQuery<R> {
+        self.value(Some("in"), value_set.to_string())
+    }
+}
+
+search_parameter!(DateParameter);
+prefix_methods!(DateParameter, &dyn DateSearchValue);
+
+impl<R: Search> DateParameter<R> {
+    fn search_value(&self, value: &dyn DateSearchValue) -> String {
+        value.to_search_value()
+    }
+}
+
+search_parameter!(NumberParameter);
+prefix_methods!(NumberParameter, f64);
+
+impl<R: Search> NumberParameter<R> {
+    fn search_value(&self, value: f64) -> String {
+        value.to_string()
+    }
+}
+
+pub struct QuantityParameter<R> {
+    query: SearchQuery<R>,
+    name: &'static str,
+    unit: Option<(String, String)>,
+}
+
+impl<R: Search> QuantityParameter<R> {
+    pub(crate) fn new(query: SearchQuery<R>, name: &'static str) -> Self {
+        QuantityParameter {
+            query,
+            name,
+            unit: None,
+        }
+    }
+
+    fn value(self, modifier: Option<&str>, value: String) -> SearchQuery<R> {
+        self.query.resource_parameter(self.name, modifier, value)
+    }
+
+    pub fn missing(self, missing: bool) -> SearchQuery<R> {
+        self.value(Some("missing"), missing.to_string())
+    }
+
+    /// Restricts the quantity to a unit, e.g. `with_unit("http://unitsofmeasure.org", "mg")`.
+    pub fn with_unit(mut self, system: &str, code: &str) -> Self {
+        self.unit = Some((system.to_string(), code.to_string()));
+        self
+    }
+
+    fn search_value(&self, value: f64) -> String {
+        match self.unit.as_ref() {
+            Some((system, code)) => format!("{}|{}|{}", value, system, code),
+            None => value.to_string(),
+        }
+    }
+}
+
+prefix_methods!(QuantityParameter, f64);
+
+search_parameter!(ReferenceParameter);
+
+impl<R: Search> ReferenceParameter<R> {
+    pub fn reference(self, resource_type: ResourceType, id: &str) -> SearchQuery<R> {
+        self.value(None, format!("{}/{}", resource_type.as_ref(), id))
+    }
+
+    pub fn id(self, id: &str) -> SearchQuery<R> {
+        self.value(None, id.to_string())
+    }
+
+    pub fn url(self, url: &str) -> SearchQuery<R> {
+        self.value(None, url.to_string())
+    }
+
+    /// Matches on the identifier of the reference.
+    pub fn identifier(self, system: &str, value: &str) -> SearchQuery<R> {
+        self.value(Some("identifier"), format!("{}|{}", system, value))
+    }
+}
+
+search_parameter!(UriParameter);
+
+impl<R: Search> UriParameter<R> {
+    pub fn equals(self, uri: &str) -> SearchQuery<R> {
+        self.value(None, uri.to_string())
+    }
+
+    pub fn above(self, uri: &str) -> SearchQuery<R> {
+        self.value(Some("above"), uri.to_string())
+    }
+
+    pub fn below(self, uri: &str) -> SearchQuery<R> {
+        self.value(Some("below"), uri.to_string())
+    }
+}
+
+search_parameter!(CompositeParameter);
+
+impl<R: Search> CompositeParameter<R> {
+    /// Values of the composite's components in order, e.g. `["http://loinc.org|8480-6", "gt150"]`.
+    pub fn components(self, components: &[&str]) -> SearchQuery<R> {
+        self.value(None, components.join("$"))
+    }
+}
+
+search_parameter!(SpecialParameter);
+
+impl<R: Search> SpecialParameter<R> {
+    pub fn equals(self, value: &str) -> SearchQuery<R> {
+        self.value(None, value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_model::r4::generated::resources::{Observation, Patient};
+
+    fn parameter<'a>(parameters: &'a ParsedParameters, name: &str) -> &'a Parameter {
+        match parameters.get(name).unwrap() {
+            ParsedParameter::Resource(parameter) | ParsedParameter::Result(parameter) => parameter,
+        }
+    }
+
+    #[test]
+    fn test_typed_search() {
+        let date = Date::Year(1990);
+        let parameters = Patient::search()
+            .family()
+            .exact("Smith")
+            .birthdate()
+            .ge(&date)
+            ._id()
+            .code("123")
+            .count(10)
+            .into_parameters();
+
+        let family = parameter(&parameters, "family");
+        assert_eq!(family.modifier.as_deref(), Some("exact"));
+        assert_eq!(family.value, vec!["Smith".to_string()]);
+        assert_eq!(
+            parameter(&parameters, "birthdate").value,
+            vec!["ge1990".to_string()]
+        );
+        assert_eq!(parameter(&parameters, "_id").value, vec!["123".to_string()]);
+        assert!(matches!(
+            parameters.get("_count"),
+            Some(ParsedParameter::Result(_))
+        ));
+
+        let parameters = Observation::search()
+            .value_quantity()
+            .with_unit("http://unitsofmeasure.org", "mg")
+            .gt(5.4)
+            .subject()
+            .reference(ResourceType::Patient, "123")
+            .into_parameters();
+        assert_eq!(
+            parameter(&parameters, "value-quantity").value,
+            vec!["gt5.4|http://unitsofmeasure.org|mg".to_string()]
+        );
+        assert_eq!(
+            parameter(&parameters, "subject").value,
+            vec!["Patient/123".to_string()]
+        );
+    }
+}
@@ -49,6 +49,7 @@ static RESULT_PARAMETERS: &[&str] = &[
     "_elements",
     "_contained",
     "_containedType",
+    "_cascade",
 ];
 
 #[derive(Debug, Clone)]
@@ -288,6 +288,137 @@ pub mod TenantInformation {
         }
     }
 }
+pub mod TenantUsage {
+    use super::*;
+    pub const CODE: &str = "usage";
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Input {}
+    impl From<Input> for Resource {
+        fn from(value: Input) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Output {
+        pub subscription: FHIRCode,
+        #[parameter_rename = "requests-per-minute"]
+        pub requests_per_minute: FHIRInteger,
+        #[parameter_rename = "requests-per-minute-limit"]
+        pub requests_per_minute_limit: Option<FHIRInteger>,
+        pub resources: FHIRInteger,
+        #[parameter_rename = "resources-limit"]
+        pub resources_limit: Option<FHIRInteger>,
+        pub projects: FHIRInteger,
+        #[parameter_rename = "projects-limit"]
+        pub projects_limit: Option<FHIRInteger>,
+        #[parameter_rename = "max-count"]
+        pub max_count: Option<FHIRInteger>,
+    }
+    impl From<Output> for Resource {
+        fn from(value: Output) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+}
+pub mod SmartLaunch {
+    use super::*;
+    pub const CODE: &str = "smart-launch";
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Input {
+        pub client_id: FHIRId,
+        pub patient: Option<FHIRId>,
+        pub encounter: Option<FHIRId>,
+        #[parameter_rename = "need-patient-banner"]
+        pub need_patient_banner: Option<FHIRBoolean>,
+    }
+    impl From<Input> for Resource {
+        fn from(value: Input) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Output {
+        pub launch: FHIRString,
+    }
+    impl From<Output> for Resource {
+        fn from(value: Output) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+}
+pub mod HasteHealthExpunge {
+    use super::*;
+    pub const CODE: &str = "expunge";
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Input {
+        pub confirm: Option<FHIRBoolean>,
+    }
+    impl From<Input> for Resource {
+        fn from(value: Input) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+    #[derive(Debug, FromParameters)]
+    pub struct Output {
+        #[parameter_rename = "return"]
+        pub return_: OperationOutcome,
+    }
+    impl From<Output> for Resource {
+        fn from(value: Output) -> Self {
+            Resource::OperationOutcome(value.return_)
+        }
+    }
+}
+pub mod HasteHealthImport {
+    use super::*;
+    pub const CODE: &str = "import";
+    #[derive(Debug, FromParameters, ToParameters)]
+    pub struct Input {
+        pub source: Option<Vec<FHIRUri>>,
+        pub resource: Option<Vec<Resource>>,
+        #[parameter_rename = "batch-size"]
+        pub batch_size: Option<FHIRInteger>,
+    }
+    impl From<Input> for Resource {
+        fn from(value: Input) -> Self {
+            let parameters: Vec<ParametersParameter> = value.into();
+            Resource::Parameters(Parameters {
+                parameter: Some(parameters),
+                ..Default::default()
+            })
+        }
+    }
+    #[derive(Debug, FromParameters)]
+    pub struct Output {
+        #[parameter_rename = "return"]
+        pub return_: OperationOutcome,
+    }
+    impl From<Output> for Resource {
+        fn from(value: Output) -> Self {
+            Resource::OperationOutcome(value.return_)
+        }
+    }
+}
 pub mod ActivityDefinitionApply {
     use super::*;
     pub const CODE: &str = "apply";
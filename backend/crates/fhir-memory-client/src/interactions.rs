@@ -0,0 +1,652 @@
+use crate::{
+    MemoryClientError, MemoryFHIRClient,
+    search::{self, SearchResult},
+    store::{Method, Store, Version},
+    transaction,
+};
+use haste_fhir_client::{
+    request::{
+        DeleteRequest, DeleteResponse, FHIRBatchResponse, FHIRCapabilitiesResponse,
+        FHIRCreateResponse, FHIRDeleteInstanceResponse, FHIRDeleteSystemResponse,
+        FHIRDeleteTypeResponse, FHIRHistoryInstanceResponse, FHIRHistorySystemResponse,
+        FHIRHistoryTypeResponse, FHIRInvokeInstanceResponse, FHIRInvokeSystemResponse,
+        FHIRInvokeTypeResponse, FHIRPatch, FHIRPatchResponse, FHIRReadResponse, FHIRRequest,
+        FHIRResponse, FHIRSearchSystemResponse, FHIRSearchTypeResponse, FHIRTransactionResponse,
+        FHIRUpdateResponse, FHIRVersionReadResponse, HistoryRequest, HistoryResponse,
+        InvocationRequest, InvokeResponse, SearchRequest, SearchResponse, UpdateRequest,
+    },
+    url::{ParsedParameter, ParsedParameters},
+};
+use haste_fhir_model::r4::{
+    datetime::{Instant, parse_instant},
+    generated::{
+        resources::{
+            Bundle, BundleEntry, BundleEntryRequest, BundleLink, CapabilityStatement,
+            CapabilityStatementRest, CapabilityStatementRestResourceOperation, Resource,
+            ResourceType,
+        },
+        terminology::{BundleType, HttpVerb, IssueType, RestfulCapabilityMode},
+        types::{FHIRCode, FHIRString, FHIRUnsignedInt, FHIRUri},
+    },
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_reflect::MetaValue;
+use haste_repository::utilities::generate_id;
+use json_patch::Patch;
+use std::io::{BufWriter, Write};
+
+pub fn resource_type_of(resource: &Resource) -> Result<ResourceType, OperationOutcomeError> {
+    ResourceType::try_from(resource.typename()).map_err(|_e| {
+        MemoryClientError::InvalidResourceType(resource.typename().to_string()).into()
+    })
+}
+
+fn check_resource_type(
+    resource_type: &ResourceType,
+    resource: &Resource,
+) -> Result<(), OperationOutcomeError> {
+    if &resource_type_of(resource)? != resource_type {
+        return Err(MemoryClientError::ResourceTypeMismatch(
+            resource_type.as_ref().to_string(),
+            resource.typename().to_string(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn resource_id(resource: &Resource) -> Option<String> {
+    resource
+        .get_field("id")
+        .and_then(|id| id.as_any().downcast_ref::<String>())
+        .cloned()
+}
+
+/// Applies a JSON Patch against the JSON serialization of the resource.
+fn apply_json_patch(resource: &Resource, patch: &Patch) -> Result<Resource, OperationOutcomeError> {
+    let mut writer = BufWriter::new(Vec::new());
+    haste_fhir_serialization_json::to_writer(&mut writer, resource).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to serialize resource for patching: ".to_string() + &e.to_string(),
+        )
+    })?;
+    writer.flush().map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to flush buffer: ".to_string() + &e.to_string(),
+        )
+    })?;
+    let content = writer.into_inner().map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to retrieve buffer content: ".to_string() + &e.to_string(),
+        )
+    })?;
+
+    let mut json: serde_json::Value = serde_json::from_slice(&content).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to deserialize JSON for patching: ".to_string() + &e.to_string(),
+        )
+    })?;
+
+    json_patch::patch(&mut json, patch).map_err(|e| {
+        OperationOutcomeError::error(
+            IssueType::Invalid(None),
+            format!("Failed to apply JSON patch: '{}'", e),
+        )
+    })?;
+
+    haste_fhir_serialization_json::from_serde_value::<Resource>(&json).map_err(|e| {
+        OperationOutcomeError::error(
+            IssueType::Invalid(None),
+            format!("Failed to deserialize patched resource '{}'.", e),
+        )
+    })
+}
+
+/// Only resource parameters are used to find the resources of conditional interactions.
+fn resource_parameters(parameters: &ParsedParameters) -> ParsedParameters {
+    ParsedParameters::new(
+        parameters
+            .parameters()
+            .iter()
+            .filter(|p| matches!(p, ParsedParameter::Resource(_)))
+            .cloned()
+            .collect(),
+    )
+}
+
+fn instance_url(version: &Version) -> String {
+    format!("{}/{}", version.resource_type.as_ref(), version.id)
+}
+
+fn history_bundle(
+    versions: Vec<&Version>,
+    parameters: &ParsedParameters,
+) -> Result<Bundle, OperationOutcomeError> {
+    let since = match parameters.get("_since") {
+        Some(ParsedParameter::Resource(since)) | Some(ParsedParameter::Result(since)) => {
+            let value = since.value.first().map(|v| v.as_str()).unwrap_or_default();
+            Some(parse_instant(value).map_err(|_e| {
+                OperationOutcomeError::error(
+                    IssueType::Invalid(None),
+                    format!("Invalid _since instant: '{}'", value),
+                )
+            })?)
+        }
+        None => None,
+    };
+    let count = match parameters.get("_count") {
+        Some(ParsedParameter::Result(count)) => count.value.first().and_then(|v| v.parse().ok()),
+        _ => None,
+    };
+
+    let entries = versions
+        .into_iter()
+        .filter(|version| {
+            since
+                .as_ref()
+                .is_none_or(|Instant::Iso8601(since)| &version.last_updated >= since)
+        })
+        .take(count.unwrap_or(usize::MAX))
+        .map(|version| {
+            let (method, url) = match version.method {
+                Method::Create => (
+                    HttpVerb::POST(None),
+                    version.resource_type.as_ref().to_string(),
+                ),
+                Method::Update => (HttpVerb::PUT(None), instance_url(version)),
+                Method::Delete => (HttpVerb::DELETE(None), instance_url(version)),
+            };
+
+            BundleEntry {
+                resource: if version.method == Method::Delete {
+                    None
+                } else {
+                    Some(Box::new(version.resource.clone()))
+                },
+                request: Some(BundleEntryRequest {
+                    method: Box::new(method),
+                    url: Box::new(FHIRUri {
+                        value: Some(url),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    Ok(Bundle {
+        type_: Box::new(BundleType::History(None)),
+        entry: Some(entries),
+        ..Default::default()
+    })
+}
+
+fn search_bundle(result: SearchResult<'_>, base: &str, parameters: &ParsedParameters) -> Bundle {
+    let link = result.next_offset.map(|offset| {
+        let query = search::to_query_string(parameters, &["_count", "_offset"]);
+        let separator = if query.is_empty() { "" } else { "&" };
+        vec![BundleLink {
+            relation: Box::new(FHIRString {
+                value: Some("next".to_string()),
+                ..Default::default()
+            }),
+            url: Box::new(FHIRUri {
+                value: Some(format!(
+                    "{}?{}{}_count={}&_offset={}",
+                    base, query, separator, result.count, offset
+                )),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }]
+    });
+
+    Bundle {
+        type_: Box::new(BundleType::Searchset(None)),
+        total: result.total.map(|total| {
+            Box::new(FHIRUnsignedInt {
+                value: Some(total as u64),
+                ..Default::default()
+            })
+        }),
+        link,
+        entry: Some(
+            result
+                .resources
+                .into_iter()
+                .map(|resource| BundleEntry {
+                    resource: Some(Box::new(resource.clone())),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+impl MemoryFHIRClient {
+    pub(crate) fn create(
+        &self,
+        store: &mut Store,
+        resource_type: ResourceType,
+        resource: Resource,
+        id: Option<String>,
+    ) -> Result<Resource, OperationOutcomeError> {
+        check_resource_type(&resource_type, &resource)?;
+        store.write(
+            Method::Create,
+            resource_type,
+            id.unwrap_or_else(|| generate_id(None)),
+            resource,
+        )
+    }
+
+    /// Updates the resource, creating it with the given id if it does not exist.
+    fn update(
+        &self,
+        store: &mut Store,
+        resource_type: ResourceType,
+        id: String,
+        resource: Resource,
+    ) -> Result<FHIRResponse, OperationOutcomeError> {
+        check_resource_type(&resource_type, &resource)?;
+        if store.read(&resource_type, &id).is_some() {
+            Ok(FHIRResponse::Update(FHIRUpdateResponse {
+                resource: store.write(Method::Update, resource_type, id, resource)?,
+            }))
+        } else {
+            Ok(FHIRResponse::Create(FHIRCreateResponse {
+                resource: store.write(Method::Create, resource_type, id, resource)?,
+            }))
+        }
+    }
+
+    fn search_matches(
+        &self,
+        store: &Store,
+        resource_type: Option<&ResourceType>,
+        parameters: &ParsedParameters,
+    ) -> Result<Vec<(ResourceType, String)>, OperationOutcomeError> {
+        let result = search::search(
+            &self.fp_engine,
+            resource_type,
+            &resource_parameters(parameters),
+            store.current(resource_type),
+        )?;
+
+        result
+            .resources
+            .into_iter()
+            .map(|resource| {
+                Ok((
+                    resource_type_of(resource)?,
+                    resource_id(resource).unwrap_or_default(),
+                ))
+            })
+            .collect()
+    }
+
+    fn delete(
+        &self,
+        store: &mut Store,
+        resource_type: ResourceType,
+        id: String,
+    ) -> Result<Resource, OperationOutcomeError> {
+        let resource = store.read(&resource_type, &id).cloned().ok_or_else(|| {
+            MemoryClientError::NotFound(resource_type.as_ref().to_string(), id.clone())
+        })?;
+
+        store.write(Method::Delete, resource_type, id, resource)
+    }
+
+    fn capabilities(&self) -> CapabilityStatement {
+        let mut operations = self.operations.keys().collect::<Vec<_>>();
+        operations.sort();
+
+        CapabilityStatement {
+            patchFormat: Some(
+                ["application/json-patch+json", "application/fhir+json"]
+                    .into_iter()
+                    .map(|format| {
+                        Box::new(FHIRCode {
+                            value: Some(format.to_string()),
+                            ..Default::default()
+                        })
+                    })
+                    .collect(),
+            ),
+            rest: Some(vec![CapabilityStatementRest {
+                mode: Box::new(RestfulCapabilityMode::Server(None)),
+                operation: Some(
+                    operations
+                        .into_iter()
+                        .map(|name| CapabilityStatementRestResourceOperation {
+                            name: Box::new(FHIRString {
+                                value: Some(name.clone()),
+                                ..Default::default()
+                            }),
+                            definition: Box::new(FHIRString {
+                                value: Some(name.clone()),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    fn invoke(&self, request: &InvocationRequest) -> Result<Resource, OperationOutcomeError> {
+        let operation = match request {
+            InvocationRequest::Instance(request) => &request.operation,
+            InvocationRequest::Type(request) => &request.operation,
+            InvocationRequest::System(request) => &request.operation,
+        };
+
+        let handler = self.operations.get(operation.name()).ok_or_else(|| {
+            MemoryClientError::OperationNotSupported(operation.name().to_string())
+        })?;
+
+        handler(request)
+    }
+
+    pub(crate) fn handle(
+        &self,
+        store: &mut Store,
+        request: FHIRRequest,
+    ) -> Result<FHIRResponse, OperationOutcomeError> {
+        match request {
+            FHIRRequest::Create(create_request) => Ok(FHIRResponse::Create(FHIRCreateResponse {
+                resource: self.create(
+                    store,
+                    create_request.resource_type,
+                    create_request.resource,
+                    None,
+                )?,
+            })),
+            FHIRRequest::Read(read_request) => Ok(FHIRResponse::Read(FHIRReadResponse {
+                resource: store
+                    .read(&read_request.resource_type, &read_request.id)
+                    .cloned(),
+            })),
+            FHIRRequest::VersionRead(vread_request) => {
+                let version = store
+                    .vread(
+                        &vread_request.resource_type,
+                        &vread_request.id,
+                        vread_request.version_id.as_ref(),
+                    )
+                    .filter(|version| version.method != Method::Delete)
+                    .ok_or_else(|| {
+                        MemoryClientError::VersionNotFound(
+                            vread_request.resource_type.as_ref().to_string(),
+                            vread_request.id.clone(),
+                            vread_request.version_id.as_ref().to_string(),
+                        )
+                    })?;
+
+                Ok(FHIRResponse::VersionRead(FHIRVersionReadResponse {
+                    resource: version.resource.clone(),
+                }))
+            }
+            FHIRRequest::Update(UpdateRequest::Instance(update_request)) => self.update(
+                store,
+                update_request.resource_type,
+                update_request.id,
+                update_request.resource,
+            ),
+            FHIRRequest::Update(UpdateRequest::Conditional(update_request)) => {
+                let matches = self.search_matches(
+                    store,
+                    Some(&update_request.resource_type),
+                    &update_request.parameters,
+                )?;
+                let body_id = resource_id(&update_request.resource);
+
+                match matches.as_slice() {
+                    [] => match body_id {
+                        Some(id) => {
+                            if store.read(&update_request.resource_type, &id).is_some() {
+                                return Err(OperationOutcomeError::error(
+                                    IssueType::NotFound(None),
+                                    "Resource exists but not found in conditional criteria."
+                                        .to_string(),
+                                ));
+                            }
+                            self.update(
+                                store,
+                                update_request.resource_type,
+                                id,
+                                update_request.resource,
+                            )
+                        }
+                        None => Ok(FHIRResponse::Create(FHIRCreateResponse {
+                            resource: self.create(
+                                store,
+                                update_request.resource_type,
+                                update_request.resource,
+                                None,
+                            )?,
+                        })),
+                    },
+                    [(_, id)] => {
+                        if body_id.as_ref().is_some_and(|body_id| body_id != id) {
+                            return Err(OperationOutcomeError::error(
+                                IssueType::Conflict(None),
+                                "Resource ID mismatch".to_string(),
+                            ));
+                        }
+                        self.update(
+                            store,
+                            update_request.resource_type,
+                            id.clone(),
+                            update_request.resource,
+                        )
+                    }
+                    _ => Err(OperationOutcomeError::error(
+                        IssueType::Conflict(None),
+                        "Multiple resources found for conditional update.".to_string(),
+                    )),
+                }
+            }
+            FHIRRequest::Patch(patch_request) => {
+                let resource = store
+                    .read(&patch_request.resource_type, &patch_request.id)
+                    .cloned()
+                    .ok_or_else(|| {
+                        MemoryClientError::NotFound(
+                            patch_request.resource_type.as_ref().to_string(),
+                            patch_request.id.clone(),
+                        )
+                    })?;
+
+                let patched_resource = match &patch_request.patch {
+                    FHIRPatch::JSONPatch(patch) => apply_json_patch(&resource, patch)?,
+                    FHIRPatch::FHIRPathPatch(parameters) => {
+                        let mut patched_resource = resource.clone();
+                        haste_fhirpath::patch::apply_patch(
+                            &self.fp_engine,
+                            &mut patched_resource,
+                            parameters.clone(),
+                        )
+                        .map_err(|e| {
+                            OperationOutcomeError::error(
+                                IssueType::Invalid(None),
+                                format!("Failed to apply FHIRPath patch: '{}'", e),
+                            )
+                        })?;
+                        patched_resource
+                    }
+                };
+
+                check_resource_type(&patch_request.resource_type, &patched_resource)?;
+                if resource_id(&patched_resource).as_deref() != Some(patch_request.id.as_str()) {
+                    return Err(OperationOutcomeError::error(
+                        IssueType::Conflict(None),
+                        "Resource ID mismatch after patching".to_string(),
+                    ));
+                }
+
+                Ok(FHIRResponse::Patch(FHIRPatchResponse {
+                    resource: store.write(
+                        Method::Update,
+                        patch_request.resource_type,
+                        patch_request.id,
+                        patched_resource,
+                    )?,
+                }))
+            }
+            FHIRRequest::Delete(DeleteRequest::Instance(delete_request)) => Ok(
+                FHIRResponse::Delete(DeleteResponse::Instance(FHIRDeleteInstanceResponse {
+                    resource: self.delete(
+                        store,
+                        delete_request.resource_type,
+                        delete_request.id,
+                    )?,
+                })),
+            ),
+            FHIRRequest::Delete(DeleteRequest::Type(delete_request)) => {
+                let resource = self
+                    .search_matches(
+                        store,
+                        Some(&delete_request.resource_type),
+                        &delete_request.parameters,
+                    )?
+                    .into_iter()
+                    .map(|(resource_type, id)| self.delete(store, resource_type, id))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(FHIRResponse::Delete(DeleteResponse::Type(
+                    FHIRDeleteTypeResponse { resource },
+                )))
+            }
+            FHIRRequest::Delete(DeleteRequest::System(delete_request)) => {
+                let resource = self
+                    .search_matches(store, None, &delete_request.parameters)?
+                    .into_iter()
+                    .map(|(resource_type, id)| self.delete(store, resource_type, id))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(FHIRResponse::Delete(DeleteResponse::System(
+                    FHIRDeleteSystemResponse { resource },
+                )))
+            }
+            FHIRRequest::Capabilities => Ok(FHIRResponse::Capabilities(FHIRCapabilitiesResponse {
+                capabilities: self.capabilities(),
+            })),
+            FHIRRequest::Search(SearchRequest::Type(search_request)) => {
+                let result = search::search(
+                    &self.fp_engine,
+                    Some(&search_request.resource_type),
+                    &search_request.parameters,
+                    store.current(Some(&search_request.resource_type)),
+                )?;
+
+                Ok(FHIRResponse::Search(SearchResponse::Type(
+                    FHIRSearchTypeResponse {
+                        bundle: search_bundle(
+                            result,
+                            search_request.resource_type.as_ref(),
+                            &search_request.parameters,
+                        ),
+                    },
+                )))
+            }
+            FHIRRequest::Search(SearchRequest::System(search_request)) => {
+                let result = search::search(
+                    &self.fp_engine,
+                    None,
+                    &search_request.parameters,
+                    store.current(None),
+                )?;
+
+                Ok(FHIRResponse::Search(SearchResponse::System(
+                    FHIRSearchSystemResponse {
+                        bundle: search_bundle(result, "", &search_request.parameters),
+                    },
+                )))
+            }
+            FHIRRequest::History(HistoryRequest::Instance(history_request)) => {
+                let versions = store.history(|version| {
+                    version.resource_type == history_request.resource_type
+                        && version.id == history_request.id
+                });
+
+                Ok(FHIRResponse::History(HistoryResponse::Instance(
+                    FHIRHistoryInstanceResponse {
+                        bundle: history_bundle(versions, &history_request.parameters)?,
+                    },
+                )))
+            }
+            FHIRRequest::History(HistoryRequest::Type(history_request)) => {
+                let versions =
+                    store.history(|version| version.resource_type == history_request.resource_type);
+
+                Ok(FHIRResponse::History(HistoryResponse::Type(
+                    FHIRHistoryTypeResponse {
+                        bundle: history_bundle(versions, &history_request.parameters)?,
+                    },
+                )))
+            }
+            FHIRRequest::History(HistoryRequest::System(history_request)) => {
+                let versions = store.history(|_| true);
+
+                Ok(FHIRResponse::History(HistoryResponse::System(
+                    FHIRHistorySystemResponse {
+                        bundle: history_bundle(versions, &history_request.parameters)?,
+                    },
+                )))
+            }
+            FHIRRequest::Invocation(invocation_request) => {
+                let resource = self.invoke(&invocation_request)?;
+                Ok(FHIRResponse::Invoke(match invocation_request {
+                    InvocationRequest::Instance(_) => {
+                        InvokeResponse::Instance(FHIRInvokeInstanceResponse { resource })
+                    }
+                    InvocationRequest::Type(_) => {
+                        InvokeResponse::Type(FHIRInvokeTypeResponse { resource })
+                    }
+                    InvocationRequest::System(_) => {
+                        InvokeResponse::System(FHIRInvokeSystemResponse { resource })
+                    }
+                }))
+            }
+            FHIRRequest::Batch(batch_request) => Ok(FHIRResponse::Batch(FHIRBatchResponse {
+                resource: transaction::process_batch(
+                    self,
+                    store,
+                    batch_request.resource.entry.unwrap_or_default(),
+                ),
+            })),
+            FHIRRequest::Transaction(transaction_request) => {
+                // Versions are shared so the snapshot is cheap, it's restored if any entry fails.
+                let snapshot = store.clone();
+                let result = transaction::process_transaction(
+                    self,
+                    store,
+                    transaction_request.resource.entry.unwrap_or_default(),
+                );
+
+                match result {
+                    Ok(bundle) => Ok(FHIRResponse::Transaction(FHIRTransactionResponse {
+                        resource: bundle,
+                    })),
+                    Err(operation_error) => {
+                        *store = snapshot;
+                        Err(operation_error)
+                    }
+                }
+            }
+        }
+    }
+}
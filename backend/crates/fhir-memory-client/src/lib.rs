@@ -0,0 +1,579 @@
+//! In-memory [`FHIRClient`] for tests, no Postgres or Elasticsearch required.
+//!
+//! Supports CRUD, versioning and history, search over the artifact search parameters,
+//! batches, transactions and registered operation stubs.
+use crate::store::{Method, Store};
+use haste_fhir_client::{
+    FHIRClient,
+    request::{
+        DeleteRequest, FHIRBatchRequest, FHIRConditionalUpdateRequest, FHIRCreateRequest,
+        FHIRDeleteInstanceRequest, FHIRDeleteSystemRequest, FHIRDeleteTypeRequest,
+        FHIRHistoryInstanceRequest, FHIRHistorySystemRequest, FHIRHistoryTypeRequest,
+        FHIRInvokeInstanceRequest, FHIRInvokeSystemRequest, FHIRInvokeTypeRequest, FHIRPatch,
+        FHIRPatchRequest, FHIRReadRequest, FHIRRequest, FHIRResponse, FHIRSearchSystemRequest,
+        FHIRSearchTypeRequest, FHIRTransactionRequest, FHIRUpdateInstanceRequest, HistoryRequest,
+        HistoryResponse, InvocationRequest, InvokeResponse, Operation, SearchRequest,
+        SearchResponse, UpdateRequest,
+    },
+    url::ParsedParameters,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Bundle, CapabilityStatement, Parameters, Resource, ResourceType},
+    terminology::IssueType,
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhirpath::FPEngine;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+mod interactions;
+mod search;
+mod store;
+mod transaction;
+
+#[derive(Debug, OperationOutcomeError)]
+pub enum MemoryClientError {
+    #[error(
+        code = "not-found",
+        diagnostic = "Resource '{arg0}' with id '{arg1}' not found."
+    )]
+    NotFound(String, String),
+    #[error(
+        code = "not-found",
+        diagnostic = "Version '{arg2}' of resource '{arg0}' with id '{arg1}' not found."
+    )]
+    VersionNotFound(String, String, String),
+    #[error(
+        code = "invalid",
+        diagnostic = "Expected resource of type '{arg0}' but found '{arg1}'."
+    )]
+    ResourceTypeMismatch(String, String),
+    #[error(code = "invalid", diagnostic = "Invalid resource type '{arg0}'.")]
+    InvalidResourceType(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "Operation '${arg0}' is not supported."
+    )]
+    OperationNotSupported(String),
+    #[error(code = "exception", diagnostic = "Unexpected response from request.")]
+    UnexpectedResponse,
+}
+
+/// Handles an operation invocation, registered with [`MemoryFHIRClient::with_operation`].
+pub type OperationHandler =
+    Arc<dyn Fn(&InvocationRequest) -> Result<Resource, OperationOutcomeError> + Send + Sync>;
+
+pub struct MemoryFHIRClient {
+    store: Mutex<Store>,
+    operations: HashMap<String, OperationHandler>,
+    fp_engine: FPEngine,
+}
+
+impl Default for MemoryFHIRClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryFHIRClient {
+    pub fn new() -> Self {
+        MemoryFHIRClient {
+            store: Mutex::new(Store::default()),
+            operations: HashMap::new(),
+            fp_engine: FPEngine::new(),
+        }
+    }
+
+    /// Registers a stub for the operation at every level (system, type and instance).
+    /// `name` is the operation code, with or without the leading `$`.
+    pub fn with_operation(
+        mut self,
+        name: &str,
+        handler: impl Fn(&InvocationRequest) -> Result<Resource, OperationOutcomeError>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.operations
+            .insert(name.trim_start_matches('$').to_string(), Arc::new(handler));
+        self
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Store>, OperationOutcomeError> {
+        self.store.lock().map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "In-memory store lock poisoned".to_string(),
+            )
+        })
+    }
+
+    fn execute(&self, request: FHIRRequest) -> Result<FHIRResponse, OperationOutcomeError> {
+        self.handle(&mut *self.lock()?, request)
+    }
+}
+
+impl<CTX: Send> FHIRClient<CTX, OperationOutcomeError> for MemoryFHIRClient {
+    async fn request(
+        &self,
+        _ctx: CTX,
+        request: FHIRRequest,
+    ) -> Result<FHIRResponse, OperationOutcomeError> {
+        self.execute(request)
+    }
+
+    async fn capabilities(&self, _ctx: CTX) -> Result<CapabilityStatement, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Capabilities)? {
+            FHIRResponse::Capabilities(capabilities_response) => {
+                Ok(capabilities_response.capabilities)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn search_system(
+        &self,
+        _ctx: CTX,
+        parameters: ParsedParameters,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Search(SearchRequest::System(
+            FHIRSearchSystemRequest { parameters },
+        )))? {
+            FHIRResponse::Search(SearchResponse::System(search_system_response)) => {
+                Ok(search_system_response.bundle)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn search_type(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        parameters: ParsedParameters,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Search(SearchRequest::Type(
+            FHIRSearchTypeRequest {
+                resource_type,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::Search(SearchResponse::Type(search_type_response)) => {
+                Ok(search_type_response.bundle)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn create(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        resource: Resource,
+    ) -> Result<Resource, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Create(FHIRCreateRequest {
+            resource_type,
+            resource,
+        }))? {
+            FHIRResponse::Create(create_response) => Ok(create_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn update(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+        resource: Resource,
+    ) -> Result<Resource, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Update(UpdateRequest::Instance(
+            FHIRUpdateInstanceRequest {
+                resource_type,
+                id,
+                resource,
+            },
+        )))? {
+            FHIRResponse::Update(update_response) => Ok(update_response.resource),
+            FHIRResponse::Create(create_response) => Ok(create_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn conditional_update(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        parameters: ParsedParameters,
+        resource: Resource,
+    ) -> Result<Resource, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Update(UpdateRequest::Conditional(
+            FHIRConditionalUpdateRequest {
+                resource_type,
+                parameters,
+                resource,
+            },
+        )))? {
+            FHIRResponse::Update(update_response) => Ok(update_response.resource),
+            FHIRResponse::Create(create_response) => Ok(create_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn patch(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+        patch: FHIRPatch,
+    ) -> Result<Resource, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Patch(FHIRPatchRequest {
+            resource_type,
+            id,
+            patch,
+        }))? {
+            FHIRResponse::Patch(patch_response) => Ok(patch_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn read(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+    ) -> Result<Option<Resource>, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Read(FHIRReadRequest { resource_type, id }))? {
+            FHIRResponse::Read(read_response) => Ok(read_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn vread(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+        version_id: String,
+    ) -> Result<Option<Resource>, OperationOutcomeError> {
+        // Unlike the version read interaction a missing version isn't an error.
+        let store = self.lock()?;
+        Ok(store
+            .vread(&resource_type, &id, &version_id)
+            .filter(|version| version.method != Method::Delete)
+            .map(|version| version.resource.clone()))
+    }
+
+    async fn delete_instance(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+    ) -> Result<(), OperationOutcomeError> {
+        match self.execute(FHIRRequest::Delete(DeleteRequest::Instance(
+            FHIRDeleteInstanceRequest {
+                resource_type,
+                id,
+                parameters: ParsedParameters::new(vec![]),
+            },
+        )))? {
+            FHIRResponse::Delete(_delete_instance_response) => Ok(()),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn delete_type(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        parameters: ParsedParameters,
+    ) -> Result<(), OperationOutcomeError> {
+        match self.execute(FHIRRequest::Delete(DeleteRequest::Type(
+            FHIRDeleteTypeRequest {
+                resource_type,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::Delete(_delete_type_response) => Ok(()),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn delete_system(
+        &self,
+        _ctx: CTX,
+        parameters: ParsedParameters,
+    ) -> Result<(), OperationOutcomeError> {
+        match self.execute(FHIRRequest::Delete(DeleteRequest::System(
+            FHIRDeleteSystemRequest { parameters },
+        )))? {
+            FHIRResponse::Delete(_delete_system_response) => Ok(()),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn history_system(
+        &self,
+        _ctx: CTX,
+        parameters: ParsedParameters,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::History(HistoryRequest::System(
+            FHIRHistorySystemRequest { parameters },
+        )))? {
+            FHIRResponse::History(HistoryResponse::System(history_system_response)) => {
+                Ok(history_system_response.bundle)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn history_type(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        parameters: ParsedParameters,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::History(HistoryRequest::Type(
+            FHIRHistoryTypeRequest {
+                resource_type,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::History(HistoryResponse::Type(history_type_response)) => {
+                Ok(history_type_response.bundle)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn history_instance(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+        parameters: ParsedParameters,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::History(HistoryRequest::Instance(
+            FHIRHistoryInstanceRequest {
+                resource_type,
+                id,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::History(HistoryResponse::Instance(history_instance_response)) => {
+                Ok(history_instance_response.bundle)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn invoke_instance(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        id: String,
+        operation: String,
+        parameters: Parameters,
+    ) -> Result<Resource, OperationOutcomeError> {
+        let operation = Operation::new(&operation).map_err(|_e| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                format!("Invalid operation name '{}'", operation),
+            )
+        })?;
+
+        match self.execute(FHIRRequest::Invocation(InvocationRequest::Instance(
+            FHIRInvokeInstanceRequest {
+                operation,
+                resource_type,
+                id,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::Invoke(InvokeResponse::Instance(invoke_response)) => {
+                Ok(invoke_response.resource)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn invoke_type(
+        &self,
+        _ctx: CTX,
+        resource_type: ResourceType,
+        operation: String,
+        parameters: Parameters,
+    ) -> Result<Resource, OperationOutcomeError> {
+        let operation = Operation::new(&operation).map_err(|_e| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                format!("Invalid operation name '{}'", operation),
+            )
+        })?;
+
+        match self.execute(FHIRRequest::Invocation(InvocationRequest::Type(
+            FHIRInvokeTypeRequest {
+                operation,
+                resource_type,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::Invoke(InvokeResponse::Type(invoke_response)) => {
+                Ok(invoke_response.resource)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn invoke_system(
+        &self,
+        _ctx: CTX,
+        operation: String,
+        parameters: Parameters,
+    ) -> Result<Resource, OperationOutcomeError> {
+        let operation = Operation::new(&operation).map_err(|_e| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                format!("Invalid operation name '{}'", operation),
+            )
+        })?;
+
+        match self.execute(FHIRRequest::Invocation(InvocationRequest::System(
+            FHIRInvokeSystemRequest {
+                operation,
+                parameters,
+            },
+        )))? {
+            FHIRResponse::Invoke(InvokeResponse::System(invoke_response)) => {
+                Ok(invoke_response.resource)
+            }
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn transaction(
+        &self,
+        _ctx: CTX,
+        bundle: Bundle,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Transaction(FHIRTransactionRequest {
+            resource: bundle,
+        }))? {
+            FHIRResponse::Transaction(transaction_response) => Ok(transaction_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+
+    async fn batch(&self, _ctx: CTX, bundle: Bundle) -> Result<Bundle, OperationOutcomeError> {
+        match self.execute(FHIRRequest::Batch(FHIRBatchRequest { resource: bundle }))? {
+            FHIRResponse::Batch(batch_response) => Ok(batch_response.resource),
+            _ => Err(MemoryClientError::UnexpectedResponse.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patient(family: &str) -> Resource {
+        haste_fhir_serialization_json::from_str::<Resource>(&format!(
+            r#"{{ "resourceType": "Patient", "name": [{{ "family": "{}" }}] }}"#,
+            family
+        ))
+        .unwrap()
+    }
+
+    fn id_of(resource: &Resource) -> String {
+        match resource {
+            Resource::Patient(patient) => patient.id.clone().unwrap(),
+            _ => panic!("Expected Patient"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crud_and_history() {
+        let client = MemoryFHIRClient::new();
+        let created = client
+            .create((), ResourceType::Patient, patient("Smith"))
+            .await
+            .unwrap();
+        let id = id_of(&created);
+
+        client
+            .update((), ResourceType::Patient, id.clone(), patient("Jones"))
+            .await
+            .unwrap();
+
+        let history = client
+            .history_instance(
+                (),
+                ResourceType::Patient,
+                id.clone(),
+                ParsedParameters::new(vec![]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(history.entry.map(|entry| entry.len()), Some(2));
+
+        let bundle = client
+            .search_type(
+                (),
+                ResourceType::Patient,
+                ParsedParameters::try_from("family=jon").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(bundle.entry.map(|entry| entry.len()), Some(1));
+
+        client
+            .delete_instance((), ResourceType::Patient, id.clone())
+            .await
+            .unwrap();
+        assert!(
+            client
+                .read((), ResourceType::Patient, id.clone())
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            client
+                .vread((), ResourceType::Patient, id, "1".to_string())
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transaction_rollback() {
+        let client = MemoryFHIRClient::new();
+        let bundle = haste_fhir_serialization_json::from_str::<Bundle>(
+            r#"{
+                "resourceType": "Bundle",
+                "type": "transaction",
+                "entry": [
+                    {
+                        "fullUrl": "urn:uuid:1",
+                        "resource": { "resourceType": "Patient" },
+                        "request": { "method": "POST", "url": "Patient" }
+                    },
+                    {
+                        "request": { "method": "DELETE", "url": "Patient/missing" }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(client.transaction((), bundle).await.is_err());
+        let bundle = client
+            .search_type((), ResourceType::Patient, ParsedParameters::new(vec![]))
+            .await
+            .unwrap();
+        assert!(bundle.entry.is_none_or(|entry| entry.is_empty()));
+    }
+}
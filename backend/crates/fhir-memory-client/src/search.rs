@@ -0,0 +1,549 @@
+//! Evaluates search parameters against resources held in memory.
+//! Resources are indexed with the same conversions used for Elasticsearch, so matching
+//! follows the server where it is supported and adds the standard FHIR prefixes.
+use haste_fhir_client::url::{Parameter, ParsedParameter, ParsedParameters};
+use haste_fhir_model::r4::{
+    datetime::parse_datetime,
+    generated::resources::{Resource, ResourceType, SearchParameter},
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_search::indexing_conversion::{
+    self, DateRange, InsertableIndex, QuantityRange, RangeValue, ReferenceIndex, TokenIndex,
+    date_time_range, get_decimal_range,
+};
+use haste_fhirpath::FPEngine;
+use std::cmp::Ordering;
+
+static DEFAULT_MAX_COUNT: usize = 50;
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum MemorySearchError {
+    #[error(
+        code = "not-found",
+        diagnostic = "Search parameter with name '{arg0}' not found.'"
+    )]
+    MissingParameter(String),
+    #[error(code = "not-supported", diagnostic = "Unsupported parameter: '{arg0}'")]
+    UnsupportedParameter(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "Unsupported sorting parameter: '{arg0}'"
+    )]
+    UnsupportedSortParameter(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "Chained parameter '{arg0}' is not supported."
+    )]
+    ChainNotSupported(String),
+    #[error(code = "invalid", diagnostic = "Invalid parameter value: '{arg0}'")]
+    InvalidParameterValue(String),
+    #[error(code = "invalid", diagnostic = "Invalid date format: '{arg0}'")]
+    InvalidDateFormat(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "Modifier '{arg0}' is not supported"
+    )]
+    ModifierNotSupported(String),
+    #[error(
+        code = "exception",
+        diagnostic = "Failed to evaluate FHIRPath expression '{arg0}'."
+    )]
+    ExpressionError(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Prefix {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Sa,
+    Eb,
+    Ap,
+}
+
+fn split_prefix(value: &str) -> (Prefix, &str) {
+    let prefix = match value.get(0..2) {
+        Some("eq") => Prefix::Eq,
+        Some("ne") => Prefix::Ne,
+        Some("gt") => Prefix::Gt,
+        Some("lt") => Prefix::Lt,
+        Some("ge") => Prefix::Ge,
+        Some("le") => Prefix::Le,
+        Some("sa") => Prefix::Sa,
+        Some("eb") => Prefix::Eb,
+        Some("ap") => Prefix::Ap,
+        _ => return (Prefix::Eq, value),
+    };
+
+    (prefix, &value[2..])
+}
+
+/// Compares the range of an indexed value against the range of the searched value.
+fn compare_ranges(prefix: Prefix, start: f64, end: f64, query_start: f64, query_end: f64) -> bool {
+    match prefix {
+        Prefix::Eq => start >= query_start && end <= query_end,
+        Prefix::Ne => !(start >= query_start && end <= query_end),
+        Prefix::Gt | Prefix::Sa => end > query_end,
+        Prefix::Lt | Prefix::Eb => start < query_start,
+        Prefix::Ge => end >= query_start,
+        Prefix::Le => start <= query_end,
+        Prefix::Ap => {
+            let margin = (query_end - query_start).max(query_start.abs() * 0.1);
+            start <= query_end + margin && end >= query_start - margin
+        }
+    }
+}
+
+fn index(
+    fp_engine: &FPEngine,
+    search_param: &SearchParameter,
+    resource: &Resource,
+) -> Result<InsertableIndex, OperationOutcomeError> {
+    let Some(expression) = search_param
+        .expression
+        .as_ref()
+        .and_then(|e| e.value.as_ref())
+    else {
+        return Ok(InsertableIndex::Special(vec![]));
+    };
+
+    let result = fp_engine
+        .evaluate(expression, vec![resource])
+        .map_err(|_e| MemorySearchError::ExpressionError(expression.to_string()))?;
+
+    indexing_conversion::to_insertable_index(search_param, result.iter().collect())
+}
+
+fn is_empty(index: &InsertableIndex) -> bool {
+    match index {
+        InsertableIndex::Meta(_) => false,
+        InsertableIndex::String(values)
+        | InsertableIndex::URI(values)
+        | InsertableIndex::Composite(values)
+        | InsertableIndex::Special(values) => values.is_empty(),
+        InsertableIndex::Number(values) => values.is_empty(),
+        InsertableIndex::Token(values) => values.is_empty(),
+        InsertableIndex::Date(values) => values.is_empty(),
+        InsertableIndex::Reference(values) => values.is_empty(),
+        InsertableIndex::Quantity(values) => values.is_empty(),
+    }
+}
+
+fn match_string(
+    modifier: Option<&str>,
+    indexed: &[String],
+    value: &str,
+) -> Result<bool, MemorySearchError> {
+    let value_lowercase = value.to_lowercase();
+    match modifier {
+        None => Ok(indexed
+            .iter()
+            .any(|s| s.to_lowercase().starts_with(&value_lowercase))),
+        Some("exact") => Ok(indexed.iter().any(|s| s == value)),
+        Some("contains") => Ok(indexed
+            .iter()
+            .any(|s| s.to_lowercase().contains(&value_lowercase))),
+        Some(modifier) => Err(MemorySearchError::ModifierNotSupported(modifier.into())),
+    }
+}
+
+/// `[code]`, `[system]|[code]`, `|[code]` and `[system]|`.
+fn match_token(indexed: &[TokenIndex], value: &str) -> Result<bool, MemorySearchError> {
+    let pieces = value.split('|').collect::<Vec<&str>>();
+    match pieces.as_slice() {
+        [code] => Ok(indexed.iter().any(|t| t.code.as_deref() == Some(*code))),
+        [system, code] => Ok(indexed.iter().any(|t| {
+            let system_matches = if system.is_empty() {
+                t.system.is_none()
+            } else {
+                t.system.as_deref() == Some(*system)
+            };
+
+            system_matches && (code.is_empty() || t.code.as_deref() == Some(*code))
+        })),
+        _ => Err(MemorySearchError::InvalidParameterValue(value.to_string())),
+    }
+}
+
+fn match_uri(
+    modifier: Option<&str>,
+    indexed: &[String],
+    value: &str,
+) -> Result<bool, MemorySearchError> {
+    match modifier {
+        None => Ok(indexed.iter().any(|uri| uri == value)),
+        Some("below") => Ok(indexed.iter().any(|uri| uri.starts_with(value))),
+        Some("above") => Ok(indexed.iter().any(|uri| value.starts_with(uri.as_str()))),
+        Some(modifier) => Err(MemorySearchError::ModifierNotSupported(modifier.into())),
+    }
+}
+
+fn match_number(indexed: &[f64], value: &str) -> Result<bool, MemorySearchError> {
+    let (prefix, number) = split_prefix(value);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_e| MemorySearchError::InvalidParameterValue(value.to_string()))?;
+    let range = get_decimal_range(number);
+
+    Ok(indexed
+        .iter()
+        .any(|n| compare_ranges(prefix, *n, *n, range.start, range.end)))
+}
+
+fn match_date(indexed: &[DateRange], value: &str) -> Result<bool, MemorySearchError> {
+    let (prefix, date) = split_prefix(value);
+    let date_time = parse_datetime(date)
+        .map_err(|_e| MemorySearchError::InvalidDateFormat(value.to_string()))?;
+    let range = date_time_range(&date_time)
+        .map_err(|_e| MemorySearchError::InvalidDateFormat(value.to_string()))?;
+
+    Ok(indexed.iter().any(|d| {
+        compare_ranges(
+            prefix,
+            d.start as f64,
+            d.end as f64,
+            range.start as f64,
+            range.end as f64,
+        )
+    }))
+}
+
+fn range_value(value: &RangeValue, infinity: f64) -> f64 {
+    match value {
+        RangeValue::Number(number) => *number,
+        RangeValue::Infinity => infinity,
+    }
+}
+
+/// `[prefix][number]`, `[prefix][number]|[system]|[code]` and `[prefix][number]||[code]`.
+fn match_quantity(indexed: &[QuantityRange], value: &str) -> Result<bool, MemorySearchError> {
+    let pieces = value.split('|').collect::<Vec<&str>>();
+    let (number, system, code) = match pieces.as_slice() {
+        [number] => (*number, "", ""),
+        [number, system, code] => (*number, *system, *code),
+        _ => return Err(MemorySearchError::InvalidParameterValue(value.to_string())),
+    };
+    let (prefix, number) = split_prefix(number);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_e| MemorySearchError::InvalidParameterValue(value.to_string()))?;
+
+    Ok(indexed.iter().any(|q| {
+        let start = range_value(&q.start_value, f64::NEG_INFINITY);
+        let end = range_value(&q.end_value, f64::INFINITY);
+        let value_matches = match prefix {
+            // Quantities are indexed with their precision, so equality checks containment.
+            Prefix::Eq | Prefix::Ap => start <= number && number <= end,
+            Prefix::Ne => !(start <= number && number <= end),
+            prefix => compare_ranges(prefix, start, end, number, number),
+        };
+
+        value_matches
+            && (system.is_empty() || q.start_system.as_deref() == Some(system))
+            && (code.is_empty() || q.start_code.as_deref() == Some(code))
+    }))
+}
+
+/// `[type]/[id]`, `[id]` (optionally typed with the modifier) and absolute urls.
+fn match_reference(
+    modifier: Option<&str>,
+    indexed: &[ReferenceIndex],
+    value: &str,
+) -> Result<bool, MemorySearchError> {
+    if value.contains("://") {
+        return Ok(indexed.iter().any(|r| r.uri.as_deref() == Some(value)));
+    }
+
+    let pieces = value.split('/').collect::<Vec<&str>>();
+    let (resource_type, id) = match (pieces.as_slice(), modifier) {
+        ([id], None) => (None, *id),
+        ([id], Some(resource_type)) => (Some(resource_type), *id),
+        ([resource_type, id], None) => (Some(*resource_type), *id),
+        _ => return Err(MemorySearchError::InvalidParameterValue(value.to_string())),
+    };
+
+    Ok(indexed.iter().any(|r| {
+        r.id.as_deref() == Some(id)
+            && resource_type
+                .is_none_or(|resource_type| r.resource_type.as_deref() == Some(resource_type))
+    }))
+}
+
+fn match_parameter(
+    fp_engine: &FPEngine,
+    search_param: &SearchParameter,
+    parameter: &Parameter,
+    resource: &Resource,
+) -> Result<bool, OperationOutcomeError> {
+    if parameter.chains.is_some() {
+        return Err(MemorySearchError::ChainNotSupported(parameter.name.clone()).into());
+    }
+
+    let index = index(fp_engine, search_param, resource)?;
+    let modifier = parameter.modifier.as_deref();
+
+    if modifier == Some("missing") {
+        return match parameter.value.first().map(|v| v.as_str()) {
+            Some("true") => Ok(is_empty(&index)),
+            Some("false") => Ok(!is_empty(&index)),
+            _ => Err(MemorySearchError::InvalidParameterValue(parameter.value.join(",")).into()),
+        };
+    }
+
+    // Values are OR'd together, `:not` excludes resources matching any of them.
+    let mut matches = false;
+    for value in parameter.value.iter() {
+        let value_matches = match &index {
+            InsertableIndex::String(indexed) => match_string(modifier, indexed, value)?,
+            InsertableIndex::Token(indexed) => match modifier {
+                None | Some("not") => match_token(indexed, value)?,
+                Some(modifier) => {
+                    return Err(MemorySearchError::ModifierNotSupported(modifier.into()).into());
+                }
+            },
+            InsertableIndex::URI(indexed) => match_uri(modifier, indexed, value)?,
+            InsertableIndex::Number(indexed) => match_number(indexed, value)?,
+            InsertableIndex::Date(indexed) => match_date(indexed, value)?,
+            InsertableIndex::Quantity(indexed) => match_quantity(indexed, value)?,
+            InsertableIndex::Reference(indexed) => match_reference(modifier, indexed, value)?,
+            InsertableIndex::Meta(_)
+            | InsertableIndex::Composite(_)
+            | InsertableIndex::Special(_) => {
+                return Err(MemorySearchError::UnsupportedParameter(parameter.name.clone()).into());
+            }
+        };
+
+        if value_matches {
+            matches = true;
+            break;
+        }
+    }
+
+    if modifier == Some("not") {
+        Ok(!matches)
+    } else {
+        Ok(matches)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+enum SortKey {
+    Missing,
+    Number(f64),
+    String(String),
+}
+
+fn sort_key(
+    fp_engine: &FPEngine,
+    search_param: &SearchParameter,
+    resource: &Resource,
+    descending: bool,
+) -> Result<SortKey, OperationOutcomeError> {
+    let key = match index(fp_engine, search_param, resource)? {
+        InsertableIndex::String(values) => values
+            .into_iter()
+            .map(|s| s.to_lowercase())
+            .min()
+            .map(SortKey::String),
+        InsertableIndex::Token(values) => values
+            .into_iter()
+            .filter_map(|t| t.code)
+            .min()
+            .map(SortKey::String),
+        // Ascending sorts on the start of the range, descending on the end.
+        InsertableIndex::Date(values) => {
+            let ms = if descending {
+                values.iter().map(|d| d.end).max()
+            } else {
+                values.iter().map(|d| d.start).min()
+            };
+            ms.map(|ms| SortKey::Number(ms as f64))
+        }
+        _ => {
+            return Err(MemorySearchError::UnsupportedSortParameter(
+                search_param.name.value.clone().unwrap_or_default(),
+            )
+            .into());
+        }
+    };
+
+    Ok(key.unwrap_or(SortKey::Missing))
+}
+
+fn search_parameter(
+    resource_type: Option<&ResourceType>,
+    name: &str,
+) -> Result<std::sync::Arc<SearchParameter>, MemorySearchError> {
+    haste_artifacts::search_parameters::get_search_parameter_for_name(resource_type, name)
+        .ok_or_else(|| MemorySearchError::MissingParameter(name.to_string()))
+}
+
+pub struct SearchResult<'a> {
+    pub resources: Vec<&'a Resource>,
+    /// Set when `_total` was requested.
+    pub total: Option<i64>,
+    /// Offset of the next page, if there are more matches.
+    pub next_offset: Option<usize>,
+    pub count: usize,
+}
+
+/// Filters, sorts and pages the resources. Result parameters follow the server,
+/// `_count` is capped at 50 and only `_count`, `_offset`, `_total` and `_sort` are supported.
+pub fn search<'a>(
+    fp_engine: &FPEngine,
+    resource_type: Option<&ResourceType>,
+    parameters: &ParsedParameters,
+    resources: Vec<&'a Resource>,
+) -> Result<SearchResult<'a>, OperationOutcomeError> {
+    let mut count = DEFAULT_MAX_COUNT;
+    let mut offset = 0;
+    let mut show_total = false;
+    let mut sort = vec![];
+    let mut filters = vec![];
+
+    for parameter in parameters.parameters().iter() {
+        match parameter {
+            ParsedParameter::Resource(resource_param) => {
+                filters.push((
+                    search_parameter(resource_type, &resource_param.name)?,
+                    resource_param,
+                ));
+            }
+            ParsedParameter::Result(result_param) => match result_param.name.as_str() {
+                "_count" => {
+                    count = std::cmp::min(
+                        result_param
+                            .value
+                            .first()
+                            .and_then(|v| v.parse::<usize>().ok())
+                            .unwrap_or(DEFAULT_MAX_COUNT),
+                        DEFAULT_MAX_COUNT,
+                    );
+                }
+                "_offset" => {
+                    offset = result_param
+                        .value
+                        .first()
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .unwrap_or(0);
+                }
+                "_total" => match result_param.value.first().map(|v| v.as_str()) {
+                    Some("none") => show_total = false,
+                    Some("accurate") | Some("estimate") => show_total = true,
+                    _ => {
+                        return Err(MemorySearchError::InvalidParameterValue(
+                            result_param.name.to_string(),
+                        )
+                        .into());
+                    }
+                },
+                "_sort" => {
+                    sort = result_param
+                        .value
+                        .iter()
+                        .map(|sort_param| {
+                            let (name, descending) = match sort_param.strip_prefix('-') {
+                                Some(name) => (name, true),
+                                None => (sort_param.as_str(), false),
+                            };
+                            Ok((search_parameter(resource_type, name)?, descending))
+                        })
+                        .collect::<Result<Vec<_>, MemorySearchError>>()?;
+                }
+                _ => {
+                    return Err(MemorySearchError::UnsupportedParameter(
+                        result_param.name.to_string(),
+                    )
+                    .into());
+                }
+            },
+        }
+    }
+
+    let mut matches = vec![];
+    for resource in resources {
+        let mut is_match = true;
+        for (search_param, parameter) in filters.iter() {
+            if !match_parameter(fp_engine, search_param, parameter, resource)? {
+                is_match = false;
+                break;
+            }
+        }
+
+        if is_match {
+            matches.push(resource);
+        }
+    }
+
+    if !sort.is_empty() {
+        let mut keyed = matches
+            .into_iter()
+            .map(|resource| {
+                let keys = sort
+                    .iter()
+                    .map(|(search_param, descending)| {
+                        sort_key(fp_engine, search_param, resource, *descending)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((keys, resource))
+            })
+            .collect::<Result<Vec<_>, OperationOutcomeError>>()?;
+
+        keyed.sort_by(|(a, _), (b, _)| {
+            for ((a, b), (_, descending)) in a.iter().zip(b.iter()).zip(sort.iter()) {
+                let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+                let ordering = if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+
+        matches = keyed.into_iter().map(|(_, resource)| resource).collect();
+    }
+
+    let total = matches.len();
+    let next_offset = if offset + count < total {
+        Some(offset + count)
+    } else {
+        None
+    };
+
+    Ok(SearchResult {
+        resources: matches.into_iter().skip(offset).take(count).collect(),
+        total: if show_total { Some(total as i64) } else { None },
+        next_offset,
+        count,
+    })
+}
+
+/// Serializes parameters back into a query string, without the result parameters in `exclude`.
+pub fn to_query_string(parameters: &ParsedParameters, exclude: &[&str]) -> String {
+    parameters
+        .parameters()
+        .iter()
+        .map(|parameter| match parameter {
+            ParsedParameter::Result(parameter) | ParsedParameter::Resource(parameter) => parameter,
+        })
+        .filter(|parameter| !exclude.contains(&parameter.name.as_str()))
+        .map(|parameter| {
+            let mut name = parameter.name.clone();
+            if let Some(modifier) = parameter.modifier.as_ref() {
+                name = format!("{}:{}", name, modifier);
+            }
+            if let Some(chains) = parameter.chains.as_ref() {
+                name = format!("{}.{}", name, chains.join("."));
+            }
+            format!("{}={}", name, parameter.value.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
@@ -0,0 +1,157 @@
+use haste_fhir_model::r4::{
+    datetime::Instant,
+    generated::{
+        resources::{Resource, ResourceType},
+        types::{FHIRId, FHIRInstant, Meta},
+    },
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_reflect::MetaValue;
+use haste_repository::utilities::{DataTransformError, set_resource_id};
+use std::{collections::BTreeMap, sync::Arc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug)]
+pub struct Version {
+    pub resource_type: ResourceType,
+    pub id: String,
+    pub version_id: String,
+    pub method: Method,
+    pub last_updated: chrono::DateTime<chrono::Utc>,
+    pub resource: Resource,
+}
+
+/// Versioned resource storage. Cloning is cheap as versions are shared,
+/// which is used to snapshot the store before a transaction.
+#[derive(Debug, Clone, Default)]
+pub struct Store {
+    /// Every version in write order.
+    versions: Vec<Arc<Version>>,
+    /// Positions in `versions` of each resource's versions, oldest first.
+    resources: BTreeMap<(ResourceType, String), Vec<usize>>,
+}
+
+fn set_meta(
+    resource: &mut Resource,
+    version_id: &str,
+    last_updated: chrono::DateTime<chrono::Utc>,
+) -> Result<(), OperationOutcomeError> {
+    let meta: &mut dyn std::any::Any =
+        resource
+            .get_field_mut("meta")
+            .ok_or(DataTransformError::InvalidData(
+                "Missing 'meta' field".to_string(),
+            ))?;
+    let meta: &mut Option<Box<Meta>> =
+        meta.downcast_mut::<Option<Box<Meta>>>()
+            .ok_or(DataTransformError::InvalidData(
+                "Invalid 'meta' field".to_string(),
+            ))?;
+
+    let meta = meta.get_or_insert_with(Default::default);
+    meta.versionId = Some(Box::new(FHIRId {
+        value: Some(version_id.to_string()),
+        ..Default::default()
+    }));
+    meta.lastUpdated = Some(Box::new(FHIRInstant {
+        value: Some(Instant::Iso8601(last_updated)),
+        ..Default::default()
+    }));
+
+    Ok(())
+}
+
+impl Store {
+    /// Latest version of a resource, including deletions.
+    pub fn latest(&self, resource_type: &ResourceType, id: &str) -> Option<&Version> {
+        self.resources
+            .get(&(resource_type.clone(), id.to_string()))
+            .and_then(|positions| positions.last())
+            .map(|position| self.versions[*position].as_ref())
+    }
+
+    /// Current resource, `None` if it does not exist or was deleted.
+    pub fn read(&self, resource_type: &ResourceType, id: &str) -> Option<&Resource> {
+        self.latest(resource_type, id)
+            .filter(|version| version.method != Method::Delete)
+            .map(|version| &version.resource)
+    }
+
+    pub fn vread(
+        &self,
+        resource_type: &ResourceType,
+        id: &str,
+        version_id: &str,
+    ) -> Option<&Version> {
+        self.resources
+            .get(&(resource_type.clone(), id.to_string()))?
+            .iter()
+            .map(|position| self.versions[*position].as_ref())
+            .find(|version| version.version_id == version_id)
+    }
+
+    /// Current resources in creation order, optionally restricted to a resource type.
+    pub fn current(&self, resource_type: Option<&ResourceType>) -> Vec<&Resource> {
+        let mut current = self
+            .resources
+            .iter()
+            .filter(|((rt, _), _)| resource_type.is_none_or(|resource_type| resource_type == rt))
+            .filter_map(|(_, positions)| {
+                let version = self.versions[*positions.last()?].as_ref();
+                if version.method == Method::Delete {
+                    None
+                } else {
+                    Some((positions[0], &version.resource))
+                }
+            })
+            .collect::<Vec<_>>();
+        current.sort_by_key(|(created, _)| *created);
+
+        current.into_iter().map(|(_, resource)| resource).collect()
+    }
+
+    /// Versions matching the filter, newest first.
+    pub fn history(&self, filter: impl Fn(&Version) -> bool) -> Vec<&Version> {
+        self.versions
+            .iter()
+            .rev()
+            .map(|version| version.as_ref())
+            .filter(|version| filter(version))
+            .collect()
+    }
+
+    /// Writes a new version of the resource, setting its id, versionId and lastUpdated.
+    pub fn write(
+        &mut self,
+        method: Method,
+        resource_type: ResourceType,
+        id: String,
+        mut resource: Resource,
+    ) -> Result<Resource, OperationOutcomeError> {
+        let version_id = (self.versions.len() + 1).to_string();
+        let last_updated = chrono::Utc::now();
+        set_resource_id(&mut resource, Some(id.clone()))?;
+        set_meta(&mut resource, &version_id, last_updated)?;
+
+        self.resources
+            .entry((resource_type.clone(), id.clone()))
+            .or_default()
+            .push(self.versions.len());
+        self.versions.push(Arc::new(Version {
+            resource_type,
+            id,
+            version_id,
+            method,
+            last_updated,
+            resource: resource.clone(),
+        }));
+
+        Ok(resource)
+    }
+}
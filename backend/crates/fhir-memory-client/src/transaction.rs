@@ -0,0 +1,382 @@
+//! Batch and transaction bundles. Transaction entries referencing each other through their
+//! fullUrl are resolved by assigning ids to created resources before any entry is processed.
+use crate::{MemoryFHIRClient, interactions::resource_type_of, store::Store};
+use haste_fhir_client::{
+    request::{
+        DeleteRequest, FHIRConditionalUpdateRequest, FHIRCreateRequest, FHIRCreateResponse,
+        FHIRDeleteInstanceRequest, FHIRDeleteSystemRequest, FHIRDeleteTypeRequest,
+        FHIRHistoryInstanceRequest, FHIRHistorySystemRequest, FHIRHistoryTypeRequest,
+        FHIRInvokeInstanceRequest, FHIRInvokeSystemRequest, FHIRInvokeTypeRequest, FHIRPatch,
+        FHIRPatchRequest, FHIRReadRequest, FHIRRequest, FHIRResponse, FHIRSearchSystemRequest,
+        FHIRSearchTypeRequest, FHIRUpdateInstanceRequest, FHIRVersionReadRequest, HistoryRequest,
+        HistoryResponse, InvocationRequest, InvokeResponse, Operation, SearchRequest,
+        SearchResponse, UpdateRequest,
+    },
+    url::ParsedParameters,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Bundle, BundleEntry, BundleEntryResponse, Parameters, Resource, ResourceType},
+    terminology::{BundleType, IssueType},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_jwt::VersionId;
+use haste_repository::utilities::generate_id;
+use std::collections::HashMap;
+
+fn invalid_entry(message: &str) -> OperationOutcomeError {
+    OperationOutcomeError::error(IssueType::Invalid(None), message.to_string())
+}
+
+fn parse_resource_type(resource_type: &str) -> Result<ResourceType, OperationOutcomeError> {
+    ResourceType::try_from(resource_type)
+        .map_err(|_e| invalid_entry(&format!("Invalid resource type '{}'", resource_type)))
+}
+
+fn parameters_body(resource: Option<Resource>) -> Result<Parameters, OperationOutcomeError> {
+    match resource {
+        Some(Resource::Parameters(parameters)) => Ok(parameters),
+        None => Ok(Parameters::default()),
+        Some(_) => Err(invalid_entry(
+            "Operation body must be a Parameters resource",
+        )),
+    }
+}
+
+fn required_resource(resource: Option<Resource>) -> Result<Resource, OperationOutcomeError> {
+    resource.ok_or_else(|| invalid_entry("Bundle entry missing resource"))
+}
+
+fn operation(name: &str) -> Result<Operation, OperationOutcomeError> {
+    Operation::new(name).map_err(|_e| invalid_entry("Invalid operation name"))
+}
+
+/// Converts the request of a bundle entry to a FHIR request, the url is relative to the server root.
+fn bundle_entry_to_fhir_request(entry: BundleEntry) -> Result<FHIRRequest, OperationOutcomeError> {
+    let request = entry
+        .request
+        .as_ref()
+        .ok_or_else(|| invalid_entry("Bundle entry missing request"))?;
+    let url = request.url.value.clone().unwrap_or_default();
+    let method: Option<String> = request.method.as_ref().into();
+    let resource = entry.resource.map(|resource| *resource);
+
+    let (path, query) = url.split_once('?').unwrap_or((url.as_str(), ""));
+    let parameters = ParsedParameters::try_from(query)?;
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    match (method.as_deref().unwrap_or_default(), segments.as_slice()) {
+        ("GET", []) => Ok(FHIRRequest::Search(SearchRequest::System(
+            FHIRSearchSystemRequest { parameters },
+        ))),
+        ("GET", ["metadata"]) => Ok(FHIRRequest::Capabilities),
+        ("GET", ["_history"]) => Ok(FHIRRequest::History(HistoryRequest::System(
+            FHIRHistorySystemRequest { parameters },
+        ))),
+        ("DELETE", []) => Ok(FHIRRequest::Delete(DeleteRequest::System(
+            FHIRDeleteSystemRequest { parameters },
+        ))),
+        ("GET" | "POST", [operation_name]) if operation_name.starts_with('$') => Ok(
+            FHIRRequest::Invocation(InvocationRequest::System(FHIRInvokeSystemRequest {
+                operation: operation(operation_name)?,
+                parameters: parameters_body(resource)?,
+            })),
+        ),
+        ("GET", [resource_type]) => Ok(FHIRRequest::Search(SearchRequest::Type(
+            FHIRSearchTypeRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                parameters,
+            },
+        ))),
+        ("POST", [resource_type]) => Ok(FHIRRequest::Create(FHIRCreateRequest {
+            resource_type: parse_resource_type(resource_type)?,
+            resource: required_resource(resource)?,
+        })),
+        ("PUT", [resource_type]) => Ok(FHIRRequest::Update(UpdateRequest::Conditional(
+            FHIRConditionalUpdateRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                parameters,
+                resource: required_resource(resource)?,
+            },
+        ))),
+        ("DELETE", [resource_type]) => Ok(FHIRRequest::Delete(DeleteRequest::Type(
+            FHIRDeleteTypeRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                parameters,
+            },
+        ))),
+        ("GET", [resource_type, "_history"]) => Ok(FHIRRequest::History(HistoryRequest::Type(
+            FHIRHistoryTypeRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                parameters,
+            },
+        ))),
+        ("GET" | "POST", [resource_type, operation_name]) if operation_name.starts_with('$') => Ok(
+            FHIRRequest::Invocation(InvocationRequest::Type(FHIRInvokeTypeRequest {
+                operation: operation(operation_name)?,
+                resource_type: parse_resource_type(resource_type)?,
+                parameters: parameters_body(resource)?,
+            })),
+        ),
+        ("GET", [resource_type, id]) => Ok(FHIRRequest::Read(FHIRReadRequest {
+            resource_type: parse_resource_type(resource_type)?,
+            id: id.to_string(),
+        })),
+        ("PUT", [resource_type, id]) => Ok(FHIRRequest::Update(UpdateRequest::Instance(
+            FHIRUpdateInstanceRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                id: id.to_string(),
+                resource: required_resource(resource)?,
+            },
+        ))),
+        // JSON Patch bodies can't be carried in a bundle without a Binary resource,
+        // so only FHIRPath Patch is supported here.
+        ("PATCH", [resource_type, id]) => match resource {
+            Some(Resource::Parameters(parameters)) => Ok(FHIRRequest::Patch(FHIRPatchRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                id: id.to_string(),
+                patch: FHIRPatch::FHIRPathPatch(parameters),
+            })),
+            _ => Err(invalid_entry(
+                "Patch entries must contain a FHIRPath Patch Parameters resource",
+            )),
+        },
+        ("DELETE", [resource_type, id]) => Ok(FHIRRequest::Delete(DeleteRequest::Instance(
+            FHIRDeleteInstanceRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                id: id.to_string(),
+                parameters: ParsedParameters::new(vec![]),
+            },
+        ))),
+        ("GET" | "POST", [resource_type, id, operation_name])
+            if operation_name.starts_with('$') =>
+        {
+            Ok(FHIRRequest::Invocation(InvocationRequest::Instance(
+                FHIRInvokeInstanceRequest {
+                    operation: operation(operation_name)?,
+                    resource_type: parse_resource_type(resource_type)?,
+                    id: id.to_string(),
+                    parameters: parameters_body(resource)?,
+                },
+            )))
+        }
+        ("GET", [resource_type, id, "_history"]) => Ok(FHIRRequest::History(
+            HistoryRequest::Instance(FHIRHistoryInstanceRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                id: id.to_string(),
+                parameters,
+            }),
+        )),
+        ("GET", [resource_type, id, "_history", version_id]) => {
+            Ok(FHIRRequest::VersionRead(FHIRVersionReadRequest {
+                resource_type: parse_resource_type(resource_type)?,
+                id: id.to_string(),
+                version_id: VersionId::new(version_id.to_string()),
+            }))
+        }
+        _ => Err(invalid_entry(&format!(
+            "Unsupported bundle entry request '{} {}'",
+            method.unwrap_or_default(),
+            url
+        ))),
+    }
+}
+
+fn convert_bundle_entry(fhir_response: Result<FHIRResponse, OperationOutcomeError>) -> BundleEntry {
+    let resource = match fhir_response {
+        Ok(FHIRResponse::Create(res)) => Some(res.resource),
+        Ok(FHIRResponse::Read(res)) => match res.resource {
+            Some(resource) => Some(resource),
+            None => {
+                return convert_bundle_entry(Err(OperationOutcomeError::error(
+                    IssueType::NotFound(None),
+                    "Resource not found".to_string(),
+                )));
+            }
+        },
+        Ok(FHIRResponse::VersionRead(res)) => Some(res.resource),
+        Ok(FHIRResponse::Update(res)) => Some(res.resource),
+        Ok(FHIRResponse::Patch(res)) => Some(res.resource),
+        Ok(FHIRResponse::Delete(_res)) => None,
+        Ok(FHIRResponse::Capabilities(res)) => {
+            Some(Resource::CapabilityStatement(res.capabilities))
+        }
+        Ok(FHIRResponse::Search(SearchResponse::Type(res))) => Some(Resource::Bundle(res.bundle)),
+        Ok(FHIRResponse::Search(SearchResponse::System(res))) => Some(Resource::Bundle(res.bundle)),
+        Ok(FHIRResponse::History(HistoryResponse::Instance(res))) => {
+            Some(Resource::Bundle(res.bundle))
+        }
+        Ok(FHIRResponse::History(HistoryResponse::Type(res))) => Some(Resource::Bundle(res.bundle)),
+        Ok(FHIRResponse::History(HistoryResponse::System(res))) => {
+            Some(Resource::Bundle(res.bundle))
+        }
+        Ok(FHIRResponse::Invoke(InvokeResponse::Instance(res))) => Some(res.resource),
+        Ok(FHIRResponse::Invoke(InvokeResponse::Type(res))) => Some(res.resource),
+        Ok(FHIRResponse::Invoke(InvokeResponse::System(res))) => Some(res.resource),
+        Ok(FHIRResponse::Batch(res)) => Some(Resource::Bundle(res.resource)),
+        Ok(FHIRResponse::Transaction(res)) => Some(Resource::Bundle(res.resource)),
+        Err(operation_error) => {
+            return BundleEntry {
+                response: Some(BundleEntryResponse {
+                    outcome: Some(Box::new(Resource::OperationOutcome(
+                        operation_error.outcome().clone(),
+                    ))),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+        }
+    };
+
+    BundleEntry {
+        resource: resource.map(Box::new),
+        ..Default::default()
+    }
+}
+
+/// Entries are processed independently, failures are returned as OperationOutcomes.
+pub fn process_batch(
+    client: &MemoryFHIRClient,
+    store: &mut Store,
+    entries: Vec<BundleEntry>,
+) -> Bundle {
+    let entries = entries
+        .into_iter()
+        .map(|entry| {
+            convert_bundle_entry(
+                bundle_entry_to_fhir_request(entry)
+                    .and_then(|request| client.handle(store, request)),
+            )
+        })
+        .collect();
+
+    Bundle {
+        type_: Box::new(BundleType::BatchResponse(None)),
+        entry: Some(entries),
+        ..Default::default()
+    }
+}
+
+/// Replaces references to the fullUrls of other entries with the assigned `Type/id`.
+fn rewrite_references(value: &mut serde_json::Value, references: &HashMap<String, String>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if key == "reference"
+                    && let serde_json::Value::String(reference) = value
+                    && let Some(replacement) = references.get(reference.as_str())
+                {
+                    *reference = replacement.clone();
+                } else {
+                    rewrite_references(value, references);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values.iter_mut() {
+                rewrite_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve_entry_references(
+    entry: &mut BundleEntry,
+    references: &HashMap<String, String>,
+) -> Result<(), OperationOutcomeError> {
+    let Some(resource) = entry.resource.as_ref() else {
+        return Ok(());
+    };
+
+    let mut json: serde_json::Value = haste_fhir_serialization_json::to_string(resource.as_ref())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .ok_or_else(|| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to serialize bundle entry".to_string(),
+            )
+        })?;
+    rewrite_references(&mut json, references);
+
+    let resource =
+        haste_fhir_serialization_json::from_serde_value::<Resource>(&json).map_err(|e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                format!("Failed to deserialize bundle entry '{}'.", e),
+            )
+        })?;
+    entry.resource = Some(Box::new(resource));
+
+    Ok(())
+}
+
+/// Processes the entries in order, the first failure fails the whole transaction.
+/// The caller is responsible for restoring the store on failure.
+pub fn process_transaction(
+    client: &MemoryFHIRClient,
+    store: &mut Store,
+    mut entries: Vec<BundleEntry>,
+) -> Result<Bundle, OperationOutcomeError> {
+    // Ids of created resources, by entry index.
+    let mut assigned_ids = HashMap::new();
+    let mut references = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let Some(full_url) = entry.fullUrl.as_ref().and_then(|f| f.value.clone()) else {
+            continue;
+        };
+        let method: Option<String> = entry
+            .request
+            .as_ref()
+            .and_then(|request| request.method.as_ref().into());
+
+        if let Some(resource) = entry.resource.as_ref()
+            && method.as_deref() == Some("POST")
+        {
+            let id = generate_id(None);
+            references.insert(
+                full_url,
+                format!("{}/{}", resource_type_of(resource)?.as_ref(), id),
+            );
+            assigned_ids.insert(index, id);
+        }
+    }
+
+    if !references.is_empty() {
+        for entry in entries.iter_mut() {
+            resolve_entry_references(entry, &references)?;
+        }
+    }
+
+    let mut response_entries = vec![];
+    for (index, entry) in entries.into_iter().enumerate() {
+        let fhir_response = match (
+            bundle_entry_to_fhir_request(entry)?,
+            assigned_ids.remove(&index),
+        ) {
+            (FHIRRequest::Create(create_request), Some(id)) => {
+                FHIRResponse::Create(FHIRCreateResponse {
+                    resource: client.create(
+                        store,
+                        create_request.resource_type,
+                        create_request.resource,
+                        Some(id),
+                    )?,
+                })
+            }
+            (request, _) => client.handle(store, request)?,
+        };
+
+        response_entries.push(convert_bundle_entry(Ok(fhir_response)));
+    }
+
+    Ok(Bundle {
+        type_: Box::new(BundleType::TransactionResponse(None)),
+        entry: Some(response_entries),
+        ..Default::default()
+    })
+}
@@ -147,6 +147,18 @@ pub struct Project {
     # [reference (targets = ["IdentityProvider"])]
     #[doc = ""]
     pub identityProvider: Option<Vec<Box<Reference>>>,
+    #[primitive]
+    #[doc = "Whether email/password users must complete multi-factor authentication to sign in to the project."]
+    pub requireMfa: Option<Box<FHIRBoolean>>,
+    #[primitive]
+    #[doc = "Whether a Provenance resource is generated from the requesting user and client application for every create and update in the project."]
+    pub autoProvenance: Option<Box<FHIRBoolean>>,
+    #[primitive]
+    #[doc = "Whether client applications can register with the project's registration endpoint without an initial access token."]
+    pub openClientRegistration: Option<Box<FHIRBoolean>>,
+    #[primitive]
+    #[doc = "Whether creates and updates must only contain relative references to existing resources and deletes are refused while other resources reference the deleted resource. References on deletes are found through the search index so resources written shortly before the delete may be missed. Changes can take up to a minute to apply."]
+    pub referentialIntegrity: Option<Box<FHIRBoolean>>,
 }
 #[derive(
     Clone,
@@ -342,6 +354,21 @@ pub struct ClientApplication {
     #[primitive]
     #[doc = ""]
     pub policyUri: Option<Box<FHIRUri>>,
+    #[primitive]
+    #[doc = "Requested authentication method for the token endpoint. Defaults to client_secret_basic when omitted."]
+    pub tokenEndpointAuthMethod: Option<Box<terminology::ClientapplicationTokenEndpointAuthMethod>>,
+    #[primitive]
+    #[doc = "URL of the client's JWK Set used to verify private_key_jwt client assertions. Must not be used together with jwks."]
+    pub jwksUri: Option<Box<FHIRUri>>,
+    #[primitive]
+    #[doc = "JSON encoded JWK Set used to verify private_key_jwt client assertions. Must not be used together with jwksUri."]
+    pub jwks: Option<Box<FHIRString>>,
+    #[primitive]
+    #[doc = "SHA-256 hash of the registration access token used to read, update and delete a dynamically registered client (RFC 7592)."]
+    pub registrationAccessToken: Option<Box<FHIRString>>,
+    #[primitive]
+    #[doc = "Set when the client was dynamically registered with an initial access token. Clients registered without one are limited to user facing grants and scopes."]
+    pub trustedRegistration: Option<Box<FHIRBoolean>>,
 }
 #[derive(
     Clone,
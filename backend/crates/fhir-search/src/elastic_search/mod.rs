@@ -1,16 +1,17 @@
 use crate::{
-    IndexResource, SearchEngine, SearchEntry, SearchOptions, SearchReturn,
+    CodeStatistics, IndexResource, SearchEngine, SearchEntry, SearchOptions, SearchReturn,
     SuccessfullyIndexedCount,
     indexing_conversion::{self, InsertableIndex},
 };
 use elasticsearch::{
-    BulkOperation, BulkParts, Elasticsearch, SearchParts,
+    BulkOperation, BulkParts, DeleteByQueryParts, Elasticsearch, SearchParts,
     auth::Credentials,
     cert::CertificateValidation,
     http::{
         Url,
         transport::{BuildError, SingleNodeConnectionPool, TransportBuilder},
     },
+    params::Conflicts,
 };
 use haste_fhir_client::request::SearchRequest;
 use haste_fhir_model::r4::generated::{
@@ -22,8 +23,11 @@ use haste_fhirpath::FPEngine;
 use haste_jwt::{ProjectId, ResourceId, TenantId, VersionId};
 use haste_repository::types::{FHIRMethod, SupportedFHIRVersions};
 use rayon::prelude::*;
-use serde::Deserialize;
-use std::{collections::HashMap, sync::Arc};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 mod migration;
 mod search;
@@ -109,6 +113,34 @@ impl ElasticSearchEngine {
         })
     }
 
+    async fn aggregate<T: DeserializeOwned>(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        query: serde_json::Value,
+    ) -> Result<T, OperationOutcomeError> {
+        let search_response = self
+            .client
+            .search(SearchParts::Index(&[get_index_name(&fhir_version)?]))
+            .body(query)
+            .send()
+            .await
+            .map_err(SearchError::from)?;
+
+        if !search_response.status_code().is_success() {
+            return Err(SearchError::ElasticSearchResponseError(
+                search_response.status_code().as_u16(),
+            )
+            .into());
+        }
+
+        let response = search_response
+            .json::<ElasticSearchAggregationResponse<T>>()
+            .await
+            .map_err(SearchError::from)?;
+
+        Ok(response.aggregations)
+    }
+
     pub async fn is_connected(&self) -> Result<(), SearchError> {
         let res = self.client.ping().send().await.map_err(SearchError::from)?;
 
@@ -191,6 +223,136 @@ struct ElasticSearchResponse {
     hits: ElasticSearchHit,
 }
 
+impl From<ElasticSearchHitResult> for SearchEntry {
+    fn from(mut hit: ElasticSearchHitResult) -> Self {
+        SearchEntry {
+            id: hit.fields.id.pop().unwrap(),
+            resource_type: hit.fields.resource_type.pop().unwrap(),
+            version_id: hit.fields.version_id.pop().unwrap(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ElasticSearchAggregationResponse<T> {
+    aggregations: T,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ElasticSearchBuckets<T> {
+    buckets: Vec<T>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ElasticSearchTopHits {
+    hits: ElasticSearchHit,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ElasticSearchStats {
+    count: i64,
+    min: Option<f64>,
+    max: Option<f64>,
+    avg: Option<f64>,
+    sum: Option<f64>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ElasticSearchMetric {
+    value_as_string: Option<String>,
+}
+
+/// Buckets of the nested terms aggregation on Observation.code.
+#[derive(serde::Deserialize, Debug)]
+struct CodeAggregations<T> {
+    codes: CodeAggregation<T>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct CodeAggregation<T> {
+    code: ElasticSearchBuckets<T>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct LastNBucket {
+    observations: LastNObservations,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct LastNObservations {
+    latest: ElasticSearchTopHits,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct StatsBucket {
+    key: Vec<String>,
+    observations: StatsObservations,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct StatsObservations {
+    doc_count: i64,
+    values: StatsValues,
+    dates: StatsDates,
+    latest: Option<ElasticSearchTopHits>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct StatsValues {
+    stats: ElasticSearchStats,
+    unit: ElasticSearchBuckets<UnitBucket>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct UnitBucket {
+    key: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct StatsDates {
+    earliest: ElasticSearchMetric,
+    latest: ElasticSearchMetric,
+}
+
+/// Terms aggregations use an empty string for missing values.
+fn non_empty(value: Option<&String>) -> Option<String> {
+    value.filter(|v| !v.is_empty()).cloned()
+}
+
+impl From<StatsBucket> for CodeStatistics {
+    fn from(bucket: StatsBucket) -> Self {
+        let observations = bucket.observations;
+        let stats = observations.values.stats;
+        let unit = observations.values.unit.buckets.into_iter().next();
+
+        CodeStatistics {
+            system: non_empty(bucket.key.get(0)),
+            code: bucket.key.get(1).cloned().unwrap_or_default(),
+            unit_system: unit.as_ref().and_then(|u| non_empty(u.key.get(0))),
+            unit_code: unit.as_ref().and_then(|u| non_empty(u.key.get(1))),
+            total_count: observations.doc_count,
+            count: stats.count,
+            min: stats.min,
+            max: stats.max,
+            average: stats.avg,
+            sum: stats.sum.filter(|_| stats.count > 0),
+            earliest: observations.dates.earliest.value_as_string,
+            latest: observations.dates.latest.value_as_string,
+            entries: observations
+                .latest
+                .map(|latest| {
+                    latest
+                        .hits
+                        .hits
+                        .into_iter()
+                        .map(SearchEntry::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
 fn unique_index_id(
     tenant: &TenantId,
     project: &ProjectId,
@@ -245,15 +407,140 @@ impl SearchEngine for ElasticSearchEngine {
                 .hits
                 .hits
                 .into_iter()
-                .map(|mut hit| SearchEntry {
-                    id: hit.fields.id.pop().unwrap(),
-                    resource_type: hit.fields.resource_type.pop().unwrap(),
-                    version_id: hit.fields.version_id.pop().unwrap(),
-                })
+                .map(SearchEntry::from)
                 .collect(),
         })
     }
 
+    async fn observation_lastn(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        search_request: &SearchRequest,
+        max: usize,
+    ) -> Result<Vec<SearchEntry>, OperationOutcomeError> {
+        let query = search::aggregation::build_lastn_query(tenant, project, search_request, max)?;
+        let aggregations = self
+            .aggregate::<CodeAggregations<LastNBucket>>(fhir_version, query)
+            .await?;
+
+        // An Observation with multiple codings falls into multiple buckets so dedupe the hits.
+        let mut seen = HashSet::new();
+        Ok(aggregations
+            .codes
+            .code
+            .buckets
+            .into_iter()
+            .flat_map(|bucket| bucket.observations.latest.hits.hits)
+            .map(SearchEntry::from)
+            .filter(|entry| seen.insert(entry.version_id.clone()))
+            .collect())
+    }
+
+    async fn observation_stats(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        search_request: &SearchRequest,
+        source_limit: Option<usize>,
+    ) -> Result<Vec<CodeStatistics>, OperationOutcomeError> {
+        let query =
+            search::aggregation::build_stats_query(tenant, project, search_request, source_limit)?;
+        let aggregations = self
+            .aggregate::<CodeAggregations<StatsBucket>>(fhir_version, query)
+            .await?;
+
+        Ok(aggregations
+            .codes
+            .code
+            .buckets
+            .into_iter()
+            .map(CodeStatistics::from)
+            .collect())
+    }
+
+    async fn inbound_references(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: &ResourceType,
+        id: &ResourceId,
+        limit: usize,
+    ) -> Result<Vec<SearchEntry>, OperationOutcomeError> {
+        let query = search::references::build_inbound_references_query(
+            tenant,
+            project,
+            resource_type,
+            id,
+            limit,
+        );
+
+        let search_response = self
+            .client
+            .search(SearchParts::Index(&[get_index_name(&fhir_version)?]))
+            .body(query)
+            .send()
+            .await
+            .map_err(SearchError::from)?;
+
+        if !search_response.status_code().is_success() {
+            return Err(SearchError::ElasticSearchResponseError(
+                search_response.status_code().as_u16(),
+            )
+            .into());
+        }
+
+        let search_results = search_response
+            .json::<ElasticSearchResponse>()
+            .await
+            .map_err(SearchError::from)?;
+
+        Ok(search_results
+            .hits
+            .hits
+            .into_iter()
+            .map(SearchEntry::from)
+            .collect())
+    }
+
+    async fn remove(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: Option<&ResourceType>,
+        id: Option<&ResourceId>,
+        excluded_types: &[ResourceType],
+    ) -> Result<u64, OperationOutcomeError> {
+        let query = search::build_remove_query(tenant, project, resource_type, id, excluded_types);
+
+        let response = self
+            .client
+            .delete_by_query(DeleteByQueryParts::Index(&[get_index_name(&fhir_version)?]))
+            .conflicts(Conflicts::Proceed)
+            .refresh(true)
+            .body(query)
+            .send()
+            .await
+            .map_err(SearchError::from)?;
+
+        if !response.status_code().is_success() {
+            return Err(
+                SearchError::ElasticSearchResponseError(response.status_code().as_u16()).into(),
+            );
+        }
+
+        let response_body = response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(SearchError::from)?;
+
+        Ok(response_body["deleted"].as_u64().unwrap_or(0))
+    }
+
     async fn index<'a>(
         &self,
         _fhir_version: &SupportedFHIRVersions,
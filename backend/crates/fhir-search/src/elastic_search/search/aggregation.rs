@@ -0,0 +1,180 @@
+use crate::elastic_search::search::{QueryBuildError, build_filter_clauses};
+use haste_fhir_client::request::SearchRequest;
+use haste_fhir_model::r4::generated::resources::ResourceType;
+use haste_jwt::{ProjectId, TenantId};
+use serde_json::json;
+
+// Maximum number of distinct codes returned from an aggregation.
+static MAX_CODE_BUCKETS: usize = 1_000;
+// Default value for Elasticsearch is 100
+// see index.max_inner_result_window
+pub static MAX_TOP_HITS: usize = 100;
+
+fn observation_parameter_url(name: &str) -> Result<String, QueryBuildError> {
+    haste_artifacts::search_parameters::get_search_parameter_for_name(
+        Some(&ResourceType::Observation),
+        name,
+    )
+    .and_then(|search_param| search_param.url.value.clone())
+    .ok_or_else(|| QueryBuildError::MissingParameter(name.to_string()))
+}
+
+/// Terms aggregation bucketing on the (system, code) pairs of Observation.code.
+/// Must be used within a nested aggregation on the code path.
+fn code_terms(code_url: &str) -> serde_json::Value {
+    json!({
+        "terms": [
+            { "field": code_url.to_string() + ".system", "missing": "" },
+            { "field": code_url.to_string() + ".code" }
+        ],
+        "size": MAX_CODE_BUCKETS
+    })
+}
+
+/// Top hits sorted by the most recent effective date.
+fn latest_hits(date_url: &str, size: usize) -> serde_json::Value {
+    json!({
+        "size": std::cmp::min(size, MAX_TOP_HITS),
+        "_source": false,
+        "fields": ["version_id", "id", "resource_type"],
+        "sort": [{
+            date_url.to_string() + ".end": {
+                "order": "desc",
+                "nested": {
+                    "path": date_url
+                }
+            }
+        }]
+    })
+}
+
+/// Builds an aggregation returning the most recent `max` Observations for each code.
+pub fn build_lastn_query(
+    tenant: &TenantId,
+    project: &ProjectId,
+    request: &SearchRequest,
+    max: usize,
+) -> Result<serde_json::Value, QueryBuildError> {
+    let clauses = build_filter_clauses(tenant, project, request)?;
+    let code_url = observation_parameter_url("code")?;
+    let date_url = observation_parameter_url("date")?;
+
+    Ok(json!({
+        "size": 0,
+        "query": {
+            "bool": {
+                "must": clauses
+            }
+        },
+        "aggs": {
+            "codes": {
+                "nested": {
+                    "path": code_url
+                },
+                "aggs": {
+                    "code": {
+                        "multi_terms": code_terms(&code_url),
+                        "aggs": {
+                            "observations": {
+                                "reverse_nested": {},
+                                "aggs": {
+                                    "latest": {
+                                        "top_hits": latest_hits(&date_url, max)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }))
+}
+
+/// Builds an aggregation computing statistics over Observation.valueQuantity for each code.
+/// When `source_limit` is set the most recent Observations for each code are also returned.
+pub fn build_stats_query(
+    tenant: &TenantId,
+    project: &ProjectId,
+    request: &SearchRequest,
+    source_limit: Option<usize>,
+) -> Result<serde_json::Value, QueryBuildError> {
+    let clauses = build_filter_clauses(tenant, project, request)?;
+    let code_url = observation_parameter_url("code")?;
+    let date_url = observation_parameter_url("date")?;
+    let value_url = observation_parameter_url("value-quantity")?;
+
+    let mut observation_aggs = json!({
+        "values": {
+            "nested": {
+                "path": value_url
+            },
+            "aggs": {
+                "stats": {
+                    "stats": {
+                        "field": value_url.to_string() + ".start_value"
+                    }
+                },
+                "unit": {
+                    "multi_terms": {
+                        "terms": [
+                            { "field": value_url.to_string() + ".start_system", "missing": "" },
+                            { "field": value_url.to_string() + ".start_code", "missing": "" }
+                        ],
+                        "size": 1
+                    }
+                }
+            }
+        },
+        "dates": {
+            "nested": {
+                "path": date_url
+            },
+            "aggs": {
+                "earliest": {
+                    "min": {
+                        "field": date_url.to_string() + ".start"
+                    }
+                },
+                "latest": {
+                    "max": {
+                        "field": date_url.to_string() + ".end"
+                    }
+                }
+            }
+        }
+    });
+
+    if let Some(source_limit) = source_limit {
+        observation_aggs["latest"] = json!({
+            "top_hits": latest_hits(&date_url, source_limit)
+        });
+    }
+
+    Ok(json!({
+        "size": 0,
+        "query": {
+            "bool": {
+                "must": clauses
+            }
+        },
+        "aggs": {
+            "codes": {
+                "nested": {
+                    "path": code_url
+                },
+                "aggs": {
+                    "code": {
+                        "multi_terms": code_terms(&code_url),
+                        "aggs": {
+                            "observations": {
+                                "reverse_nested": {},
+                                "aggs": observation_aggs
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }))
+}
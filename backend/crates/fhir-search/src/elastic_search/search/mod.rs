@@ -8,11 +8,13 @@ use haste_fhir_model::r4::generated::{
     terminology::SearchParamType,
 };
 use haste_fhir_operation_error::derive::OperationOutcomeError;
-use haste_jwt::{ProjectId, TenantId};
+use haste_jwt::{ProjectId, ResourceId, TenantId};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+pub mod aggregation;
 mod clauses;
+pub mod references;
 
 #[derive(OperationOutcomeError, Debug)]
 pub enum QueryBuildError {
@@ -169,6 +171,110 @@ fn get_parameters<'a>(request: &'a SearchRequest) -> &'a ParsedParameters {
     }
 }
 
+fn resource_parameter_clause(
+    resource_type: Option<&ResourceType>,
+    parameter: &Parameter,
+) -> Result<serde_json::Value, QueryBuildError> {
+    let search_param = haste_artifacts::search_parameters::get_search_parameter_for_name(
+        resource_type,
+        &parameter.name,
+    )
+    .ok_or_else(|| QueryBuildError::MissingParameter(parameter.name.to_string()))?;
+
+    parameter_to_elasticsearch_clauses(&search_param, parameter)
+}
+
+/// Clauses restricting the query to the tenant, project and optionally the resource type.
+fn scope_clauses(
+    tenant: &TenantId,
+    project: &ProjectId,
+    resource_type: Option<&ResourceType>,
+) -> Vec<serde_json::Value> {
+    let mut clauses = vec![];
+    if let Some(resource_type) = resource_type {
+        clauses.push(json!({
+            "match": {
+                "resource_type": resource_type.as_ref()
+            }
+        }));
+    }
+
+    clauses.push(json! ({
+        "match": {
+            "tenant": tenant.as_ref()
+        }
+    }));
+
+    clauses.push(json! ({
+        "match": {
+            "project": project.as_ref()
+        }
+    }));
+
+    clauses
+}
+
+/// Builds a query matching every document in the project, narrowed by type and id and
+/// skipping documents of `excluded_types`.
+pub fn build_remove_query(
+    tenant: &TenantId,
+    project: &ProjectId,
+    resource_type: Option<&ResourceType>,
+    id: Option<&ResourceId>,
+    excluded_types: &[ResourceType],
+) -> serde_json::Value {
+    let mut clauses = scope_clauses(tenant, project, resource_type);
+    if let Some(id) = id {
+        clauses.push(json!({
+            "match": {
+                "id": id.as_ref()
+            }
+        }));
+    }
+
+    json!({
+        "query": {
+            "bool": {
+                "must": clauses,
+                "must_not": [{
+                    "terms": {
+                        "resource_type": excluded_types
+                            .iter()
+                            .map(|resource_type| resource_type.as_ref())
+                            .collect::<Vec<_>>()
+                    }
+                }]
+            }
+        }
+    })
+}
+
+/// Builds the filter clauses for a request used by aggregations.
+/// Result parameters are not supported as aggregations do not page or sort hits.
+pub fn build_filter_clauses(
+    tenant: &TenantId,
+    project: &ProjectId,
+    request: &SearchRequest,
+) -> Result<Vec<serde_json::Value>, QueryBuildError> {
+    let resource_type = get_resource_type(request);
+    let mut clauses = get_parameters(request)
+        .parameters()
+        .iter()
+        .map(|parameter| match parameter {
+            ParsedParameter::Resource(resource_param) => {
+                resource_parameter_clause(resource_type, resource_param)
+            }
+            ParsedParameter::Result(result_param) => Err(QueryBuildError::UnsupportedParameter(
+                result_param.name.to_string(),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    clauses.extend(scope_clauses(tenant, project, resource_type));
+
+    Ok(clauses)
+}
+
 pub fn build_elastic_search_query(
     tenant: &TenantId,
     project: &ProjectId,
@@ -193,16 +299,7 @@ pub fn build_elastic_search_query(
     for parameter in parameters.parameters().iter() {
         match parameter {
             ParsedParameter::Resource(resource_param) => {
-                let search_param =
-                    haste_artifacts::search_parameters::get_search_parameter_for_name(
-                        resource_type,
-                        &resource_param.name,
-                    )
-                    .ok_or_else(|| {
-                        QueryBuildError::MissingParameter(resource_param.name.to_string())
-                    })?;
-                let clause = parameter_to_elasticsearch_clauses(&search_param, &resource_param)?;
-                clauses.push(clause);
+                clauses.push(resource_parameter_clause(resource_type, resource_param)?);
             }
             ParsedParameter::Result(result_param) => match result_param.name.as_str() {
                 "_count" => {
@@ -288,25 +385,7 @@ pub fn build_elastic_search_query(
         }
     }
 
-    if let Some(resource_type) = resource_type {
-        clauses.push(json!({
-            "match": {
-                "resource_type": resource_type.as_ref()
-            }
-        }));
-    }
-
-    clauses.push(json! ({
-        "match": {
-            "tenant": tenant.as_ref()
-        }
-    }));
-
-    clauses.push(json! ({
-        "match": {
-            "project": project.as_ref()
-        }
-    }));
+    clauses.extend(scope_clauses(tenant, project, resource_type));
 
     let query = json!({
         "fields": ["version_id", "id", "resource_type"],
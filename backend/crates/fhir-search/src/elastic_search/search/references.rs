@@ -0,0 +1,71 @@
+use crate::elastic_search::search::scope_clauses;
+use haste_fhir_model::r4::generated::{resources::ResourceType, terminology::SearchParamType};
+use haste_jwt::{ProjectId, ResourceId, TenantId};
+use serde_json::json;
+
+/// Urls of the reference search parameters that can target `resource_type`.
+fn reference_parameter_urls(resource_type: &ResourceType) -> Vec<String> {
+    haste_artifacts::search_parameters::get_all_search_parameters()
+        .into_iter()
+        .filter(|parameter| matches!(parameter.type_.as_ref(), SearchParamType::Reference(_)))
+        .filter(|parameter| match parameter.target.as_ref() {
+            // No target means the parameter can reference any resource type.
+            None => true,
+            Some(targets) => targets.iter().any(|target| {
+                let target: Option<String> = target.as_ref().into();
+                target.as_deref() == Some(resource_type.as_ref())
+            }),
+        })
+        .filter_map(|parameter| parameter.url.value.clone())
+        .collect()
+}
+
+/// Builds a query for resources in the project with a reference search parameter pointing at
+/// `resource_type/id`. The resource itself is excluded.
+pub fn build_inbound_references_query(
+    tenant: &TenantId,
+    project: &ProjectId,
+    resource_type: &ResourceType,
+    id: &ResourceId,
+    limit: usize,
+) -> serde_json::Value {
+    let references = reference_parameter_urls(resource_type)
+        .into_iter()
+        .map(|url| {
+            json!({
+                "nested": {
+                    "path": url,
+                    "query": {
+                        "bool": {
+                            "must": [
+                                { "match": { url.clone() + ".resource_type": resource_type.as_ref() } },
+                                { "match": { url.clone() + ".id": id.as_ref() } }
+                            ]
+                        }
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "fields": ["version_id", "id", "resource_type"],
+        "size": limit,
+        "_source": false,
+        "query": {
+            "bool": {
+                "must": scope_clauses(tenant, project, None),
+                "should": references,
+                "minimum_should_match": 1,
+                "must_not": [{
+                    "bool": {
+                        "must": scope_clauses(tenant, project, Some(resource_type))
+                            .into_iter()
+                            .chain(std::iter::once(json!({ "match": { "id": id.as_ref() } })))
+                            .collect::<Vec<_>>()
+                    }
+                }]
+            }
+        }
+    })
+}
@@ -18,25 +18,25 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenIndex {
-    system: Option<String>,
-    code: Option<String>,
+    pub system: Option<String>,
+    pub code: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
-enum RangeValue {
+pub enum RangeValue {
     Number(f64),
     Infinity,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QuantityRange {
-    start_value: RangeValue,
-    start_code: Option<String>,
-    start_system: Option<String>,
-    end_value: RangeValue,
-    end_code: Option<String>,
-    end_system: Option<String>,
+    pub start_value: RangeValue,
+    pub start_code: Option<String>,
+    pub start_system: Option<String>,
+    pub end_value: RangeValue,
+    pub end_code: Option<String>,
+    pub end_system: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -48,9 +48,9 @@ pub struct DateRange {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ReferenceIndex {
-    id: Option<String>,
-    resource_type: Option<String>,
-    uri: Option<String>,
+    pub id: Option<String>,
+    pub resource_type: Option<String>,
+    pub uri: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -6,7 +6,7 @@ use haste_repository::types::{FHIRMethod, SupportedFHIRVersions};
 use serde::Deserialize;
 
 pub mod elastic_search;
-mod indexing_conversion;
+pub mod indexing_conversion;
 
 pub struct RemoveIndex {
     // resource_type: ResourceType,
@@ -43,6 +43,29 @@ pub struct SearchOptions {
 
 pub struct SuccessfullyIndexedCount(pub usize);
 
+/// Aggregated statistics over the quantity values of Observations sharing a code.
+#[derive(Debug)]
+pub struct CodeStatistics {
+    pub system: Option<String>,
+    pub code: String,
+    /// System and code of the unit used by the values.
+    pub unit_system: Option<String>,
+    pub unit_code: Option<String>,
+    /// Number of Observations with the code.
+    pub total_count: i64,
+    /// Number of Observations with a quantity value.
+    pub count: i64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub average: Option<f64>,
+    pub sum: Option<f64>,
+    /// Earliest and latest effective dates of the Observations.
+    pub earliest: Option<String>,
+    pub latest: Option<String>,
+    /// Most recent Observations when sources were requested.
+    pub entries: Vec<SearchEntry>,
+}
+
 pub trait SearchEngine: Send + Sync {
     fn search(
         &self,
@@ -53,6 +76,51 @@ pub trait SearchEngine: Send + Sync {
         options: Option<SearchOptions>,
     ) -> impl Future<Output = Result<SearchReturn, OperationOutcomeError>> + Send + Sync;
 
+    /// Returns the most recent `max` Observations for each code matching the request.
+    fn observation_lastn(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        search_request: &SearchRequest,
+        max: usize,
+    ) -> impl Future<Output = Result<Vec<SearchEntry>, OperationOutcomeError>> + Send + Sync;
+
+    /// Returns statistics for each code over the Observations matching the request.
+    /// When `source_limit` is set the most recent Observations for each code are included.
+    fn observation_stats(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        search_request: &SearchRequest,
+        source_limit: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<CodeStatistics>, OperationOutcomeError>> + Send + Sync;
+
+    /// Returns resources in the project with a reference search parameter pointing at
+    /// `resource_type/id`, up to `limit`.
+    fn inbound_references(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: &ResourceType,
+        id: &ResourceId,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<SearchEntry>, OperationOutcomeError>> + Send + Sync;
+
+    /// Removes the index documents in the project, narrowed by type and id.
+    /// Documents of `excluded_types` are kept. Returns the number of documents removed.
+    fn remove(
+        &self,
+        fhir_version: &SupportedFHIRVersions,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: Option<&ResourceType>,
+        id: Option<&ResourceId>,
+        excluded_types: &[ResourceType],
+    ) -> impl Future<Output = Result<u64, OperationOutcomeError>> + Send + Sync;
+
     fn index(
         &self,
         fhir_version: &SupportedFHIRVersions,
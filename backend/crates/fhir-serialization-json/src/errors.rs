@@ -26,4 +26,8 @@ pub enum DeserializeError {
     CardinalityViolation(String),
     #[error("Reference target validation failed: expected one of '{0:?}', found '{1}'")]
     ReferenceTargetValidationFailed(Vec<String>, String),
+    #[error("Failed to read input: {0}")]
+    IOError(#[from] std::io::Error),
+    #[error("Invalid JSON stream: {0}")]
+    InvalidStream(String),
 }
@@ -5,6 +5,8 @@ pub use traits::*;
 mod deserialize_primitives;
 pub mod errors;
 mod serialize_primitives;
+#[cfg(feature = "stream")]
+pub mod stream;
 mod traits;
 
 #[cfg(feature = "derive")]
@@ -0,0 +1,398 @@
+//! Incremental readers and writers for payloads too large to hold in memory at once,
+//! such as NDJSON imports and large Bundles.
+use crate::{
+    Context, FHIRJSONDeserializer, FHIRJSONSerializer, SerializeError, errors::DeserializeError,
+};
+use futures::{Stream, stream};
+use serde_json::{Map, Value};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+/// Reads newline delimited JSON one resource at a time. Blank lines are skipped.
+pub struct NDJSONReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: AsyncBufRead + Unpin> NDJSONReader<R> {
+    pub fn new(reader: R) -> Self {
+        NDJSONReader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// Line number (starting at 1) of the last value returned.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Deserializes the next non blank line. A line that fails to deserialize doesn't end
+    /// the stream, so callers can report it and continue.
+    pub async fn next<T: FHIRJSONDeserializer>(&mut self) -> Option<Result<T, DeserializeError>> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line).await {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.line_number += 1;
+                    let line = self.line.trim();
+                    if !line.is_empty() {
+                        return Some(T::from_json_str(line));
+                    }
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+
+    pub fn into_stream<T: FHIRJSONDeserializer>(
+        self,
+    ) -> impl Stream<Item = Result<T, DeserializeError>> {
+        stream::unfold(self, |mut reader| async move {
+            reader.next::<T>().await.map(|item| (item, reader))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    Fields { first: bool },
+    Elements { first: bool },
+    Done,
+    Failed,
+}
+
+/// Reads the elements of one top level array field of a JSON object one at a time, for
+/// example the `entry` field of a Bundle. Only the current element is buffered; the
+/// object's other fields are kept and returned by [`JSONArrayFieldReader::finish`].
+pub struct JSONArrayFieldReader<R> {
+    reader: R,
+    field: String,
+    fields: Map<String, Value>,
+    state: State,
+    buffer: Vec<u8>,
+}
+
+impl<R: AsyncBufRead + Unpin> JSONArrayFieldReader<R> {
+    pub fn new(reader: R, field: &str) -> Self {
+        JSONArrayFieldReader {
+            reader,
+            field: field.to_string(),
+            fields: Map::new(),
+            state: State::Start,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Fields of the object read so far, other than the array field.
+    pub fn fields(&self) -> &Map<String, Value> {
+        &self.fields
+    }
+
+    pub async fn next<T: FHIRJSONDeserializer>(&mut self) -> Option<Result<T, DeserializeError>> {
+        match self.next_value().await {
+            Ok(Some(value)) => Some(T::from_serde_value(&value, Context::AsValue)),
+            Ok(None) => None,
+            Err(e) => {
+                self.state = State::Failed;
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Reads the rest of the object, skipping any elements not yet read, and returns its
+    /// fields other than the array field.
+    pub async fn finish(mut self) -> Result<Map<String, Value>, DeserializeError> {
+        while self.next_value().await?.is_some() {}
+        Ok(self.fields)
+    }
+
+    async fn next_value(&mut self) -> Result<Option<Value>, DeserializeError> {
+        loop {
+            match self.state {
+                State::Start => {
+                    self.expect(b'{').await?;
+                    self.state = State::Fields { first: true };
+                }
+                State::Fields { first } => {
+                    self.skip_whitespace().await?;
+                    if self.peek().await? == Some(b'}') {
+                        self.reader.consume(1);
+                        self.skip_whitespace().await?;
+                        if self.peek().await?.is_some() {
+                            return Err(DeserializeError::InvalidStream(
+                                "trailing characters after object".to_string(),
+                            ));
+                        }
+                        self.state = State::Done;
+                        continue;
+                    }
+                    if !first {
+                        self.expect(b',').await?;
+                    }
+                    self.state = State::Fields { first: false };
+
+                    let key = self.read_key().await?;
+                    if key == self.field {
+                        self.expect(b'[').await?;
+                        self.state = State::Elements { first: true };
+                    } else {
+                        let value = self.read_value().await?;
+                        self.fields.insert(key, value);
+                    }
+                }
+                State::Elements { first } => {
+                    self.skip_whitespace().await?;
+                    if self.peek().await? == Some(b']') {
+                        self.reader.consume(1);
+                        self.state = State::Fields { first: false };
+                        continue;
+                    }
+                    if !first {
+                        self.expect(b',').await?;
+                    }
+                    self.state = State::Elements { first: false };
+
+                    return Ok(Some(self.read_value().await?));
+                }
+                State::Done => return Ok(None),
+                State::Failed => {
+                    return Err(DeserializeError::InvalidStream(
+                        "stream failed on a previous read".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    async fn peek(&mut self) -> Result<Option<u8>, DeserializeError> {
+        Ok(self.reader.fill_buf().await?.first().copied())
+    }
+
+    async fn skip_whitespace(&mut self) -> Result<(), DeserializeError> {
+        loop {
+            let available = self.reader.fill_buf().await?;
+            if available.is_empty() {
+                return Ok(());
+            }
+            let whitespace = available
+                .iter()
+                .take_while(|byte| byte.is_ascii_whitespace())
+                .count();
+            let done = whitespace < available.len();
+            self.reader.consume(whitespace);
+            if done {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn expect(&mut self, expected: u8) -> Result<(), DeserializeError> {
+        self.skip_whitespace().await?;
+        match self.peek().await? {
+            Some(byte) if byte == expected => {
+                self.reader.consume(1);
+                Ok(())
+            }
+            Some(byte) => Err(DeserializeError::InvalidStream(format!(
+                "expected '{}' found '{}'",
+                expected as char, byte as char
+            ))),
+            None => Err(DeserializeError::InvalidStream(format!(
+                "expected '{}' found end of input",
+                expected as char
+            ))),
+        }
+    }
+
+    async fn read_key(&mut self) -> Result<String, DeserializeError> {
+        self.skip_whitespace().await?;
+        if self.peek().await? != Some(b'"') {
+            return Err(DeserializeError::InvalidStream(
+                "expected object key".to_string(),
+            ));
+        }
+        let Value::String(key) = self.read_value().await? else {
+            return Err(DeserializeError::InvalidStream(
+                "expected object key".to_string(),
+            ));
+        };
+        self.expect(b':').await?;
+
+        Ok(key)
+    }
+
+    /// Buffers the bytes of the next JSON value by tracking nesting and strings, then parses them.
+    async fn read_value(&mut self) -> Result<Value, DeserializeError> {
+        self.skip_whitespace().await?;
+        self.buffer.clear();
+
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        loop {
+            let available = self.reader.fill_buf().await?;
+            if available.is_empty() {
+                break;
+            }
+
+            let mut used = 0;
+            let mut complete = false;
+            for &byte in available {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if byte == b'\\' {
+                        escaped = true;
+                    } else if byte == b'"' {
+                        in_string = false;
+                    }
+                } else {
+                    match byte {
+                        b'"' => in_string = true,
+                        b'{' | b'[' => depth += 1,
+                        // Closing an enclosing container ends a scalar value.
+                        b'}' | b']' if depth == 0 => {
+                            complete = true;
+                            break;
+                        }
+                        b'}' | b']' => depth -= 1,
+                        b',' if depth == 0 => {
+                            complete = true;
+                            break;
+                        }
+                        byte if depth == 0 && byte.is_ascii_whitespace() => {
+                            complete = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+
+                used += 1;
+                if depth == 0 && !in_string && matches!(byte, b'}' | b']' | b'"') {
+                    complete = true;
+                    break;
+                }
+            }
+
+            self.buffer.extend_from_slice(&available[..used]);
+            self.reader.consume(used);
+            if complete {
+                break;
+            }
+        }
+
+        Ok(serde_json::from_slice(&self.buffer)?)
+    }
+}
+
+/// Serializes `head` with `items` as its array field `field`, producing a chunk per item
+/// so the whole document is never held in memory. The field is left out if `items` is empty.
+pub fn to_array_field_stream<H, T, I>(
+    head: &H,
+    field: &str,
+    items: I,
+) -> Result<impl Stream<Item = Result<Vec<u8>, SerializeError>> + use<H, T, I>, SerializeError>
+where
+    H: FHIRJSONSerializer,
+    T: FHIRJSONSerializer,
+    I: IntoIterator<Item = T>,
+{
+    let head = crate::to_string(head)?;
+    let mut items = items.into_iter().peekable();
+
+    let (open, close) = if items.peek().is_none() {
+        (head, None)
+    } else {
+        let body = head.strip_suffix('}').ok_or_else(|| {
+            SerializeError::InvalidValue("expected head to serialize as an object".to_string())
+        })?;
+        let separator = if body.ends_with('{') { "" } else { "," };
+        (
+            format!("{}{}\"{}\":[", body, separator, field),
+            Some(b"]}".to_vec()),
+        )
+    };
+
+    let mut first = true;
+    let items = items.map(move |item| {
+        let mut chunk = if first { Vec::new() } else { b",".to_vec() };
+        first = false;
+        crate::to_writer(&mut chunk, &item)?;
+        Ok(chunk)
+    });
+
+    Ok(stream::iter(
+        std::iter::once(Ok(open.into_bytes()))
+            .chain(items)
+            .chain(close.map(Ok)),
+    ))
+}
+
+/// Serializes `items` as newline delimited JSON, one chunk per item.
+pub fn to_ndjson_stream<T, I>(items: I) -> impl Stream<Item = Result<Vec<u8>, SerializeError>>
+where
+    T: FHIRJSONSerializer,
+    I: IntoIterator<Item = T>,
+{
+    stream::iter(items.into_iter().map(|item| {
+        let mut chunk = Vec::new();
+        crate::to_writer(&mut chunk, &item)?;
+        chunk.push(b'\n');
+        Ok(chunk)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_array_field_reader() {
+        let json = r#" { "resourceType": "Bundle", "meta": { "tag": [{ "code": "]}" }] },
+            "entry": [ "x]},\"y" , "z" ], "total": 2 } "#;
+        let mut reader = JSONArrayFieldReader::new(json.as_bytes(), "entry");
+
+        let mut elements = vec![];
+        while let Some(element) = reader.next::<String>().await {
+            elements.push(element.unwrap());
+        }
+        assert_eq!(elements, vec!["x]},\"y".to_string(), "z".to_string()]);
+
+        let fields = reader.finish().await.unwrap();
+        assert_eq!(fields.get("resourceType"), Some(&Value::from("Bundle")));
+        assert_eq!(fields.get("total"), Some(&Value::from(2)));
+        assert!(fields.contains_key("meta"));
+    }
+
+    #[tokio::test]
+    async fn test_array_field_reader_invalid() {
+        let mut reader = JSONArrayFieldReader::new(r#"{ "entry": [1,] }"#.as_bytes(), "entry");
+        assert!(reader.next::<i64>().await.unwrap().is_ok());
+        assert!(reader.next::<i64>().await.unwrap().is_err());
+        assert!(reader.finish().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_round_trip() {
+        let chunks = to_ndjson_stream(vec!["a".to_string(), "b".to_string()])
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .concat();
+
+        let mut reader = NDJSONReader::new(&chunks[..]);
+        assert_eq!(reader.next::<String>().await.unwrap().unwrap(), "a");
+        assert_eq!(reader.next::<String>().await.unwrap().unwrap(), "b");
+        assert_eq!(reader.line_number(), 2);
+        assert!(reader.next::<String>().await.is_none());
+    }
+}
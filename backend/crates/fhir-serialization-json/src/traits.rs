@@ -12,6 +12,8 @@ pub enum SerializeError {
     SerializeError(#[from] std::io::IntoInnerError<BufWriter<Vec<u8>>>),
     #[error("UTF-8 conversion error: {0}")]
     Utf8Error(#[from] std::string::FromUtf8Error),
+    #[error("Invalid value: {0}")]
+    InvalidValue(String),
 }
 pub trait FHIRJSONSerializer {
     fn serialize_value(&self, writer: &mut dyn std::io::Write) -> Result<bool, SerializeError>;
@@ -41,4 +41,6 @@ pub enum FHIRPathError {
     FailedTypeNameDerivation,
     #[error("Function error: {0}")]
     FunctionError(#[from] FunctionError),
+    #[error("Invalid patch: {0}")]
+    InvalidPatch(String),
 }
@@ -2,6 +2,7 @@
 
 mod error;
 mod parser;
+pub mod patch;
 use crate::{
     error::{FunctionError, OperationError},
     parser::{Expression, FunctionInvocation, Identifier, Invocation, Literal, Operation, Term},
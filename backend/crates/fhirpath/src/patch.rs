@@ -0,0 +1,484 @@
+//! FHIRPath Patch (https://hl7.org/fhir/R4/fhirpatch.html).
+//! Operations locate their targets with FHIRPath and are applied in place through reflection.
+use crate::{FHIRPathError, FPEngine};
+use haste_fhir_model::r4::generated::resources::{
+    Parameters, ParametersParameter, ParametersParameterValueTypeChoice,
+};
+use haste_reflect::MetaValue;
+use std::any::Any;
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Field(&'static str),
+    Index(usize),
+}
+
+type Path = Vec<PathSegment>;
+
+enum PatchOperation {
+    Add {
+        path: String,
+        name: String,
+        value: Box<dyn Any>,
+    },
+    Insert {
+        path: String,
+        index: usize,
+        value: Box<dyn Any>,
+    },
+    Delete {
+        path: String,
+    },
+    Replace {
+        path: String,
+        value: Box<dyn Any>,
+    },
+    Move {
+        path: String,
+        source: usize,
+        destination: usize,
+    },
+}
+
+fn invalid_patch(message: impl Into<String>) -> FHIRPathError {
+    FHIRPathError::InvalidPatch(message.into())
+}
+
+fn find_part<'a>(parts: &'a [ParametersParameter], name: &str) -> Option<&'a ParametersParameter> {
+    parts
+        .iter()
+        .find(|part| part.name.value.as_deref() == Some(name))
+}
+
+fn string_part(parts: &[ParametersParameter], name: &str) -> Result<String, FHIRPathError> {
+    let value = match find_part(parts, name).and_then(|part| part.value.as_ref()) {
+        Some(ParametersParameterValueTypeChoice::String(value)) => value.value.clone(),
+        Some(ParametersParameterValueTypeChoice::Code(value)) => value.value.clone(),
+        _ => None,
+    };
+    value.ok_or_else(|| invalid_patch(format!("Operation is missing a '{name}' string part.")))
+}
+
+fn index_part(parts: &[ParametersParameter], name: &str) -> Result<usize, FHIRPathError> {
+    let index = match find_part(parts, name).and_then(|part| part.value.as_ref()) {
+        Some(ParametersParameterValueTypeChoice::Integer(value)) => {
+            value.value.and_then(|index| usize::try_from(index).ok())
+        }
+        _ => None,
+    };
+    index.ok_or_else(|| invalid_patch(format!("Operation is missing a '{name}' integer part.")))
+}
+
+fn value_part(parts: &mut [ParametersParameter]) -> Result<Box<dyn Any>, FHIRPathError> {
+    parts
+        .iter_mut()
+        .find(|part| part.name.value.as_deref() == Some("value"))
+        .and_then(|part| part.value.take())
+        .map(|value| MetaValue::into_any(Box::new(value)))
+        .ok_or_else(|| invalid_patch("Operation is missing a 'value' part with a value[x]."))
+}
+
+impl TryFrom<ParametersParameter> for PatchOperation {
+    type Error = FHIRPathError;
+
+    fn try_from(parameter: ParametersParameter) -> Result<Self, Self::Error> {
+        if parameter.name.value.as_deref() != Some("operation") {
+            return Err(invalid_patch(
+                "FHIRPath Patch parameters must be named 'operation'.",
+            ));
+        }
+
+        let mut parts = parameter.part.unwrap_or_default();
+        let operation_type = string_part(&parts, "type")?;
+        let path = string_part(&parts, "path")?;
+
+        match operation_type.as_str() {
+            "add" => Ok(PatchOperation::Add {
+                name: string_part(&parts, "name")?,
+                value: value_part(&mut parts)?,
+                path,
+            }),
+            "insert" => Ok(PatchOperation::Insert {
+                index: index_part(&parts, "index")?,
+                value: value_part(&mut parts)?,
+                path,
+            }),
+            "delete" => Ok(PatchOperation::Delete { path }),
+            "replace" => Ok(PatchOperation::Replace {
+                value: value_part(&mut parts)?,
+                path,
+            }),
+            "move" => Ok(PatchOperation::Move {
+                source: index_part(&parts, "source")?,
+                destination: index_part(&parts, "destination")?,
+                path,
+            }),
+            _ => Err(invalid_patch(format!(
+                "Unknown patch operation type '{operation_type}'."
+            ))),
+        }
+    }
+}
+
+/// Nodes are the same if they share an address and type.
+/// The type check disambiguates a struct from a field stored at its start.
+fn is_same_node(node: &dyn MetaValue, target: &dyn MetaValue) -> bool {
+    std::ptr::addr_eq(node as *const dyn MetaValue, target as *const dyn MetaValue)
+        && node.typename() == target.typename()
+}
+
+fn locate_in_value(value: &dyn MetaValue, target: &dyn MetaValue, path: &mut Path) -> bool {
+    value
+        .flatten()
+        .into_iter()
+        .any(|node| is_same_node(node, target) || locate_in_fields(node, target, path))
+}
+
+fn locate_in_fields(node: &dyn MetaValue, target: &dyn MetaValue, path: &mut Path) -> bool {
+    for field in node.fields() {
+        let Some(child) = node.get_field(field) else {
+            continue;
+        };
+        path.push(PathSegment::Field(field));
+
+        if child.get_index(0).is_some() {
+            let mut index = 0;
+            while let Some(item) = child.get_index(index) {
+                path.push(PathSegment::Index(index));
+                if locate_in_value(item, target, path) {
+                    return true;
+                }
+                path.pop();
+                index += 1;
+            }
+        } else if locate_in_value(child, target, path) {
+            return true;
+        }
+
+        path.pop();
+    }
+
+    false
+}
+
+/// Reflection path from the root to a node returned by FHIRPath evaluation.
+fn locate(root: &dyn MetaValue, target: &dyn MetaValue) -> Option<Path> {
+    let mut path = vec![];
+    if is_same_node(root, target) || locate_in_value(root, target, &mut path) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn evaluate_paths(
+    engine: &FPEngine,
+    resource: &dyn MetaValue,
+    expression: &str,
+) -> Result<Vec<Path>, FHIRPathError> {
+    let result = engine.evaluate(expression, vec![resource])?;
+    result
+        .values
+        .iter()
+        .map(|node| {
+            locate(resource, *node).ok_or_else(|| {
+                invalid_patch(format!(
+                    "Path '{expression}' does not resolve to an element of the resource."
+                ))
+            })
+        })
+        .collect()
+}
+
+fn evaluate_single_path(
+    engine: &FPEngine,
+    resource: &dyn MetaValue,
+    expression: &str,
+) -> Result<Path, FHIRPathError> {
+    let mut paths = evaluate_paths(engine, resource, expression)?;
+    if paths.len() != 1 {
+        return Err(invalid_patch(format!(
+            "Path '{expression}' must resolve to a single element but found {}.",
+            paths.len()
+        )));
+    }
+    Ok(paths.remove(0))
+}
+
+/// Path of the collection an insert or move operates on.
+/// An empty collection is resolved through its parent element.
+fn evaluate_collection_path(
+    engine: &FPEngine,
+    resource: &dyn MetaValue,
+    expression: &str,
+) -> Result<Path, FHIRPathError> {
+    let paths = evaluate_paths(engine, resource, expression)?;
+
+    if let Some(first) = paths.first() {
+        let Some((PathSegment::Index(_), collection)) = first.split_last() else {
+            return Err(invalid_patch(format!(
+                "Path '{expression}' does not resolve to a collection."
+            )));
+        };
+        if paths
+            .iter()
+            .any(|path| path.len() != first.len() || !path.starts_with(collection))
+        {
+            return Err(invalid_patch(format!(
+                "Path '{expression}' must resolve to a single collection."
+            )));
+        }
+        return Ok(collection.to_vec());
+    }
+
+    let (parent, field) = expression
+        .rsplit_once('.')
+        .filter(|(_, field)| {
+            !field.is_empty() && field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+        .ok_or_else(|| {
+            invalid_patch(format!(
+                "Path '{expression}' does not resolve to a collection."
+            ))
+        })?;
+    let mut path = evaluate_single_path(engine, resource, parent)?;
+    let node = navigate(resource, &path)
+        .ok_or_else(|| invalid_patch(format!("Path '{parent}' could not be resolved.")))?;
+    let field = node
+        .fields()
+        .into_iter()
+        .find(|name| *name == field)
+        .ok_or_else(|| invalid_patch(format!("Element '{field}' is not valid at '{parent}'.")))?;
+    path.push(PathSegment::Field(field));
+
+    Ok(path)
+}
+
+fn navigate<'a>(node: &'a dyn MetaValue, path: &[PathSegment]) -> Option<&'a dyn MetaValue> {
+    path.iter().try_fold(node, |node, segment| match segment {
+        PathSegment::Field(field) => node.get_field(field),
+        PathSegment::Index(index) => node.get_index(*index),
+    })
+}
+
+fn navigate_mut<'a>(
+    node: &'a mut dyn MetaValue,
+    path: &[PathSegment],
+) -> Result<&'a mut dyn MetaValue, FHIRPathError> {
+    path.iter()
+        .try_fold(node, |node, segment| match segment {
+            PathSegment::Field(field) => node.get_field_mut(field),
+            PathSegment::Index(index) => node.get_index_mut(*index),
+        })
+        .ok_or_else(|| invalid_patch("Patch target could not be resolved."))
+}
+
+fn collection_length(collection: &dyn MetaValue) -> usize {
+    let mut length = 0;
+    while collection.get_index(length).is_some() {
+        length += 1;
+    }
+    length
+}
+
+fn apply_operation(
+    engine: &FPEngine,
+    resource: &mut dyn MetaValue,
+    operation: PatchOperation,
+) -> Result<(), FHIRPathError> {
+    match operation {
+        PatchOperation::Add { path, name, value } => {
+            let element_path = evaluate_single_path(engine, resource, &path)?;
+            let element = navigate_mut(resource, &element_path)?;
+            let field = element.get_field_mut(&name).ok_or_else(|| {
+                invalid_patch(format!("Element '{name}' is not valid at '{path}'."))
+            })?;
+            let length = collection_length(field);
+            field.insert_index(length, value).map_err(|_| {
+                invalid_patch(format!(
+                    "Value cannot be added to element '{name}' at '{path}'."
+                ))
+            })
+        }
+        PatchOperation::Insert { path, index, value } => {
+            let collection_path = evaluate_collection_path(engine, resource, &path)?;
+            navigate_mut(resource, &collection_path)?
+                .insert_index(index, value)
+                .map_err(|_| {
+                    invalid_patch(format!(
+                        "Value cannot be inserted at index {index} of '{path}'."
+                    ))
+                })
+        }
+        PatchOperation::Delete { path } => {
+            let mut paths = evaluate_paths(engine, resource, &path)?;
+            if paths.len() > 1 {
+                return Err(invalid_patch(format!(
+                    "Path '{path}' must resolve to at most one element but found {}.",
+                    paths.len()
+                )));
+            }
+            // Deleting an element that does not exist is not an error.
+            let Some(target) = paths.pop() else {
+                return Ok(());
+            };
+            let deleted = match target.split_last() {
+                Some((PathSegment::Index(index), collection)) => {
+                    navigate_mut(resource, collection)?.remove_index(*index)
+                }
+                Some((PathSegment::Field(field), parent)) => navigate_mut(resource, parent)?
+                    .get_field_mut(field)
+                    .map(|value| value.clear_value())
+                    .unwrap_or(false),
+                None => false,
+            };
+            if deleted {
+                Ok(())
+            } else {
+                Err(invalid_patch(format!(
+                    "Element at '{path}' cannot be deleted."
+                )))
+            }
+        }
+        PatchOperation::Replace { path, value } => {
+            let target = evaluate_single_path(engine, resource, &path)?;
+            if target.is_empty() {
+                return Err(invalid_patch("The resource itself cannot be replaced."));
+            }
+            navigate_mut(resource, &target)?
+                .set_value(value)
+                .map_err(|_| invalid_patch(format!("Value is not valid for '{path}'.")))
+        }
+        PatchOperation::Move {
+            path,
+            source,
+            destination,
+        } => {
+            let collection_path = evaluate_collection_path(engine, resource, &path)?;
+            if navigate_mut(resource, &collection_path)?.move_index(source, destination) {
+                Ok(())
+            } else {
+                Err(invalid_patch(format!(
+                    "Cannot move index {source} to {destination} of '{path}'."
+                )))
+            }
+        }
+    }
+}
+
+/// Applies a FHIRPath Patch to a value, operations are applied in order.
+/// On error the value may be partially patched, so callers should apply patches to a copy.
+pub fn apply_patch(
+    engine: &FPEngine,
+    value: &mut dyn MetaValue,
+    patch: Parameters,
+) -> Result<(), FHIRPathError> {
+    for parameter in patch.parameter.unwrap_or_default() {
+        apply_operation(engine, value, PatchOperation::try_from(parameter)?)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_model::r4::generated::resources::{Patient, Resource};
+
+    fn patch_patient(patient: serde_json::Value, patch: serde_json::Value) -> serde_json::Value {
+        let mut patient = haste_fhir_serialization_json::from_str::<Resource>(&patient.to_string())
+            .expect("valid patient");
+        let patch = haste_fhir_serialization_json::from_str::<Parameters>(&patch.to_string())
+            .expect("valid parameters");
+
+        apply_patch(&FPEngine::new(), &mut patient, patch).expect("patch applies");
+
+        serde_json::from_str(&haste_fhir_serialization_json::to_string(&patient).unwrap()).unwrap()
+    }
+
+    fn operation(parts: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "name": "operation", "part": parts })
+    }
+
+    #[test]
+    fn test_fhir_patch_operations() {
+        let patient = serde_json::json!({
+            "resourceType": "Patient",
+            "id": "example",
+            "gender": "male",
+            "name": [{ "given": ["Bob"] }, { "given": ["Alice"] }],
+            "deceasedBoolean": false
+        });
+
+        let patch = serde_json::json!({
+            "resourceType": "Parameters",
+            "parameter": [
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "add" },
+                    { "name": "path", "valueString": "Patient" },
+                    { "name": "name", "valueString": "birthDate" },
+                    { "name": "value", "valueDate": "1990-01-01" }
+                ])),
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "replace" },
+                    { "name": "path", "valueString": "Patient.gender" },
+                    { "name": "value", "valueCode": "female" }
+                ])),
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "insert" },
+                    { "name": "path", "valueString": "Patient.name[0].given" },
+                    { "name": "index", "valueInteger": 0 },
+                    { "name": "value", "valueString": "Robert" }
+                ])),
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "move" },
+                    { "name": "path", "valueString": "Patient.name" },
+                    { "name": "source", "valueInteger": 1 },
+                    { "name": "destination", "valueInteger": 0 }
+                ])),
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "delete" },
+                    { "name": "path", "valueString": "Patient.deceased" }
+                ])),
+                operation(serde_json::json!([
+                    { "name": "type", "valueCode": "add" },
+                    { "name": "path", "valueString": "Patient" },
+                    { "name": "name", "valueString": "deceased" },
+                    { "name": "value", "valueDateTime": "2020-01-01" }
+                ]))
+            ]
+        });
+
+        assert_eq!(
+            patch_patient(patient, patch),
+            serde_json::json!({
+                "resourceType": "Patient",
+                "id": "example",
+                "gender": "female",
+                "birthDate": "1990-01-01",
+                "name": [{ "given": ["Alice"] }, { "given": ["Robert", "Bob"] }],
+                "deceasedDateTime": "2020-01-01"
+            })
+        );
+    }
+
+    #[test]
+    fn test_fhir_patch_rejects_invalid_value() {
+        let mut patient = Resource::Patient(Patient::default());
+        let patch = haste_fhir_serialization_json::from_str::<Parameters>(
+            &serde_json::json!({
+                "resourceType": "Parameters",
+                "parameter": [operation(serde_json::json!([
+                    { "name": "type", "valueCode": "add" },
+                    { "name": "path", "valueString": "Patient" },
+                    { "name": "name", "valueString": "birthDate" },
+                    { "name": "value", "valueBoolean": true }
+                ]))]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(apply_patch(&FPEngine::new(), &mut patient, patch).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::parser::{Component, Field, FieldValue, Hl7V2Message, MessageHeader, Segment};
+
+/// HL7 Table 0008 - Acknowledgment code (original mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcknowledgmentCode {
+    /// Application accept.
+    AA,
+    /// Application error, the message was accepted but processing failed.
+    AE,
+    /// Application reject, the message could not be accepted IE it failed to parse.
+    AR,
+}
+
+impl AsRef<str> for AcknowledgmentCode {
+    fn as_ref(&self) -> &str {
+        match self {
+            AcknowledgmentCode::AA => "AA",
+            AcknowledgmentCode::AE => "AE",
+            AcknowledgmentCode::AR => "AR",
+        }
+    }
+}
+
+fn text_field(value: &str) -> Field {
+    Field {
+        value: Some(FieldValue {
+            value: Some(Component {
+                value: Some(value.to_string()),
+                subcomponents: None,
+            }),
+            components: None,
+        }),
+        repetitions: None,
+    }
+}
+
+/// Builds the ACK for a received message. The sending and receiving application/facility are swapped
+/// and MSA-2 echoes the control id of the original message.
+/// When the original header could not be parsed the defaults are used and MSA-2 is left empty.
+pub fn acknowledgment(
+    original: Option<&MessageHeader>,
+    code: AcknowledgmentCode,
+    control_id: &str,
+    datetime_of_message: &str,
+    text: Option<&str>,
+) -> Hl7V2Message {
+    let encoding_characters = original
+        .map(|h| h.encoding_characters().to_string())
+        .unwrap_or_else(|| "^~\\&".to_string());
+    let component_separator = encoding_characters.chars().next().unwrap_or('^');
+
+    // MSH-9.2 trigger event of the original message IE A01 from ADT^A01.
+    let trigger_event = original
+        .and_then(|h| h.message_type())
+        .and_then(|message_type| message_type.split(component_separator).nth(1))
+        .filter(|trigger_event| !trigger_event.is_empty());
+
+    let header = MessageHeader {
+        field_separator: original.map(|h| h.field_separator()).unwrap_or('|'),
+        encoding_characters,
+        sending_application: original.and_then(|h| h.receiving_application.clone()),
+        sending_facility: original.and_then(|h| h.receiving_facility.clone()),
+        receiving_application: original.and_then(|h| h.sending_application.clone()),
+        receiving_facility: original.and_then(|h| h.sending_facility.clone()),
+        datetime_of_message: Some(datetime_of_message.to_string()),
+        security: None,
+        message_type: Some(match trigger_event {
+            Some(trigger_event) => format!(
+                "ACK{}{}{}ACK",
+                component_separator, trigger_event, component_separator
+            ),
+            None => "ACK".to_string(),
+        }),
+        message_control_id: Some(control_id.to_string()),
+        processing_id: Some(
+            original
+                .and_then(|h| h.processing_id())
+                .unwrap_or("P")
+                .to_string(),
+        ),
+        version_id: Some(
+            original
+                .and_then(|h| h.version_id())
+                .unwrap_or("2.5")
+                .to_string(),
+        ),
+        additional_fields: vec![],
+    };
+
+    let mut msa_fields = vec![
+        text_field(code.as_ref()),
+        text_field(
+            original
+                .and_then(|h| h.message_control_id())
+                .unwrap_or_default(),
+        ),
+    ];
+    if let Some(text) = text {
+        msa_fields.push(text_field(text));
+    }
+
+    Hl7V2Message {
+        header,
+        segments: vec![Segment {
+            id: "MSA".to_string(),
+            fields: msa_fields,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode;
+
+    #[test]
+    fn test_acknowledgment() {
+        let input = std::fs::read_to_string("./test_data/message1.bin").unwrap();
+        let message = Hl7V2Message::try_from(input.as_str()).unwrap();
+
+        let ack = acknowledgment(
+            Some(&message.header),
+            AcknowledgmentCode::AA,
+            "ACK1",
+            "20110613061612",
+            None,
+        );
+
+        assert_eq!(
+            encode(&ack),
+            "MSH|^~\\&|RECEIVING_APPLICATION|RECEIVING_FACILITY|SENDING_APPLICATION|SENDING_FACILITY|20110613061612||ACK^S12^ACK|ACK1|P|2.3\rMSA|AA|24916560\r"
+        );
+    }
+
+    #[test]
+    fn test_reject_without_header() {
+        let ack = acknowledgment(
+            None,
+            AcknowledgmentCode::AR,
+            "ACK2",
+            "20110613061612",
+            Some("Missing MSH segment"),
+        );
+
+        assert_eq!(
+            encode(&ack),
+            "MSH|^~\\&|||||20110613061612||ACK|ACK2|P|2.5\rMSA|AR||Missing MSH segment\r"
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use crate::{
+    conversion::ConversionConfig,
+    parser::{Field, FieldValue},
+};
+use haste_fhir_model::r4::{
+    datetime::{parse_date, parse_datetime, parse_instant},
+    generated::{
+        resources::ResourceType,
+        terminology::{
+            AddressUse, AdministrativeGender, ContactPointSystem, ContactPointUse, NameUse,
+        },
+        types::{
+            Address, CodeableConcept, Coding, ContactPoint, FHIRCode, FHIRDate, FHIRDateTime,
+            FHIRInstant, FHIRString, FHIRUri, HumanName, Identifier, Reference,
+        },
+    },
+};
+
+pub fn fhir_string(value: &str) -> Box<FHIRString> {
+    Box::new(FHIRString {
+        value: Some(value.to_string()),
+        ..Default::default()
+    })
+}
+
+pub fn fhir_uri(value: &str) -> Box<FHIRUri> {
+    Box::new(FHIRUri {
+        value: Some(value.to_string()),
+        ..Default::default()
+    })
+}
+
+pub fn fhir_code(value: &str) -> Box<FHIRCode> {
+    Box::new(FHIRCode {
+        value: Some(value.to_string()),
+        ..Default::default()
+    })
+}
+
+/// Applies a mapping function to every repetition of an optional field.
+pub fn map_repetitions<T>(field: Option<&Field>, f: impl Fn(&FieldValue) -> Option<T>) -> Vec<T> {
+    field
+        .map(|field| field.repetitions().into_iter().filter_map(f).collect())
+        .unwrap_or_default()
+}
+
+/// Wraps a non-empty vector of values into Option<Vec<Box<T>>>.
+pub fn boxed_vec<T>(values: Vec<T>) -> Option<Vec<Box<T>>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.into_iter().map(Box::new).collect())
+    }
+}
+
+/// Resolves the identifier system from an HD (hierarchic designator) assigning authority.
+/// Uses the configured namespace mapping, then ISO OIDs, then falls back to the default system.
+fn resolve_identifier_system(
+    config: &ConversionConfig,
+    value: &FieldValue,
+    position: usize,
+    default_system: &str,
+) -> String {
+    let authority = value.component(position);
+    let namespace = authority.and_then(|a| a.subcomponent(1));
+    let universal_id = authority.and_then(|a| a.subcomponent(2));
+    let universal_id_type = authority.and_then(|a| a.subcomponent(3));
+
+    if let Some(system) = namespace.and_then(|ns| config.identifier_systems.get(ns)) {
+        system.clone()
+    } else if let Some(universal_id) = universal_id {
+        match universal_id_type {
+            Some("ISO") => format!("urn:oid:{}", universal_id),
+            Some("UUID") => format!("urn:uuid:{}", universal_id),
+            _ => universal_id.to_string(),
+        }
+    } else {
+        default_system.to_string()
+    }
+}
+
+/// CX - Extended composite ID with check digit.
+pub fn cx_to_identifier(
+    config: &ConversionConfig,
+    value: &FieldValue,
+    default_system: &str,
+) -> Option<Identifier> {
+    let id = value.component_str(1)?;
+    let identifier_type = value.component_str(5).map(|code| {
+        Box::new(CodeableConcept {
+            coding: Some(vec![Box::new(Coding {
+                system: Some(fhir_uri("http://terminology.hl7.org/CodeSystem/v2-0203")),
+                code: Some(fhir_code(code)),
+                ..Default::default()
+            })]),
+            ..Default::default()
+        })
+    });
+
+    Some(Identifier {
+        system: Some(fhir_uri(&resolve_identifier_system(
+            config,
+            value,
+            4,
+            default_system,
+        ))),
+        value: Some(fhir_string(id)),
+        type_: identifier_type,
+        ..Default::default()
+    })
+}
+
+/// EI - Entity identifier.
+pub fn ei_to_identifier(
+    config: &ConversionConfig,
+    value: &FieldValue,
+    default_system: &str,
+) -> Option<Identifier> {
+    let id = value.component_str(1)?;
+    let system = if let Some(namespace) = value.component_str(2)
+        && let Some(system) = config.identifier_systems.get(namespace)
+    {
+        system.clone()
+    } else if let Some(universal_id) = value.component_str(3) {
+        match value.component_str(4) {
+            Some("ISO") => format!("urn:oid:{}", universal_id),
+            _ => universal_id.to_string(),
+        }
+    } else {
+        default_system.to_string()
+    };
+
+    Some(Identifier {
+        system: Some(fhir_uri(&system)),
+        value: Some(fhir_string(id)),
+        ..Default::default()
+    })
+}
+
+/// Identifier built from the given system and value.
+pub fn identifier(system: &str, value: &str) -> Identifier {
+    Identifier {
+        system: Some(fhir_uri(system)),
+        value: Some(fhir_string(value)),
+        ..Default::default()
+    }
+}
+
+/// XPN - Extended person name. Also used for the name components of XCN with an offset.
+fn name_from_components(value: &FieldValue, offset: usize) -> Option<HumanName> {
+    // FN (family name) can have subcomponents the surname is the first.
+    let family = value
+        .component(offset + 1)
+        .and_then(|c| c.subcomponent(1).or_else(|| c.as_str()));
+    let given = [
+        value.component_str(offset + 2),
+        value.component_str(offset + 3),
+    ]
+    .into_iter()
+    .flatten()
+    .map(fhir_string)
+    .collect::<Vec<_>>();
+    let suffix = value.component_str(offset + 4);
+    let prefix = value.component_str(offset + 5);
+
+    if family.is_none() && given.is_empty() {
+        return None;
+    }
+
+    Some(HumanName {
+        family: family.map(fhir_string),
+        given: if given.is_empty() { None } else { Some(given) },
+        suffix: suffix.map(|s| vec![fhir_string(s)]),
+        prefix: prefix.map(|p| vec![fhir_string(p)]),
+        ..Default::default()
+    })
+}
+
+pub fn xpn_to_human_name(value: &FieldValue) -> Option<HumanName> {
+    let mut name = name_from_components(value, 0)?;
+    // HL7 Table 0200 - Name type.
+    name.use_ = value
+        .component_str(7)
+        .and_then(|name_type| match name_type {
+            "L" => Some("official"),
+            "D" => Some("usual"),
+            "M" | "B" => Some("maiden"),
+            "N" => Some("nickname"),
+            "A" | "S" => Some("anonymous"),
+            "T" => Some("temp"),
+            _ => None,
+        })
+        .and_then(|code| NameUse::try_from(code.to_string()).ok())
+        .map(Box::new);
+
+    Some(name)
+}
+
+/// XAD - Extended address.
+pub fn xad_to_address(value: &FieldValue) -> Option<Address> {
+    let line = [
+        value
+            .component(1)
+            .and_then(|c| c.subcomponent(1).or_else(|| c.as_str())),
+        value.component_str(2),
+    ]
+    .into_iter()
+    .flatten()
+    .map(fhir_string)
+    .collect::<Vec<_>>();
+
+    let address = Address {
+        line: if line.is_empty() { None } else { Some(line) },
+        city: value.component_str(3).map(fhir_string),
+        state: value.component_str(4).map(fhir_string),
+        postalCode: value.component_str(5).map(fhir_string),
+        country: value.component_str(6).map(fhir_string),
+        // HL7 Table 0190 - Address type.
+        use_: value
+            .component_str(7)
+            .and_then(|address_type| match address_type {
+                "H" => Some("home"),
+                "B" | "O" => Some("work"),
+                "C" => Some("temp"),
+                "BA" => Some("old"),
+                _ => None,
+            })
+            .and_then(|code| AddressUse::try_from(code.to_string()).ok())
+            .map(Box::new),
+        ..Default::default()
+    };
+
+    if address.line.is_none()
+        && address.city.is_none()
+        && address.state.is_none()
+        && address.postalCode.is_none()
+        && address.country.is_none()
+    {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// XTN - Extended telecommunication number.
+pub fn xtn_to_contact_point(value: &FieldValue, default_use: &str) -> Option<ContactPoint> {
+    // HL7 Table 0202 - Telecommunication equipment type.
+    let equipment_type = value.component_str(3);
+    let email = value.component_str(4);
+
+    let (system, number) = if let Some(email) = email {
+        ("email", email.to_string())
+    } else {
+        let number = match (
+            value.component_str(6),
+            value.component_str(7),
+            value.component_str(1),
+        ) {
+            (Some(area), Some(local), _) => format!("({}) {}", area, local),
+            (None, Some(local), _) => local.to_string(),
+            (_, None, Some(unformatted)) => unformatted.to_string(),
+            (_, None, None) => return None,
+        };
+        let system = match equipment_type {
+            Some("FX") => "fax",
+            Some("BP") => "pager",
+            Some("Internet") | Some("X.400") => "email",
+            _ => "phone",
+        };
+        (system, number)
+    };
+
+    // HL7 Table 0201 - Telecommunication use code.
+    let use_ = match value.component_str(2) {
+        Some("PRN") | Some("ORN") | Some("VHN") => "home",
+        Some("WPN") => "work",
+        Some("PRS") => "mobile",
+        Some("EMR") | Some("NET") | Some("ASN") | Some("BPN") | None => default_use,
+        Some(_) => default_use,
+    };
+    let use_ = if equipment_type == Some("CP") {
+        "mobile"
+    } else {
+        use_
+    };
+
+    Some(ContactPoint {
+        system: ContactPointSystem::try_from(system.to_string())
+            .ok()
+            .map(Box::new),
+        value: Some(fhir_string(&number)),
+        use_: ContactPointUse::try_from(use_.to_string())
+            .ok()
+            .map(Box::new),
+        ..Default::default()
+    })
+}
+
+fn coding(
+    config: &ConversionConfig,
+    code: &str,
+    display: Option<&str>,
+    system: Option<&str>,
+) -> Coding {
+    Coding {
+        system: system
+            .map(|system| {
+                config.code_systems.get(system).cloned().unwrap_or_else(|| {
+                    // HL7 defined tables are referenced as HL7nnnn.
+                    if let Some(table) = system.strip_prefix("HL7") {
+                        format!("http://terminology.hl7.org/CodeSystem/v2-{}", table)
+                    } else {
+                        system.to_string()
+                    }
+                })
+            })
+            .map(|s| fhir_uri(&s)),
+        code: Some(fhir_code(code)),
+        display: display.map(fhir_string),
+        ..Default::default()
+    }
+}
+
+/// CE/CWE - Coded element / coded with exceptions. Includes the alternate coding (components 4-6).
+pub fn cwe_to_codeable_concept(
+    config: &ConversionConfig,
+    value: &FieldValue,
+) -> Option<CodeableConcept> {
+    let mut codings = vec![];
+
+    if let Some(code) = value.component_str(1) {
+        codings.push(coding(
+            config,
+            code,
+            value.component_str(2),
+            value.component_str(3),
+        ));
+    }
+    if let Some(code) = value.component_str(4) {
+        codings.push(coding(
+            config,
+            code,
+            value.component_str(5),
+            value.component_str(6),
+        ));
+    }
+
+    let text = value.component_str(9).or_else(|| value.component_str(2));
+
+    if codings.is_empty() && text.is_none() {
+        return None;
+    }
+
+    Some(CodeableConcept {
+        coding: boxed_vec(codings),
+        text: text.map(fhir_string),
+        ..Default::default()
+    })
+}
+
+/// CodeableConcept for a code from an HL7 defined table IE 0127 allergen type.
+pub fn hl7_table_codeable_concept(table: &str, code: &str) -> CodeableConcept {
+    CodeableConcept {
+        coding: Some(vec![Box::new(Coding {
+            system: Some(fhir_uri(&format!(
+                "http://terminology.hl7.org/CodeSystem/v2-{}",
+                table
+            ))),
+            code: Some(fhir_code(code)),
+            ..Default::default()
+        })]),
+        ..Default::default()
+    }
+}
+
+/// XCN - Extended composite ID number and name for persons.
+/// Converted to a logical reference as the practitioner may not exist on the server.
+pub fn xcn_to_reference(
+    config: &ConversionConfig,
+    value: &FieldValue,
+    resource_type: ResourceType,
+) -> Option<Reference> {
+    let id = value.component_str(1);
+    let name = name_from_components(value, 1);
+    let display = name.as_ref().map(|name| {
+        name.given
+            .iter()
+            .flatten()
+            .chain(name.family.iter())
+            .filter_map(|n| n.value.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    if id.is_none() && display.is_none() {
+        return None;
+    }
+
+    Some(Reference {
+        type_: Some(fhir_uri(resource_type.as_ref())),
+        identifier_: id.map(|id| {
+            Box::new(Identifier {
+                system: Some(fhir_uri(&resolve_identifier_system(
+                    config,
+                    value,
+                    9,
+                    "urn:haste-health:hl7v2:practitioner",
+                ))),
+                value: Some(fhir_string(id)),
+                ..Default::default()
+            })
+        }),
+        display: display.map(|d| fhir_string(&d)),
+        ..Default::default()
+    })
+}
+
+/// HL7 Table 0001 - Administrative sex.
+pub fn administrative_gender(value: &str) -> Option<AdministrativeGender> {
+    let code = match value {
+        "M" => "male",
+        "F" => "female",
+        "O" | "A" | "N" => "other",
+        "U" => "unknown",
+        _ => return None,
+    };
+    AdministrativeGender::try_from(code.to_string()).ok()
+}
+
+/// Converts an HL7 DTM/TS value (YYYY[MM[DD[HH[MM[SS[.S+]]]]]][+/-ZZZZ]) to a FHIR formatted string.
+/// Values carrying a time always include an offset using the configured default if absent.
+pub fn dtm_to_fhir_string(config: &ConversionConfig, value: &str) -> Option<String> {
+    // TS has a degree of precision component after the timestamp.
+    let value = value.split('^').next()?;
+    let (datetime, offset) = match value.find(|c| c == '+' || c == '-') {
+        Some(index) => (&value[..index], Some(&value[index..])),
+        None => (value, None),
+    };
+    let (datetime, fraction) = match datetime.split_once('.') {
+        Some((datetime, fraction)) => (datetime, Some(fraction)),
+        None => (datetime, None),
+    };
+
+    if !datetime.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    match datetime.len() {
+        4 => Some(datetime.to_string()),
+        6 => Some(format!("{}-{}", &datetime[0..4], &datetime[4..6])),
+        8 => Some(format!(
+            "{}-{}-{}",
+            &datetime[0..4],
+            &datetime[4..6],
+            &datetime[6..8]
+        )),
+        10 | 12 | 14 => {
+            let offset = match offset {
+                Some(offset) if offset.len() == 5 => format!("{}:{}", &offset[0..3], &offset[3..5]),
+                _ => config.default_timezone_offset.clone(),
+            };
+            Some(format!(
+                "{}-{}-{}T{}:{}:{}{}{}",
+                &datetime[0..4],
+                &datetime[4..6],
+                &datetime[6..8],
+                &datetime[8..10],
+                datetime.get(10..12).unwrap_or("00"),
+                datetime.get(12..14).unwrap_or("00"),
+                fraction.map(|f| format!(".{}", f)).unwrap_or_default(),
+                offset
+            ))
+        }
+        _ => None,
+    }
+}
+
+pub fn dtm_to_datetime(config: &ConversionConfig, value: &str) -> Option<FHIRDateTime> {
+    let value = parse_datetime(&dtm_to_fhir_string(config, value)?).ok()?;
+    Some(FHIRDateTime {
+        value: Some(value),
+        ..Default::default()
+    })
+}
+
+pub fn dtm_to_date(config: &ConversionConfig, value: &str) -> Option<FHIRDate> {
+    let formatted = dtm_to_fhir_string(config, value)?;
+    let value = parse_date(formatted.get(..10).unwrap_or(&formatted)).ok()?;
+    Some(FHIRDate {
+        value: Some(value),
+        ..Default::default()
+    })
+}
+
+/// Instants require a full timestamp so date only values are rejected.
+pub fn dtm_to_instant(config: &ConversionConfig, value: &str) -> Option<FHIRInstant> {
+    let value = parse_instant(&dtm_to_fhir_string(config, value)?).ok()?;
+    Some(FHIRInstant {
+        value: Some(value),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dtm_to_fhir_string() {
+        let config = ConversionConfig::default();
+        assert_eq!(
+            dtm_to_fhir_string(&config, "2011"),
+            Some("2011".to_string())
+        );
+        assert_eq!(
+            dtm_to_fhir_string(&config, "20110613"),
+            Some("2011-06-13".to_string())
+        );
+        assert_eq!(
+            dtm_to_fhir_string(&config, "20110613061611"),
+            Some("2011-06-13T06:16:11Z".to_string())
+        );
+        assert_eq!(
+            dtm_to_fhir_string(&config, "201106130616-0500"),
+            Some("2011-06-13T06:16:00-05:00".to_string())
+        );
+        assert_eq!(
+            dtm_to_fhir_string(&config, "20110613061611.123+0100"),
+            Some("2011-06-13T06:16:11.123+01:00".to_string())
+        );
+        assert_eq!(dtm_to_fhir_string(&config, "June"), None);
+    }
+}
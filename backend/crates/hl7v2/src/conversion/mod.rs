@@ -0,0 +1,422 @@
+use crate::parser::{Hl7V2Message, Segment};
+use haste_fhir_client::FHIRClient;
+use haste_fhir_model::r4::generated::{
+    resources::{Bundle, BundleEntry, BundleEntryRequest, Resource, ResourceType},
+    terminology::{BundleType, HttpVerb, IssueType},
+    types::{FHIRString, FHIRUri, Identifier, Reference},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use std::collections::HashMap;
+
+pub mod datatypes;
+mod segments;
+
+pub use segments::*;
+
+#[derive(Debug, Clone)]
+pub struct ConversionConfig {
+    /// Identifier system used for patient identifiers when PID-3 has no resolvable assigning authority.
+    pub patient_identifier_system: String,
+    /// Identifier system used for visit numbers (PV1-19, PV1-50) and Encounter identifiers
+    /// derived from the patient and admit time.
+    pub encounter_identifier_system: String,
+    /// Identifier system used for placer/filler order numbers (OBR-2, OBR-3).
+    pub order_identifier_system: String,
+    /// Identifier system used for generated Observation identifiers.
+    pub observation_identifier_system: String,
+    /// Identifier system used for generated AllergyIntolerance identifiers.
+    pub allergy_identifier_system: String,
+    /// Identifier system used for generated RelatedPerson identifiers.
+    pub related_person_identifier_system: String,
+    /// Identifier system used for appointment ids (SCH-1, SCH-2).
+    pub appointment_identifier_system: String,
+    /// Map of assigning authority namespace ids (HD.1) to identifier systems.
+    pub identifier_systems: HashMap<String, String>,
+    /// Map of HL7 v2 coding system names (CWE.3) to FHIR code system uris IE LN => http://loinc.org.
+    pub code_systems: HashMap<String, String>,
+    /// Offset applied to HL7 timestamps that carry a time but no offset IE "Z" or "+05:00".
+    pub default_timezone_offset: String,
+}
+
+impl Default for ConversionConfig {
+    fn default() -> Self {
+        ConversionConfig {
+            patient_identifier_system: "urn:haste-health:hl7v2:patient".to_string(),
+            encounter_identifier_system: "urn:haste-health:hl7v2:visit".to_string(),
+            order_identifier_system: "urn:haste-health:hl7v2:order".to_string(),
+            observation_identifier_system: "urn:haste-health:hl7v2:observation".to_string(),
+            allergy_identifier_system: "urn:haste-health:hl7v2:allergy".to_string(),
+            related_person_identifier_system: "urn:haste-health:hl7v2:next-of-kin".to_string(),
+            appointment_identifier_system: "urn:haste-health:hl7v2:appointment".to_string(),
+            identifier_systems: HashMap::new(),
+            code_systems: HashMap::from([
+                ("LN".to_string(), "http://loinc.org".to_string()),
+                ("LOINC".to_string(), "http://loinc.org".to_string()),
+                ("SCT".to_string(), "http://snomed.info/sct".to_string()),
+                ("SNM".to_string(), "http://snomed.info/sct".to_string()),
+                (
+                    "I9C".to_string(),
+                    "http://hl7.org/fhir/sid/icd-9-cm".to_string(),
+                ),
+                (
+                    "I10".to_string(),
+                    "http://hl7.org/fhir/sid/icd-10".to_string(),
+                ),
+                ("UCUM".to_string(), "http://unitsofmeasure.org".to_string()),
+                (
+                    "RXNORM".to_string(),
+                    "http://www.nlm.nih.gov/research/umls/rxnorm".to_string(),
+                ),
+                ("CVX".to_string(), "http://hl7.org/fhir/sid/cvx".to_string()),
+                ("NDC".to_string(), "http://hl7.org/fhir/sid/ndc".to_string()),
+            ]),
+            default_timezone_offset: "Z".to_string(),
+        }
+    }
+}
+
+/// Mutable state shared between segment mappers while converting a single message.
+pub struct ConversionState<'a> {
+    pub message: &'a Hl7V2Message,
+    entries: Vec<BundleEntry>,
+    /// fullUrl of the Patient created from PID.
+    pub patient: Option<String>,
+    /// Primary patient identifier used to derive identifiers of child resources.
+    pub patient_identifier: Option<Identifier>,
+    /// fullUrl of the Encounter created from PV1.
+    pub encounter: Option<String>,
+    /// Entry index of the DiagnosticReport created from the most recent OBR.
+    pub diagnostic_report: Option<usize>,
+    /// Order number of the most recent OBR used to derive Observation identifiers.
+    pub order_number: Option<String>,
+    /// Entry index of the Appointment created from SCH.
+    /// SCH precedes PID in SIU messages so the patient participant is linked once PID is mapped.
+    pub appointment: Option<usize>,
+}
+
+impl<'a> ConversionState<'a> {
+    fn new(message: &'a Hl7V2Message) -> Self {
+        ConversionState {
+            message,
+            entries: vec![],
+            patient: None,
+            patient_identifier: None,
+            encounter: None,
+            diagnostic_report: None,
+            order_number: None,
+            appointment: None,
+        }
+    }
+
+    /// Adds a resource to the transaction. When an identifier with both system and value is
+    /// provided the entry is a conditional update on that identifier otherwise it's a create.
+    /// Returns the fullUrl of the entry to be used for references.
+    pub fn add_entry(
+        &mut self,
+        resource_type: ResourceType,
+        resource: Resource,
+        identifier: Option<&Identifier>,
+    ) -> String {
+        // fullUrls only need to be unique within the bundle so derive them from the entry index.
+        let full_url = format!(
+            "urn:uuid:00000000-0000-0000-0000-{:012x}",
+            self.entries.len()
+        );
+        let search_identifier = identifier.and_then(|identifier| {
+            let system = identifier.system.as_ref().and_then(|s| s.value.as_ref())?;
+            let value = identifier.value.as_ref().and_then(|v| v.value.as_ref())?;
+            Some(format!("{}|{}", system, value))
+        });
+
+        let request = match search_identifier {
+            Some(search_identifier) => BundleEntryRequest {
+                method: Box::new(HttpVerb::PUT(None)),
+                url: Box::new(FHIRUri {
+                    value: Some(format!(
+                        "{}?identifier={}",
+                        resource_type.as_ref(),
+                        url::form_urlencoded::byte_serialize(search_identifier.as_bytes())
+                            .collect::<String>()
+                    )),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            None => BundleEntryRequest {
+                method: Box::new(HttpVerb::POST(None)),
+                url: Box::new(FHIRUri {
+                    value: Some(resource_type.as_ref().to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        };
+
+        self.entries.push(BundleEntry {
+            fullUrl: Some(Box::new(FHIRUri {
+                value: Some(full_url.clone()),
+                ..Default::default()
+            })),
+            resource: Some(Box::new(resource)),
+            request: Some(request),
+            ..Default::default()
+        });
+
+        full_url
+    }
+
+    /// Index of the last entry added.
+    pub fn last_entry_index(&self) -> Option<usize> {
+        self.entries.len().checked_sub(1)
+    }
+
+    pub fn entry_mut(&mut self, index: usize) -> Option<&mut BundleEntry> {
+        self.entries.get_mut(index)
+    }
+
+    pub fn entry_by_full_url_mut(&mut self, full_url: &str) -> Option<&mut BundleEntry> {
+        self.entries.iter_mut().find(|e| {
+            e.fullUrl
+                .as_ref()
+                .and_then(|f| f.value.as_deref())
+                .map(|f| f == full_url)
+                .unwrap_or(false)
+        })
+    }
+
+    pub fn full_url(&self, index: usize) -> Option<String> {
+        self.entries
+            .get(index)
+            .and_then(|e| e.fullUrl.as_ref())
+            .and_then(|f| f.value.clone())
+    }
+
+    /// Reference to another entry within the transaction.
+    pub fn reference(full_url: &str) -> Reference {
+        Reference {
+            reference: Some(Box::new(FHIRString {
+                value: Some(full_url.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    pub fn patient_reference(&self) -> Option<Reference> {
+        self.patient.as_ref().map(|p| Self::reference(p))
+    }
+
+    pub fn encounter_reference(&self) -> Option<Reference> {
+        self.encounter.as_ref().map(|e| Self::reference(e))
+    }
+
+    /// Value of the primary patient identifier used as a prefix for derived identifiers.
+    pub fn patient_identifier_value(&self) -> Option<&str> {
+        self.patient_identifier
+            .as_ref()
+            .and_then(|i| i.value.as_ref())
+            .and_then(|v| v.value.as_deref())
+    }
+}
+
+/// Maps a single HL7 v2 segment into FHIR resources added to the conversion state.
+pub trait SegmentMapper: Send + Sync {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError>;
+}
+
+/// Converts HL7 v2 messages into FHIR transaction Bundles.
+/// Resources are emitted as conditional updates on their identifier
+/// (IE PUT Patient?identifier=system|value) so replayed messages update rather than duplicate.
+pub struct Hl7V2Converter {
+    config: ConversionConfig,
+    mappers: HashMap<String, Box<dyn SegmentMapper>>,
+}
+
+impl Hl7V2Converter {
+    /// Creates a converter with the default segment mappers registered.
+    pub fn new(config: ConversionConfig) -> Self {
+        let mut converter = Hl7V2Converter {
+            config,
+            mappers: HashMap::new(),
+        };
+
+        converter.register("PID", Box::new(segments::PIDMapper {}));
+        converter.register("PD1", Box::new(segments::PD1Mapper {}));
+        converter.register("PV1", Box::new(segments::PV1Mapper {}));
+        converter.register("NK1", Box::new(segments::NK1Mapper {}));
+        converter.register("AL1", Box::new(segments::AL1Mapper {}));
+        converter.register("OBR", Box::new(segments::OBRMapper {}));
+        converter.register("OBX", Box::new(segments::OBXMapper {}));
+        converter.register("SCH", Box::new(segments::SCHMapper {}));
+
+        converter
+    }
+
+    /// Register a mapper for a segment id replacing any existing mapper.
+    pub fn register(&mut self, segment_id: &str, mapper: Box<dyn SegmentMapper>) {
+        self.mappers.insert(segment_id.to_string(), mapper);
+    }
+
+    /// Remove the mapper for a segment id so the segment is ignored.
+    pub fn remove(&mut self, segment_id: &str) {
+        self.mappers.remove(segment_id);
+    }
+
+    pub fn config(&self) -> &ConversionConfig {
+        &self.config
+    }
+
+    /// Convert the message into a transaction Bundle.
+    /// Segments without a registered mapper are ignored.
+    pub fn convert(&self, message: &Hl7V2Message) -> Result<Bundle, OperationOutcomeError> {
+        let mut state = ConversionState::new(message);
+
+        for segment in message.segments.iter() {
+            if let Some(mapper) = self.mappers.get(&segment.id) {
+                mapper.map(&self.config, &mut state, segment)?;
+            }
+        }
+
+        if state.entries.is_empty() {
+            return Err(OperationOutcomeError::error(
+                IssueType::NotSupported(None),
+                format!(
+                    "No FHIR resources could be produced from message '{}'.",
+                    message.header.message_type().unwrap_or_default()
+                ),
+            ));
+        }
+
+        Ok(Bundle {
+            type_: Box::new(BundleType::Transaction(None)),
+            entry: Some(state.entries),
+            ..Default::default()
+        })
+    }
+
+    /// Convert the message and submit the resulting transaction through the FHIR client.
+    pub async fn submit<CTX, Client: FHIRClient<CTX, OperationOutcomeError>>(
+        &self,
+        client: &Client,
+        ctx: CTX,
+        message: &Hl7V2Message,
+    ) -> Result<Bundle, OperationOutcomeError> {
+        let bundle = self.convert(message)?;
+        client.transaction(ctx, bundle).await
+    }
+}
+
+impl Default for Hl7V2Converter {
+    fn default() -> Self {
+        Hl7V2Converter::new(ConversionConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requests(bundle: &Bundle) -> Vec<(&'static str, String)> {
+        bundle
+            .entry
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|e| {
+                let request = e.request.as_ref().unwrap();
+                let method = match request.method.as_ref() {
+                    HttpVerb::PUT(_) => "PUT",
+                    HttpVerb::POST(_) => "POST",
+                    _ => "OTHER",
+                };
+                (method, request.url.value.clone().unwrap_or_default())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_convert_siu_message() {
+        let input = std::fs::read_to_string("./test_data/message1.bin").unwrap();
+        let message = Hl7V2Message::try_from(input.as_str()).unwrap();
+
+        let converter = Hl7V2Converter::default();
+        let bundle = converter.convert(&message).unwrap();
+        let replayed = converter.convert(&message).unwrap();
+
+        // Every entry is a conditional update so replaying the message updates the same resources.
+        assert_eq!(requests(&bundle), requests(&replayed));
+        assert_eq!(
+            requests(&bundle),
+            vec![
+                (
+                    "PUT",
+                    "Appointment?identifier=urn%3Ahaste-health%3Ahl7v2%3Aappointment%7C2196178"
+                        .to_string()
+                ),
+                (
+                    "PUT",
+                    "Patient?identifier=urn%3Ahaste-health%3Ahl7v2%3Apatient%7C42".to_string()
+                ),
+                // PV1-19 visit number is not populated so PV1-50 alternate visit id is used.
+                (
+                    "PUT",
+                    "Encounter?identifier=urn%3Ahaste-health%3Ahl7v2%3Avisit%7C99158".to_string()
+                ),
+            ]
+        );
+
+        let entries = bundle.entry.unwrap();
+        let patient_url = entries[1].fullUrl.as_ref().unwrap().value.clone();
+
+        match entries[0].resource.as_deref() {
+            Some(Resource::Appointment(appointment)) => {
+                assert_eq!(appointment.participant.len(), 1);
+                assert_eq!(
+                    appointment.participant[0]
+                        .actor
+                        .as_ref()
+                        .and_then(|a| a.reference.as_ref())
+                        .and_then(|r| r.value.clone()),
+                    patient_url
+                );
+            }
+            _ => panic!("Expected Appointment"),
+        }
+
+        match entries[1].resource.as_deref() {
+            Some(Resource::Patient(patient)) => {
+                let name = &patient.name.as_ref().unwrap()[0];
+                assert_eq!(
+                    name.family.as_ref().and_then(|f| f.value.as_deref()),
+                    Some("BEEBLEBROX")
+                );
+                assert_eq!(patient.address.as_ref().map(|a| a.len()), Some(1));
+            }
+            _ => panic!("Expected Patient"),
+        }
+    }
+
+    #[test]
+    fn test_encounter_identifier_from_admit_time() {
+        let input = format!(
+            "MSH|^~\\&|A|B|C|D|20240101120000||ADT^A01|1|P|2.5\rPID|1||42||DOE^JOHN\rPV1|1|I{}20240101120000",
+            "|".repeat(42)
+        );
+        let message = Hl7V2Message::try_from(input.as_str()).unwrap();
+
+        let bundle = Hl7V2Converter::default().convert(&message).unwrap();
+        assert_eq!(
+            requests(&bundle)[1],
+            (
+                "PUT",
+                "Encounter?identifier=urn%3Ahaste-health%3Ahl7v2%3Avisit%7C42-20240101120000"
+                    .to_string()
+            )
+        );
+    }
+}
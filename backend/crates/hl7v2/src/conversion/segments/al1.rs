@@ -0,0 +1,123 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            cwe_to_codeable_concept, dtm_to_datetime, fhir_code, fhir_string, fhir_uri, identifier,
+        },
+        segments::missing_patient,
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{AllergyIntolerance, AllergyIntoleranceReaction, Resource, ResourceType},
+    terminology::{AllergyIntoleranceCategory, AllergyIntoleranceCriticality},
+    types::{CodeableConcept, Coding},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// HL7 Table 0127 - Allergen type.
+fn allergy_category(allergen_type: &str) -> Option<AllergyIntoleranceCategory> {
+    match allergen_type {
+        "DA" | "MA" => Some(AllergyIntoleranceCategory::Medication(None)),
+        "FA" => Some(AllergyIntoleranceCategory::Food(None)),
+        "EA" | "AA" | "PA" | "LA" => Some(AllergyIntoleranceCategory::Environment(None)),
+        _ => None,
+    }
+}
+
+/// HL7 Table 0128 - Allergy severity.
+fn allergy_criticality(severity: &str) -> Option<AllergyIntoleranceCriticality> {
+    match severity {
+        "SV" => Some(AllergyIntoleranceCriticality::High(None)),
+        "MO" | "MI" => Some(AllergyIntoleranceCriticality::Low(None)),
+        "U" => Some(AllergyIntoleranceCriticality::UnableToAssess(None)),
+        _ => None,
+    }
+}
+
+/// AL1 - Patient allergy information => AllergyIntolerance.
+pub struct AL1Mapper {}
+
+impl SegmentMapper for AL1Mapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        let patient = state
+            .patient_reference()
+            .ok_or_else(|| missing_patient("AL1"))?;
+
+        let allergen = segment.field(3).and_then(|f| f.first());
+        let code = allergen.and_then(|value| cwe_to_codeable_concept(config, value));
+
+        // Derive the identifier from the patient and allergen so repeated messages update the allergy.
+        let identifier = state
+            .patient_identifier_value()
+            .zip(allergen.and_then(|value| value.component_str(1).or(value.component_str(2))))
+            .map(|(patient_id, allergen)| {
+                identifier(
+                    &config.allergy_identifier_system,
+                    &format!("{}-{}", patient_id, allergen),
+                )
+            });
+
+        let manifestation = segment
+            .field(5)
+            .map(|f| f.repetitions())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|value| cwe_to_codeable_concept(config, value))
+            .map(Box::new)
+            .collect::<Vec<_>>();
+
+        let allergy_intolerance = AllergyIntolerance {
+            identifier_: identifier.clone().map(|i| vec![Box::new(i)]),
+            clinicalStatus: Some(Box::new(CodeableConcept {
+                coding: Some(vec![Box::new(Coding {
+                    system: Some(fhir_uri(
+                        "http://terminology.hl7.org/CodeSystem/allergyintolerance-clinical",
+                    )),
+                    code: Some(fhir_code("active")),
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            })),
+            category: segment
+                .field_str(2)
+                .and_then(allergy_category)
+                .map(|category| vec![Box::new(category)]),
+            criticality: segment
+                .field_str(4)
+                .and_then(allergy_criticality)
+                .map(Box::new),
+            code: code.map(Box::new),
+            patient: Box::new(patient),
+            encounter: state.encounter_reference().map(Box::new),
+            // AL1-6 identification date.
+            recordedDate: segment
+                .field_str(6)
+                .and_then(|date| dtm_to_datetime(config, date))
+                .map(Box::new),
+            reaction: if manifestation.is_empty() {
+                None
+            } else {
+                Some(vec![AllergyIntoleranceReaction {
+                    manifestation,
+                    description: segment.field_str(5).map(fhir_string),
+                    ..Default::default()
+                }])
+            },
+            ..Default::default()
+        };
+
+        state.add_entry(
+            ResourceType::AllergyIntolerance,
+            Resource::AllergyIntolerance(allergy_intolerance),
+            identifier.as_ref(),
+        );
+
+        Ok(())
+    }
+}
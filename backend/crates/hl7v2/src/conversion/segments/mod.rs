@@ -0,0 +1,49 @@
+use crate::conversion::ConversionState;
+use haste_fhir_model::r4::generated::{
+    resources::{AppointmentParticipant, Resource},
+    terminology::{IssueType, Participationstatus},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+mod al1;
+mod nk1;
+mod obr;
+mod obx;
+mod pd1;
+mod pid;
+mod pv1;
+mod sch;
+
+pub use al1::AL1Mapper;
+pub use nk1::NK1Mapper;
+pub use obr::OBRMapper;
+pub use obx::OBXMapper;
+pub use pd1::PD1Mapper;
+pub use pid::PIDMapper;
+pub use pv1::PV1Mapper;
+pub use sch::SCHMapper;
+
+fn missing_patient(segment_id: &str) -> OperationOutcomeError {
+    OperationOutcomeError::error(
+        IssueType::Invalid(None),
+        format!("{} segment requires a preceding PID segment.", segment_id),
+    )
+}
+
+/// Adds the patient as a participant of the appointment if both have been mapped.
+fn link_patient_to_appointment(state: &mut ConversionState) {
+    let (Some(index), Some(patient)) = (state.appointment, state.patient_reference()) else {
+        return;
+    };
+
+    if let Some(entry) = state.entry_mut(index)
+        && let Some(resource) = entry.resource.as_mut()
+        && let Resource::Appointment(appointment) = resource.as_mut()
+    {
+        appointment.participant.push(AppointmentParticipant {
+            actor: Some(Box::new(patient)),
+            status: Box::new(Participationstatus::Accepted(None)),
+            ..Default::default()
+        });
+    }
+}
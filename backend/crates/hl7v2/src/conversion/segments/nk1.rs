@@ -0,0 +1,67 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            boxed_vec, cwe_to_codeable_concept, identifier, map_repetitions, xad_to_address,
+            xpn_to_human_name, xtn_to_contact_point,
+        },
+        segments::missing_patient,
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::resources::{RelatedPerson, Resource, ResourceType};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// NK1 - Next of kin => RelatedPerson.
+pub struct NK1Mapper {}
+
+impl SegmentMapper for NK1Mapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        let patient = state
+            .patient_reference()
+            .ok_or_else(|| missing_patient("NK1"))?;
+
+        // Next of kin have no identifier of their own so derive one from the patient and set id.
+        let identifier = state
+            .patient_identifier_value()
+            .zip(segment.field_str(1))
+            .map(|(patient_id, set_id)| {
+                identifier(
+                    &config.related_person_identifier_system,
+                    &format!("{}-{}", patient_id, set_id),
+                )
+            });
+
+        let mut telecom = map_repetitions(segment.field(5), |value| {
+            xtn_to_contact_point(value, "home")
+        });
+        telecom.extend(map_repetitions(segment.field(6), |value| {
+            xtn_to_contact_point(value, "work")
+        }));
+
+        let related_person = RelatedPerson {
+            identifier_: identifier.clone().map(|i| vec![Box::new(i)]),
+            patient: Box::new(patient),
+            name: boxed_vec(map_repetitions(segment.field(2), xpn_to_human_name)),
+            relationship: boxed_vec(map_repetitions(segment.field(3), |value| {
+                cwe_to_codeable_concept(config, value)
+            })),
+            address: boxed_vec(map_repetitions(segment.field(4), xad_to_address)),
+            telecom: boxed_vec(telecom),
+            ..Default::default()
+        };
+
+        state.add_entry(
+            ResourceType::RelatedPerson,
+            Resource::RelatedPerson(related_person),
+            identifier.as_ref(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            boxed_vec, cwe_to_codeable_concept, dtm_to_datetime, dtm_to_instant, ei_to_identifier,
+        },
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{DiagnosticReport, DiagnosticReportEffectiveTypeChoice, Resource, ResourceType},
+    terminology::DiagnosticReportStatus,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// HL7 Table 0123 - Result status.
+fn report_status(status: Option<&str>) -> DiagnosticReportStatus {
+    match status {
+        Some("O") | Some("I") | Some("S") => DiagnosticReportStatus::Registered(None),
+        Some("A") => DiagnosticReportStatus::Partial(None),
+        Some("P") | Some("R") => DiagnosticReportStatus::Preliminary(None),
+        Some("C") => DiagnosticReportStatus::Corrected(None),
+        Some("F") => DiagnosticReportStatus::Final(None),
+        Some("X") => DiagnosticReportStatus::Cancelled(None),
+        _ => DiagnosticReportStatus::Unknown(None),
+    }
+}
+
+/// OBR - Observation request => DiagnosticReport.
+/// Following OBX segments are added as results of the report.
+pub struct OBRMapper {}
+
+impl SegmentMapper for OBRMapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        // OBR-3 filler order number is preferred over OBR-2 placer order number.
+        let filler = segment
+            .field(3)
+            .and_then(|f| f.first())
+            .and_then(|value| ei_to_identifier(config, value, &config.order_identifier_system));
+        let placer = segment
+            .field(2)
+            .and_then(|f| f.first())
+            .and_then(|value| ei_to_identifier(config, value, &config.order_identifier_system));
+        let primary_identifier = filler.clone().or_else(|| placer.clone());
+
+        let diagnostic_report = DiagnosticReport {
+            identifier_: boxed_vec([filler, placer].into_iter().flatten().collect()),
+            status: Box::new(report_status(segment.field_str(25))),
+            code: Box::new(
+                segment
+                    .field(4)
+                    .and_then(|f| f.first())
+                    .and_then(|value| cwe_to_codeable_concept(config, value))
+                    .unwrap_or_default(),
+            ),
+            subject: state.patient_reference().map(Box::new),
+            encounter: state.encounter_reference().map(Box::new),
+            // OBR-7 observation date/time.
+            effective: segment
+                .field_str(7)
+                .and_then(|date| dtm_to_datetime(config, date))
+                .map(|date| DiagnosticReportEffectiveTypeChoice::DateTime(Box::new(date))),
+            // OBR-22 results rpt/status change date/time.
+            issued: segment
+                .field_str(22)
+                .and_then(|date| dtm_to_instant(config, date))
+                .map(Box::new),
+            ..Default::default()
+        };
+
+        state.add_entry(
+            ResourceType::DiagnosticReport,
+            Resource::DiagnosticReport(diagnostic_report),
+            primary_identifier.as_ref(),
+        );
+
+        state.diagnostic_report = state.last_entry_index();
+        state.order_number = primary_identifier
+            .and_then(|i| i.value)
+            .and_then(|v| v.value);
+
+        Ok(())
+    }
+}
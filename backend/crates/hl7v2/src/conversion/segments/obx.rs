@@ -0,0 +1,196 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            boxed_vec, cwe_to_codeable_concept, dtm_to_datetime, fhir_code, fhir_string, fhir_uri,
+            identifier,
+        },
+    },
+    parser::{Field, Segment},
+};
+use haste_fhir_model::r4::generated::{
+    resources::{
+        Observation, ObservationEffectiveTypeChoice, ObservationReferenceRange,
+        ObservationValueTypeChoice, Resource, ResourceType,
+    },
+    terminology::ObservationStatus,
+    types::{CodeableConcept, Coding, FHIRDecimal, Quantity},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// HL7 Table 0085 - Observation result status.
+fn observation_status(status: Option<&str>) -> ObservationStatus {
+    match status {
+        Some("F") => ObservationStatus::Final(None),
+        Some("P") | Some("R") | Some("S") | Some("I") => ObservationStatus::Preliminary(None),
+        Some("C") => ObservationStatus::Corrected(None),
+        Some("X") => ObservationStatus::Cancelled(None),
+        Some("D") | Some("W") => ObservationStatus::EnteredInError(None),
+        _ => ObservationStatus::Unknown(None),
+    }
+}
+
+fn text_value(field: &Field) -> Option<String> {
+    let text = field
+        .repetitions()
+        .into_iter()
+        .filter_map(|value| {
+            let components = value
+                .components
+                .as_ref()
+                .map(|components| {
+                    components
+                        .iter()
+                        .filter_map(|c| c.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .or_else(|| value.as_str().map(|s| s.to_string()))?;
+            if components.is_empty() {
+                None
+            } else {
+                Some(components)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if text.is_empty() { None } else { Some(text) }
+}
+
+/// Converts OBX-5 observation value based on the OBX-2 value type.
+fn observation_value(
+    config: &ConversionConfig,
+    segment: &Segment,
+) -> Option<ObservationValueTypeChoice> {
+    let field = segment.field(5)?;
+    let value_type = segment.field_str(2);
+
+    match value_type {
+        Some("NM") => {
+            let number = field.as_str()?;
+            match number.trim().parse::<f64>() {
+                Ok(number) => {
+                    // OBX-6 units.
+                    let units = segment.field(6).and_then(|f| f.first());
+                    let unit_system = units.and_then(|u| u.component_str(3));
+                    Some(ObservationValueTypeChoice::Quantity(Box::new(Quantity {
+                        value: Some(Box::new(FHIRDecimal {
+                            value: Some(number),
+                            ..Default::default()
+                        })),
+                        unit: units
+                            .and_then(|u| u.component_str(2).or(u.component_str(1)))
+                            .map(fhir_string),
+                        system: unit_system
+                            .and_then(|s| config.code_systems.get(s))
+                            .map(|s| fhir_uri(s)),
+                        code: units.and_then(|u| u.component_str(1)).map(fhir_code),
+                        ..Default::default()
+                    })))
+                }
+                Err(_) => Some(ObservationValueTypeChoice::String(fhir_string(number))),
+            }
+        }
+        Some("CE") | Some("CWE") | Some("CNE") => field
+            .first()
+            .and_then(|value| cwe_to_codeable_concept(config, value))
+            .map(|concept| ObservationValueTypeChoice::CodeableConcept(Box::new(concept))),
+        Some("DT") | Some("TS") | Some("DTM") => field
+            .as_str()
+            .and_then(|date| dtm_to_datetime(config, date))
+            .map(|date| ObservationValueTypeChoice::DateTime(Box::new(date))),
+        _ => text_value(field).map(|text| ObservationValueTypeChoice::String(fhir_string(&text))),
+    }
+}
+
+/// OBX - Observation/result => Observation.
+/// When preceded by an OBR the observation is added to the DiagnosticReport results.
+pub struct OBXMapper {}
+
+impl SegmentMapper for OBXMapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        let code = segment.field(3).and_then(|f| f.first());
+
+        // Observations are identified by the order number, observation code and set id.
+        let identifier = match (
+            state.order_number.as_deref(),
+            code.and_then(|c| c.component_str(1)),
+            segment.field_str(1),
+        ) {
+            (Some(order_number), Some(code), Some(set_id)) => Some(identifier(
+                &config.observation_identifier_system,
+                &format!("{}-{}-{}", order_number, code, set_id),
+            )),
+            _ => None,
+        };
+
+        let interpretation = segment
+            .field(8)
+            .map(|f| f.repetitions())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|value| value.component_str(1))
+            .map(|code| CodeableConcept {
+                coding: Some(vec![Box::new(Coding {
+                    system: Some(fhir_uri(
+                        "http://terminology.hl7.org/CodeSystem/v3-ObservationInterpretation",
+                    )),
+                    code: Some(fhir_code(code)),
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let observation = Observation {
+            identifier_: identifier.clone().map(|i| vec![Box::new(i)]),
+            status: Box::new(observation_status(segment.field_str(11))),
+            code: Box::new(
+                code.and_then(|value| cwe_to_codeable_concept(config, value))
+                    .unwrap_or_default(),
+            ),
+            subject: state.patient_reference().map(Box::new),
+            encounter: state.encounter_reference().map(Box::new),
+            value: observation_value(config, segment),
+            // OBX-7 references range.
+            referenceRange: segment.field_str(7).map(|range| {
+                vec![ObservationReferenceRange {
+                    text: Some(fhir_string(range)),
+                    ..Default::default()
+                }]
+            }),
+            interpretation: boxed_vec(interpretation),
+            // OBX-14 date/time of the observation.
+            effective: segment
+                .field_str(14)
+                .and_then(|date| dtm_to_datetime(config, date))
+                .map(|date| ObservationEffectiveTypeChoice::DateTime(Box::new(date))),
+            ..Default::default()
+        };
+
+        let full_url = state.add_entry(
+            ResourceType::Observation,
+            Resource::Observation(observation),
+            identifier.as_ref(),
+        );
+
+        if let Some(index) = state.diagnostic_report
+            && let Some(entry) = state.entry_mut(index)
+            && let Some(resource) = entry.resource.as_mut()
+            && let Resource::DiagnosticReport(report) = resource.as_mut()
+        {
+            report
+                .result
+                .get_or_insert_with(Vec::new)
+                .push(Box::new(ConversionState::reference(&full_url)));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{boxed_vec, map_repetitions, xcn_to_reference},
+        segments::missing_patient,
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::resources::{Resource, ResourceType};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// PD1 - Patient additional demographic => Patient.generalPractitioner.
+pub struct PD1Mapper {}
+
+impl SegmentMapper for PD1Mapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        let patient = state
+            .patient
+            .clone()
+            .ok_or_else(|| missing_patient("PD1"))?;
+
+        // PD1-4 patient primary care provider.
+        let practitioners = map_repetitions(segment.field(4), |value| {
+            xcn_to_reference(config, value, ResourceType::Practitioner)
+        });
+
+        if let Some(entry) = state.entry_by_full_url_mut(&patient)
+            && let Some(resource) = entry.resource.as_mut()
+            && let Resource::Patient(patient) = resource.as_mut()
+            && !practitioners.is_empty()
+        {
+            patient.generalPractitioner = boxed_vec(practitioners);
+        }
+
+        Ok(())
+    }
+}
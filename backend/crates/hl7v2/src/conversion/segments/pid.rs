@@ -0,0 +1,89 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            administrative_gender, boxed_vec, cwe_to_codeable_concept, cx_to_identifier,
+            dtm_to_date, dtm_to_datetime, map_repetitions, xad_to_address, xpn_to_human_name,
+            xtn_to_contact_point,
+        },
+        segments::link_patient_to_appointment,
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Patient, PatientDeceasedTypeChoice, Resource, ResourceType},
+    types::FHIRBoolean,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// PID - Patient identification => Patient.
+pub struct PIDMapper {}
+
+impl SegmentMapper for PIDMapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        let identifiers = map_repetitions(segment.field(3), |value| {
+            cx_to_identifier(config, value, &config.patient_identifier_system)
+        });
+        let primary_identifier = identifiers.first().cloned();
+
+        let mut telecom = map_repetitions(segment.field(13), |value| {
+            xtn_to_contact_point(value, "home")
+        });
+        telecom.extend(map_repetitions(segment.field(14), |value| {
+            xtn_to_contact_point(value, "work")
+        }));
+
+        // PID-29 death date and time takes precedence over the PID-30 death indicator.
+        let deceased = match (segment.field_str(29), segment.field_str(30)) {
+            (Some(date), _) => dtm_to_datetime(config, date)
+                .map(|date| PatientDeceasedTypeChoice::DateTime(Box::new(date))),
+            (None, Some(indicator)) => {
+                Some(PatientDeceasedTypeChoice::Boolean(Box::new(FHIRBoolean {
+                    value: Some(indicator == "Y"),
+                    ..Default::default()
+                })))
+            }
+            (None, None) => None,
+        };
+
+        let patient = Patient {
+            identifier_: boxed_vec(identifiers),
+            name: boxed_vec(map_repetitions(segment.field(5), xpn_to_human_name)),
+            birthDate: segment
+                .field_str(7)
+                .and_then(|date| dtm_to_date(config, date))
+                .map(Box::new),
+            gender: segment
+                .field_str(8)
+                .and_then(administrative_gender)
+                .map(Box::new),
+            address: boxed_vec(map_repetitions(segment.field(11), xad_to_address)),
+            telecom: boxed_vec(telecom),
+            maritalStatus: segment
+                .field(16)
+                .and_then(|f| f.first())
+                .and_then(|value| cwe_to_codeable_concept(config, value))
+                .map(Box::new),
+            deceased,
+            ..Default::default()
+        };
+
+        let full_url = state.add_entry(
+            ResourceType::Patient,
+            Resource::Patient(patient),
+            primary_identifier.as_ref(),
+        );
+
+        state.patient = Some(full_url);
+        state.patient_identifier = primary_identifier;
+
+        link_patient_to_appointment(state);
+
+        Ok(())
+    }
+}
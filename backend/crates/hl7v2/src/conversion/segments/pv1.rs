@@ -0,0 +1,148 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{
+            cx_to_identifier, dtm_to_datetime, fhir_code, fhir_uri, identifier, xcn_to_reference,
+        },
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Encounter, EncounterParticipant, Resource, ResourceType},
+    terminology::EncounterStatus,
+    types::{CodeableConcept, Coding, Period},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// HL7 Table 0004 - Patient class to v3 ActCode.
+fn encounter_class(patient_class: Option<&str>) -> Coding {
+    let (system, code) = match patient_class {
+        Some("I") => ("http://terminology.hl7.org/CodeSystem/v3-ActCode", "IMP"),
+        Some("O") => ("http://terminology.hl7.org/CodeSystem/v3-ActCode", "AMB"),
+        Some("E") => ("http://terminology.hl7.org/CodeSystem/v3-ActCode", "EMER"),
+        Some("P") => ("http://terminology.hl7.org/CodeSystem/v3-ActCode", "PRENC"),
+        Some(other) => ("http://terminology.hl7.org/CodeSystem/v2-0004", other),
+        None => ("http://terminology.hl7.org/CodeSystem/v3-NullFlavor", "UNK"),
+    };
+
+    Coding {
+        system: Some(fhir_uri(system)),
+        code: Some(fhir_code(code)),
+        ..Default::default()
+    }
+}
+
+fn participant_type(code: &str) -> CodeableConcept {
+    CodeableConcept {
+        coding: Some(vec![Box::new(Coding {
+            system: Some(fhir_uri(
+                "http://terminology.hl7.org/CodeSystem/v3-ParticipationType",
+            )),
+            code: Some(fhir_code(code)),
+            ..Default::default()
+        })]),
+        ..Default::default()
+    }
+}
+
+/// PV1 - Patient visit => Encounter.
+pub struct PV1Mapper {}
+
+impl SegmentMapper for PV1Mapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        // PV1-19 visit number falling back to PV1-50 alternate visit id. When neither is sent the
+        // identifier is derived from the patient and PV1-44 admit time so repeated messages update
+        // the encounter. Encounters without any of these are created.
+        let identifier = [19, 50]
+            .into_iter()
+            .find_map(|position| {
+                segment
+                    .field(position)
+                    .and_then(|f| f.first())
+                    .and_then(|value| {
+                        cx_to_identifier(config, value, &config.encounter_identifier_system)
+                    })
+            })
+            .or_else(|| {
+                state
+                    .patient_identifier_value()
+                    .zip(segment.field_str(44))
+                    .map(|(patient_id, admit)| {
+                        identifier(
+                            &config.encounter_identifier_system,
+                            &format!("{}-{}", patient_id, admit),
+                        )
+                    })
+            });
+
+        // PV1-7 attending, PV1-8 referring and PV1-17 admitting doctor.
+        let participant = [(7, "ATND"), (8, "REF"), (17, "ADM")]
+            .into_iter()
+            .flat_map(|(position, type_)| {
+                segment
+                    .field(position)
+                    .map(|f| f.repetitions())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(move |value| {
+                        xcn_to_reference(config, value, ResourceType::Practitioner)
+                    })
+                    .map(move |individual| EncounterParticipant {
+                        type_: Some(vec![Box::new(participant_type(type_))]),
+                        individual: Some(Box::new(individual)),
+                        ..Default::default()
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        // PV1-44 admit and PV1-45 discharge date/time.
+        let start = segment
+            .field_str(44)
+            .and_then(|date| dtm_to_datetime(config, date));
+        let end = segment
+            .field_str(45)
+            .and_then(|date| dtm_to_datetime(config, date));
+
+        let status = if end.is_some() {
+            EncounterStatus::Finished(None)
+        } else {
+            EncounterStatus::InProgress(None)
+        };
+
+        let encounter = Encounter {
+            identifier_: identifier.clone().map(|i| vec![Box::new(i)]),
+            status: Box::new(status),
+            class: Box::new(encounter_class(segment.field_str(2))),
+            subject: state.patient_reference().map(Box::new),
+            participant: if participant.is_empty() {
+                None
+            } else {
+                Some(participant)
+            },
+            period: if start.is_some() || end.is_some() {
+                Some(Box::new(Period {
+                    start: start.map(Box::new),
+                    end: end.map(Box::new),
+                    ..Default::default()
+                }))
+            } else {
+                None
+            },
+            ..Default::default()
+        };
+
+        let full_url = state.add_entry(
+            ResourceType::Encounter,
+            Resource::Encounter(encounter),
+            identifier.as_ref(),
+        );
+        state.encounter = Some(full_url);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    conversion::{
+        ConversionConfig, ConversionState, SegmentMapper,
+        datatypes::{boxed_vec, cwe_to_codeable_concept, dtm_to_instant, ei_to_identifier},
+        segments::link_patient_to_appointment,
+    },
+    parser::Segment,
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Appointment, Resource, ResourceType},
+    terminology::Appointmentstatus,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// HL7 Table 0278 - Filler status codes.
+fn appointment_status(status: Option<&str>) -> Appointmentstatus {
+    match status {
+        Some("Pending") | Some("Waitlist") => Appointmentstatus::Pending(None),
+        Some("Cancelled") | Some("Deleted") => Appointmentstatus::Cancelled(None),
+        Some("Complete") => Appointmentstatus::Fulfilled(None),
+        Some("Noshow") => Appointmentstatus::Noshow(None),
+        Some("Started") => Appointmentstatus::Arrived(None),
+        _ => Appointmentstatus::Booked(None),
+    }
+}
+
+/// SCH - Scheduling activity information => Appointment.
+pub struct SCHMapper {}
+
+impl SegmentMapper for SCHMapper {
+    fn map(
+        &self,
+        config: &ConversionConfig,
+        state: &mut ConversionState,
+        segment: &Segment,
+    ) -> Result<(), OperationOutcomeError> {
+        // SCH-2 filler appointment id is preferred over SCH-1 placer appointment id.
+        let filler = segment.field(2).and_then(|f| f.first()).and_then(|value| {
+            ei_to_identifier(config, value, &config.appointment_identifier_system)
+        });
+        let placer = segment.field(1).and_then(|f| f.first()).and_then(|value| {
+            ei_to_identifier(config, value, &config.appointment_identifier_system)
+        });
+        let primary_identifier = filler.clone().or_else(|| placer.clone());
+
+        // SCH-11 appointment timing quantity with start (TQ.4) and end (TQ.5).
+        let timing = segment.field(11).and_then(|f| f.first());
+
+        let appointment = Appointment {
+            identifier_: boxed_vec([filler, placer].into_iter().flatten().collect()),
+            status: Box::new(appointment_status(segment.field_str(25))),
+            // SCH-7 appointment reason.
+            reasonCode: segment
+                .field(7)
+                .and_then(|f| f.first())
+                .and_then(|value| cwe_to_codeable_concept(config, value))
+                .map(|reason| vec![Box::new(reason)]),
+            // SCH-8 appointment type.
+            appointmentType: segment
+                .field(8)
+                .and_then(|f| f.first())
+                .and_then(|value| cwe_to_codeable_concept(config, value))
+                .map(Box::new),
+            start: timing
+                .and_then(|t| t.component_str(4))
+                .and_then(|date| dtm_to_instant(config, date))
+                .map(Box::new),
+            end: timing
+                .and_then(|t| t.component_str(5))
+                .and_then(|date| dtm_to_instant(config, date))
+                .map(Box::new),
+            participant: vec![],
+            ..Default::default()
+        };
+
+        state.add_entry(
+            ResourceType::Appointment,
+            Resource::Appointment(appointment),
+            primary_identifier.as_ref(),
+        );
+        state.appointment = state.last_entry_index();
+
+        link_patient_to_appointment(state);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::parser::{
+    Component, Delimiters, Field, FieldValue, Hl7V2Message, MessageHeader, Segment,
+};
+
+/// Segment terminator used when encoding messages.
+pub static SEGMENT_TERMINATOR: char = '\r';
+
+/// Escapes delimiter characters and line breaks within a value using the message delimiters.
+/// IE with the default encoding characters "a|b" => "a\F\b".
+/// Without an escape character declared the value is written as is.
+pub fn escape(delimiters: &Delimiters, value: &str) -> String {
+    let Some(escape_character) = delimiters.escape else {
+        return value.to_string();
+    };
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        let sequence = if c == delimiters.field {
+            Some("F")
+        } else if c == delimiters.component {
+            Some("S")
+        } else if Some(c) == delimiters.subcomponent {
+            Some("T")
+        } else if c == delimiters.repetition {
+            Some("R")
+        } else if c == escape_character {
+            Some("E")
+        } else if Some(c) == delimiters.truncation {
+            Some("P")
+        } else if c == '\n' {
+            Some(".br")
+        } else if c == '\r' {
+            Some("X0D")
+        } else {
+            None
+        };
+
+        match sequence {
+            Some(sequence) => {
+                escaped.push(escape_character);
+                escaped.push_str(sequence);
+                escaped.push(escape_character);
+            }
+            None => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn encode_component(delimiters: &Delimiters, component: &Component) -> String {
+    match (&component.value, &component.subcomponents) {
+        (_, Some(subcomponents)) => subcomponents
+            .iter()
+            .map(|s| escape(delimiters, s))
+            .collect::<Vec<_>>()
+            .join(&delimiters.subcomponent.unwrap_or('&').to_string()),
+        (Some(value), None) => escape(delimiters, value),
+        (None, None) => String::new(),
+    }
+}
+
+fn encode_field_value(delimiters: &Delimiters, value: &FieldValue) -> String {
+    match (&value.value, &value.components) {
+        (_, Some(components)) => components
+            .iter()
+            .map(|c| encode_component(delimiters, c))
+            .collect::<Vec<_>>()
+            .join(&delimiters.component.to_string()),
+        (Some(component), None) => encode_component(delimiters, component),
+        (None, None) => String::new(),
+    }
+}
+
+fn encode_field(delimiters: &Delimiters, field: &Field) -> String {
+    field
+        .repetitions()
+        .into_iter()
+        .map(|value| encode_field_value(delimiters, value))
+        .collect::<Vec<_>>()
+        .join(&delimiters.repetition.to_string())
+}
+
+/// Header fields are stored unparsed so they are written as is.
+fn encode_raw_field(field: &Field) -> String {
+    field
+        .first()
+        .and_then(|value| value.value.as_ref())
+        .and_then(|component| component.value.clone())
+        .unwrap_or_default()
+}
+
+fn encode_header(header: &MessageHeader) -> String {
+    let fields = [
+        header.encoding_characters(),
+        header.sending_application().unwrap_or_default(),
+        header.sending_facility().unwrap_or_default(),
+        header.receiving_application().unwrap_or_default(),
+        header.receiving_facility().unwrap_or_default(),
+        header.datetime_of_message().unwrap_or_default(),
+        header.security().unwrap_or_default(),
+        header.message_type().unwrap_or_default(),
+        header.message_control_id().unwrap_or_default(),
+        header.processing_id().unwrap_or_default(),
+        header.version_id().unwrap_or_default(),
+    ]
+    .into_iter()
+    .map(|f| f.to_string())
+    .chain(header.additional_fields().iter().map(encode_raw_field))
+    .collect::<Vec<_>>();
+
+    format!(
+        "MSH{}{}",
+        header.field_separator(),
+        fields.join(&header.field_separator().to_string())
+    )
+}
+
+fn encode_segment(delimiters: &Delimiters, segment: &Segment) -> String {
+    let mut encoded = segment.id.clone();
+    for field in segment.fields.iter() {
+        encoded.push(delimiters.field);
+        encoded.push_str(&encode_field(delimiters, field));
+    }
+    encoded
+}
+
+/// Serializes the message to pipe delimited text with each segment terminated by a carriage return.
+pub fn encode(message: &Hl7V2Message) -> String {
+    let delimiters = message.header.delimiters();
+    let mut encoded = encode_header(&message.header);
+    encoded.push(SEGMENT_TERMINATOR);
+
+    for segment in message.segments.iter() {
+        encoded.push_str(&encode_segment(&delimiters, segment));
+        encoded.push(SEGMENT_TERMINATOR);
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_round_trip() {
+        let input = std::fs::read_to_string("./test_data/message1.bin").unwrap();
+        let message = Hl7V2Message::try_from(input.as_str()).unwrap();
+
+        let encoded = encode(&message);
+        assert_eq!(
+            encoded,
+            input
+                .lines()
+                .map(|line| format!("{}\r", line))
+                .collect::<String>()
+        );
+        assert_eq!(Hl7V2Message::try_from(encoded.as_str()).unwrap(), message);
+    }
+
+    #[test]
+    fn test_escape() {
+        let message = Hl7V2Message::try_from("MSH|^~\\&|APP").unwrap();
+        assert_eq!(
+            escape(&message.header.delimiters(), "a|b^c&d~e\\f\ng"),
+            "a\\F\\b\\S\\c\\T\\d\\R\\e\\E\\f\\.br\\g"
+        );
+    }
+}
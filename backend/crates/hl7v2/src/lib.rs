@@ -1 +1,6 @@
+pub mod ack;
+pub mod conversion;
+pub mod encoder;
+pub mod mllp;
 pub mod parser;
+pub mod terser;
@@ -0,0 +1,114 @@
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// Minimal Lower Layer Protocol block characters.
+/// Frames are <VT> message <FS><CR>.
+pub const START_BLOCK: u8 = 0x0B;
+pub const END_BLOCK: u8 = 0x1C;
+pub const CARRIAGE_RETURN: u8 = 0x0D;
+
+/// Default limit on the size of a single frame to avoid unbounded buffering.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 10 * 1024 * 1024;
+
+/// Wraps a message in an MLLP frame.
+pub fn frame(message: &str) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(message.len() + 3);
+    framed.push(START_BLOCK);
+    framed.extend_from_slice(message.as_bytes());
+    framed.push(END_BLOCK);
+    framed.push(CARRIAGE_RETURN);
+    framed
+}
+
+/// Incrementally deframes MLLP messages from a byte stream.
+/// Bytes received outside of a frame are discarded.
+pub struct MllpDecoder {
+    buffer: Vec<u8>,
+    max_frame_size: usize,
+}
+
+impl MllpDecoder {
+    pub fn new(max_frame_size: usize) -> Self {
+        MllpDecoder {
+            buffer: vec![],
+            max_frame_size,
+        }
+    }
+
+    /// Append bytes read from the stream.
+    pub fn push(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Returns the next complete message if one is buffered.
+    pub fn next_message(&mut self) -> Result<Option<String>, OperationOutcomeError> {
+        let Some(start) = self.buffer.iter().position(|b| *b == START_BLOCK) else {
+            self.buffer.clear();
+            return Ok(None);
+        };
+        self.buffer.drain(..start);
+
+        let end = self
+            .buffer
+            .windows(2)
+            .position(|w| w[0] == END_BLOCK && w[1] == CARRIAGE_RETURN);
+
+        let Some(end) = end else {
+            if self.buffer.len() > self.max_frame_size {
+                self.buffer.clear();
+                return Err(OperationOutcomeError::error(
+                    IssueType::TooLong(None),
+                    format!(
+                        "MLLP frame exceeds the maximum size of {} bytes.",
+                        self.max_frame_size
+                    ),
+                ));
+            }
+            return Ok(None);
+        };
+
+        let frame = self.buffer.drain(..end + 2).collect::<Vec<_>>();
+        let message = String::from_utf8(frame[1..end].to_vec()).map_err(|e| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                format!("MLLP frame is not valid UTF-8: {}", e),
+            )
+        })?;
+
+        Ok(Some(message))
+    }
+}
+
+impl Default for MllpDecoder {
+    fn default() -> Self {
+        MllpDecoder::new(DEFAULT_MAX_FRAME_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deframe_partial_and_multiple_messages() {
+        let mut decoder = MllpDecoder::default();
+        let mut stream = b"noise".to_vec();
+        stream.extend(frame("MSH|^~\\&|A\rPID|1"));
+        stream.extend(frame("MSH|^~\\&|B"));
+
+        let (first, second) = stream.split_at(10);
+        decoder.push(first);
+        assert_eq!(decoder.next_message().unwrap(), None);
+
+        decoder.push(second);
+        assert_eq!(
+            decoder.next_message().unwrap(),
+            Some("MSH|^~\\&|A\rPID|1".to_string())
+        );
+        assert_eq!(
+            decoder.next_message().unwrap(),
+            Some("MSH|^~\\&|B".to_string())
+        );
+        assert_eq!(decoder.next_message().unwrap(), None);
+    }
+}
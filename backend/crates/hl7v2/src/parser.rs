@@ -27,21 +27,21 @@ pub struct Segment {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct MessageHeader {
-    field_separator: char,
+    pub(crate) field_separator: char,
     /// component separator, repetition separator, escape character, and subcomponent separator
-    encoding_characters: String,
-    sending_application: Option<String>,
-    sending_facility: Option<String>,
-    receiving_application: Option<String>,
-    receiving_facility: Option<String>,
-    datetime_of_message: Option<String>,
-    security: Option<String>,
-    message_type: Option<String>,
-    message_control_id: Option<String>,
-    processing_id: Option<String>,
-    version_id: Option<String>,
+    pub(crate) encoding_characters: String,
+    pub(crate) sending_application: Option<String>,
+    pub(crate) sending_facility: Option<String>,
+    pub(crate) receiving_application: Option<String>,
+    pub(crate) receiving_facility: Option<String>,
+    pub(crate) datetime_of_message: Option<String>,
+    pub(crate) security: Option<String>,
+    pub(crate) message_type: Option<String>,
+    pub(crate) message_control_id: Option<String>,
+    pub(crate) processing_id: Option<String>,
+    pub(crate) version_id: Option<String>,
 
-    additional_fields: Vec<Field>,
+    pub(crate) additional_fields: Vec<Field>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -50,6 +50,451 @@ pub struct Hl7V2Message {
     pub segments: Vec<Segment>,
 }
 
+fn non_empty(value: &str) -> Option<&str> {
+    if value.is_empty() { None } else { Some(value) }
+}
+
+impl Component {
+    /// Returns the value of the component. If the component has subcomponents returns the first subcomponent.
+    pub fn as_str(&self) -> Option<&str> {
+        match (&self.value, &self.subcomponents) {
+            (Some(value), _) => non_empty(value),
+            (None, Some(subcomponents)) => subcomponents.first().and_then(|s| non_empty(s)),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the subcomponent at the given 1-based position.
+    pub fn subcomponent(&self, position: usize) -> Option<&str> {
+        match (&self.value, &self.subcomponents) {
+            (_, Some(subcomponents)) => subcomponents
+                .get(position.checked_sub(1)?)
+                .and_then(|s| non_empty(s)),
+            (Some(value), None) if position == 1 => non_empty(value),
+            _ => None,
+        }
+    }
+}
+
+impl FieldValue {
+    /// Returns the component at the given 1-based position IE XPN.1 => component(1).
+    /// A field value without components is treated as having a single component.
+    pub fn component(&self, position: usize) -> Option<&Component> {
+        match (&self.value, &self.components) {
+            (_, Some(components)) => components.get(position.checked_sub(1)?),
+            (Some(value), None) if position == 1 => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the string value of the component at the given 1-based position.
+    pub fn component_str(&self, position: usize) -> Option<&str> {
+        self.component(position).and_then(|c| c.as_str())
+    }
+
+    /// Returns the first component as a string.
+    pub fn as_str(&self) -> Option<&str> {
+        self.component_str(1)
+    }
+}
+
+impl Field {
+    /// Returns all repetitions of the field. A non repeating field returns a single value.
+    pub fn repetitions(&self) -> Vec<&FieldValue> {
+        match (&self.value, &self.repetitions) {
+            (_, Some(repetitions)) => repetitions.iter().collect(),
+            (Some(value), None) => vec![value],
+            (None, None) => vec![],
+        }
+    }
+
+    /// Returns the first repetition of the field.
+    pub fn first(&self) -> Option<&FieldValue> {
+        self.repetitions().into_iter().next()
+    }
+
+    /// Returns the first component of the first repetition as a string.
+    pub fn as_str(&self) -> Option<&str> {
+        self.first().and_then(|v| v.as_str())
+    }
+}
+
+impl Segment {
+    /// Returns the field at the given HL7 position IE PID-3 => field(3).
+    /// Position is 1-based and does not include the segment id.
+    pub fn field(&self, position: usize) -> Option<&Field> {
+        self.fields.get(position.checked_sub(1)?)
+    }
+
+    /// Returns the first component of the field at the given HL7 position as a string.
+    pub fn field_str(&self, position: usize) -> Option<&str> {
+        self.field(position).and_then(|f| f.as_str())
+    }
+}
+
+impl MessageHeader {
+    pub fn field_separator(&self) -> char {
+        self.field_separator
+    }
+    pub fn encoding_characters(&self) -> &str {
+        &self.encoding_characters
+    }
+    /// Delimiters declared in MSH-1 and MSH-2.
+    pub fn delimiters(&self) -> Delimiters {
+        Delimiters::new(self.field_separator, &self.encoding_characters)
+    }
+
+    pub fn sending_application(&self) -> Option<&str> {
+        self.sending_application.as_deref()
+    }
+    pub fn sending_facility(&self) -> Option<&str> {
+        self.sending_facility.as_deref()
+    }
+    pub fn receiving_application(&self) -> Option<&str> {
+        self.receiving_application.as_deref()
+    }
+    pub fn receiving_facility(&self) -> Option<&str> {
+        self.receiving_facility.as_deref()
+    }
+    pub fn datetime_of_message(&self) -> Option<&str> {
+        self.datetime_of_message.as_deref()
+    }
+    pub fn security(&self) -> Option<&str> {
+        self.security.as_deref()
+    }
+    /// Raw message type IE ADT^A01.
+    pub fn message_type(&self) -> Option<&str> {
+        self.message_type.as_deref()
+    }
+    pub fn message_control_id(&self) -> Option<&str> {
+        self.message_control_id.as_deref()
+    }
+    pub fn processing_id(&self) -> Option<&str> {
+        self.processing_id.as_deref()
+    }
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+    pub fn additional_fields(&self) -> &Vec<Field> {
+        &self.additional_fields
+    }
+
+    fn raw_field(&self, position: usize) -> Option<&str> {
+        match position {
+            3 => self.sending_application.as_deref(),
+            4 => self.sending_facility.as_deref(),
+            5 => self.receiving_application.as_deref(),
+            6 => self.receiving_facility.as_deref(),
+            7 => self.datetime_of_message.as_deref(),
+            8 => self.security.as_deref(),
+            9 => self.message_type.as_deref(),
+            10 => self.message_control_id.as_deref(),
+            11 => self.processing_id.as_deref(),
+            12 => self.version_id.as_deref(),
+            _ => self
+                .additional_fields
+                .get(position.checked_sub(13)?)
+                .and_then(|field| field.first())
+                .and_then(|value| value.value.as_ref())
+                .and_then(|component| component.value.as_deref()),
+        }
+    }
+
+    /// Returns the parsed field at the given HL7 position IE MSH-9 => field(9).
+    /// MSH-1 and MSH-2 are returned as literals.
+    pub fn field(&self, position: usize) -> Option<Field> {
+        match position {
+            0 => None,
+            1 => Some(literal_field(&self.field_separator.to_string())),
+            2 => Some(literal_field(&self.encoding_characters)),
+            _ => self
+                .raw_field(position)
+                .map(|field| parse_field(&self.delimiters(), field)),
+        }
+    }
+}
+
+impl Hl7V2Message {
+    /// Returns all segments with the given id IE OBX.
+    pub fn segments_by_id<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a Segment> + 'a {
+        self.segments.iter().filter(move |segment| segment.id == id)
+    }
+}
+
+/// Delimiters declared by a header segment (MSH, BHS or FHS).
+/// The escape, subcomponent and truncation characters are optional and are only
+/// applied when declared in the encoding characters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Delimiters {
+    pub field: char,
+    pub component: char,
+    pub repetition: char,
+    pub escape: Option<char>,
+    pub subcomponent: Option<char>,
+    /// Truncation character introduced in v2.7 IE #.
+    pub truncation: Option<char>,
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Delimiters {
+            field: '|',
+            component: '^',
+            repetition: '~',
+            escape: Some('\\'),
+            subcomponent: Some('&'),
+            truncation: None,
+        }
+    }
+}
+
+impl Delimiters {
+    pub fn new(field: char, encoding_characters: &str) -> Self {
+        let mut characters = encoding_characters.chars();
+        Delimiters {
+            field,
+            component: characters.next().unwrap_or('^'),
+            repetition: characters.next().unwrap_or('~'),
+            escape: characters.next(),
+            subcomponent: characters.next(),
+            truncation: characters.next(),
+        }
+    }
+
+    /// Reads the delimiters from a header segment line IE MSH|^~\&|...
+    /// Returns the delimiters, the raw encoding characters and the remainder of the line after them.
+    fn from_header_line<'a>(
+        segment_id: &str,
+        line: &'a str,
+    ) -> Result<(Self, &'a str, Option<&'a str>), OperationOutcomeError> {
+        let field_separator = line
+            .get(3..)
+            .and_then(|s| s.chars().next())
+            .ok_or_else(|| {
+                OperationOutcomeError::error(
+                    IssueType::Exception(None),
+                    format!("Missing field separator in {} segment", segment_id),
+                )
+            })?;
+
+        let rest = &line[3 + field_separator.len_utf8()..];
+        let (encoding_characters, rest) = match rest.split_once(field_separator) {
+            Some((encoding_characters, rest)) => (encoding_characters, Some(rest)),
+            None => (rest, None),
+        };
+
+        if encoding_characters.is_empty() {
+            return Err(OperationOutcomeError::error(
+                IssueType::Exception(None),
+                "Missing encoding characters".to_string(),
+            ));
+        }
+
+        Ok((
+            Delimiters::new(field_separator, encoding_characters),
+            encoding_characters,
+            rest,
+        ))
+    }
+}
+
+/// Decodes a single escape sequence (without the surrounding escape characters).
+/// Returns None for sequences that are not recognized so they are kept as is.
+fn decode_escape_sequence(delimiters: &Delimiters, sequence: &str) -> Option<String> {
+    match sequence {
+        "F" => Some(delimiters.field.to_string()),
+        "S" => Some(delimiters.component.to_string()),
+        "R" => Some(delimiters.repetition.to_string()),
+        "T" => delimiters.subcomponent.map(|c| c.to_string()),
+        "E" => delimiters.escape.map(|c| c.to_string()),
+        "P" => delimiters.truncation.map(|c| c.to_string()),
+        ".br" => Some("\n".to_string()),
+        // Highlighting has no plain text equivalent.
+        "H" | "N" => Some(String::new()),
+        _ if sequence.starts_with('X') => {
+            let hex = &sequence[1..];
+            if hex.len() % 2 != 0 {
+                return None;
+            }
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()?;
+            Some(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        _ if sequence.starts_with(".sp") => {
+            let lines = sequence[3..].trim().parse::<usize>().unwrap_or(1).max(1);
+            Some("\n".repeat(lines))
+        }
+        // Remaining formatting commands (.fi, .nf, .in, .ti, .sk, .ce) and
+        // character set (C, M) or locally defined (Z) escapes are dropped.
+        _ if sequence.starts_with('.')
+            || sequence.starts_with('C')
+            || sequence.starts_with('M')
+            || sequence.starts_with('Z') =>
+        {
+            Some(String::new())
+        }
+        _ => None,
+    }
+}
+
+/// Decodes the escape sequences in a value IE "a\F\b" => "a|b".
+pub fn unescape(delimiters: &Delimiters, value: &str) -> String {
+    let Some(escape) = delimiters.escape else {
+        return value.to_string();
+    };
+    if !value.contains(escape) {
+        return value.to_string();
+    }
+
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find(escape) {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + escape.len_utf8()..];
+
+        match after.find(escape) {
+            Some(end) => {
+                let sequence = &after[..end];
+                match decode_escape_sequence(delimiters, sequence) {
+                    Some(decoded) => result.push_str(&decoded),
+                    None => {
+                        result.push(escape);
+                        result.push_str(sequence);
+                        result.push(escape);
+                    }
+                }
+                rest = &after[end + escape.len_utf8()..];
+            }
+            // Unterminated escape sequences are kept as literal text.
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+fn parse_component(delimiters: &Delimiters, component: &str) -> Component {
+    let subcomponents = match delimiters.subcomponent {
+        Some(separator) => component.split(separator).collect::<Vec<_>>(),
+        None => vec![component],
+    };
+
+    if subcomponents.len() > 1 {
+        Component {
+            value: None,
+            subcomponents: Some(
+                subcomponents
+                    .iter()
+                    .map(|s| unescape(delimiters, s))
+                    .collect(),
+            ),
+        }
+    } else {
+        Component {
+            value: Some(unescape(delimiters, component)),
+            subcomponents: None,
+        }
+    }
+}
+
+fn parse_field_value(delimiters: &Delimiters, value: &str) -> FieldValue {
+    let mut components = value
+        .split(delimiters.component)
+        .map(|component| parse_component(delimiters, component))
+        .collect::<Vec<_>>();
+
+    if components.len() > 1 {
+        FieldValue {
+            value: None,
+            components: Some(components),
+        }
+    } else {
+        FieldValue {
+            value: components.pop(),
+            components: None,
+        }
+    }
+}
+
+/// Parses a raw field splitting repetitions, components and subcomponents and decoding escape sequences.
+pub fn parse_field(delimiters: &Delimiters, field: &str) -> Field {
+    let mut repetitions = field
+        .split(delimiters.repetition)
+        .map(|value| parse_field_value(delimiters, value))
+        .collect::<Vec<_>>();
+
+    if repetitions.len() > 1 {
+        Field {
+            value: None,
+            repetitions: Some(repetitions),
+        }
+    } else {
+        Field {
+            value: repetitions.pop(),
+            repetitions: None,
+        }
+    }
+}
+
+/// Field holding a literal value that is not split or unescaped IE MSH-1 and MSH-2.
+fn literal_field(value: &str) -> Field {
+    Field {
+        value: Some(FieldValue {
+            value: Some(Component {
+                value: Some(value.to_string()),
+                subcomponents: None,
+            }),
+            components: None,
+        }),
+        repetitions: None,
+    }
+}
+
+fn parse_segment(delimiters: &Delimiters, line: &str) -> Result<Segment, OperationOutcomeError> {
+    let mut fields = line.split(delimiters.field);
+    let segment_id = fields.next().filter(|id| !id.is_empty()).ok_or_else(|| {
+        OperationOutcomeError::error(IssueType::Exception(None), "Missing segment ID".to_string())
+    })?;
+
+    Ok(Segment {
+        id: segment_id.to_string(),
+        fields: fields.map(|field| parse_field(delimiters, field)).collect(),
+    })
+}
+
+/// Parses a batch or file header segment (BHS, FHS) which declares its own delimiters like MSH.
+/// Fields are numbered as for MSH so field(1) is the field separator and field(2) the encoding characters.
+fn parse_header_segment(segment_id: &str, line: &str) -> Result<Segment, OperationOutcomeError> {
+    let (delimiters, encoding_characters, rest) = Delimiters::from_header_line(segment_id, line)?;
+    let mut fields = vec![
+        literal_field(&delimiters.field.to_string()),
+        literal_field(encoding_characters),
+    ];
+    if let Some(rest) = rest {
+        fields.extend(
+            rest.split(delimiters.field)
+                .map(|field| parse_field(&delimiters, field)),
+        );
+    }
+
+    Ok(Segment {
+        id: segment_id.to_string(),
+        fields,
+    })
+}
+
+fn segment_lines(value: &str) -> impl Iterator<Item = &str> {
+    // Segments are terminated by carriage returns (as over MLLP) but files often use newlines.
+    value.split(['\r', '\n']).filter(|line| !line.is_empty())
+}
+
 impl TryFrom<&str> for MessageHeader {
     type Error = OperationOutcomeError;
 
@@ -61,26 +506,16 @@ impl TryFrom<&str> for MessageHeader {
                 "Not an MSH segment".to_string(),
             ));
         }
-        let field_separator = value.chars().nth(3).ok_or_else(|| {
-            OperationOutcomeError::error(
-                IssueType::Exception(None),
-                "Missing field separator".to_string(),
-            )
-        })?;
 
-        let mut fields = value[4..].split(field_separator);
+        let (delimiters, encoding_characters, rest) = Delimiters::from_header_line("MSH", value)?;
+        let mut fields = rest
+            .map(|rest| rest.split(delimiters.field).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter();
 
         Ok(MessageHeader {
-            field_separator: field_separator,
-            encoding_characters: fields
-                .next()
-                .ok_or_else(|| {
-                    OperationOutcomeError::error(
-                        IssueType::Exception(None),
-                        "Missing encoding characters".to_string(),
-                    )
-                })?
-                .to_string(),
+            field_separator: delimiters.field,
+            encoding_characters: encoding_characters.to_string(),
             sending_application: fields.next().map(|s| s.to_string()),
             sending_facility: fields.next().map(|s| s.to_string()),
             receiving_application: fields.next().map(|s| s.to_string()),
@@ -91,18 +526,26 @@ impl TryFrom<&str> for MessageHeader {
             message_control_id: fields.next().map(|s| s.to_string()),
             processing_id: fields.next().map(|s| s.to_string()),
             version_id: fields.next().map(|s| s.to_string()),
-            additional_fields: fields
-                .map(|f| Field {
-                    value: Some(FieldValue {
-                        value: Some(Component {
-                            value: Some(f.to_string()),
-                            subcomponents: None,
-                        }),
-                        components: None,
-                    }),
-                    repetitions: None,
-                })
-                .collect(),
+            additional_fields: fields.map(literal_field).collect(),
+        })
+    }
+}
+
+impl Hl7V2Message {
+    fn from_lines<'a>(
+        header: &'a str,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let message_header = MessageHeader::try_from(header)?;
+        let delimiters = message_header.delimiters();
+
+        let segments = lines
+            .map(|line| parse_segment(&delimiters, line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Hl7V2Message {
+            header: message_header,
+            segments,
         })
     }
 }
@@ -111,104 +554,134 @@ impl TryFrom<&str> for Hl7V2Message {
     type Error = OperationOutcomeError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut segments = vec![];
-        let mut segment_lines = value.lines();
-
-        let message_header = MessageHeader::try_from(segment_lines.next().ok_or_else(|| {
+        let mut lines = segment_lines(value);
+        let header = lines.next().ok_or_else(|| {
             OperationOutcomeError::error(
                 IssueType::Invalid(None),
                 "Missing MSH segment".to_string(),
             )
-        })?)?;
+        })?;
 
-        for segment in segment_lines {
-            let mut segment = segment.split(message_header.field_separator);
-            let segment_id = segment.next().ok_or_else(|| {
-                OperationOutcomeError::error(
-                    IssueType::Exception(None),
-                    "Missing segment ID".to_string(),
-                )
-            })?;
+        if header.starts_with("FHS") || header.starts_with("BHS") {
+            return Err(OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                "Input is a batch, parse it as an Hl7V2File instead".to_string(),
+            ));
+        }
+
+        Hl7V2Message::from_lines(header, lines)
+    }
+}
+
+/// A batch of messages wrapped in BHS/BTS segments.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hl7V2Batch {
+    pub header: Option<Segment>,
+    pub messages: Vec<Hl7V2Message>,
+    pub trailer: Option<Segment>,
+}
 
-            let segment_fields = segment.map(|field| {
-                let fields = field
-                    .split(
-                        message_header
-                            .encoding_characters
-                            .chars()
-                            .nth(1)
-                            .unwrap_or('~'),
-                    )
-                    .map(|field| {
-                        let components = field
-                            .split(
-                                message_header
-                                    .encoding_characters
-                                    .chars()
-                                    .nth(0)
-                                    .unwrap_or('^'),
-                            )
-                            .map(|component| {
-                                let subcomponent = field
-                                    .split(
-                                        message_header
-                                            .encoding_characters
-                                            .chars()
-                                            .nth(3)
-                                            .unwrap_or('&'),
-                                    )
-                                    .collect::<Vec<_>>();
-                                if subcomponent.len() > 1 {
-                                    Component {
-                                        value: None,
-                                        subcomponents: Some(
-                                            subcomponent.iter().map(|s| s.to_string()).collect(),
-                                        ),
-                                    }
-                                } else {
-                                    Component {
-                                        value: Some(component.to_string()),
-                                        subcomponents: None,
-                                    }
-                                }
-                            })
-                            .collect::<Vec<_>>();
-                        if components.len() > 1 {
-                            FieldValue {
-                                value: None,
-                                components: Some(components),
-                            }
-                        } else {
-                            FieldValue {
-                                value: Some(components.into_iter().next().unwrap()),
-                                components: None,
-                            }
+/// A file of batches wrapped in FHS/FTS segments.
+/// Files without FHS/BHS wrappers (IE a stream of messages) are read as a single batch.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hl7V2File {
+    pub header: Option<Segment>,
+    pub batches: Vec<Hl7V2Batch>,
+    pub trailer: Option<Segment>,
+}
+
+impl Hl7V2File {
+    /// All messages across batches in order.
+    pub fn messages(&self) -> impl Iterator<Item = &Hl7V2Message> {
+        self.batches.iter().flat_map(|batch| batch.messages.iter())
+    }
+}
+
+impl TryFrom<&str> for Hl7V2File {
+    type Error = OperationOutcomeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut file = Hl7V2File {
+            header: None,
+            batches: vec![],
+            trailer: None,
+        };
+        // Lines of the message currently being read, starting with its MSH.
+        let mut message_lines: Vec<&str> = vec![];
+        // Delimiters for trailer segments are taken from the closest header.
+        let mut delimiters = Delimiters::default();
+
+        fn flush_message(
+            file: &mut Hl7V2File,
+            message_lines: &mut Vec<&str>,
+        ) -> Result<(), OperationOutcomeError> {
+            if let Some((header, rest)) = message_lines.split_first() {
+                let message = Hl7V2Message::from_lines(header, rest.iter().copied())?;
+                match file.batches.last_mut() {
+                    Some(batch) if batch.trailer.is_none() => batch.messages.push(message),
+                    _ => file.batches.push(Hl7V2Batch {
+                        header: None,
+                        messages: vec![message],
+                        trailer: None,
+                    }),
+                }
+            }
+            message_lines.clear();
+            Ok(())
+        }
+
+        for line in segment_lines(value) {
+            match line.get(..3) {
+                Some("FHS") => {
+                    flush_message(&mut file, &mut message_lines)?;
+                    delimiters = Delimiters::from_header_line("FHS", line)?.0;
+                    file.header = Some(parse_header_segment("FHS", line)?);
+                }
+                Some("BHS") => {
+                    flush_message(&mut file, &mut message_lines)?;
+                    delimiters = Delimiters::from_header_line("BHS", line)?.0;
+                    file.batches.push(Hl7V2Batch {
+                        header: Some(parse_header_segment("BHS", line)?),
+                        messages: vec![],
+                        trailer: None,
+                    });
+                }
+                Some("BTS") => {
+                    flush_message(&mut file, &mut message_lines)?;
+                    let trailer = parse_segment(&delimiters, line)?;
+                    match file.batches.last_mut() {
+                        Some(batch) if batch.trailer.is_none() => batch.trailer = Some(trailer),
+                        _ => {
+                            return Err(OperationOutcomeError::error(
+                                IssueType::Invalid(None),
+                                "BTS segment without a matching batch".to_string(),
+                            ));
                         }
-                    })
-                    .collect::<Vec<_>>();
-                if fields.len() > 1 {
-                    Field {
-                        value: None,
-                        repetitions: Some(fields),
                     }
-                } else {
-                    Field {
-                        value: Some(fields.into_iter().next().unwrap()),
-                        repetitions: None,
+                }
+                Some("FTS") => {
+                    flush_message(&mut file, &mut message_lines)?;
+                    file.trailer = Some(parse_segment(&delimiters, line)?);
+                }
+                Some("MSH") => {
+                    flush_message(&mut file, &mut message_lines)?;
+                    message_lines.push(line);
+                }
+                _ => {
+                    if message_lines.is_empty() {
+                        return Err(OperationOutcomeError::error(
+                            IssueType::Invalid(None),
+                            format!("Segment '{}' is not part of a message", line),
+                        ));
                     }
+                    message_lines.push(line);
                 }
-            });
-
-            segments.push(Segment {
-                id: segment_id.to_string(),
-                fields: segment_fields.collect(),
-            });
+            }
         }
 
-        Ok(Hl7V2Message {
-            header: message_header,
-            segments,
-        })
+        flush_message(&mut file, &mut message_lines)?;
+
+        Ok(file)
     }
 }
 
@@ -254,4 +727,80 @@ mod tests {
         assert_eq!(message.segments[5].id, "AIL");
         assert_eq!(message.segments[6].id, "AIP");
     }
+
+    #[test]
+    fn test_unescape() {
+        let delimiters = Delimiters::default();
+        assert_eq!(
+            unescape(&delimiters, "a\\F\\b\\S\\c\\T\\d\\R\\e\\E\\f"),
+            "a|b^c&d~e\\f"
+        );
+        assert_eq!(unescape(&delimiters, "line\\.br\\next"), "line\nnext");
+        assert_eq!(unescape(&delimiters, "\\X48454C4C4F\\"), "HELLO");
+        assert_eq!(unescape(&delimiters, "\\H\\bold\\N\\"), "bold");
+        assert_eq!(unescape(&delimiters, "unknown\\Q\\"), "unknown\\Q\\");
+        assert_eq!(unescape(&delimiters, "trailing\\"), "trailing\\");
+    }
+
+    #[test]
+    fn test_non_standard_delimiters() {
+        let message =
+            Hl7V2Message::try_from("MSH#$*/!#APP#FAC\rPID#1##42$MRN!H1#*A#DOE$JOHN/S/X").unwrap();
+
+        assert_eq!(message.header.sending_application(), Some("APP"));
+        let pid = &message.segments[0];
+        assert_eq!(
+            pid.field(3).unwrap().first().unwrap().component_str(2),
+            Some("MRN")
+        );
+        assert_eq!(
+            pid.field(3)
+                .unwrap()
+                .first()
+                .unwrap()
+                .component(2)
+                .unwrap()
+                .subcomponent(2),
+            Some("H1")
+        );
+        assert_eq!(pid.field(4).unwrap().repetitions().len(), 2);
+        assert_eq!(
+            pid.field(5).unwrap().first().unwrap().component_str(2),
+            Some("JOHN$X")
+        );
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let input = [
+            "FHS|^~\\&|SENDER",
+            "BHS|^~\\&|SENDER",
+            "MSH|^~\\&|SENDER|||||||ORU^R01|1|P|2.5",
+            "PID|1||1",
+            "MSH|^~\\&|SENDER|||||||ORU^R01|2|P|2.5",
+            "PID|1||2",
+            "BTS|2",
+            "FTS|1",
+        ]
+        .join("\r");
+
+        assert!(Hl7V2Message::try_from(input.as_str()).is_err());
+
+        let file = Hl7V2File::try_from(input.as_str()).unwrap();
+        assert_eq!(file.header.as_ref().unwrap().field_str(3), Some("SENDER"));
+        assert_eq!(file.header.as_ref().unwrap().field_str(2), Some("^~\\&"));
+        assert_eq!(file.batches.len(), 1);
+        assert_eq!(file.batches[0].messages.len(), 2);
+        assert_eq!(
+            file.batches[0].trailer.as_ref().unwrap().field_str(1),
+            Some("2")
+        );
+        assert_eq!(file.trailer.as_ref().unwrap().field_str(1), Some("1"));
+        assert_eq!(
+            file.messages()
+                .map(|m| m.header.message_control_id().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+    }
 }
@@ -0,0 +1,145 @@
+use crate::parser::{Field, Hl7V2Message};
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+
+/// Location of a value within a message IE PID-5.1 or OBX(2)-5(1).2.1
+/// Format is SEG[(segment repetition)]-field[(field repetition)][.component[.subcomponent]].
+/// Positions and repetitions are 1-based. When the component or subcomponent is omitted the first is used.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TerserPath {
+    pub segment: String,
+    pub segment_repetition: usize,
+    pub field: usize,
+    pub field_repetition: usize,
+    pub component: Option<usize>,
+    pub subcomponent: Option<usize>,
+}
+
+fn invalid_path(path: &str) -> OperationOutcomeError {
+    OperationOutcomeError::error(
+        IssueType::Invalid(None),
+        format!("Invalid terser path '{}'", path),
+    )
+}
+
+/// Parses "name(repetition)" returning the name and the repetition defaulting to 1.
+fn with_repetition<'a>(
+    path: &str,
+    value: &'a str,
+) -> Result<(&'a str, usize), OperationOutcomeError> {
+    match value.split_once('(') {
+        Some((name, repetition)) => {
+            let repetition = repetition
+                .strip_suffix(')')
+                .and_then(|r| r.parse::<usize>().ok())
+                .filter(|r| *r > 0)
+                .ok_or_else(|| invalid_path(path))?;
+            Ok((name, repetition))
+        }
+        None => Ok((value, 1)),
+    }
+}
+
+fn position(path: &str, value: &str) -> Result<usize, OperationOutcomeError> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|p| *p > 0)
+        .ok_or_else(|| invalid_path(path))
+}
+
+impl TryFrom<&str> for TerserPath {
+    type Error = OperationOutcomeError;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        let (segment, rest) = path.split_once('-').ok_or_else(|| invalid_path(path))?;
+        let (segment, segment_repetition) = with_repetition(path, segment)?;
+        if segment.len() != 3 || !segment.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid_path(path));
+        }
+
+        let mut parts = rest.split('.');
+        let (field, field_repetition) =
+            with_repetition(path, parts.next().ok_or_else(|| invalid_path(path))?)?;
+        let field = position(path, field)?;
+        let component = parts.next().map(|c| position(path, c)).transpose()?;
+        let subcomponent = parts.next().map(|s| position(path, s)).transpose()?;
+
+        if parts.next().is_some() {
+            return Err(invalid_path(path));
+        }
+
+        Ok(TerserPath {
+            segment: segment.to_string(),
+            segment_repetition,
+            field,
+            field_repetition,
+            component,
+            subcomponent,
+        })
+    }
+}
+
+fn field_value(field: &Field, path: &TerserPath) -> Option<String> {
+    let value = field
+        .repetitions()
+        .into_iter()
+        .nth(path.field_repetition - 1)?;
+    let component = value.component(path.component.unwrap_or(1))?;
+    match path.subcomponent {
+        Some(subcomponent) => component.subcomponent(subcomponent),
+        None => component.as_str(),
+    }
+    .map(|s| s.to_string())
+}
+
+impl Hl7V2Message {
+    /// Returns the value at a terser style path IE message.get("PID-5.1").
+    /// Returns None when the value is not populated and an error when the path is invalid.
+    pub fn get(&self, path: &str) -> Result<Option<String>, OperationOutcomeError> {
+        let path = TerserPath::try_from(path)?;
+        Ok(self.get_path(&path))
+    }
+
+    pub fn get_path(&self, path: &TerserPath) -> Option<String> {
+        if path.segment == "MSH" {
+            if path.segment_repetition != 1 {
+                return None;
+            }
+            let field = self.header.field(path.field)?;
+            return field_value(&field, path);
+        }
+
+        let segment = self
+            .segments_by_id(&path.segment)
+            .nth(path.segment_repetition - 1)?;
+        field_value(segment.field(path.field)?, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terser_get() {
+        let input = std::fs::read_to_string("./test_data/message1.bin").unwrap();
+        let message = Hl7V2Message::try_from(input.as_str()).unwrap();
+
+        assert_eq!(
+            message.get("PID-5.1").unwrap(),
+            Some("BEEBLEBROX".to_string())
+        );
+        assert_eq!(message.get("PID-5.2").unwrap(), Some("ZAPHOD".to_string()));
+        assert_eq!(message.get("PID-3").unwrap(), Some("42".to_string()));
+        assert_eq!(message.get("MSH-9.2").unwrap(), Some("S12".to_string()));
+        assert_eq!(message.get("MSH-2").unwrap(), Some("^~\\&".to_string()));
+        assert_eq!(
+            message.get("PV1-7(1).2").unwrap(),
+            Some("Adams".to_string())
+        );
+        assert_eq!(message.get("PID(2)-5.1").unwrap(), None);
+        assert!(message.get("PID5").is_err());
+        assert!(message.get("PID-0").is_err());
+    }
+}
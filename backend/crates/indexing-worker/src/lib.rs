@@ -1,13 +1,21 @@
 use crate::indexing_lock::IndexLockProvider;
-use haste_config::get_config;
-use haste_fhir_model::r4::generated::resources::ResourceTypeError;
+use haste_config::{ConfigType, LayeredConfigOptions, get_config};
+use haste_fhir_model::r4::{
+    datetime::Instant,
+    generated::{
+        resources::{Resource, ResourceTypeError},
+        types::Meta,
+    },
+};
 use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
 use haste_fhir_search::{IndexResource, SearchEngine, elastic_search::ElasticSearchEngine};
 use haste_fhirpath::FHIRPathError;
 use haste_jwt::TenantId;
+use haste_reflect::MetaValue;
 use haste_repository::{fhir::FHIRRepository, types::SupportedFHIRVersions};
+use metrics_exporter_prometheus::PrometheusBuilder;
 use sqlx::{Pool, Postgres, query_as, types::time::OffsetDateTime};
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc, time::SystemTime};
 use tokio::sync::Mutex;
 
 mod indexing_lock;
@@ -62,6 +70,22 @@ async fn get_tenants(
     Ok(result)
 }
 
+/// Seconds since the oldest resource waiting to be indexed was written.
+fn indexing_lag_seconds(resource: &Resource) -> Option<f64> {
+    let last_updated = resource
+        .get_field("meta")
+        .and_then(|meta| meta.as_any().downcast_ref::<Box<Meta>>())
+        .and_then(|meta| meta.lastUpdated.as_ref())
+        .and_then(|last_updated| last_updated.value.as_ref())?;
+    let Instant::Iso8601(last_updated) = last_updated;
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()?
+        .as_millis() as i64;
+
+    Some((now - last_updated.timestamp_millis()).max(0) as f64 / 1000.0)
+}
+
 static TOTAL_INDEXED: std::sync::LazyLock<Mutex<usize>> =
     std::sync::LazyLock::new(|| Mutex::new(0));
 
@@ -80,6 +104,7 @@ async fn index_tenant_next_sequence<
         return Ok(());
     }
 
+    let repository_start = std::time::Instant::now();
     let resources = tx
         .get_sequence(
             tenant_id,
@@ -87,9 +112,25 @@ async fn index_tenant_next_sequence<
             Some(1000),
         )
         .await?;
+    metrics::histogram!(
+        "indexing_worker_repository_duration_seconds",
+        "operation" => "get_sequence"
+    )
+    .record(repository_start.elapsed().as_secs_f64());
+
+    let tenant_label = tenant_id.as_ref().to_string();
+    metrics::gauge!("indexing_worker_lag_seconds", "tenant" => tenant_label.clone()).set(
+        resources
+            .first()
+            .and_then(|resource| indexing_lag_seconds(&resource.resource.0))
+            .unwrap_or(0.0),
+    );
+    metrics::gauge!("indexing_worker_pending_resources", "tenant" => tenant_label.clone())
+        .set(resources.len() as f64);
 
     // Perform indexing if there are resources to index.
     if !resources.is_empty() {
+        let elasticsearch_start = std::time::Instant::now();
         let result = search_client
             .index(
                 &SupportedFHIRVersions::R4,
@@ -106,7 +147,12 @@ async fn index_tenant_next_sequence<
                     })
                     .collect(),
             )
-            .await?;
+            .await
+            .inspect_err(|_| {
+                metrics::counter!("indexing_worker_elasticsearch_errors_total").increment(1);
+            })?;
+        metrics::histogram!("indexing_worker_elasticsearch_duration_seconds")
+            .record(elasticsearch_start.elapsed().as_secs_f64());
 
         if result.0 != resources.len() {
             tracing::error!(
@@ -132,10 +178,18 @@ async fn index_tenant_next_sequence<
                 );
             }
 
+            let repository_start = std::time::Instant::now();
             tx.update_lock(tenant_id.as_ref(), resource.sequence as usize)
                 .await?;
+            metrics::histogram!(
+                "indexing_worker_repository_duration_seconds",
+                "operation" => "update_lock"
+            )
+            .record(repository_start.elapsed().as_secs_f64());
 
             let elapsed = start.elapsed();
+            metrics::histogram!("indexing_worker_batch_duration_seconds")
+                .record(elapsed.as_secs_f64());
             tracing::info!(
                 "Indexed {} resources for tenant '{}' in {:.2?} (up to sequence {})",
                 result.0,
@@ -145,6 +199,8 @@ async fn index_tenant_next_sequence<
             );
         }
 
+        metrics::counter!("indexing_worker_resources_indexed_total", "tenant" => tenant_label)
+            .increment(result.0 as u64);
         *(TOTAL_INDEXED.lock().await) += result.0;
     }
 
@@ -179,6 +235,8 @@ pub enum IndexingWorkerEnvironmentVariables {
     ElasticSearchURL,
     ElasticSearchUsername,
     ElasticSearchPassword,
+    // Address the Prometheus scrape endpoint listens on.
+    MetricsAddress,
 }
 
 impl From<IndexingWorkerEnvironmentVariables> for String {
@@ -192,14 +250,33 @@ impl From<IndexingWorkerEnvironmentVariables> for String {
             IndexingWorkerEnvironmentVariables::ElasticSearchPassword => {
                 "ELASTICSEARCH_PASSWORD".to_string()
             }
+            IndexingWorkerEnvironmentVariables::MetricsAddress => "METRICS_ADDRESS".to_string(),
         }
     }
 }
 
 pub async fn run_worker() -> Result<(), OperationOutcomeError> {
-    let config = get_config::<IndexingWorkerEnvironmentVariables>("environment".into());
+    let config = get_config::<IndexingWorkerEnvironmentVariables>(ConfigType::Layered(
+        LayeredConfigOptions::new().required([
+            IndexingWorkerEnvironmentVariables::DatabaseURL,
+            IndexingWorkerEnvironmentVariables::ElasticSearchURL,
+            IndexingWorkerEnvironmentVariables::ElasticSearchUsername,
+            IndexingWorkerEnvironmentVariables::ElasticSearchPassword,
+        ]),
+    ))?;
     let subscriber = tracing_subscriber::FmtSubscriber::new();
     tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let metrics_address = config
+        .get(IndexingWorkerEnvironmentVariables::MetricsAddress)
+        .unwrap_or_else(|_| "0.0.0.0:9091".to_string())
+        .parse::<SocketAddr>()
+        .expect("Invalid METRICS_ADDRESS");
+    PrometheusBuilder::new()
+        .with_http_listener(metrics_address)
+        .install()
+        .expect("Failed to install Prometheus exporter");
+
     let fp_engine = Arc::new(haste_fhirpath::FPEngine::new());
 
     let search_engine = Arc::new(
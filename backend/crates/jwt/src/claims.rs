@@ -22,4 +22,7 @@ pub struct UserTokenClaims {
     pub access_policy_version_ids: Vec<VersionId>,
     #[serde(rename = "https://haste.health/membership")]
     pub membership: Option<String>,
+    /// Authentication methods references (RFC 8176) IE pwd and otp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amr: Option<Vec<String>>,
 }
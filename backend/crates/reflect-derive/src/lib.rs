@@ -37,10 +37,10 @@ pub fn haste_reflect(input: TokenStream) -> TokenStream {
 
     match input.data {
         Data::Struct(data) => {
-            let all_fields = data
-                .fields
-                .iter()
-                .map(|field| field.ident.to_owned().unwrap().to_string());
+            let all_fields = data.fields.iter().map(|field| {
+                get_attribute_rename(&field.attrs)
+                    .unwrap_or_else(|| field.ident.to_owned().unwrap().to_string())
+            });
 
             let name = input.ident;
             let name_str = name.to_string();
@@ -127,6 +127,10 @@ pub fn haste_reflect(input: TokenStream) -> TokenStream {
                         vec![self]
                     }
 
+                    fn set_value(&mut self, value: Box<dyn std::any::Any>) -> Result<(), Box<dyn std::any::Any>> {
+                        *self = <Self as haste_reflect::MetaValue>::from_any(value)?;
+                        Ok(())
+                    }
                 }
             };
 
@@ -192,6 +196,24 @@ pub fn haste_reflect(input: TokenStream) -> TokenStream {
                 }
             });
 
+            let variants_from_any = data.variants.iter().map(|variant| {
+                let name = variant.ident.to_owned();
+                let variant_type = &variant.fields.iter().next().unwrap().ty;
+                quote! {
+                    let value = match <#variant_type as haste_reflect::MetaValue>::from_any(value) {
+                        Ok(k) => return Ok(Self::#name(k)),
+                        Err(value) => value,
+                    };
+                }
+            });
+
+            let variants_into_any = data.variants.iter().map(|variant| {
+                let name = variant.ident.to_owned();
+                quote! {
+                    Self::#name(k) => haste_reflect::MetaValue::into_any(Box::new(k))
+                }
+            });
+
             let expanded = quote! {
                 impl haste_reflect::MetaValue for #enum_name {
                     fn fields(&self) -> Vec<&'static str> {
@@ -241,6 +263,27 @@ pub fn haste_reflect(input: TokenStream) -> TokenStream {
                             #(#variants_flatten),*
                         }
                     }
+
+                    // Variants are tried in order, so a value builds the first variant it fits.
+                    fn from_any(value: Box<dyn std::any::Any>) -> Result<Self, Box<dyn std::any::Any>> {
+                        let value = match value.downcast::<Self>() {
+                            Ok(value) => return Ok(*value),
+                            Err(value) => value,
+                        };
+                        #(#variants_from_any)*
+                        Err(value)
+                    }
+
+                    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+                        match *self {
+                            #(#variants_into_any),*
+                        }
+                    }
+
+                    fn set_value(&mut self, value: Box<dyn std::any::Any>) -> Result<(), Box<dyn std::any::Any>> {
+                        *self = <Self as haste_reflect::MetaValue>::from_any(value)?;
+                        Ok(())
+                    }
                 }
             };
 
@@ -1,7 +1,7 @@
 use crate::traits::MetaValue;
 use std::any::Any;
 
-/**
+/*
  * 067  public static final String FP_String = "http://hl7.org/fhirpath/System.String";
  * 068  public static final String FP_Boolean = "http://hl7.org/fhirpath/System.Boolean";
  * 069  public static final String FP_Integer = "http://hl7.org/fhirpath/System.Integer";
@@ -20,7 +20,7 @@ impl MetaValue for i64 {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -28,7 +28,7 @@ impl MetaValue for i64 {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -43,6 +43,11 @@ impl MetaValue for i64 {
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         vec![self]
     }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
 }
 
 impl MetaValue for u64 {
@@ -54,7 +59,7 @@ impl MetaValue for u64 {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -62,7 +67,7 @@ impl MetaValue for u64 {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -77,6 +82,11 @@ impl MetaValue for u64 {
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         vec![self]
     }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
 }
 
 impl MetaValue for f64 {
@@ -88,7 +98,7 @@ impl MetaValue for f64 {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -96,7 +106,7 @@ impl MetaValue for f64 {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -111,6 +121,11 @@ impl MetaValue for f64 {
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         vec![self]
     }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
 }
 
 impl MetaValue for bool {
@@ -122,7 +137,7 @@ impl MetaValue for bool {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -130,7 +145,7 @@ impl MetaValue for bool {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -145,6 +160,11 @@ impl MetaValue for bool {
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         vec![self]
     }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
 }
 
 impl MetaValue for String {
@@ -156,7 +176,7 @@ impl MetaValue for String {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -164,7 +184,7 @@ impl MetaValue for String {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -179,6 +199,11 @@ impl MetaValue for String {
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         vec![self]
     }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
 }
 
 impl MetaValue for &'static str {
@@ -190,7 +215,7 @@ impl MetaValue for &'static str {
         None
     }
 
-    fn get_index<'a>(&'a self, _index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, _index: usize) -> Option<&dyn MetaValue> {
         None
     }
 
@@ -198,7 +223,7 @@ impl MetaValue for &'static str {
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, _index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, _index: usize) -> Option<&mut dyn MetaValue> {
         None
     }
 
@@ -231,7 +256,7 @@ where
         None
     }
 
-    fn get_index<'a>(&'a self, index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, index: usize) -> Option<&dyn MetaValue> {
         if self.get(index).is_some() {
             let k: &dyn MetaValue = &self[index];
             Some(k)
@@ -244,7 +269,7 @@ where
         None
     }
 
-    fn get_index_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, index: usize) -> Option<&mut dyn MetaValue> {
         if self.get(index).is_some() {
             let k: &mut dyn MetaValue = &mut self[index];
             Some(k)
@@ -268,6 +293,46 @@ where
     fn flatten(&self) -> Vec<&dyn MetaValue> {
         self.iter().flat_map(|item| item.flatten()).collect()
     }
+
+    /// A single item builds a collection holding just that item.
+    fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+        match value.downcast::<Self>() {
+            Ok(value) => Ok(*value),
+            Err(value) => T::from_any(value).map(|item| vec![item]),
+        }
+    }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
+
+    fn insert_index(&mut self, index: usize, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        if index > self.len() {
+            return Err(value);
+        }
+        self.insert(index, T::from_any(value)?);
+        Ok(())
+    }
+
+    fn remove_index(&mut self, index: usize) -> bool {
+        if index < self.len() {
+            self.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn move_index(&mut self, from: usize, to: usize) -> bool {
+        if from < self.len() && to < self.len() {
+            let item = self.remove(from);
+            self.insert(to, item);
+            true
+        } else {
+            false
+        }
+    }
 }
 
 // Used for mutable access which requires setting optional fields.
@@ -301,10 +366,10 @@ where
     }
 
     fn flatten(&self) -> Vec<&dyn MetaValue> {
-        self.as_ref().map(|v| v.flatten()).unwrap_or_else(|| vec![])
+        self.as_ref().map(|v| v.flatten()).unwrap_or_default()
     }
 
-    fn get_index<'a>(&'a self, index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, index: usize) -> Option<&dyn MetaValue> {
         self.as_ref().and_then(|v| v.get_index(index))
     }
 
@@ -312,9 +377,56 @@ where
         self.as_mut().and_then(|value| value.get_field_mut(field))
     }
 
-    fn get_index_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, index: usize) -> Option<&mut dyn MetaValue> {
         self.as_mut().and_then(|v| v.get_index_mut(index))
     }
+
+    fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+        match value.downcast::<Self>() {
+            Ok(value) => Ok(*value),
+            Err(value) => T::from_any(value).map(Some),
+        }
+    }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
+
+    fn clear_value(&mut self) -> bool {
+        *self = None;
+        true
+    }
+
+    /// Inserting into an empty optional initializes it with the value.
+    fn insert_index(&mut self, index: usize, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        match self {
+            Some(inner) => inner.insert_index(index, value),
+            None if index == 0 => {
+                *self = Some(T::from_any(value)?);
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Removing the last item of a collection clears the optional, as FHIR does not allow empty arrays.
+    fn remove_index(&mut self, index: usize) -> bool {
+        let Some(inner) = self.as_mut() else {
+            return false;
+        };
+        let removed = inner.remove_index(index);
+        if removed && inner.get_index(0).is_none() {
+            *self = None;
+        }
+        removed
+    }
+
+    fn move_index(&mut self, from: usize, to: usize) -> bool {
+        self.as_mut()
+            .map(|inner| inner.move_index(from, to))
+            .unwrap_or(false)
+    }
 }
 
 impl<T> MetaValue for Box<T>
@@ -341,7 +453,7 @@ where
         self.as_ref().flatten()
     }
 
-    fn get_index<'a>(&'a self, index: usize) -> Option<&'a dyn MetaValue> {
+    fn get_index(&self, index: usize) -> Option<&dyn MetaValue> {
         self.as_ref().get_index(index)
     }
 
@@ -349,7 +461,39 @@ where
         self.as_mut().get_field_mut(field)
     }
 
-    fn get_index_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut dyn MetaValue> {
+    fn get_index_mut(&mut self, index: usize) -> Option<&mut dyn MetaValue> {
         self.as_mut().get_index_mut(index)
     }
+
+    fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+        match value.downcast::<Self>() {
+            Ok(value) => Ok(*value),
+            Err(value) => T::from_any(value).map(Box::new),
+        }
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        T::into_any(*self)
+    }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        *self = Self::from_any(value)?;
+        Ok(())
+    }
+
+    fn clear_value(&mut self) -> bool {
+        self.as_mut().clear_value()
+    }
+
+    fn insert_index(&mut self, index: usize, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        self.as_mut().insert_index(index, value)
+    }
+
+    fn remove_index(&mut self, index: usize) -> bool {
+        self.as_mut().remove_index(index)
+    }
+
+    fn move_index(&mut self, from: usize, to: usize) -> bool {
+        self.as_mut().move_index(from, to)
+    }
 }
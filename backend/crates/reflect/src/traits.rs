@@ -14,4 +14,46 @@ pub trait MetaValue: Any + Debug + Send + Sync {
     fn as_any(&self) -> &dyn Any;
 
     fn typename(&self) -> &'static str;
+
+    /// Builds a value from a type erased one, returning the input back if it is not compatible.
+    /// Wrappers (Box, Option, Vec) build themselves from their inner type.
+    fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>>
+    where
+        Self: Sized,
+    {
+        value.downcast::<Self>().map(|value| *value)
+    }
+
+    /// Type erases an owned value, unwrapping boxes and type choices to the inner value.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Replaces this value in place.
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        Err(value)
+    }
+
+    /// Clears an optional value.
+    fn clear_value(&mut self) -> bool {
+        false
+    }
+
+    /// Inserts an item into a collection at the given index.
+    fn insert_index(&mut self, _index: usize, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        Err(value)
+    }
+
+    /// Removes the item at the given index from a collection.
+    fn remove_index(&mut self, _index: usize) -> bool {
+        false
+    }
+
+    /// Moves an item within a collection.
+    fn move_index(&mut self, _from: usize, _to: usize) -> bool {
+        false
+    }
 }
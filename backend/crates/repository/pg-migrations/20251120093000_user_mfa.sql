@@ -0,0 +1,24 @@
+CREATE TABLE
+    user_mfa (
+        tenant TEXT NOT NULL,
+        user_id TEXT NOT NULL,
+        totp_secret TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL DEFAULT false,
+        -- Last accepted TOTP time step, prevents a code being replayed.
+        last_used_step BIGINT,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        CONSTRAINT user_mfa_pkey PRIMARY KEY (tenant, user_id),
+        CONSTRAINT fk_user FOREIGN KEY (tenant, user_id) REFERENCES users (tenant, id) ON DELETE CASCADE
+    );
+
+CREATE TABLE
+    user_mfa_recovery_codes (
+        tenant TEXT NOT NULL,
+        user_id TEXT NOT NULL,
+        -- Hashed with crypt same as passwords.
+        code TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        CONSTRAINT fk_user_mfa FOREIGN KEY (tenant, user_id) REFERENCES user_mfa (tenant, user_id) ON DELETE CASCADE
+    );
+
+CREATE INDEX user_mfa_recovery_codes_user_idx ON user_mfa_recovery_codes USING btree (tenant, user_id);
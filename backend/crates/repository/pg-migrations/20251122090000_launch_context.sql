@@ -0,0 +1,2 @@
+-- Opaque SMART launch parameters bound to a patient and/or encounter.
+ALTER TYPE code_kind ADD VALUE 'launch_context';
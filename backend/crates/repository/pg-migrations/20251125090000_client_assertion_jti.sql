@@ -0,0 +1,13 @@
+-- jti of private_key_jwt client assertions, kept until the assertion expires to prevent replay.
+CREATE TABLE
+    client_assertion_jti (
+        tenant TEXT NOT NULL,
+        project TEXT NOT NULL,
+        client_id TEXT NOT NULL,
+        jti TEXT NOT NULL,
+        expires_at TIMESTAMPTZ NOT NULL,
+        CONSTRAINT client_assertion_jti_pkey PRIMARY KEY (tenant, project, client_id, jti),
+        CONSTRAINT fk_project FOREIGN KEY (tenant, project) REFERENCES project (tenant, id) ON DELETE CASCADE
+    );
+
+CREATE INDEX client_assertion_jti_expires_idx ON client_assertion_jti USING btree (expires_at);
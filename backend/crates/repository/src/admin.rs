@@ -1,5 +1,8 @@
 /// Authentication traits include management for user and Authorization codes.
-use crate::types::user::{LoginMethod, LoginResult};
+use crate::types::{
+    mfa::UserMFA,
+    user::{LoginMethod, LoginResult},
+};
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_jwt::{ProjectId, TenantId};
 
@@ -11,6 +14,61 @@ pub trait Login {
     ) -> impl Future<Output = Result<LoginResult, OperationOutcomeError>> + Send;
 }
 
+/// TOTP based multi-factor authentication for email/password users.
+pub trait MultiFactorAuth {
+    fn read_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Option<UserMFA>, OperationOutcomeError>> + Send;
+    /// Stores a new (disabled) TOTP secret for the user, replacing any existing enrolment.
+    fn enroll_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        totp_secret: &str,
+    ) -> impl Future<Output = Result<UserMFA, OperationOutcomeError>> + Send;
+    /// Enables MFA after the first code is verified, replacing recovery codes with hashes of the given codes.
+    fn enable_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        recovery_codes: &[String],
+    ) -> impl Future<Output = Result<(), OperationOutcomeError>> + Send;
+    fn delete_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+    ) -> impl Future<Output = Result<(), OperationOutcomeError>> + Send;
+    /// Marks a TOTP time step as used, returns false if the step (or a later one) was already used.
+    fn use_totp_step(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        step: i64,
+    ) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send;
+    /// Consumes a recovery code, returns false if the code does not match an unused code.
+    fn use_recovery_code(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        code: &str,
+    ) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send;
+}
+
+/// Replay protection for private_key_jwt client assertions.
+pub trait ClientAssertionReplay {
+    /// Records the assertion's jti until it expires (unix seconds), returns false if it was already used.
+    fn use_client_assertion(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        client_id: &str,
+        jti: &str,
+        expires_at: i64,
+    ) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send;
+}
+
 pub trait Migrate {
     fn migrate(&self) -> impl Future<Output = Result<(), OperationOutcomeError>> + Send;
 }
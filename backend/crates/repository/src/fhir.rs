@@ -53,6 +53,30 @@ pub trait FHIRRepository: Sized {
         id: &str,
     ) -> impl Future<Output = Result<Resource, OperationOutcomeError>> + Send;
 
+    /// Bulk loads resources as given, keeping their ids and lastUpdated. New version ids are
+    /// generated with the source versionId kept as a source-version-id tag. Every version is
+    /// stored as a create (POST) even when the resources come from a history export.
+    /// Returns the version ids that were stored.
+    fn import(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        user: &UserTokenClaims,
+        fhir_version: &SupportedFHIRVersions,
+        resources: &mut [Resource],
+    ) -> impl Future<Output = Result<Vec<VersionId>, OperationOutcomeError>> + Send;
+
+    /// Permanently removes every version of the matching resources, narrowed by type and id.
+    /// Resources of `excluded_types` are kept. Returns the version ids that were removed.
+    fn expunge(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: Option<&ResourceType>,
+        id: Option<&ResourceId>,
+        excluded_types: &[ResourceType],
+    ) -> impl Future<Output = Result<Vec<VersionId>, OperationOutcomeError>> + Send;
+
     fn read_by_version_ids(
         &self,
         tenant_id: &TenantId,
@@ -79,6 +103,11 @@ pub trait FHIRRepository: Sized {
         sequence_id: u64,
         count: Option<u64>,
     ) -> impl Future<Output = Result<Vec<ResourcePollingValue>, OperationOutcomeError>> + Send;
+    /// Number of resources (excluding deleted resources and prior versions) stored for a tenant.
+    fn count_resources(
+        &self,
+        tenant_id: &TenantId,
+    ) -> impl Future<Output = Result<i64, OperationOutcomeError>> + Send;
     fn transaction<'a>(
         &'a self,
         register: bool,
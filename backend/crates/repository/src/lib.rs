@@ -1,5 +1,7 @@
 use crate::{
-    admin::{Login, Migrate, ProjectAuthAdmin, TenantAuthAdmin},
+    admin::{
+        ClientAssertionReplay, Login, Migrate, MultiFactorAuth, ProjectAuthAdmin, TenantAuthAdmin,
+    },
     fhir::FHIRRepository,
     types::{
         authorization_code::{
@@ -40,6 +42,8 @@ pub trait Repository:
     > + ProjectAuthAdmin<CreateMembership, Membership, MembershipSearchClaims, Membership, String>
     + ProjectAuthAdmin<CreateScope, Scope, ScopeSearchClaims, UpdateScope, ScopeKey>
     + Login
+    + MultiFactorAuth
+    + ClientAssertionReplay
     + Migrate
 {
 }
@@ -0,0 +1,73 @@
+use crate::{
+    admin::ClientAssertionReplay,
+    pg::{PGConnection, StoreError},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_jwt::{ProjectId, TenantId};
+use sqlx::{Acquire, Postgres, QueryBuilder};
+
+fn use_client_assertion<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    project: &'a ProjectId,
+    client_id: &'a str,
+    jti: &'a str,
+    expires_at: i64,
+) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        // Expired assertions are rejected before reaching here so their jti no longer needs tracking.
+        let mut query_builder =
+            QueryBuilder::new(r#"DELETE FROM client_assertion_jti WHERE expires_at < NOW()"#);
+        query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"INSERT INTO client_assertion_jti (tenant, project, client_id, jti, expires_at) VALUES ("#,
+        );
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(", ")
+            .push_bind(project.as_ref())
+            .push(", ")
+            .push_bind(client_id)
+            .push(", ")
+            .push_bind(jti)
+            .push(", to_timestamp(")
+            .push_bind(expires_at)
+            .push(")) ON CONFLICT DO NOTHING");
+
+        let result = query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl ClientAssertionReplay for PGConnection {
+    async fn use_client_assertion(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        client_id: &str,
+        jti: &str,
+        expires_at: i64,
+    ) -> Result<bool, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => {
+                use_client_assertion(pool, tenant, project, client_id, jti, expires_at).await
+            }
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                use_client_assertion(&mut *conn, tenant, project, client_id, jti, expires_at).await
+            }
+        }
+    }
+}
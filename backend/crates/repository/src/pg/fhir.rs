@@ -6,11 +6,15 @@ use crate::{
 };
 use haste_fhir_client::request::HistoryRequest;
 use haste_fhir_model::r4::{
-    generated::resources::{Resource, ResourceType},
+    generated::{
+        resources::{Resource, ResourceType},
+        types::FHIRInstant,
+    },
     sqlx::{FHIRJson, FHIRJsonRef},
 };
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_jwt::{ProjectId, ResourceId, TenantId, VersionId, claims::UserTokenClaims};
+use haste_reflect::MetaValue;
 use moka::future::Cache;
 use sqlx::{Acquire, Postgres, QueryBuilder, Transaction};
 use std::sync::Arc;
@@ -203,6 +207,69 @@ impl FHIRRepository for PGConnection {
         }
     }
 
+    async fn import(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        author: &UserTokenClaims,
+        fhir_version: &SupportedFHIRVersions,
+        resources: &mut [Resource],
+    ) -> Result<Vec<VersionId>, OperationOutcomeError> {
+        if resources.is_empty() {
+            return Ok(vec![]);
+        }
+
+        match self {
+            PGConnection::Pool(_pool, _) => {
+                let tx = create_transaction(self, true).await?;
+                let res = {
+                    let mut conn = tx.lock().await;
+                    import(&mut *conn, tenant, project, author, fhir_version, resources).await?
+                };
+
+                commit_transaction(tx).await?;
+                Ok(res)
+            }
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                import(&mut *conn, tenant, project, author, fhir_version, resources).await
+            }
+        }
+    }
+
+    async fn expunge(
+        &self,
+        tenant: &TenantId,
+        project: &ProjectId,
+        resource_type: Option<&ResourceType>,
+        id: Option<&ResourceId>,
+        excluded_types: &[ResourceType],
+    ) -> Result<Vec<VersionId>, OperationOutcomeError> {
+        let removed = match self {
+            PGConnection::Pool(pool, _) => {
+                expunge(pool, tenant, project, resource_type, id, excluded_types).await?
+            }
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                expunge(
+                    &mut *conn,
+                    tenant,
+                    project,
+                    resource_type,
+                    id,
+                    excluded_types,
+                )
+                .await?
+            }
+        };
+
+        for version_id in removed.iter() {
+            self.cache().invalidate(version_id).await;
+        }
+
+        Ok(removed)
+    }
+
     async fn read_by_version_ids(
         &self,
         tenant_id: &TenantId,
@@ -332,6 +399,16 @@ impl FHIRRepository for PGConnection {
         }
     }
 
+    async fn count_resources(&self, tenant_id: &TenantId) -> Result<i64, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => count_resources(pool, tenant_id).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                count_resources(&mut *conn, tenant_id).await
+            }
+        }
+    }
+
     fn in_transaction(&self) -> bool {
         match self {
             PGConnection::Transaction(_tx, _) => true,
@@ -480,6 +557,118 @@ fn update<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
     }
 }
 
+fn import<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    project: &'a ProjectId,
+    author: &'a UserTokenClaims,
+    fhir_version: &'a SupportedFHIRVersions,
+    resources: &'a mut [Resource],
+) -> impl Future<Output = Result<Vec<VersionId>, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut last_updated = Vec::with_capacity(resources.len());
+        for resource in resources.iter_mut() {
+            let id = resource
+                .get_field("id")
+                .and_then(|id| id.as_any().downcast_ref::<String>())
+                .cloned();
+            utilities::set_resource_id(resource, id)?;
+
+            let meta = resource.get_field("meta");
+            // lastUpdated is set by trigger from created_at so it's kept by inserting as created_at.
+            last_updated.push(
+                meta.and_then(|meta| meta.get_field("lastUpdated"))
+                    .and_then(|lu| lu.as_any().downcast_ref::<Box<FHIRInstant>>())
+                    .and_then(|lu| lu.value.as_ref())
+                    .map(|lu| lu.to_string()),
+            );
+
+            // Source version ids are only unique per resource so new ones are always generated.
+            utilities::set_imported_version_id(resource)?;
+        }
+
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO resources (tenant, project, author_id, fhir_version, resource, deleted, request_method, author_type, fhir_method, created_at) ",
+        );
+        query_builder.push_values(
+            resources.iter().zip(last_updated.iter()),
+            |mut row, (resource, last_updated)| {
+                row.push_bind(tenant.as_ref() as &str)
+                    .push_bind(project.as_ref() as &str)
+                    .push_bind(author.sub.as_ref() as &str)
+                    .push_bind(fhir_version)
+                    .push_bind(FHIRJsonRef(resource))
+                    .push_bind(false)
+                    .push_bind("POST")
+                    .push_bind(author.resource_type.as_ref() as &str)
+                    .push_bind(FHIRMethod::Create)
+                    .push("COALESCE(CAST(")
+                    .push_bind_unseparated(last_updated.as_deref())
+                    .push_unseparated(" AS timestamptz), now())");
+            },
+        );
+        query_builder.push(" RETURNING version_id");
+
+        let stored: Vec<VersionId> = query_builder
+            .build_query_scalar()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(StoreError::from)?;
+
+        Ok(stored)
+    }
+}
+
+fn expunge<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    project: &'a ProjectId,
+    resource_type: Option<&'a ResourceType>,
+    id: Option<&'a ResourceId>,
+    excluded_types: &'a [ResourceType],
+) -> impl Future<Output = Result<Vec<VersionId>, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        let mut query_builder: QueryBuilder<Postgres> =
+            QueryBuilder::new("DELETE FROM resources WHERE tenant = ");
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(" AND project = ")
+            .push_bind(project.as_ref());
+
+        if let Some(resource_type) = resource_type {
+            query_builder
+                .push(" AND resource_type = ")
+                .push_bind(resource_type.as_ref());
+        }
+        if let Some(id) = id {
+            query_builder.push(" AND id = ").push_bind(id.as_ref());
+        }
+        if !excluded_types.is_empty() {
+            query_builder
+                .push(" AND resource_type <> ALL(")
+                .push_bind(
+                    excluded_types
+                        .iter()
+                        .map(|resource_type| resource_type.as_ref().to_string())
+                        .collect::<Vec<_>>(),
+                )
+                .push(")");
+        }
+        query_builder.push(" RETURNING version_id");
+
+        let removed: Vec<VersionId> = query_builder
+            .build_query_scalar()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(StoreError::from)?;
+
+        Ok(removed)
+    }
+}
+
 fn read_by_version_ids<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
     connection: Connection,
     tenant_id: &'a TenantId,
@@ -640,3 +829,28 @@ fn get_sequence<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a
         Ok(result)
     }
 }
+
+fn count_resources<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant_id: &'a TenantId,
+) -> impl Future<Output = Result<i64, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::from)?;
+
+        // Only the latest version of each resource is counted and skipped if it is a delete.
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            r#"SELECT COUNT(*) FROM (SELECT DISTINCT ON (project, resource_type, id) deleted FROM resources WHERE tenant = "#,
+        );
+        query_builder
+            .push_bind(tenant_id.as_ref())
+            .push(" ORDER BY project, resource_type, id, sequence DESC) AS latest WHERE NOT latest.deleted");
+
+        let count: i64 = query_builder
+            .build_query_scalar()
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(StoreError::from)?;
+
+        Ok(count)
+    }
+}
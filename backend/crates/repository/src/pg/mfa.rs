@@ -0,0 +1,313 @@
+use crate::{
+    admin::MultiFactorAuth,
+    pg::{PGConnection, StoreError},
+    types::mfa::UserMFA,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_jwt::TenantId;
+use sqlx::{Acquire, Postgres, QueryBuilder};
+
+fn read_mfa<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+) -> impl Future<Output = Result<Option<UserMFA>, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT tenant, user_id, totp_secret, enabled, last_used_step FROM user_mfa WHERE tenant = "#,
+        );
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id);
+
+        let mfa: Option<UserMFA> = query_builder
+            .build_query_as()
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        Ok(mfa)
+    }
+}
+
+fn enroll_mfa<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+    totp_secret: &'a str,
+) -> impl Future<Output = Result<UserMFA, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+        let mut tx = conn.begin().await.map_err(StoreError::SQLXError)?;
+
+        // Recovery codes from a previous enrolment are no longer valid.
+        let mut delete_codes =
+            QueryBuilder::new(r#"DELETE FROM user_mfa_recovery_codes WHERE tenant = "#);
+        delete_codes
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id);
+        delete_codes
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"INSERT INTO user_mfa(tenant, user_id, totp_secret, enabled) VALUES ("#,
+        );
+        let mut seperator = query_builder.separated(", ");
+        seperator
+            .push_bind(tenant.as_ref())
+            .push_bind(user_id)
+            .push_bind(totp_secret)
+            .push_bind(false);
+        query_builder.push(
+            r#") ON CONFLICT (tenant, user_id) DO UPDATE SET totp_secret = EXCLUDED.totp_secret, enabled = false, last_used_step = NULL
+                RETURNING tenant, user_id, totp_secret, enabled, last_used_step"#,
+        );
+
+        let mfa: UserMFA = query_builder
+            .build_query_as()
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        tx.commit().await.map_err(StoreError::SQLXError)?;
+
+        Ok(mfa)
+    }
+}
+
+fn enable_mfa<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+    recovery_codes: &'a [String],
+) -> impl Future<Output = Result<(), OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+        let mut tx = conn.begin().await.map_err(StoreError::SQLXError)?;
+
+        let mut query_builder =
+            QueryBuilder::new(r#"UPDATE user_mfa SET enabled = true WHERE tenant = "#);
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id);
+        query_builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        let mut delete_codes =
+            QueryBuilder::new(r#"DELETE FROM user_mfa_recovery_codes WHERE tenant = "#);
+        delete_codes
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id);
+        delete_codes
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        if !recovery_codes.is_empty() {
+            let mut insert_codes =
+                QueryBuilder::new(r#"INSERT INTO user_mfa_recovery_codes(tenant, user_id, code) "#);
+            insert_codes.push_values(recovery_codes, |mut row, code| {
+                row.push_bind(tenant.as_ref())
+                    .push_bind(user_id)
+                    .push("crypt(")
+                    .push_bind_unseparated(code)
+                    .push_unseparated(", gen_salt('bf'))");
+            });
+            insert_codes
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(StoreError::SQLXError)?;
+        }
+
+        tx.commit().await.map_err(StoreError::SQLXError)?;
+
+        Ok(())
+    }
+}
+
+fn delete_mfa<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+) -> impl Future<Output = Result<(), OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        // Recovery codes are removed via cascade.
+        let mut query_builder = QueryBuilder::new(r#"DELETE FROM user_mfa WHERE tenant = "#);
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id);
+        query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        Ok(())
+    }
+}
+
+fn use_totp_step<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+    step: i64,
+) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        let mut query_builder = QueryBuilder::new(r#"UPDATE user_mfa SET last_used_step = "#);
+        query_builder
+            .push_bind(step)
+            .push(" WHERE tenant = ")
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id)
+            .push(" AND (last_used_step IS NULL OR last_used_step < ")
+            .push_bind(step)
+            .push(")");
+
+        let result = query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn use_recovery_code<'a, 'c, Connection: Acquire<'c, Database = Postgres> + Send + 'a>(
+    connection: Connection,
+    tenant: &'a TenantId,
+    user_id: &'a str,
+    code: &'a str,
+) -> impl Future<Output = Result<bool, OperationOutcomeError>> + Send + 'a {
+    async move {
+        let mut conn = connection.acquire().await.map_err(StoreError::SQLXError)?;
+
+        let mut query_builder =
+            QueryBuilder::new(r#"DELETE FROM user_mfa_recovery_codes WHERE tenant = "#);
+        query_builder
+            .push_bind(tenant.as_ref())
+            .push(" AND user_id = ")
+            .push_bind(user_id)
+            .push(" AND code = crypt(")
+            .push_bind(code)
+            .push(", code)");
+
+        let result = query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(StoreError::SQLXError)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl MultiFactorAuth for PGConnection {
+    async fn read_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+    ) -> Result<Option<UserMFA>, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => read_mfa(pool, tenant, user_id).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                read_mfa(&mut *conn, tenant, user_id).await
+            }
+        }
+    }
+
+    async fn enroll_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        totp_secret: &str,
+    ) -> Result<UserMFA, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => enroll_mfa(pool, tenant, user_id, totp_secret).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                enroll_mfa(&mut *conn, tenant, user_id, totp_secret).await
+            }
+        }
+    }
+
+    async fn enable_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        recovery_codes: &[String],
+    ) -> Result<(), OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => enable_mfa(pool, tenant, user_id, recovery_codes).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                enable_mfa(&mut *conn, tenant, user_id, recovery_codes).await
+            }
+        }
+    }
+
+    async fn delete_mfa(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+    ) -> Result<(), OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => delete_mfa(pool, tenant, user_id).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                delete_mfa(&mut *conn, tenant, user_id).await
+            }
+        }
+    }
+
+    async fn use_totp_step(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        step: i64,
+    ) -> Result<bool, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => use_totp_step(pool, tenant, user_id, step).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                use_totp_step(&mut *conn, tenant, user_id, step).await
+            }
+        }
+    }
+
+    async fn use_recovery_code(
+        &self,
+        tenant: &TenantId,
+        user_id: &str,
+        code: &str,
+    ) -> Result<bool, OperationOutcomeError> {
+        match self {
+            PGConnection::Pool(pool, _) => use_recovery_code(pool, tenant, user_id, code).await,
+            PGConnection::Transaction(tx, _) => {
+                let mut conn = tx.lock().await;
+                use_recovery_code(&mut *conn, tenant, user_id, code).await
+            }
+        }
+    }
+}
@@ -9,8 +9,10 @@ use tokio::sync::Mutex;
 use crate::Repository;
 
 mod authorization_code;
+mod client_assertion;
 mod fhir;
 mod membership;
+mod mfa;
 mod migrate;
 mod project;
 mod scope;
@@ -13,6 +13,8 @@ pub enum AuthorizationCodeKind {
     OAuth2CodeGrant,
     #[sqlx(rename = "refresh_token")]
     RefreshToken,
+    #[sqlx(rename = "launch_context")]
+    LaunchContext,
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, sqlx::Type, serde::Deserialize, serde::Serialize)]
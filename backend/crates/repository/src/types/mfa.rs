@@ -0,0 +1,12 @@
+use haste_jwt::TenantId;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct UserMFA {
+    pub tenant: TenantId,
+    pub user_id: String,
+    /// Base32 encoded TOTP shared secret.
+    pub totp_secret: String,
+    /// MFA is only enabled after the user has verified a code from their authenticator.
+    pub enabled: bool,
+    pub last_used_step: Option<i64>,
+}
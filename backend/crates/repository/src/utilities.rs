@@ -1,11 +1,15 @@
 use haste_fhir_model::r4::generated::{
     resources::Resource,
     terminology::IssueType,
-    types::{FHIRId, Meta},
+    types::{Coding, FHIRCode, FHIRId, FHIRUri, Meta},
 };
 use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
 use haste_reflect::MetaValue;
 
+/// Tag holding the versionId an imported resource had in its source system.
+pub static SOURCE_VERSION_ID_TAG_SYSTEM: &str =
+    "https://haste.health/fhir/CodeSystem/source-version-id";
+
 static ID_CHARACTERS: &[char] = &[
     '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
     'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '-',
@@ -84,3 +88,98 @@ pub fn set_version_id(resource: &mut Resource) -> Result<(), OperationOutcomeErr
 
     Ok(())
 }
+
+/// Sets a new version id keeping the previous one as a source-version-id tag
+/// IE for imported resources whose version ids are only unique in the source system.
+pub fn set_imported_version_id(resource: &mut Resource) -> Result<(), OperationOutcomeError> {
+    let meta: &mut dyn std::any::Any =
+        resource
+            .get_field_mut("meta")
+            .ok_or(DataTransformError::InvalidData(
+                "Missing 'meta' field".to_string(),
+            ))?;
+    let meta: &mut Option<Box<Meta>> =
+        meta.downcast_mut::<Option<Box<Meta>>>()
+            .ok_or(DataTransformError::InvalidData(
+                "Invalid 'meta' field".to_string(),
+            ))?;
+
+    if let Some(meta) = meta.as_mut()
+        && let Some(source_version_id) = meta.versionId.take().and_then(|vid| vid.value)
+    {
+        let tag = Box::new(Coding {
+            system: Some(Box::new(FHIRUri {
+                value: Some(SOURCE_VERSION_ID_TAG_SYSTEM.to_string()),
+                ..Default::default()
+            })),
+            code: Some(Box::new(FHIRCode {
+                value: Some(source_version_id),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+
+        match &mut meta.tag {
+            Some(tags) => tags.push(tag),
+            None => meta.tag = Some(vec![tag]),
+        }
+    }
+
+    set_version_id(resource)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_model::r4::generated::resources::Patient;
+
+    fn version_and_tags(resource: &Resource) -> (Option<String>, Vec<(String, String)>) {
+        let Resource::Patient(patient) = resource else {
+            panic!("Expected Patient");
+        };
+        let meta = patient.meta.as_ref().unwrap();
+
+        (
+            meta.versionId.as_ref().and_then(|vid| vid.value.clone()),
+            meta.tag
+                .iter()
+                .flatten()
+                .map(|tag| {
+                    (
+                        tag.system.as_ref().and_then(|s| s.value.clone()).unwrap(),
+                        tag.code.as_ref().and_then(|c| c.value.clone()).unwrap(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_imported_version_ids_are_unique() {
+        let exported = Resource::Patient(Patient {
+            meta: Some(Box::new(Meta {
+                versionId: Some(Box::new(FHIRId {
+                    value: Some("1".to_string()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+
+        let mut first = exported.clone();
+        let mut second = exported;
+        set_imported_version_id(&mut first).unwrap();
+        set_imported_version_id(&mut second).unwrap();
+
+        let (first_version, first_tags) = version_and_tags(&first);
+        let (second_version, second_tags) = version_and_tags(&second);
+        assert!(first_version.is_some());
+        assert_ne!(first_version, Some("1".to_string()));
+        assert_ne!(first_version, second_version);
+
+        let source_tag = vec![(SOURCE_VERSION_ID_TAG_SYSTEM.to_string(), "1".to_string())];
+        assert_eq!(first_tags, source_tag);
+        assert_eq!(second_tags, source_tag);
+    }
+}
@@ -0,0 +1,543 @@
+use crate::auth_n::certificates::{JSONWebKey, JSONWebKeyAlgorithm, JSONWebKeyType};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+use p256::{
+    SecretKey,
+    elliptic_curve::sec1::ToEncodedPoint,
+    pkcs8::{DecodePrivateKey, EncodePrivateKey},
+};
+use rand::rngs::OsRng;
+use rsa::{
+    RsaPrivateKey,
+    pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
+    pkcs8::LineEnding,
+    traits::PublicKeyParts,
+};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+static KEYS_DIRECTORY: &str = "keys";
+static MANIFEST_FILENAME: &str = "manifest.json";
+static LOCK_FILENAME: &str = "rotate.lock";
+
+/// Retired keys stay published so tokens signed before a rotation verify until they expire.
+pub static RETIRED_KEY_GRACE_PERIOD: i64 = 60 * 60 * 24; // 1 day
+/// The next key is published this long before it starts signing so clients can refresh their JWKS cache.
+static PREPUBLISH_PERIOD: i64 = 60 * 60 * 24; // 1 day
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningAlgorithm {
+    RS256,
+    ES256,
+}
+
+impl TryFrom<&str> for SigningAlgorithm {
+    type Error = OperationOutcomeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "RS256" => Ok(SigningAlgorithm::RS256),
+            "ES256" => Ok(SigningAlgorithm::ES256),
+            _ => Err(OperationOutcomeError::fatal(
+                IssueType::Invalid(None),
+                format!("Unsupported JWT signing algorithm '{}'", value),
+            )),
+        }
+    }
+}
+
+impl From<SigningAlgorithm> for jsonwebtoken::Algorithm {
+    fn from(value: SigningAlgorithm) -> Self {
+        match value {
+            SigningAlgorithm::RS256 => jsonwebtoken::Algorithm::RS256,
+            SigningAlgorithm::ES256 => jsonwebtoken::Algorithm::ES256,
+        }
+    }
+}
+
+/// Times are unix timestamps in seconds.
+/// A key signs between activates_at and retires_at and is published from creation until
+/// retires_at + RETIRED_KEY_GRACE_PERIOD.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyMetadata {
+    pub kid: String,
+    pub alg: SigningAlgorithm,
+    pub created_at: i64,
+    pub activates_at: i64,
+    pub retires_at: i64,
+}
+
+impl KeyMetadata {
+    fn is_signing(&self, now: i64) -> bool {
+        self.activates_at <= now && now < self.retires_at
+    }
+
+    fn is_expired(&self, now: i64) -> bool {
+        self.retires_at + RETIRED_KEY_GRACE_PERIOD <= now
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct KeyManifest {
+    keys: Vec<KeyMetadata>,
+}
+
+pub struct LoadedKey {
+    pub metadata: KeyMetadata,
+    pub jwk: JSONWebKey,
+    pub encoding_key: jsonwebtoken::EncodingKey,
+    pub decoding_key: jsonwebtoken::DecodingKey,
+}
+
+fn io_error(e: impl std::fmt::Display) -> OperationOutcomeError {
+    OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string())
+}
+
+fn thumbprint(bytes: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(bytes);
+    URL_SAFE_NO_PAD.encode(hasher.finalize())
+}
+
+fn rsa_kid(private_key: &RsaPrivateKey) -> Result<String, OperationOutcomeError> {
+    let public_key = private_key.to_public_key();
+    Ok(thumbprint(
+        public_key.to_pkcs1_der().map_err(io_error)?.as_bytes(),
+    ))
+}
+
+fn ec_kid(secret_key: &SecretKey) -> String {
+    thumbprint(secret_key.public_key().to_encoded_point(false).as_bytes())
+}
+
+/// Generates a private key returning the kid and PEM.
+/// RSA keys are PKCS#1 and EC keys are PKCS#8.
+fn generate_key(alg: SigningAlgorithm) -> Result<(String, String), OperationOutcomeError> {
+    let mut rng = OsRng;
+    match alg {
+        SigningAlgorithm::RS256 => {
+            let private_key = RsaPrivateKey::new(&mut rng, 2048).map_err(io_error)?;
+            let pem = private_key
+                .to_pkcs1_pem(LineEnding::default())
+                .map_err(io_error)?;
+            Ok((rsa_kid(&private_key)?, pem.to_string()))
+        }
+        SigningAlgorithm::ES256 => {
+            let secret_key = SecretKey::random(&mut rng);
+            let pem = secret_key
+                .to_pkcs8_pem(LineEnding::default())
+                .map_err(io_error)?;
+            Ok((ec_kid(&secret_key), pem.to_string()))
+        }
+    }
+}
+
+/// Writes a private key readable only by the owner.
+fn write_private_key(path: &Path, pem: &str) -> Result<(), OperationOutcomeError> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path).map_err(io_error)?;
+    file.write_all(pem.as_bytes()).map_err(io_error)
+}
+
+fn load_key(metadata: KeyMetadata, pem: &str) -> Result<LoadedKey, OperationOutcomeError> {
+    match metadata.alg {
+        SigningAlgorithm::RS256 => {
+            let private_key = RsaPrivateKey::from_pkcs1_pem(pem).map_err(io_error)?;
+            let public_key = private_key.to_public_key();
+            let x5t = thumbprint(public_key.to_pkcs1_der().map_err(io_error)?.as_bytes());
+
+            Ok(LoadedKey {
+                jwk: JSONWebKey {
+                    kid: metadata.kid.clone(),
+                    alg: JSONWebKeyAlgorithm::RS256,
+                    kty: JSONWebKeyType::RSA,
+                    use_: Some("sig".to_string()),
+                    e: Some(URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be())),
+                    n: Some(URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be())),
+                    x5t: Some(x5t),
+                    crv: None,
+                    x: None,
+                    y: None,
+                },
+                encoding_key: jsonwebtoken::EncodingKey::from_rsa_pem(pem.as_bytes())
+                    .map_err(io_error)?,
+                decoding_key: jsonwebtoken::DecodingKey::from_rsa_components(
+                    &URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+                    &URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+                )
+                .map_err(io_error)?,
+                metadata,
+            })
+        }
+        SigningAlgorithm::ES256 => {
+            let secret_key = SecretKey::from_pkcs8_pem(pem).map_err(io_error)?;
+            let point = secret_key.public_key().to_encoded_point(false);
+            let x = URL_SAFE_NO_PAD.encode(point.x().ok_or_else(|| io_error("Invalid EC key"))?);
+            let y = URL_SAFE_NO_PAD.encode(point.y().ok_or_else(|| io_error("Invalid EC key"))?);
+
+            Ok(LoadedKey {
+                encoding_key: jsonwebtoken::EncodingKey::from_ec_pem(pem.as_bytes())
+                    .map_err(io_error)?,
+                decoding_key: jsonwebtoken::DecodingKey::from_ec_components(&x, &y)
+                    .map_err(io_error)?,
+                jwk: JSONWebKey {
+                    kid: metadata.kid.clone(),
+                    alg: JSONWebKeyAlgorithm::ES256,
+                    kty: JSONWebKeyType::EC,
+                    use_: Some("sig".to_string()),
+                    e: None,
+                    n: None,
+                    x5t: None,
+                    crv: Some("P-256".to_string()),
+                    x: Some(x),
+                    y: Some(y),
+                },
+                metadata,
+            })
+        }
+    }
+}
+
+/// File backed store of signing keys under CERTIFICATION_DIR/keys.
+/// Each key is stored as <kid>.pem with the activation and retirement dates kept in manifest.json.
+pub struct KeyStore {
+    directory: PathBuf,
+    algorithm: SigningAlgorithm,
+    rotation_period: i64,
+    keys: RwLock<Vec<Arc<LoadedKey>>>,
+    /// Modification time of the manifest the keys were loaded from.
+    loaded_modified: RwLock<Option<SystemTime>>,
+}
+
+impl KeyStore {
+    pub fn new(
+        certificate_dir: &Path,
+        algorithm: SigningAlgorithm,
+        rotation_period: i64,
+    ) -> Result<Self, OperationOutcomeError> {
+        let directory = certificate_dir.join(KEYS_DIRECTORY);
+        std::fs::create_dir_all(&directory).map_err(io_error)?;
+
+        Ok(KeyStore {
+            directory,
+            algorithm,
+            rotation_period,
+            keys: RwLock::new(vec![]),
+            loaded_modified: RwLock::new(None),
+        })
+    }
+
+    fn manifest_modified(&self) -> Option<SystemTime> {
+        std::fs::metadata(self.directory.join(MANIFEST_FILENAME))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    fn read_manifest(&self) -> Result<Option<KeyManifest>, OperationOutcomeError> {
+        let manifest_file = self.directory.join(MANIFEST_FILENAME);
+        if !manifest_file.exists() {
+            return Ok(None);
+        }
+
+        let manifest = std::fs::read_to_string(manifest_file).map_err(io_error)?;
+        Ok(Some(serde_json::from_str(&manifest).map_err(io_error)?))
+    }
+
+    fn write_manifest(&self, manifest: &KeyManifest) -> Result<(), OperationOutcomeError> {
+        // Write then rename so readers never see a partial manifest.
+        let temporary_file = self.directory.join(format!("{}.tmp", MANIFEST_FILENAME));
+        std::fs::write(
+            &temporary_file,
+            serde_json::to_string_pretty(manifest).map_err(io_error)?,
+        )
+        .map_err(io_error)?;
+        std::fs::rename(temporary_file, self.directory.join(MANIFEST_FILENAME)).map_err(io_error)
+    }
+
+    fn key_file(&self, kid: &str) -> PathBuf {
+        self.directory.join(format!("{}.pem", kid))
+    }
+
+    /// Exclusive lock on the directory so instances sharing it don't change keys concurrently.
+    /// Released when the returned file is dropped.
+    fn lock(&self) -> Result<File, OperationOutcomeError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.directory.join(LOCK_FILENAME))
+            .map_err(io_error)?;
+        file.lock().map_err(io_error)?;
+
+        Ok(file)
+    }
+
+    fn create_key(
+        &self,
+        activates_at: i64,
+        now: i64,
+    ) -> Result<KeyMetadata, OperationOutcomeError> {
+        let (kid, pem) = generate_key(self.algorithm)?;
+        write_private_key(&self.key_file(&kid), &pem)?;
+
+        tracing::info!("Created signing key '{}' ({:?})", kid, self.algorithm);
+
+        Ok(KeyMetadata {
+            kid,
+            alg: self.algorithm,
+            created_at: now,
+            activates_at,
+            retires_at: activates_at + self.rotation_period,
+        })
+    }
+
+    /// Imports a private_key.pem created before the key store existed so its tokens keep verifying.
+    pub fn import_legacy_key(
+        &self,
+        private_key_file: &Path,
+        now: i64,
+    ) -> Result<(), OperationOutcomeError> {
+        let _lock = self.lock()?;
+        if self.read_manifest()?.is_some() || !private_key_file.exists() {
+            return Ok(());
+        }
+
+        let pem = std::fs::read_to_string(private_key_file).map_err(io_error)?;
+        let kid = rsa_kid(&RsaPrivateKey::from_pkcs1_pem(&pem).map_err(io_error)?)?;
+        write_private_key(&self.key_file(&kid), &pem)?;
+
+        self.write_manifest(&KeyManifest {
+            keys: vec![KeyMetadata {
+                kid,
+                alg: SigningAlgorithm::RS256,
+                created_at: now,
+                activates_at: now,
+                retires_at: now + self.rotation_period,
+            }],
+        })
+    }
+
+    /// Removes expired keys, creates the signing key if missing and publishes the next key ahead of rotation.
+    /// Changing the configured algorithm retires the current key immediately.
+    pub fn rotate(&self, now: i64) -> Result<(), OperationOutcomeError> {
+        let _lock = self.lock()?;
+        let mut manifest = self.read_manifest()?.unwrap_or_default();
+        let mut changed = false;
+
+        let (expired, mut keys): (Vec<_>, Vec<_>) = std::mem::take(&mut manifest.keys)
+            .into_iter()
+            .partition(|k| k.is_expired(now));
+        for key in expired {
+            tracing::info!("Removing expired signing key '{}'", key.kid);
+            let _ = std::fs::remove_file(self.key_file(&key.kid));
+            changed = true;
+        }
+
+        // Keys not yet active under a different algorithm are discarded so the new algorithm is used.
+        keys.retain(|k| {
+            let keep = k.alg == self.algorithm || k.activates_at <= now;
+            if !keep {
+                let _ = std::fs::remove_file(self.key_file(&k.kid));
+                changed = true;
+            }
+            keep
+        });
+
+        let current = keys
+            .iter_mut()
+            .filter(|k| k.is_signing(now))
+            .max_by_key(|k| k.activates_at);
+
+        let current_retires_at = match current {
+            Some(current) if current.alg != self.algorithm => {
+                current.retires_at = now;
+                changed = true;
+                None
+            }
+            Some(current) => Some(current.retires_at),
+            None => None,
+        };
+
+        let current_retires_at = match current_retires_at {
+            Some(retires_at) => retires_at,
+            None => {
+                let key = self.create_key(now, now)?;
+                let retires_at = key.retires_at;
+                keys.push(key);
+                changed = true;
+                retires_at
+            }
+        };
+
+        let prepublish_period = std::cmp::min(PREPUBLISH_PERIOD, self.rotation_period / 2);
+        if current_retires_at - now <= prepublish_period
+            && !keys.iter().any(|k| k.activates_at >= current_retires_at)
+        {
+            keys.push(self.create_key(current_retires_at, now)?);
+            changed = true;
+        }
+
+        manifest.keys = keys;
+        if changed {
+            self.write_manifest(&manifest)?;
+        }
+
+        self.load(manifest)
+    }
+
+    fn load(&self, manifest: KeyManifest) -> Result<(), OperationOutcomeError> {
+        let modified = self.manifest_modified();
+        let keys = manifest
+            .keys
+            .into_iter()
+            .map(|metadata| {
+                let pem =
+                    std::fs::read_to_string(self.key_file(&metadata.kid)).map_err(io_error)?;
+                Ok(Arc::new(load_key(metadata, &pem)?))
+            })
+            .collect::<Result<Vec<_>, OperationOutcomeError>>()?;
+
+        *self.keys.write().unwrap() = keys;
+        *self.loaded_modified.write().unwrap() = modified;
+
+        Ok(())
+    }
+
+    /// Reloads the keys from disk when the manifest changed IE when another instance rotated keys.
+    pub fn reload(&self) -> Result<(), OperationOutcomeError> {
+        if self.manifest_modified() == *self.loaded_modified.read().unwrap() {
+            return Ok(());
+        }
+
+        match self.read_manifest()? {
+            Some(manifest) => self.load(manifest),
+            None => Ok(()),
+        }
+    }
+
+    /// Key used to sign new tokens.
+    pub fn signing_key(&self, now: i64) -> Option<Arc<LoadedKey>> {
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|k| k.metadata.is_signing(now))
+            .max_by_key(|k| k.metadata.activates_at)
+            .cloned()
+    }
+
+    pub fn find(&self, kid: &str) -> Option<Arc<LoadedKey>> {
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .find(|k| k.metadata.kid == kid)
+            .cloned()
+    }
+
+    /// Keys that can verify tokens, including upcoming and recently retired keys.
+    pub fn published_keys(&self, now: i64) -> Vec<Arc<LoadedKey>> {
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|k| !k.metadata.is_expired(now))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "haste-key-store-{}-{}",
+            name,
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let directory = temporary_directory("rotation");
+        let rotation_period = 60 * 60 * 24 * 10;
+        let store = KeyStore::new(&directory, SigningAlgorithm::ES256, rotation_period).unwrap();
+
+        let start = 1_700_000_000;
+        store.rotate(start).unwrap();
+        let first = store.signing_key(start).unwrap();
+        assert_eq!(store.published_keys(start).len(), 1);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(store.key_file(&first.metadata.kid))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Next key is published ahead of rotation but the current key keeps signing.
+        let before_rotation = start + rotation_period - 60;
+        store.rotate(before_rotation).unwrap();
+        assert_eq!(store.published_keys(before_rotation).len(), 2);
+        assert_eq!(
+            store.signing_key(before_rotation).unwrap().metadata.kid,
+            first.metadata.kid
+        );
+
+        // After rotation the previous key is still published for verification.
+        let after_rotation = start + rotation_period + 60;
+        store.rotate(after_rotation).unwrap();
+        let second = store.signing_key(after_rotation).unwrap();
+        assert_ne!(second.metadata.kid, first.metadata.kid);
+        assert!(store.find(&first.metadata.kid).is_some());
+
+        // Once the grace period passes the previous key is removed.
+        let after_grace = start + rotation_period + RETIRED_KEY_GRACE_PERIOD + 60;
+        store.rotate(after_grace).unwrap();
+        assert!(store.find(&first.metadata.kid).is_none());
+        assert!(store.find(&second.metadata.kid).is_some());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_algorithm_change_retires_current_key() {
+        let directory = temporary_directory("algorithm");
+        let rotation_period = 60 * 60 * 24 * 10;
+        let now = 1_700_000_000;
+
+        let store = KeyStore::new(&directory, SigningAlgorithm::ES256, rotation_period).unwrap();
+        store.rotate(now).unwrap();
+        let ec_key = store.signing_key(now).unwrap();
+
+        let store = KeyStore::new(&directory, SigningAlgorithm::RS256, rotation_period).unwrap();
+        store.rotate(now + 1).unwrap();
+        let rsa_key = store.signing_key(now + 1).unwrap();
+
+        assert_eq!(rsa_key.metadata.alg, SigningAlgorithm::RS256);
+        assert!(store.find(&ec_key.metadata.kid).is_some());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
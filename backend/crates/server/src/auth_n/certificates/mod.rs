@@ -1,78 +1,122 @@
-use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
-use haste_config::{Config, ConfigType, get_config};
+use haste_config::Config;
 use haste_fhir_model::r4::generated::terminology::IssueType;
 use haste_fhir_operation_error::OperationOutcomeError;
-use rand::rngs::OsRng;
-use rsa::{
-    RsaPrivateKey, RsaPublicKey,
-    pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
-    pkcs8::LineEnding,
-    traits::PublicKeyParts,
-};
-use serde::{Deserialize, Serialize};
-use sha1::{Digest, Sha1};
-use std::{path::Path, sync::LazyLock};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{path::Path, sync::OnceLock};
 
 use crate::ServerEnvironmentVariables;
 
+mod key_store;
+
+pub use key_store::{KeyMetadata, KeyStore, SigningAlgorithm};
+
 static PRIVATE_KEY_FILENAME: &str = "private_key.pem";
-static PUBLIC_KEY_FILENAME: &str = "public_key.pem";
+static DEFAULT_ROTATION_DAYS: i64 = 90;
+/// How often the background task checks whether keys need rotating.
+pub static ROTATION_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+/// How often the background task reloads keys rotated by other instances.
+pub static RELOAD_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+static KEY_STORE: OnceLock<KeyStore> = OnceLock::new();
+
+fn key_store() -> Result<&'static KeyStore, OperationOutcomeError> {
+    KEY_STORE.get().ok_or_else(|| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Signing keys have not been initialized.".to_string(),
+        )
+    })
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
 
+/// Initializes the signing key store from CERTIFICATION_DIR.
+/// JWT_SIGNING_ALGORITHM selects RS256 (default) or ES256 and JWT_KEY_ROTATION_DAYS how long a key signs for.
 pub fn create_certifications(
     config: &dyn Config<ServerEnvironmentVariables>,
 ) -> Result<(), OperationOutcomeError> {
-    let certificate_dir = config
-        .get(ServerEnvironmentVariables::CertificationDir)
-        .unwrap();
+    let certificate_dir = config.get(ServerEnvironmentVariables::CertificationDir)?;
     let dir: &Path = Path::new(&certificate_dir);
 
-    let mut rng = OsRng;
-    let bits = 2048;
+    let algorithm = match config.get(ServerEnvironmentVariables::JWTSigningAlgorithm) {
+        Ok(algorithm) => SigningAlgorithm::try_from(algorithm.as_str())?,
+        Err(_) => SigningAlgorithm::RS256,
+    };
+    let rotation_days = match config.get(ServerEnvironmentVariables::JWTKeyRotationDays) {
+        Ok(days) => days.parse::<i64>().ok().filter(|d| *d > 0).ok_or_else(|| {
+            OperationOutcomeError::fatal(
+                IssueType::Invalid(None),
+                format!("Invalid JWT_KEY_ROTATION_DAYS '{}'", days),
+            )
+        })?,
+        Err(_) => DEFAULT_ROTATION_DAYS,
+    };
 
-    let private_key_file = dir.join(PRIVATE_KEY_FILENAME);
-    let public_key_file = dir.join(PUBLIC_KEY_FILENAME);
+    let store = KeyStore::new(dir, algorithm, rotation_days * 60 * 60 * 24)?;
+    let now = now();
+    store.import_legacy_key(&dir.join(PRIVATE_KEY_FILENAME), now)?;
+    store.rotate(now)?;
 
-    // If no private key than write.
-    if !private_key_file.exists() {
-        let priv_key = RsaPrivateKey::new(&mut rng, bits).expect("failed to generate a key");
-        let pub_key = RsaPublicKey::from(&priv_key);
-        std::fs::create_dir_all(certificate_dir).unwrap();
-        std::fs::write(
-            private_key_file,
-            priv_key.to_pkcs1_pem(LineEnding::default()).unwrap(),
+    KEY_STORE.set(store).map_err(|_| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Signing keys have already been initialized.".to_string(),
         )
-        .map_err(|e| OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string()))?;
+    })
+}
 
-        std::fs::write(
-            public_key_file,
-            pub_key.to_pkcs1_pem(LineEnding::default()).unwrap(),
-        )
-        .map_err(|e| OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string()))?;
-    }
+/// Rotates the signing keys if needed, run periodically by the server.
+/// Runs on the blocking pool as rotating locks the key directory and may generate keys.
+pub async fn rotate_keys() -> Result<(), OperationOutcomeError> {
+    let store = key_store()?;
+    tokio::task::spawn_blocking(move || store.rotate(now()))
+        .await
+        .map_err(|e| OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string()))?
+}
 
-    Ok(())
+/// Reloads keys rotated by other instances, run periodically by the server.
+pub async fn reload_keys() -> Result<(), OperationOutcomeError> {
+    let store = key_store()?;
+    tokio::task::spawn_blocking(move || store.reload())
+        .await
+        .map_err(|e| OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string()))?
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum JSONWebKeyAlgorithm {
     RS256,
+    ES256,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum JSONWebKeyType {
     RSA,
+    EC,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JSONWebKey {
     kid: String,
 
     alg: JSONWebKeyAlgorithm,
     kty: JSONWebKeyType,
+    #[serde(rename = "use", skip_serializing_if = "Option::is_none")]
+    use_: Option<String>,
     // Base64 URL SAFE
-    e: String,
-    n: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     x5t: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -80,69 +124,66 @@ pub struct JSONWebKeySet {
     keys: Vec<JSONWebKey>,
 }
 
-pub static JWK_SET: LazyLock<JSONWebKeySet> = LazyLock::new(|| {
-    let config = get_config(ConfigType::Environment);
-    let certificate_dir = config
-        .get(ServerEnvironmentVariables::CertificationDir)
-        .unwrap();
-    let cert_dir: &Path = Path::new(&certificate_dir);
-    let rsa_private = RsaPrivateKey::from_pkcs1_pem(
-        &std::fs::read_to_string(&cert_dir.join(PRIVATE_KEY_FILENAME)).unwrap(),
-    )
-    .unwrap();
-    let rsa_public_key = rsa_private.to_public_key();
-
-    let mut hasher = Sha1::new();
-    hasher.update(rsa_public_key.to_pkcs1_der().unwrap().as_bytes());
-    let x5t = hasher.finalize();
-
-    let rsa_public = JSONWebKey {
-        kid: URL_SAFE_NO_PAD.encode(&x5t),
-        alg: JSONWebKeyAlgorithm::RS256,
-        kty: JSONWebKeyType::RSA,
-        e: URL_SAFE_NO_PAD.encode(&rsa_public_key.e().clone().to_bytes_be()),
-        n: URL_SAFE_NO_PAD.encode(&rsa_public_key.n().clone().to_bytes_be()),
-        x5t: Some(URL_SAFE_NO_PAD.encode(&x5t)),
+/// Current, upcoming and recently retired public keys.
+pub fn jwk_set() -> Result<JSONWebKeySet, OperationOutcomeError> {
+    let store = key_store()?;
+    let now = now();
+
+    Ok(JSONWebKeySet {
+        keys: store
+            .published_keys(now)
+            .iter()
+            .map(|k| k.jwk.clone())
+            .collect(),
+    })
+}
+
+/// Signs claims with the active key setting the kid header.
+pub fn sign<T: Serialize>(claims: &T) -> Result<String, OperationOutcomeError> {
+    let signing_key = key_store()?.signing_key(now()).ok_or_else(|| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "No active signing key.".to_string(),
+        )
+    })?;
+
+    let mut header = jsonwebtoken::Header::new(signing_key.metadata.alg.into());
+    header.kid = Some(signing_key.metadata.kid.clone());
+
+    jsonwebtoken::encode(&header, claims, &signing_key.encoding_key)
+        .map_err(|e| OperationOutcomeError::fatal(IssueType::Exception(None), e.to_string()))
+}
+
+/// Verifies a token signed by any published key.
+/// The key is selected by the kid header, tokens without a kid are checked against every key.
+/// Keys rotated by other instances are picked up by the background reload.
+pub fn decode<T: DeserializeOwned>(
+    token: &str,
+    validation: &jsonwebtoken::Validation,
+) -> Result<jsonwebtoken::TokenData<T>, jsonwebtoken::errors::Error> {
+    let store = key_store().map_err(|_| {
+        jsonwebtoken::errors::Error::from(jsonwebtoken::errors::ErrorKind::InvalidKeyFormat)
+    })?;
+    let header = jsonwebtoken::decode_header(token)?;
+    let now = now();
+
+    let candidates = match header.kid.as_ref() {
+        Some(kid) => store.find(kid).into_iter().collect::<Vec<_>>(),
+        None => store.published_keys(now),
     };
 
-    JSONWebKeySet {
-        keys: vec![rsa_public],
+    let mut result = Err(jsonwebtoken::errors::ErrorKind::InvalidSignature.into());
+    for key in candidates
+        .iter()
+        .filter(|k| jsonwebtoken::Algorithm::from(k.metadata.alg) == header.alg)
+    {
+        let mut validation = validation.clone();
+        validation.algorithms = vec![header.alg];
+        result = jsonwebtoken::decode::<T>(token, &key.decoding_key, &validation);
+        if result.is_ok() {
+            return result;
+        }
     }
-});
-
-#[allow(unused)]
-// Only used if an environment.
-static DECODING_KEY: LazyLock<jsonwebtoken::DecodingKey> = LazyLock::new(|| {
-    // let key = CERTIFICATES.public_key.clone();
-    let config = get_config(ConfigType::Environment);
-    let certificate_dir = config
-        .get(ServerEnvironmentVariables::CertificationDir)
-        .unwrap();
-    let cert_dir: &Path = Path::new(&certificate_dir);
-    jsonwebtoken::DecodingKey::from_rsa_pem(
-        &std::fs::read(cert_dir.join(PUBLIC_KEY_FILENAME)).unwrap(),
-    )
-    .unwrap()
-});
-
-static ENCODING_KEY: LazyLock<jsonwebtoken::EncodingKey> = LazyLock::new(|| {
-    // let key = CERTIFICATES.public_key.clone();
-    let config = get_config(ConfigType::Environment);
-    let certificate_dir = config
-        .get(ServerEnvironmentVariables::CertificationDir)
-        .unwrap();
-    let cert_dir: &Path = Path::new(&certificate_dir);
-    jsonwebtoken::EncodingKey::from_rsa_pem(
-        &std::fs::read(cert_dir.join(PRIVATE_KEY_FILENAME)).unwrap(),
-    )
-    .unwrap()
-});
-
-#[allow(unused)]
-pub fn decoding_key() -> &'static jsonwebtoken::DecodingKey {
-    &*DECODING_KEY
-}
 
-pub fn encoding_key() -> &'static jsonwebtoken::EncodingKey {
-    &*ENCODING_KEY
+    result
 }
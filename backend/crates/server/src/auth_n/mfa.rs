@@ -0,0 +1,184 @@
+use data_encoding::BASE32_NOPAD;
+use haste_fhir_model::r4::generated::{resources::Project, terminology::IssueType};
+use haste_fhir_operation_error::OperationOutcomeError;
+use hmac::{Hmac, Mac};
+use rand::{Rng, RngCore, rngs::OsRng};
+use sha1::Sha1;
+
+/// Authentication method references (RFC 8176) placed in the amr claim.
+pub static AMR_PASSWORD: &str = "pwd";
+pub static AMR_OTP: &str = "otp";
+pub static AMR_MFA: &str = "mfa";
+
+static TOTP_PERIOD: u64 = 30;
+static TOTP_DIGITS: u32 = 6;
+/// Number of steps either side of the current step a code is accepted for to allow for clock drift.
+static TOTP_WINDOW: i64 = 1;
+static SECRET_BYTES: usize = 20;
+
+static RECOVERY_CODE_COUNT: usize = 10;
+static RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Generates a new base32 encoded TOTP secret.
+pub fn generate_secret() -> String {
+    let mut secret = vec![0u8; SECRET_BYTES];
+    OsRng.fill_bytes(&mut secret);
+    BASE32_NOPAD.encode(&secret)
+}
+
+fn encode_uri_component(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+/// otpauth URI used by authenticator apps (typically rendered as a QR code).
+/// See https://github.com/google/google-authenticator/wiki/Key-Uri-Format
+pub fn provisioning_uri(issuer: &str, account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        encode_uri_component(issuer),
+        encode_uri_component(account),
+        secret,
+        encode_uri_component(issuer),
+        TOTP_DIGITS,
+        TOTP_PERIOD
+    )
+}
+
+fn decode_secret(secret: &str) -> Result<Vec<u8>, OperationOutcomeError> {
+    BASE32_NOPAD
+        .decode(secret.trim_end_matches('=').to_uppercase().as_bytes())
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Invalid TOTP secret.".to_string(),
+            )
+        })
+}
+
+/// HOTP value (RFC 4226) for the given counter.
+fn hotp(key: &[u8], counter: u64) -> Result<u32, OperationOutcomeError> {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).map_err(|_e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Invalid TOTP secret.".to_string(),
+        )
+    })?;
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = ((hash[offset] as u32 & 0x7f) << 24)
+        | ((hash[offset + 1] as u32) << 16)
+        | ((hash[offset + 2] as u32) << 8)
+        | (hash[offset + 3] as u32);
+
+    Ok(binary % 10u32.pow(TOTP_DIGITS))
+}
+
+fn format_code(value: u32) -> String {
+    format!("{:0width$}", value, width = TOTP_DIGITS as usize)
+}
+
+/// Verifies a TOTP code (RFC 6238) at the given unix time.
+/// Returns the matched time step so callers can reject replays of the same code.
+pub fn verify_totp(
+    secret: &str,
+    code: &str,
+    unix_time: i64,
+) -> Result<Option<i64>, OperationOutcomeError> {
+    let code = code.trim().replace(' ', "");
+    if code.len() != TOTP_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let key = decode_secret(secret)?;
+    let current_step = unix_time / TOTP_PERIOD as i64;
+
+    for step in (current_step - TOTP_WINDOW)..=(current_step + TOTP_WINDOW) {
+        if step < 0 {
+            continue;
+        }
+        let expected = format_code(hotp(&key, step as u64)?);
+        if expected == code {
+            return Ok(Some(step));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Single use recovery codes shown once to the user when enrolling.
+pub fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = OsRng;
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let chars = (0..10)
+                .map(|_| {
+                    RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char
+                })
+                .collect::<String>();
+            format!("{}-{}", &chars[0..5], &chars[5..])
+        })
+        .collect()
+}
+
+/// Recovery codes are case insensitive and the dash is optional when entered.
+pub fn normalize_recovery_code(code: &str) -> String {
+    let chars = code
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>();
+
+    if chars.len() == 10 {
+        format!("{}-{}", &chars[0..5], &chars[5..])
+    } else {
+        chars
+    }
+}
+
+/// Whether the project requires email/password users to use MFA.
+pub fn project_requires_mfa(project: &Project) -> bool {
+    project
+        .requireMfa
+        .as_ref()
+        .and_then(|require_mfa| require_mfa.value)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // RFC 6238 Appendix B SHA1 secret "12345678901234567890" truncated to 6 digits.
+        let secret = BASE32_NOPAD.encode(b"12345678901234567890");
+
+        assert_eq!(verify_totp(&secret, "287082", 59).unwrap(), Some(1));
+        assert_eq!(
+            verify_totp(&secret, "081804", 1111111109).unwrap(),
+            Some(37037036)
+        );
+        assert_eq!(
+            verify_totp(&secret, "279037", 2000000000).unwrap(),
+            Some(66666666)
+        );
+        assert_eq!(verify_totp(&secret, "000000", 59).unwrap(), None);
+    }
+
+    #[test]
+    fn test_recovery_codes() {
+        let codes = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        for code in codes.iter() {
+            assert_eq!(
+                &normalize_recovery_code(&code.to_uppercase().replace('-', "")),
+                code
+            );
+        }
+    }
+}
@@ -44,8 +44,10 @@ pub async fn basic_auth_middleware<
             &project,
             &None,
             &OAuth2TokenBody {
-                client_id: credentials.0,
+                client_id: Some(credentials.0),
                 client_secret: Some(credentials.1),
+                client_assertion: None,
+                client_assertion_type: None,
                 code: None,
                 code_verifier: None,
                 grant_type: OAuth2TokenBodyGrantType::ClientCredentials,
@@ -29,13 +29,9 @@ static VALIDATION_CONFIG: LazyLock<Validation> = LazyLock::new(|| {
     config
 });
 
-fn validate_jwt(token: &str) -> Result<UserTokenClaims, StatusCode> {
-    let result = jsonwebtoken::decode::<UserTokenClaims>(
-        token,
-        certificates::decoding_key(),
-        &*VALIDATION_CONFIG,
-    )
-    .map_err(|_| StatusCode::UNAUTHORIZED)?;
+pub fn validate_jwt(token: &str) -> Result<UserTokenClaims, StatusCode> {
+    let result = certificates::decode::<UserTokenClaims>(token, &*VALIDATION_CONFIG)
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
     Ok(result.claims)
 }
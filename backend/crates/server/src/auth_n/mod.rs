@@ -1,5 +1,6 @@
 pub mod certificates;
 pub mod global;
+pub mod mfa;
 pub mod middleware;
 pub mod oidc;
 pub mod session;
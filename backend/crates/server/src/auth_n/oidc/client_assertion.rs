@@ -0,0 +1,251 @@
+//! SMART Backend Services client authentication (private_key_jwt).
+//! See https://hl7.org/fhir/smart-app-launch/client-confidential-asymmetric.html
+use crate::auth_n::oidc::error::{OIDCError, OIDCErrorCode};
+use haste_fhir_model::r4::generated::{
+    resources::ClientApplication, terminology::ClientapplicationTokenEndpointAuthMethod,
+};
+use haste_jwt::{ProjectId, TenantId};
+use haste_repository::{Repository, admin::ClientAssertionReplay};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, jwk::JwkSet};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+pub static JWT_BEARER_CLIENT_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Client assertions can not be valid for more than five minutes.
+static MAX_ASSERTION_LIFETIME: i64 = 60 * 5;
+/// JWKS fetched from a jwks_uri are reused for this long.
+static JWKS_CACHE_TTL: Duration = Duration::from_secs(60 * 5);
+static JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+static MAX_JWKS_SIZE: usize = 64 * 1024;
+
+static JWKS_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .timeout(JWKS_FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default()
+});
+
+static JWKS_CACHE: LazyLock<Mutex<HashMap<String, (Instant, JwkSet)>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Deserialize, Debug)]
+struct ClientAssertionClaims {
+    sub: String,
+    exp: i64,
+    jti: Option<String>,
+}
+
+fn invalid_client(description: &str) -> OIDCError {
+    OIDCError::new(
+        OIDCErrorCode::InvalidClient,
+        Some(description.to_string()),
+        None,
+    )
+}
+
+pub fn uses_private_key_jwt(client_app: &ClientApplication) -> bool {
+    matches!(
+        client_app.tokenEndpointAuthMethod.as_deref(),
+        Some(ClientapplicationTokenEndpointAuthMethod::Private_key_jwt(_))
+    )
+}
+
+/// Reads the client id (sub claim) of an assertion before it is verified
+/// so the client application and its keys can be looked up.
+pub fn assertion_client_id(assertion: &str) -> Result<String, OIDCError> {
+    let mut validation = Validation::default();
+    validation.insecure_disable_signature_validation();
+    validation.validate_exp = false;
+    validation.validate_aud = false;
+    validation.set_required_spec_claims::<&str>(&[]);
+
+    let claims = jsonwebtoken::decode::<ClientAssertionClaims>(
+        assertion,
+        &DecodingKey::from_secret(&[]),
+        &validation,
+    )
+    .map_err(|_| invalid_client("Client assertion is not a valid JWT."))?
+    .claims;
+
+    Ok(claims.sub)
+}
+
+/// Fetches a JWKS, reading at most MAX_JWKS_SIZE bytes of the response.
+async fn fetch_jwks(jwks_uri: &str) -> Result<JwkSet, OIDCError> {
+    let fetch_failed = || invalid_client("Failed to fetch client application's JWKS.");
+    let too_large = || invalid_client("Client application's JWKS is too large.");
+
+    let mut response = JWKS_CLIENT
+        .get(jwks_uri)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|_| fetch_failed())?;
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_JWKS_SIZE as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|_| fetch_failed())? {
+        if body.len() + chunk.len() > MAX_JWKS_SIZE {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    serde_json::from_slice::<JwkSet>(&body)
+        .map_err(|_| invalid_client("Client application's JWKS is invalid."))
+}
+
+async fn remote_jwks(jwks_uri: &str) -> Result<JwkSet, OIDCError> {
+    if let Some((fetched_at, jwks)) = JWKS_CACHE.lock().unwrap().get(jwks_uri)
+        && fetched_at.elapsed() < JWKS_CACHE_TTL
+    {
+        return Ok(jwks.clone());
+    }
+
+    let jwks = fetch_jwks(jwks_uri).await?;
+    JWKS_CACHE
+        .lock()
+        .unwrap()
+        .insert(jwks_uri.to_string(), (Instant::now(), jwks.clone()));
+
+    Ok(jwks)
+}
+
+async fn client_jwks(client_app: &ClientApplication) -> Result<JwkSet, OIDCError> {
+    if let Some(jwks) = client_app
+        .jwks
+        .as_ref()
+        .and_then(|jwks| jwks.value.as_ref())
+    {
+        return serde_json::from_str::<JwkSet>(jwks)
+            .map_err(|_| invalid_client("Client application's registered JWKS is invalid."));
+    }
+
+    let Some(jwks_uri) = client_app
+        .jwksUri
+        .as_ref()
+        .and_then(|jwks_uri| jwks_uri.value.as_ref())
+    else {
+        return Err(invalid_client("Client application has no registered JWKS."));
+    };
+
+    remote_jwks(jwks_uri).await
+}
+
+/// Verifies a signed client assertion against the client's registered JWKS.
+/// The assertion's jti is recorded until it expires so it can not be replayed.
+pub async fn verify_client_assertion<Repo: Repository>(
+    repo: &Repo,
+    tenant: &TenantId,
+    project: &ProjectId,
+    client_app: &ClientApplication,
+    token_endpoint: &str,
+    assertion_type: Option<&str>,
+    assertion: &str,
+) -> Result<(), OIDCError> {
+    if assertion_type != Some(JWT_BEARER_CLIENT_ASSERTION_TYPE) {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidRequest,
+            Some(format!(
+                "client_assertion_type must be '{}'.",
+                JWT_BEARER_CLIENT_ASSERTION_TYPE
+            )),
+            None,
+        ));
+    }
+
+    if !uses_private_key_jwt(client_app) {
+        return Err(invalid_client(
+            "Client application is not registered for private_key_jwt authentication.",
+        ));
+    }
+
+    let client_id = client_app.id.clone().unwrap_or_default();
+
+    let header = jsonwebtoken::decode_header(assertion)
+        .map_err(|_| invalid_client("Client assertion is not a valid JWT."))?;
+
+    // Shared secrets are never registered so symmetric algorithms are rejected outright.
+    if matches!(
+        header.alg,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    ) {
+        return Err(invalid_client(
+            "Client assertion must be signed with an asymmetric algorithm.",
+        ));
+    }
+
+    let jwks = client_jwks(client_app).await?;
+    let jwk = match header.kid.as_ref() {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| invalid_client("No registered key matches the client assertion."))?;
+
+    let decoding_key = DecodingKey::from_jwk(jwk)
+        .map_err(|_| invalid_client("Client application's registered key is invalid."))?;
+
+    let mut validation = Validation::new(header.alg);
+    // The jti is only tracked until exp so assertions can not be accepted past it.
+    validation.leeway = 0;
+    validation.set_audience(&[token_endpoint]);
+    validation.set_issuer(&[&client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "sub", "aud"]);
+
+    let claims =
+        jsonwebtoken::decode::<ClientAssertionClaims>(assertion, &decoding_key, &validation)
+            .map_err(|e| {
+                tracing::warn!("Client assertion verification failed: {:?}", e);
+                invalid_client("Client assertion could not be verified.")
+            })?
+            .claims;
+
+    if claims.sub != client_id {
+        return Err(invalid_client(
+            "Client assertion sub must match the client id.",
+        ));
+    }
+
+    if claims.exp - chrono::Utc::now().timestamp()
+        > MAX_ASSERTION_LIFETIME + validation.leeway as i64
+    {
+        return Err(invalid_client(
+            "Client assertion must expire within five minutes.",
+        ));
+    }
+
+    let jti = claims
+        .jti
+        .ok_or_else(|| invalid_client("Client assertion is missing a jti claim."))?;
+
+    let first_use = repo
+        .use_client_assertion(tenant, project, &client_id, &jti, claims.exp)
+        .await
+        .map_err(|_| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to record client assertion.".to_string()),
+                None,
+            )
+        })?;
+
+    if !first_use {
+        return Err(invalid_client("Client assertion has already been used."));
+    }
+
+    Ok(())
+}
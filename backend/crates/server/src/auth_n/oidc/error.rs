@@ -72,6 +72,25 @@ pub enum OIDCErrorCode {
 
     #[serde(rename = "access_denied")]
     AccessDenied,
+
+    /**
+     * The access token provided is expired, revoked, malformed, or
+     * invalid for other reasons (RFC 6750 Section 3.1).
+     */
+    #[serde(rename = "invalid_token")]
+    InvalidToken,
+    /**
+     * The value of one or more redirection URIs is invalid
+     * (RFC 7591 Section 3.2.2).
+     */
+    #[serde(rename = "invalid_redirect_uri")]
+    InvalidRedirectUri,
+    /**
+     * The value of one of the client metadata fields is invalid and the
+     * server has rejected this request (RFC 7591 Section 3.2.2).
+     */
+    #[serde(rename = "invalid_client_metadata")]
+    InvalidClientMetadata,
 }
 
 impl From<&OIDCErrorCode> for &str {
@@ -86,6 +105,9 @@ impl From<&OIDCErrorCode> for &str {
             OIDCErrorCode::InvalidClient => "invalid_client",
             OIDCErrorCode::InvalidGrant => "invalid_grant",
             OIDCErrorCode::AccessDenied => "access_denied",
+            OIDCErrorCode::InvalidToken => "invalid_token",
+            OIDCErrorCode::InvalidRedirectUri => "invalid_redirect_uri",
+            OIDCErrorCode::InvalidClientMetadata => "invalid_client_metadata",
         }
     }
 }
@@ -126,8 +148,12 @@ impl IntoResponse for OIDCError {
 
             Redirect::to(&redirect_uri).into_response()
         } else {
+            let status = match self.code {
+                OIDCErrorCode::InvalidToken => axum::http::StatusCode::UNAUTHORIZED,
+                _ => axum::http::StatusCode::BAD_REQUEST,
+            };
             let json_body = serde_json::to_string(&self).unwrap_or_default();
-            (axum::http::StatusCode::BAD_REQUEST, json_body).into_response()
+            (status, json_body).into_response()
         }
     }
 }
@@ -0,0 +1,135 @@
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_jwt::{
+    ProjectId, TenantId,
+    scopes::{LaunchType, LaunchTypeScope, Scope, Scopes, SmartScope},
+};
+use haste_repository::{
+    Repository,
+    admin::ProjectAuthAdmin,
+    types::authorization_code::{
+        AuthorizationCode, AuthorizationCodeKind, AuthorizationCodeSearchClaims,
+        CreateAuthorizationCode,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long an opaque launch parameter can be exchanged at the authorize endpoint.
+pub static LAUNCH_CONTEXT_EXPIRATION: Duration = Duration::from_secs(60 * 10);
+
+/// SMART launch context carried from the launch parameter through authorization codes and refresh tokens.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LaunchContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patient: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encounter: Option<String>,
+    #[serde(default)]
+    pub need_patient_banner: bool,
+}
+
+impl LaunchContext {
+    /// Reads the launch context stored on an authorization code or refresh token.
+    pub fn from_code(code: &AuthorizationCode) -> Option<Self> {
+        code.meta
+            .as_ref()
+            .and_then(|meta| meta.get("launch"))
+            .and_then(|launch| serde_json::from_value::<LaunchContext>(launch.clone()).ok())
+    }
+
+    /// Whether the requested scopes ask for a patient that this context does not yet provide.
+    pub fn requires_patient(&self, scopes: &Scopes) -> bool {
+        self.patient.is_none()
+            && scopes.contains_scope(&Scope::SMART(SmartScope::LaunchType(LaunchTypeScope {
+                launch_type: LaunchType::Patient,
+            })))
+    }
+}
+
+/// Stores a launch context and returns the opaque launch parameter for it.
+/// The launch is bound to the client application and the user who will authorize it.
+pub async fn create_launch_context<Repo: Repository>(
+    repo: &Repo,
+    tenant: &TenantId,
+    project: &ProjectId,
+    user_id: &str,
+    client_id: &str,
+    context: &LaunchContext,
+) -> Result<String, OperationOutcomeError> {
+    let launch = ProjectAuthAdmin::create(
+        repo,
+        tenant,
+        project,
+        CreateAuthorizationCode {
+            membership: None,
+            expires_in: LAUNCH_CONTEXT_EXPIRATION,
+            kind: AuthorizationCodeKind::LaunchContext,
+            user_id: user_id.to_string(),
+            client_id: Some(client_id.to_string()),
+            pkce_code_challenge: None,
+            pkce_code_challenge_method: None,
+            redirect_uri: None,
+            meta: Some(sqlx::types::Json(serde_json::json!({ "launch": context }))),
+        },
+    )
+    .await?;
+
+    Ok(launch.code)
+}
+
+/// Resolves an opaque launch parameter created for the client and user.
+pub async fn resolve_launch_context<Repo: Repository>(
+    repo: &Repo,
+    tenant: &TenantId,
+    project: &ProjectId,
+    user_id: &str,
+    client_id: &str,
+    launch: &str,
+) -> Result<LaunchContext, OperationOutcomeError> {
+    let code = ProjectAuthAdmin::<CreateAuthorizationCode, _, _, _, _>::search(
+        repo,
+        tenant,
+        project,
+        &AuthorizationCodeSearchClaims {
+            client_id: Some(client_id.to_string()),
+            code: Some(launch.to_string()),
+            kind: Some(AuthorizationCodeKind::LaunchContext),
+            user_id: Some(user_id.to_string()),
+            user_agent: None,
+            is_expired: Some(false),
+        },
+    )
+    .await?
+    .into_iter()
+    .next();
+
+    code.as_ref()
+        .and_then(LaunchContext::from_code)
+        .ok_or_else(|| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                "Launch parameter is invalid or has expired.".to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_patient() {
+        let scopes = Scopes::try_from("launch/patient patient/*.r").unwrap();
+
+        assert!(LaunchContext::default().requires_patient(&scopes));
+        assert!(
+            !LaunchContext {
+                patient: Some("123".to_string()),
+                ..Default::default()
+            }
+            .requires_patient(&scopes)
+        );
+        assert!(!LaunchContext::default().requires_patient(&Scopes::try_from("openid").unwrap()));
+    }
+}
@@ -1,7 +1,9 @@
+pub mod client_assertion;
 mod code_verification;
 pub mod error;
 mod extract;
 pub mod hardcoded_clients;
+pub mod launch_context;
 mod middleware;
 pub mod routes;
 pub mod schemas;
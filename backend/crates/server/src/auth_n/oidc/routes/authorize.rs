@@ -1,10 +1,16 @@
 use crate::{
     auth_n::{
+        mfa,
         oidc::{
             error::{OIDCError, OIDCErrorCode},
             extract::{client_app::OIDCClientApplication, scopes::Scopes},
+            launch_context::{LaunchContext, resolve_launch_context},
             middleware::OIDCParameters,
-            routes::scope::{ScopeForm, verify_requested_scope_is_subset},
+            routes::{
+                launch::search_patients,
+                route_string::oidc_route_string,
+                scope::{ScopeForm, verify_requested_scope_is_subset},
+            },
             utilities::is_valid_redirect_url,
         },
         session,
@@ -32,9 +38,10 @@ use haste_repository::{
         },
         membership::{Membership, MembershipSearchClaims},
         scope::{ClientId, CreateScope, ScopeKey, UserId},
-        user::{User, UserRole},
+        user::{AuthMethod, User, UserRole},
     },
 };
+use serde_json::json;
 use std::{sync::Arc, time::Duration};
 use tower_sessions::Session;
 
@@ -178,6 +185,48 @@ pub async fn authorize<
         ));
     }
 
+    let authentication_methods =
+        session::user::get_authentication_methods(&current_session, &tenant)
+            .await
+            .map_err(|_e| {
+                OIDCError::new(
+                    OIDCErrorCode::ServerError,
+                    Some("Failed to retrieve authentication methods from session.".to_string()),
+                    Some(redirect_uri.to_string()),
+                )
+            })?;
+
+    // Sessions created before the project required MFA must sign in again.
+    if mfa::project_requires_mfa(&project_resource)
+        && user.method == AuthMethod::EmailPassword
+        && !authentication_methods.iter().any(|m| m == mfa::AMR_OTP)
+    {
+        session::user::clear_user(&current_session, &tenant)
+            .await
+            .map_err(|_e| {
+                OIDCError::new(
+                    OIDCErrorCode::ServerError,
+                    Some("Failed to clear user session.".to_string()),
+                    Some(redirect_uri.to_string()),
+                )
+            })?;
+
+        // Parameters may have come from a POST body so rebuild the query from them.
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(oidc_params.parameters.iter())
+            .finish();
+        let login_route = oidc_route_string(&tenant, &project, "interactions/login");
+        return Ok(Redirect::to(
+            &(login_route
+                .to_str()
+                .expect("Failed to create login route.")
+                .to_string()
+                + "?"
+                + &query),
+        )
+        .into_response());
+    }
+
     let Some(code_challenge) = oidc_params.parameters.get("code_challenge") else {
         return Err(OIDCError::new(
             OIDCErrorCode::InvalidRequest,
@@ -254,12 +303,77 @@ pub async fn authorize<
                 code_challenge: code_challenge.to_string(),
                 code_challenge_method: String::from(code_challenge_method),
                 redirect_uri: redirect_uri.to_string(),
+                launch: oidc_params.parameters.get("launch").cloned(),
                 accept: None,
             },
         )
         .into_response());
     }
 
+    // EHR launches pass an opaque launch parameter bound to this client and user.
+    let launch_context = match oidc_params.parameters.get("launch") {
+        Some(launch) => Some(
+            resolve_launch_context(
+                &*app_state.repo,
+                &tenant,
+                &project,
+                &user.id,
+                &client_id,
+                launch,
+            )
+            .await
+            .map_err(|_e| {
+                OIDCError::new(
+                    OIDCErrorCode::InvalidRequest,
+                    Some("Launch parameter is invalid or has expired.".to_string()),
+                    Some(redirect_uri.to_string()),
+                )
+            })?,
+        ),
+        None => None,
+    };
+
+    // Standalone launches requesting launch/patient pick the patient here.
+    if launch_context
+        .as_ref()
+        .unwrap_or(&LaunchContext::default())
+        .requires_patient(&scopes)
+    {
+        let patients = search_patients(
+            &*app_state.repo,
+            &*app_state.search,
+            &tenant,
+            &project,
+            None,
+        )
+        .await
+        .map_err(|_e| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to search for patients.".to_string()),
+                Some(redirect_uri.to_string()),
+            )
+        })?;
+
+        return Ok(pages::patient_picker::patient_picker_html(
+            &tenant,
+            &project_resource,
+            &client_app,
+            &oidc_params.parameters,
+            &patients,
+            None,
+        )
+        .into_response());
+    }
+
+    let mut code_meta = serde_json::Map::new();
+    if !authentication_methods.is_empty() {
+        code_meta.insert("amr".to_string(), json!(authentication_methods));
+    }
+    if let Some(launch_context) = launch_context {
+        code_meta.insert("launch".to_string(), json!(launch_context));
+    }
+
     let authorization_code = ProjectAuthAdmin::create(
         &*app_state.repo,
         &tenant,
@@ -273,7 +387,11 @@ pub async fn authorize<
             pkce_code_challenge: Some(code_challenge.to_string()),
             pkce_code_challenge_method: Some(code_challenge_method),
             redirect_uri: Some(redirect_uri.to_string()),
-            meta: None,
+            meta: if code_meta.is_empty() {
+                None
+            } else {
+                Some(sqlx::types::Json(serde_json::Value::Object(code_meta)))
+            },
         },
     )
     .await
@@ -1,7 +1,7 @@
 use crate::{
     auth_n::oidc::{
         error::{OIDCError, OIDCErrorCode},
-        routes::{AUTH_NESTED_PATH, authorize, jwks, token},
+        routes::{AUTH_NESTED_PATH, authorize, jwks, register, token},
     },
     extract::path_tenant::{ProjectIdentifier, TenantIdentifier},
     services::AppState,
@@ -26,6 +26,8 @@ pub struct WellKnownDiscoveryDocument {
     pub authorization_endpoint: String,
     pub jwks_uri: String,
     pub token_endpoint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_endpoint: Option<String>,
     pub scopes_supported: Vec<String>,
     pub response_types_supported: Vec<String>,
     pub token_endpoint_auth_methods_supported: Vec<String>,
@@ -33,6 +35,24 @@ pub struct WellKnownDiscoveryDocument {
     pub subject_types_supported: Vec<String>,
 }
 
+/// SMART App Launch discovery document served at [base]/.well-known/smart-configuration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SmartConfigurationDocument {
+    pub issuer: String,
+    pub jwks_uri: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_endpoint: Option<String>,
+    pub grant_types_supported: Vec<String>,
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    pub token_endpoint_auth_signing_alg_values_supported: Vec<String>,
+    pub scopes_supported: Vec<String>,
+    pub response_types_supported: Vec<String>,
+    pub code_challenge_methods_supported: Vec<String>,
+    pub capabilities: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OAuthProtectedResourceDocument {
     /**
@@ -169,7 +189,7 @@ pub struct OAuthProtectedResourceDocument {
     dpop_bound_access_tokens_required: Option<bool>,
 }
 
-fn construct_oidc_route(tenant: &TenantId, project: &ProjectId, path: &str) -> String {
+pub fn construct_oidc_route(tenant: &TenantId, project: &ProjectId, path: &str) -> String {
     format!(
         "/w/{}/{}/api/v1/oidc{}",
         tenant.as_ref(),
@@ -304,12 +324,19 @@ pub fn create_oidc_discovery_document(
         &(AUTH_NESTED_PATH.to_string() + token::TokenPath.to_string().as_str()),
     );
 
+    let registration_path = construct_oidc_route(
+        &tenant,
+        &project,
+        &(AUTH_NESTED_PATH.to_string() + register::RegisterPath.to_string().as_str()),
+    );
+
     let jwks_path = construct_oidc_route(&tenant, &project, &(jwks::JWKSPath.to_string().as_str()));
 
     let oidc_response = WellKnownDiscoveryDocument {
         issuer: api_url.to_string(),
         authorization_endpoint: api_url.join(&authorize_path).unwrap().to_string(),
         token_endpoint: api_url.join(&token_path).unwrap().to_string(),
+        registration_endpoint: Some(api_url.join(&registration_path).unwrap().to_string()),
         jwks_uri: api_url.join(&jwks_path).unwrap().to_string(),
         scopes_supported: vec![
             "openid".to_string(),
@@ -325,6 +352,7 @@ pub fn create_oidc_discovery_document(
         token_endpoint_auth_methods_supported: vec![
             "client_secret_basic".to_string(),
             "client_secret_post".to_string(),
+            "private_key_jwt".to_string(),
         ],
         id_token_signing_alg_values_supported: vec!["RS256".to_string()],
         subject_types_supported: vec!["public".to_string()],
@@ -353,3 +381,77 @@ pub async fn openid_configuration<
         &api_url_string,
     )?))
 }
+
+pub async fn smart_configuration<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+) -> Result<Json<SmartConfigurationDocument>, OIDCError> {
+    let api_url_string = state
+        .config
+        .get(crate::ServerEnvironmentVariables::APIURI)
+        .unwrap_or_default();
+
+    let oidc_document = create_oidc_discovery_document(&tenant, &project, &api_url_string)?;
+
+    Ok(Json(SmartConfigurationDocument {
+        issuer: oidc_document.issuer,
+        jwks_uri: oidc_document.jwks_uri,
+        authorization_endpoint: oidc_document.authorization_endpoint,
+        token_endpoint: oidc_document.token_endpoint,
+        registration_endpoint: oidc_document.registration_endpoint,
+        grant_types_supported: vec![
+            "authorization_code".to_string(),
+            "client_credentials".to_string(),
+            "refresh_token".to_string(),
+        ],
+        token_endpoint_auth_methods_supported: oidc_document.token_endpoint_auth_methods_supported,
+        token_endpoint_auth_signing_alg_values_supported: vec![
+            "RS256".to_string(),
+            "RS384".to_string(),
+            "ES256".to_string(),
+            "ES384".to_string(),
+        ],
+        scopes_supported: vec![
+            "openid",
+            "profile",
+            "fhirUser",
+            "launch",
+            "launch/patient",
+            "launch/encounter",
+            "offline_access",
+            "online_access",
+            "patient/*.rs",
+            "user/*.cruds",
+            "system/*.cruds",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect(),
+        response_types_supported: vec!["code".to_string()],
+        code_challenge_methods_supported: vec!["S256".to_string()],
+        capabilities: vec![
+            "launch-ehr",
+            "launch-standalone",
+            "client-public",
+            "client-confidential-symmetric",
+            "client-confidential-asymmetric",
+            "context-ehr-patient",
+            "context-ehr-encounter",
+            "context-standalone-patient",
+            "context-banner",
+            "permission-offline",
+            "permission-patient",
+            "permission-user",
+            "permission-v2",
+            "sso-openid-connect",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect(),
+    }))
+}
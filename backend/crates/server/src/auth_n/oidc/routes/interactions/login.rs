@@ -1,5 +1,6 @@
 use crate::{
     auth_n::{
+        mfa,
         oidc::{
             extract::client_app::OIDCClientApplication, routes::authorize::redirect_authorize_uri,
         },
@@ -28,6 +29,7 @@ use haste_fhir_terminology::FHIRTerminology;
 use haste_jwt::ProjectId;
 use haste_repository::{
     Repository,
+    admin::MultiFactorAuth,
     types::user::{LoginMethod, LoginResult},
 };
 use maud::Markup;
@@ -163,7 +165,39 @@ pub async fn login_post<
 
     match login_result {
         LoginResult::Success { user } => {
+            let mfa_enabled = state
+                .repo
+                .read_mfa(&tenant, &user.id)
+                .await?
+                .map(|user_mfa| user_mfa.enabled)
+                .unwrap_or(false);
+
+            // Password is verified but the user must complete MFA before being signed in.
+            if mfa_enabled || mfa::project_requires_mfa(&project_resource) {
+                session::user::clear_user(&current_session, &tenant).await?;
+                session::mfa::set_pending_user(&current_session, &tenant, &user).await?;
+
+                let mfa_path = if mfa_enabled {
+                    "/interactions/mfa"
+                } else {
+                    "/interactions/mfa/enroll"
+                };
+
+                return Ok(Redirect::to(
+                    &(uri.path().replace("/interactions/login", mfa_path)
+                        + "?"
+                        + uri.query().unwrap_or("")),
+                )
+                .into_response());
+            }
+
             session::user::set_user(&current_session, &tenant, &user).await?;
+            session::user::set_authentication_methods(
+                &current_session,
+                &tenant,
+                &vec![mfa::AMR_PASSWORD.to_string()],
+            )
+            .await?;
             let authorization_redirect =
                 Redirect::to(&(redirect_authorize_uri(&uri, "/interactions/login")));
 
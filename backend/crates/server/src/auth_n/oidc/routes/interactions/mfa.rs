@@ -0,0 +1,321 @@
+use crate::{
+    auth_n::{
+        mfa,
+        oidc::routes::{authorize::redirect_authorize_uri, route_string::oidc_route_string},
+        session,
+    },
+    extract::path_tenant::{Project, ProjectIdentifier, TenantIdentifier},
+    services::AppState,
+    ui::pages,
+};
+use axum::{
+    Form,
+    extract::{OriginalUri, State},
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::{extract::Cached, routing::TypedPath};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, TenantId};
+use haste_repository::{
+    Repository,
+    admin::MultiFactorAuth,
+    types::user::{AuthMethod, User},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tower_sessions::Session;
+
+/// Failed verifications allowed before the user has to sign in again.
+static MAX_FAILED_ATTEMPTS: u32 = 5;
+
+#[derive(TypedPath)]
+#[typed_path("/mfa")]
+pub struct MFAVerify;
+
+#[derive(TypedPath)]
+#[typed_path("/mfa/enroll")]
+pub struct MFAEnroll;
+
+#[derive(Deserialize)]
+pub struct MFAVerifyForm {
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MFAEnrollForm {
+    pub code: String,
+}
+
+fn login_redirect(tenant: &TenantId, project: &ProjectId, uri: &OriginalUri) -> Response {
+    let login_route = oidc_route_string(tenant, project, "interactions/login");
+    Redirect::to(
+        &(login_route
+            .to_str()
+            .expect("Failed to create login route.")
+            .to_string()
+            + "?"
+            + uri.query().unwrap_or("")),
+    )
+    .into_response()
+}
+
+/// Moves the pending user into the session as fully authenticated.
+async fn complete_login(
+    current_session: &Session,
+    tenant: &TenantId,
+    user: &User,
+) -> Result<(), OperationOutcomeError> {
+    session::mfa::clear_pending_user(current_session, tenant).await?;
+    session::user::set_user(current_session, tenant, user).await?;
+    session::user::set_authentication_methods(
+        current_session,
+        tenant,
+        &vec![
+            mfa::AMR_PASSWORD.to_string(),
+            mfa::AMR_OTP.to_string(),
+            mfa::AMR_MFA.to_string(),
+        ],
+    )
+    .await
+}
+
+pub async fn mfa_verify_get(
+    _: MFAVerify,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    Cached(current_session): Cached<Session>,
+    uri: OriginalUri,
+) -> Result<Response, OperationOutcomeError> {
+    if session::mfa::get_pending_user(&current_session, &tenant)
+        .await?
+        .is_none()
+    {
+        return Ok(login_redirect(&tenant, &project, &uri));
+    }
+
+    Ok(
+        pages::mfa::mfa_verify_html(&tenant, &project_resource, &uri.to_string(), None)
+            .into_response(),
+    )
+}
+
+pub async fn mfa_verify_post<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    _: MFAVerify,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    Cached(current_session): Cached<Session>,
+    uri: OriginalUri,
+    Form(form): Form<MFAVerifyForm>,
+) -> Result<Response, OperationOutcomeError> {
+    let Some(user) = session::mfa::get_pending_user(&current_session, &tenant).await? else {
+        return Ok(login_redirect(&tenant, &project, &uri));
+    };
+
+    let Some(user_mfa) = state
+        .repo
+        .read_mfa(&tenant, &user.id)
+        .await?
+        .filter(|user_mfa| user_mfa.enabled)
+    else {
+        return Ok(login_redirect(&tenant, &project, &uri));
+    };
+
+    let verified = if let Some(code) = form.code.as_ref().filter(|c| !c.trim().is_empty()) {
+        match mfa::verify_totp(&user_mfa.totp_secret, code, chrono::Utc::now().timestamp())? {
+            Some(step) => state.repo.use_totp_step(&tenant, &user.id, step).await?,
+            None => false,
+        }
+    } else if let Some(recovery_code) = form.recovery_code.as_ref().filter(|c| !c.trim().is_empty())
+    {
+        state
+            .repo
+            .use_recovery_code(
+                &tenant,
+                &user.id,
+                &mfa::normalize_recovery_code(recovery_code),
+            )
+            .await?
+    } else {
+        false
+    };
+
+    if !verified {
+        if session::mfa::record_failed_attempt(&current_session, &tenant).await?
+            >= MAX_FAILED_ATTEMPTS
+        {
+            session::mfa::clear_pending_user(&current_session, &tenant).await?;
+            return Ok(login_redirect(&tenant, &project, &uri));
+        }
+
+        return Ok(pages::mfa::mfa_verify_html(
+            &tenant,
+            &project_resource,
+            &uri.to_string(),
+            Some(vec!["Invalid code. Please try again.".to_string()]),
+        )
+        .into_response());
+    }
+
+    complete_login(&current_session, &tenant, &user).await?;
+
+    Ok(Redirect::to(&redirect_authorize_uri(&uri, "/interactions/mfa")).into_response())
+}
+
+/// User being enrolled, either mid login (pending) or already signed in.
+async fn enrolling_user(
+    current_session: &Session,
+    tenant: &TenantId,
+) -> Result<Option<User>, OperationOutcomeError> {
+    if let Some(user) = session::mfa::get_pending_user(current_session, tenant).await? {
+        return Ok(Some(user));
+    }
+
+    Ok(session::user::get_user(current_session, tenant)
+        .await?
+        .filter(|user| user.method == AuthMethod::EmailPassword))
+}
+
+fn issuer(project: &haste_fhir_model::r4::generated::resources::Project) -> String {
+    project
+        .name
+        .value
+        .clone()
+        .or_else(|| project.id.clone())
+        .unwrap_or_else(|| "Haste Health".to_string())
+}
+
+pub async fn mfa_enroll_get<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    _: MFAEnroll,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    Cached(current_session): Cached<Session>,
+    uri: OriginalUri,
+) -> Result<Response, OperationOutcomeError> {
+    let Some(user) = enrolling_user(&current_session, &tenant).await? else {
+        return Ok(login_redirect(&tenant, &project, &uri));
+    };
+
+    // Re-enrolling would allow bypassing an existing authenticator.
+    let user_mfa = match state.repo.read_mfa(&tenant, &user.id).await? {
+        Some(user_mfa) if user_mfa.enabled => {
+            return Ok(pages::message::message_html(
+                &tenant,
+                &project_resource,
+                maud::html! {
+                    p class="text-slate-700" { "Two-factor authentication is already enabled for your account." }
+                },
+            )
+            .into_response());
+        }
+        // Keep the secret stable across reloads of the page.
+        Some(user_mfa) => user_mfa,
+        None => {
+            state
+                .repo
+                .enroll_mfa(&tenant, &user.id, &mfa::generate_secret())
+                .await?
+        }
+    };
+
+    let provisioning_uri = mfa::provisioning_uri(
+        &issuer(&project_resource),
+        user.email.as_ref().unwrap_or(&user.id),
+        &user_mfa.totp_secret,
+    );
+
+    Ok(pages::mfa::mfa_enroll_html(
+        &tenant,
+        &project_resource,
+        &uri.to_string(),
+        &provisioning_uri,
+        &user_mfa.totp_secret,
+        None,
+    )
+    .into_response())
+}
+
+pub async fn mfa_enroll_post<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    _: MFAEnroll,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    Cached(current_session): Cached<Session>,
+    uri: OriginalUri,
+    Form(form): Form<MFAEnrollForm>,
+) -> Result<Response, OperationOutcomeError> {
+    let Some(user) = enrolling_user(&current_session, &tenant).await? else {
+        return Ok(login_redirect(&tenant, &project, &uri));
+    };
+
+    let Some(user_mfa) = state
+        .repo
+        .read_mfa(&tenant, &user.id)
+        .await?
+        .filter(|user_mfa| !user_mfa.enabled)
+    else {
+        return Ok(Redirect::to(&uri.to_string()).into_response());
+    };
+
+    let step = mfa::verify_totp(
+        &user_mfa.totp_secret,
+        &form.code,
+        chrono::Utc::now().timestamp(),
+    )?;
+
+    let Some(step) = step else {
+        let provisioning_uri = mfa::provisioning_uri(
+            &issuer(&project_resource),
+            user.email.as_ref().unwrap_or(&user.id),
+            &user_mfa.totp_secret,
+        );
+
+        return Ok(pages::mfa::mfa_enroll_html(
+            &tenant,
+            &project_resource,
+            &uri.to_string(),
+            &provisioning_uri,
+            &user_mfa.totp_secret,
+            Some(vec!["Invalid code. Please try again.".to_string()]),
+        )
+        .into_response());
+    };
+
+    state.repo.use_totp_step(&tenant, &user.id, step).await?;
+    let recovery_codes = mfa::generate_recovery_codes();
+    state
+        .repo
+        .enable_mfa(&tenant, &user.id, &recovery_codes)
+        .await?;
+
+    // Verifying the first code counts as completing MFA for this login.
+    complete_login(&current_session, &tenant, &user).await?;
+
+    Ok(pages::mfa::recovery_codes_html(
+        &tenant,
+        &project_resource,
+        &recovery_codes,
+        &redirect_authorize_uri(&uri, "/interactions/mfa/enroll"),
+    )
+    .into_response())
+}
@@ -15,6 +15,7 @@ use tower::ServiceBuilder;
 
 mod login;
 mod logout;
+mod mfa;
 mod password_reset;
 
 pub fn interactions_router<
@@ -35,6 +36,15 @@ pub fn interactions_router<
             (*AUTHORIZE_PARAMETERS).clone(),
         )));
 
+    let mfa_routes = Router::new()
+        .typed_get(mfa::mfa_verify_get)
+        .typed_post(mfa::mfa_verify_post)
+        .typed_get(mfa::mfa_enroll_get)
+        .typed_post(mfa::mfa_enroll_post)
+        .route_layer(ServiceBuilder::new().layer(OIDCParameterInjectLayer::new(
+            (*AUTHORIZE_PARAMETERS).clone(),
+        )));
+
     let logout_routes = Router::new()
         .typed_post(logout::logout)
         .typed_get(logout::logout)
@@ -45,6 +55,7 @@ pub fn interactions_router<
 
     Router::new()
         .merge(login_routes)
+        .merge(mfa_routes)
         .merge(logout_routes)
         .merge(password_reset_routes)
 }
@@ -1,6 +1,7 @@
 use crate::{
     ServerEnvironmentVariables,
     auth_n::oidc::utilities::set_user_password,
+    email::EmailMessage,
     extract::path_tenant::{Project, ProjectIdentifier, TenantIdentifier},
     services::AppState,
     ui::{
@@ -14,7 +15,6 @@ use axum::{
     http::Uri,
 };
 use axum_extra::{extract::Cached, routing::TypedPath};
-use haste_config::Config;
 use haste_fhir_model::r4::generated::terminology::IssueType;
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_search::SearchEngine;
@@ -28,38 +28,9 @@ use haste_repository::{
     },
 };
 use maud::{Markup, html};
-use sendgrid::v3::{Content, Email, Message, Personalization, Sender};
 use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
 
-async fn send_email(
-    config: &dyn Config<ServerEnvironmentVariables>,
-    to: &str,
-    subject: &str,
-    body: &str,
-) -> Result<(), OperationOutcomeError> {
-    let from_address = config.get(ServerEnvironmentVariables::EmailFromAddress)?;
-    let api_key = config.get(ServerEnvironmentVariables::SendGridAPIKey)?;
-    let sender = Sender::new(api_key, None);
-
-    let m = Message::new(Email::new(&from_address))
-        .set_subject(subject)
-        .add_content(Content::new().set_content_type("text/html").set_value(body))
-        .add_personalization(Personalization::new(Email::new(to)));
-
-    let resp = sender.send(&m).await.map_err(|e| {
-        tracing::error!("Failed to send email '{}'", e);
-        OperationOutcomeError::fatal(
-            IssueType::Exception(None),
-            "Failed to send email".to_string(),
-        )
-    })?;
-
-    tracing::info!("Email sent status: '{}'", resp.status());
-
-    Ok(())
-}
-
 #[derive(TypedPath)]
 #[typed_path("/password-reset")]
 pub struct PasswordResetInitiate;
@@ -168,13 +139,14 @@ pub async fn password_reset_initiate_post<
             )
         })?;
 
-        send_email(
-            &*state.config,
-            email,
-            "Password Reset",
-            &password_reset_html.into_string(),
-        )
-        .await?;
+        state
+            .email
+            .send(&EmailMessage {
+                to: email,
+                subject: "Password Reset",
+                html: &password_reset_html.into_string(),
+            })
+            .await?;
 
         Ok(message_html(
             &tenant,
@@ -1,11 +1,12 @@
-use crate::auth_n::certificates::{JSONWebKeySet, JWK_SET};
+use crate::auth_n::certificates::{self, JSONWebKeySet};
 use axum::Json;
 use axum_extra::routing::TypedPath;
+use haste_fhir_operation_error::OperationOutcomeError;
 
 #[derive(TypedPath)]
 #[typed_path("/certs/jwks")]
 pub struct JWKSPath;
 
-pub async fn jwks_get(_: JWKSPath) -> Json<&'static JSONWebKeySet> {
-    Json(&*JWK_SET)
+pub async fn jwks_get(_: JWKSPath) -> Result<Json<JSONWebKeySet>, OperationOutcomeError> {
+    Ok(Json(certificates::jwk_set()?))
 }
@@ -0,0 +1,260 @@
+use crate::{
+    auth_n::{
+        oidc::{
+            error::{OIDCError, OIDCErrorCode},
+            extract::client_app::OIDCClientApplication,
+            launch_context::{LaunchContext, create_launch_context, resolve_launch_context},
+            middleware::OIDCParameters,
+            routes::route_string::oidc_route_string,
+        },
+        session,
+    },
+    extract::path_tenant::{Project, ProjectIdentifier, TenantIdentifier},
+    services::AppState,
+    ui::pages,
+};
+use axum::{
+    Extension, Form,
+    extract::State,
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::{extract::Cached, routing::TypedPath};
+use haste_fhir_client::{
+    request::{FHIRSearchTypeRequest, SearchRequest},
+    url::{Parameter, ParsedParameter, ParsedParameters},
+};
+use haste_fhir_model::r4::generated::resources::{Patient, Resource, ResourceType};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, ResourceId, TenantId};
+use haste_repository::{Repository, fhir::CachePolicy, types::SupportedFHIRVersions};
+use serde::Deserialize;
+use std::sync::Arc;
+use tower_sessions::Session;
+
+/// Maximum number of patients listed in the picker.
+static PATIENT_PICKER_LIMIT: usize = 20;
+
+#[derive(TypedPath)]
+#[typed_path("/launch/patient")]
+pub struct LaunchPatientPath;
+
+#[derive(Deserialize, Debug)]
+pub struct PatientSelectionForm {
+    pub patient: Option<String>,
+    pub name: Option<String>,
+}
+
+/// Patients listed in the picker, optionally filtered by name.
+pub async fn search_patients<Repo: Repository, Search: SearchEngine>(
+    repo: &Repo,
+    search: &Search,
+    tenant: &TenantId,
+    project: &ProjectId,
+    name: Option<&str>,
+) -> Result<Vec<Patient>, OperationOutcomeError> {
+    let mut parameters = vec![ParsedParameter::Result(Parameter {
+        name: "_count".to_string(),
+        value: vec![PATIENT_PICKER_LIMIT.to_string()],
+        modifier: None,
+        chains: None,
+    })];
+
+    if let Some(name) = name.filter(|name| !name.is_empty()) {
+        parameters.push(ParsedParameter::Resource(Parameter {
+            name: "name".to_string(),
+            value: vec![name.to_string()],
+            modifier: None,
+            chains: None,
+        }));
+    }
+
+    let result = search
+        .search(
+            &SupportedFHIRVersions::R4,
+            tenant,
+            project,
+            &SearchRequest::Type(FHIRSearchTypeRequest {
+                resource_type: ResourceType::Patient,
+                parameters: ParsedParameters::new(parameters),
+            }),
+            None,
+        )
+        .await?;
+
+    let version_ids = result
+        .entries
+        .iter()
+        .map(|entry| &entry.version_id)
+        .collect::<Vec<_>>();
+
+    let patients = repo
+        .read_by_version_ids(tenant, project, &version_ids, CachePolicy::NoCache)
+        .await?
+        .into_iter()
+        .filter_map(|resource| match resource {
+            Resource::Patient(patient) => Some(patient),
+            _ => None,
+        })
+        .collect();
+
+    Ok(patients)
+}
+
+/// Standalone launch patient selection.
+/// Selecting a patient creates a launch context and resumes authorization with it.
+pub async fn launch_patient_post<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    _: LaunchPatientPath,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    State(app_state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    OIDCClientApplication(client_app): OIDCClientApplication,
+    Extension(oidc_params): Extension<OIDCParameters>,
+    Cached(current_session): Cached<Session>,
+    Form(selection): Form<PatientSelectionForm>,
+) -> Result<Response, OIDCError> {
+    let redirect_uri = oidc_params.parameters.get("redirect_uri").cloned();
+
+    let Some(patient_id) = selection.patient.filter(|patient| !patient.is_empty()) else {
+        let patients = search_patients(
+            &*app_state.repo,
+            &*app_state.search,
+            &tenant,
+            &project,
+            selection.name.as_deref(),
+        )
+        .await
+        .map_err(|_e| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to search for patients.".to_string()),
+                redirect_uri.clone(),
+            )
+        })?;
+
+        return Ok(pages::patient_picker::patient_picker_html(
+            &tenant,
+            &project_resource,
+            &client_app,
+            &oidc_params.parameters,
+            &patients,
+            selection.name.as_deref(),
+        )
+        .into_response());
+    };
+
+    let user = session::user::get_user(&current_session, &tenant)
+        .await
+        .map_err(|_e| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to retrieve user from session.".to_string()),
+                redirect_uri.clone(),
+            )
+        })?
+        .unwrap();
+
+    let client_id = client_app.id.clone().ok_or_else(|| {
+        OIDCError::new(
+            OIDCErrorCode::ServerError,
+            Some("Failed to retrieve client ID.".to_string()),
+            redirect_uri.clone(),
+        )
+    })?;
+
+    let patient = app_state
+        .repo
+        .read_latest(
+            &tenant,
+            &project,
+            &ResourceType::Patient,
+            &ResourceId::new(patient_id.clone()),
+        )
+        .await
+        .map_err(|_e| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to read patient.".to_string()),
+                redirect_uri.clone(),
+            )
+        })?;
+
+    if patient.is_none() {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidRequest,
+            Some(format!("Patient '{patient_id}' was not found.")),
+            redirect_uri,
+        ));
+    }
+
+    // Keep any encounter from an EHR launch the picker was shown for.
+    let existing_launch = match oidc_params.parameters.get("launch") {
+        Some(launch) => Some(
+            resolve_launch_context(
+                &*app_state.repo,
+                &tenant,
+                &project,
+                &user.id,
+                &client_id,
+                launch,
+            )
+            .await
+            .map_err(|_e| {
+                OIDCError::new(
+                    OIDCErrorCode::InvalidRequest,
+                    Some("Launch parameter is invalid or has expired.".to_string()),
+                    redirect_uri.clone(),
+                )
+            })?,
+        ),
+        None => None,
+    };
+
+    let launch = create_launch_context(
+        &*app_state.repo,
+        &tenant,
+        &project,
+        &user.id,
+        &client_id,
+        &LaunchContext {
+            patient: Some(patient_id),
+            encounter: existing_launch.and_then(|launch| launch.encounter),
+            need_patient_banner: true,
+        },
+    )
+    .await
+    .map_err(|_e| {
+        OIDCError::new(
+            OIDCErrorCode::ServerError,
+            Some("Failed to create launch context.".to_string()),
+            redirect_uri.clone(),
+        )
+    })?;
+
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            oidc_params
+                .parameters
+                .iter()
+                .filter(|(name, _)| name.as_str() != "launch"),
+        )
+        .append_pair("launch", &launch)
+        .finish();
+    let authorize_route = oidc_route_string(&tenant, &project, "auth/authorize");
+
+    Ok(Redirect::to(
+        &(authorize_route
+            .to_str()
+            .expect("Failed to create authorize route.")
+            .to_string()
+            + "?"
+            + &query),
+    )
+    .into_response())
+}
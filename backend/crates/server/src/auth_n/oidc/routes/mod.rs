@@ -17,6 +17,8 @@ pub mod discovery;
 pub mod federated;
 mod interactions;
 mod jwks;
+mod launch;
+pub mod register;
 pub mod route_string;
 pub mod scope;
 pub mod token;
@@ -32,7 +34,12 @@ static AUTHORIZE_PARAMETERS: LazyLock<Arc<ParameterConfig>> = LazyLock::new(|| {
             "code_challenge".to_string(),
             "code_challenge_method".to_string(),
         ],
-        optional_parameters: vec!["scope".to_string(), "redirect_uri".to_string()],
+        optional_parameters: vec![
+            "scope".to_string(),
+            "redirect_uri".to_string(),
+            "launch".to_string(),
+            "aud".to_string(),
+        ],
         allow_launch_parameters: true,
     })
 });
@@ -59,6 +66,13 @@ pub fn create_router<
             AUTH_NESTED_PATH,
             Router::new()
                 .merge(Router::new().typed_post(token::token))
+                .merge(
+                    Router::new()
+                        .typed_post(register::register)
+                        .typed_get(register::registration_read)
+                        .typed_put(register::registration_update)
+                        .typed_delete(register::registration_delete),
+                )
                 .merge(
                     Router::new()
                         .merge(
@@ -66,6 +80,7 @@ pub fn create_router<
                                 .typed_post(authorize::authorize)
                                 .typed_get(authorize::authorize)
                                 .typed_post(scope::scope_post)
+                                .typed_post(launch::launch_patient_post)
                                 .route_layer(ServiceBuilder::new().layer(
                                     OIDCParameterInjectLayer::new((*AUTHORIZE_PARAMETERS).clone()),
                                 )),
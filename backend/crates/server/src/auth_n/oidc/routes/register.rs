@@ -0,0 +1,878 @@
+// OAuth 2.0 Dynamic Client Registration (RFC 7591) and its management protocol (RFC 7592).
+use crate::{
+    ServerEnvironmentVariables,
+    auth_n::{
+        middleware::jwt::validate_jwt,
+        oidc::{
+            error::{OIDCError, OIDCErrorCode},
+            routes::{AUTH_NESTED_PATH, discovery::construct_oidc_route},
+        },
+    },
+    extract::{
+        bearer_token::AuthBearer,
+        path_tenant::{Project, ProjectIdentifier, TenantIdentifier},
+    },
+    fhir_client::{FHIRServerClient, ServerCTX},
+    services::AppState,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use axum_extra::{extract::Cached, routing::TypedPath};
+use data_encoding::HEXLOWER;
+use haste_fhir_client::FHIRClient;
+use haste_fhir_model::r4::generated::{
+    resources::{ClientApplication, Resource, ResourceType},
+    terminology::{
+        ClientapplicationGrantType, ClientapplicationResponseTypes,
+        ClientapplicationTokenEndpointAuthMethod, ContactPointSystem,
+    },
+    types::{ContactPoint, FHIRBoolean, FHIRString, FHIRUri},
+};
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{
+    ProjectId, TenantId,
+    scopes::{Scope, Scopes, SmartResourceScopeUser, SmartScope},
+};
+use haste_repository::{Repository, types::SupportedFHIRVersions, utilities::generate_id};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use url::Url;
+
+static DEFAULT_CLIENT_NAME: &str = "Unnamed client";
+static DEFAULT_AUTH_METHOD: &str = "client_secret_basic";
+static MAX_REDIRECT_URIS: usize = 5;
+/// Grant types a client can register for. Basic auth is reserved for manually created clients.
+static REGISTRABLE_GRANT_TYPES: &[&str] =
+    &["authorization_code", "refresh_token", "client_credentials"];
+
+#[derive(TypedPath)]
+#[typed_path("/register")]
+pub struct RegisterPath;
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/register/{client_id}")]
+pub struct RegistrationManagementPath {
+    pub client_id: String,
+}
+
+/// Client metadata from RFC 7591 Section 2.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ClientMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirect_uris: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grant_types: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_types: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tos_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ClientUpdateRequest {
+    pub client_id: String,
+    #[serde(flatten)]
+    pub metadata: ClientMetadata,
+}
+
+/// Client information response from RFC 7591 Section 3.2.1 and RFC 7592 Section 3.
+#[derive(Serialize, Debug)]
+pub struct ClientInformationResponse {
+    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret_expires_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_access_token: Option<String>,
+    pub registration_client_uri: String,
+    #[serde(flatten)]
+    pub metadata: ClientMetadata,
+}
+
+fn invalid_metadata(description: String) -> OIDCError {
+    OIDCError::new(
+        OIDCErrorCode::InvalidClientMetadata,
+        Some(description),
+        None,
+    )
+}
+
+fn fhir_string(value: String) -> Box<FHIRString> {
+    Box::new(FHIRString {
+        value: Some(value),
+        ..Default::default()
+    })
+}
+
+fn fhir_uri(field: &str, value: Option<String>) -> Result<Option<Box<FHIRUri>>, OIDCError> {
+    value
+        .map(|value| {
+            Url::parse(&value)
+                .map_err(|_| invalid_metadata(format!("'{field}' must be a valid URL.")))?;
+            Ok(Box::new(FHIRUri {
+                value: Some(value),
+                ..Default::default()
+            }))
+        })
+        .transpose()
+}
+
+fn hash_registration_access_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    HEXLOWER.encode(&hasher.finalize())
+}
+
+fn uses_client_secret(auth_method: &ClientapplicationTokenEndpointAuthMethod) -> bool {
+    matches!(
+        auth_method,
+        ClientapplicationTokenEndpointAuthMethod::Client_secret_basic(_)
+            | ClientapplicationTokenEndpointAuthMethod::Client_secret_post(_)
+    )
+}
+
+/// Checks applied to clients registered without an initial access token, both on registration
+/// and on later updates. These clients are limited to user facing grants and scopes and can not
+/// have the server fetch a jwks_uri on their behalf.
+fn verify_open_registration(metadata: &ClientMetadata) -> Result<(), OIDCError> {
+    if metadata
+        .grant_types
+        .iter()
+        .any(|grant_type| grant_type == "client_credentials")
+    {
+        return Err(invalid_metadata(
+            "client_credentials clients require an initial access token.".to_string(),
+        ));
+    }
+
+    if metadata.jwks_uri.is_some() {
+        return Err(invalid_metadata(
+            "jwks_uri requires an initial access token, register jwks instead.".to_string(),
+        ));
+    }
+
+    let has_system_scope = metadata
+        .scope
+        .as_deref()
+        .and_then(|scope| Scopes::try_from(scope).ok())
+        .is_some_and(|scopes| {
+            scopes.0.iter().any(|scope| {
+                matches!(
+                    scope,
+                    Scope::SMART(SmartScope::Resource(resource))
+                        if resource.user == SmartResourceScopeUser::System
+                )
+            })
+        });
+    if has_system_scope {
+        return Err(invalid_metadata(
+            "system scopes require an initial access token.".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates registration metadata and applies it to a client application.
+/// Server managed fields (id, secret and registration access token) are kept from `existing`.
+pub fn client_application_from_metadata(
+    metadata: ClientMetadata,
+    existing: ClientApplication,
+) -> Result<ClientApplication, OIDCError> {
+    let grant_types = if metadata.grant_types.is_empty() {
+        vec!["authorization_code".to_string()]
+    } else {
+        metadata.grant_types
+    };
+
+    let grant_type = grant_types
+        .into_iter()
+        .map(|grant_type| {
+            if !REGISTRABLE_GRANT_TYPES.contains(&grant_type.as_str()) {
+                return Err(invalid_metadata(format!(
+                    "Grant type '{grant_type}' is not supported."
+                )));
+            }
+            ClientapplicationGrantType::try_from(grant_type)
+                .map(Box::new)
+                .map_err(invalid_metadata)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let response_types = match metadata.response_types.as_slice() {
+        [] => ClientapplicationResponseTypes::Code(None),
+        [response_type] => ClientapplicationResponseTypes::try_from(response_type.clone())
+            .map_err(invalid_metadata)?,
+        _ => {
+            return Err(invalid_metadata(
+                "Only a single response type can be registered.".to_string(),
+            ));
+        }
+    };
+
+    let uses_authorization_code = grant_type.iter().any(|grant_type| {
+        matches!(
+            **grant_type,
+            ClientapplicationGrantType::Authorization_code(_)
+        )
+    });
+
+    if uses_authorization_code && metadata.redirect_uris.is_empty() {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidRedirectUri,
+            Some("redirect_uris are required for the authorization_code grant.".to_string()),
+            None,
+        ));
+    }
+    if metadata.redirect_uris.len() > MAX_REDIRECT_URIS {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidRedirectUri,
+            Some(format!(
+                "At most {MAX_REDIRECT_URIS} redirect_uris can be registered."
+            )),
+            None,
+        ));
+    }
+    for redirect_uri in metadata.redirect_uris.iter() {
+        let valid = Url::parse(redirect_uri)
+            .map(|url| url.fragment().is_none())
+            .unwrap_or(false);
+        if !valid {
+            return Err(OIDCError::new(
+                OIDCErrorCode::InvalidRedirectUri,
+                Some(format!("Invalid redirect URI '{redirect_uri}'.")),
+                None,
+            ));
+        }
+    }
+
+    let auth_method = ClientapplicationTokenEndpointAuthMethod::try_from(
+        metadata
+            .token_endpoint_auth_method
+            .unwrap_or_else(|| DEFAULT_AUTH_METHOD.to_string()),
+    )
+    .map_err(invalid_metadata)?;
+
+    if metadata.jwks.is_some() && metadata.jwks_uri.is_some() {
+        return Err(invalid_metadata(
+            "jwks and jwks_uri must not both be present.".to_string(),
+        ));
+    }
+    if matches!(
+        auth_method,
+        ClientapplicationTokenEndpointAuthMethod::Private_key_jwt(_)
+    ) && metadata.jwks.is_none()
+        && metadata.jwks_uri.is_none()
+    {
+        return Err(invalid_metadata(
+            "private_key_jwt clients must register jwks or jwks_uri.".to_string(),
+        ));
+    }
+
+    let jwks = metadata
+        .jwks
+        .map(|jwks| {
+            if jwks.get("keys").and_then(|keys| keys.as_array()).is_none() {
+                return Err(invalid_metadata(
+                    "jwks must be a JWK Set containing a keys array.".to_string(),
+                ));
+            }
+            Ok(fhir_string(jwks.to_string()))
+        })
+        .transpose()?;
+
+    if let Some(scope) = metadata.scope.as_ref() {
+        Scopes::try_from(scope.as_str())
+            .map_err(|_| invalid_metadata(format!("Invalid scope '{scope}'.")))?;
+    }
+
+    let secret = if uses_client_secret(&auth_method) {
+        existing
+            .secret
+            .or_else(|| Some(fhir_string(generate_id(Some(45)))))
+    } else {
+        None
+    };
+
+    Ok(ClientApplication {
+        id: existing.id,
+        meta: existing.meta,
+        name: fhir_string(
+            metadata
+                .client_name
+                .unwrap_or_else(|| DEFAULT_CLIENT_NAME.to_string()),
+        ),
+        description: existing.description,
+        grantType: grant_type,
+        responseTypes: Box::new(response_types),
+        secret,
+        redirectUri: if metadata.redirect_uris.is_empty() {
+            None
+        } else {
+            Some(
+                metadata
+                    .redirect_uris
+                    .into_iter()
+                    .map(fhir_string)
+                    .collect(),
+            )
+        },
+        uri: fhir_uri("client_uri", metadata.client_uri)?,
+        logoUri: fhir_uri("logo_uri", metadata.logo_uri)?,
+        scope: metadata.scope.map(fhir_string),
+        contact: metadata.contacts.into_iter().next().map(|contact| {
+            Box::new(ContactPoint {
+                system: Some(Box::new(ContactPointSystem::Email(None))),
+                value: Some(fhir_string(contact)),
+                ..Default::default()
+            })
+        }),
+        tosUri: fhir_uri("tos_uri", metadata.tos_uri)?,
+        policyUri: fhir_uri("policy_uri", metadata.policy_uri)?,
+        tokenEndpointAuthMethod: Some(Box::new(auth_method)),
+        jwksUri: fhir_uri("jwks_uri", metadata.jwks_uri)?,
+        jwks,
+        registrationAccessToken: existing.registrationAccessToken,
+        trustedRegistration: existing.trustedRegistration,
+    })
+}
+
+fn metadata_from_client_application(client_app: &ClientApplication) -> ClientMetadata {
+    let value = |v: &Option<Box<FHIRUri>>| v.as_ref().and_then(|v| v.value.clone());
+
+    ClientMetadata {
+        redirect_uris: client_app
+            .redirectUri
+            .iter()
+            .flatten()
+            .filter_map(|uri| uri.value.clone())
+            .collect(),
+        token_endpoint_auth_method: client_app
+            .tokenEndpointAuthMethod
+            .as_ref()
+            .and_then(|method| Into::<Option<String>>::into(&**method)),
+        grant_types: client_app
+            .grantType
+            .iter()
+            .filter_map(|grant_type| Into::<Option<String>>::into(&**grant_type))
+            .collect(),
+        response_types: Into::<Option<String>>::into(&*client_app.responseTypes)
+            .into_iter()
+            .collect(),
+        client_name: client_app.name.value.clone(),
+        client_uri: value(&client_app.uri),
+        logo_uri: value(&client_app.logoUri),
+        scope: client_app.scope.as_ref().and_then(|s| s.value.clone()),
+        contacts: client_app
+            .contact
+            .as_ref()
+            .and_then(|contact| contact.value.as_ref())
+            .and_then(|value| value.value.clone())
+            .into_iter()
+            .collect(),
+        tos_uri: value(&client_app.tosUri),
+        policy_uri: value(&client_app.policyUri),
+        jwks_uri: value(&client_app.jwksUri),
+        jwks: client_app
+            .jwks
+            .as_ref()
+            .and_then(|jwks| jwks.value.as_ref())
+            .and_then(|jwks| serde_json::from_str(jwks).ok()),
+    }
+}
+
+fn client_information_response(
+    api_url: &str,
+    tenant: &TenantId,
+    project: &ProjectId,
+    client_app: &ClientApplication,
+    registration_access_token: Option<String>,
+) -> Result<ClientInformationResponse, OIDCError> {
+    let client_id = client_app.id.clone().unwrap_or_default();
+    let registration_client_uri = Url::parse(api_url)
+        .and_then(|api_url| {
+            api_url.join(&construct_oidc_route(
+                tenant,
+                project,
+                &format!(
+                    "{}{}",
+                    AUTH_NESTED_PATH,
+                    RegistrationManagementPath {
+                        client_id: client_id.clone()
+                    }
+                ),
+            ))
+        })
+        .map_err(|_| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Invalid API_URL format".to_string()),
+                None,
+            )
+        })?;
+
+    let client_secret = client_app.secret.as_ref().and_then(|s| s.value.clone());
+
+    Ok(ClientInformationResponse {
+        client_id,
+        client_secret_expires_at: client_secret.as_ref().map(|_| 0),
+        client_secret,
+        registration_access_token,
+        registration_client_uri: registration_client_uri.to_string(),
+        metadata: metadata_from_client_application(client_app),
+    })
+}
+
+fn project_allows_open_registration(
+    project: &haste_fhir_model::r4::generated::resources::Project,
+) -> bool {
+    project
+        .openClientRegistration
+        .as_ref()
+        .and_then(|open| open.value)
+        .unwrap_or(false)
+}
+
+/// Reads a dynamically registered client and verifies the registration access token for it.
+async fn authorize_registration_access<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    fhir_client: &Arc<FHIRServerClient<Repo, Search, Terminology>>,
+    tenant: &TenantId,
+    project: &ProjectId,
+    client_id: String,
+    token: Option<String>,
+) -> Result<ClientApplication, OIDCError> {
+    let invalid_token = || {
+        OIDCError::new(
+            OIDCErrorCode::InvalidToken,
+            Some("Registration access token is invalid.".to_string()),
+            None,
+        )
+    };
+
+    let token = token.ok_or_else(invalid_token)?;
+
+    let client_app = fhir_client
+        .read(
+            Arc::new(ServerCTX::system(
+                tenant.clone(),
+                project.clone(),
+                fhir_client.clone(),
+            )),
+            ResourceType::ClientApplication,
+            client_id,
+        )
+        .await
+        .map_err(|_| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to retrieve client application.".to_string()),
+                None,
+            )
+        })?;
+
+    let Some(Resource::ClientApplication(client_app)) = client_app else {
+        return Err(invalid_token());
+    };
+
+    let expected = client_app
+        .registrationAccessToken
+        .as_ref()
+        .and_then(|hash| hash.value.as_ref())
+        .ok_or_else(invalid_token)?;
+    let matches: bool = expected
+        .as_bytes()
+        .ct_eq(hash_registration_access_token(&token).as_bytes())
+        .into();
+    if !matches {
+        return Err(invalid_token());
+    }
+
+    Ok(client_app)
+}
+
+pub async fn register<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    _: RegisterPath,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    Cached(Project(project_resource)): Cached<Project>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    AuthBearer(token): AuthBearer,
+    Json(metadata): Json<ClientMetadata>,
+) -> Result<Response, OIDCError> {
+    // Clients registered with an initial access token are created as the token's user so
+    // access policies apply. Open registration is limited to user facing grants.
+    let trusted = token.is_some();
+    let ctx = match token {
+        Some(token) => {
+            let claims = validate_jwt(&token)
+                .ok()
+                .filter(|claims| {
+                    claims.tenant == tenant && claims.project.as_ref() == Some(&project)
+                })
+                .ok_or_else(|| {
+                    OIDCError::new(
+                        OIDCErrorCode::InvalidToken,
+                        Some("Initial access token is invalid.".to_string()),
+                        None,
+                    )
+                })?;
+
+            Arc::new(ServerCTX::new(
+                tenant.clone(),
+                project.clone(),
+                SupportedFHIRVersions::R4,
+                Arc::new(claims),
+                state.fhir_client.clone(),
+            ))
+        }
+        None if project_allows_open_registration(&project_resource) => {
+            verify_open_registration(&metadata)?;
+
+            Arc::new(ServerCTX::system(
+                tenant.clone(),
+                project.clone(),
+                state.fhir_client.clone(),
+            ))
+        }
+        None => {
+            return Err(OIDCError::new(
+                OIDCErrorCode::InvalidToken,
+                Some("An initial access token is required to register clients.".to_string()),
+                None,
+            ));
+        }
+    };
+
+    let registration_access_token = generate_id(Some(45));
+    let client_app = client_application_from_metadata(
+        metadata,
+        ClientApplication {
+            registrationAccessToken: Some(fhir_string(hash_registration_access_token(
+                &registration_access_token,
+            ))),
+            trustedRegistration: Some(Box::new(FHIRBoolean {
+                value: Some(trusted),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+    )?;
+
+    let created = state
+        .fhir_client
+        .create(
+            ctx,
+            ResourceType::ClientApplication,
+            Resource::ClientApplication(client_app),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to register client application: {:?}", e);
+            OIDCError::new(
+                OIDCErrorCode::AccessDenied,
+                Some("Failed to register client application.".to_string()),
+                None,
+            )
+        })?;
+
+    let Resource::ClientApplication(created) = created else {
+        return Err(OIDCError::new(
+            OIDCErrorCode::ServerError,
+            Some("Failed to register client application.".to_string()),
+            None,
+        ));
+    };
+
+    let api_url = state
+        .config
+        .get(ServerEnvironmentVariables::APIURI)
+        .unwrap_or_default();
+
+    Ok((
+        StatusCode::CREATED,
+        Json(client_information_response(
+            &api_url,
+            &tenant,
+            &project,
+            &created,
+            Some(registration_access_token),
+        )?),
+    )
+        .into_response())
+}
+
+pub async fn registration_read<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    RegistrationManagementPath { client_id }: RegistrationManagementPath,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    AuthBearer(token): AuthBearer,
+) -> Result<Json<ClientInformationResponse>, OIDCError> {
+    let client_app =
+        authorize_registration_access(&state.fhir_client, &tenant, &project, client_id, token)
+            .await?;
+
+    let api_url = state
+        .config
+        .get(ServerEnvironmentVariables::APIURI)
+        .unwrap_or_default();
+
+    Ok(Json(client_information_response(
+        &api_url,
+        &tenant,
+        &project,
+        &client_app,
+        None,
+    )?))
+}
+
+pub async fn registration_update<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    RegistrationManagementPath { client_id }: RegistrationManagementPath,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    AuthBearer(token): AuthBearer,
+    Json(update): Json<ClientUpdateRequest>,
+) -> Result<Json<ClientInformationResponse>, OIDCError> {
+    if update.client_id != client_id {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidRequest,
+            Some("client_id does not match the registration being updated.".to_string()),
+            None,
+        ));
+    }
+
+    let existing = authorize_registration_access(
+        &state.fhir_client,
+        &tenant,
+        &project,
+        client_id.clone(),
+        token,
+    )
+    .await?;
+
+    let trusted = existing
+        .trustedRegistration
+        .as_ref()
+        .and_then(|trusted| trusted.value)
+        .unwrap_or(false);
+    if !trusted {
+        verify_open_registration(&update.metadata)?;
+    }
+
+    let client_app = client_application_from_metadata(update.metadata, existing)?;
+
+    let updated = state
+        .fhir_client
+        .update(
+            Arc::new(ServerCTX::system(
+                tenant.clone(),
+                project.clone(),
+                state.fhir_client.clone(),
+            )),
+            ResourceType::ClientApplication,
+            client_id,
+            Resource::ClientApplication(client_app),
+        )
+        .await
+        .map_err(|_| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to update client application.".to_string()),
+                None,
+            )
+        })?;
+
+    let Resource::ClientApplication(updated) = updated else {
+        return Err(OIDCError::new(
+            OIDCErrorCode::ServerError,
+            Some("Failed to update client application.".to_string()),
+            None,
+        ));
+    };
+
+    let api_url = state
+        .config
+        .get(ServerEnvironmentVariables::APIURI)
+        .unwrap_or_default();
+
+    Ok(Json(client_information_response(
+        &api_url, &tenant, &project, &updated, None,
+    )?))
+}
+
+pub async fn registration_delete<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    RegistrationManagementPath { client_id }: RegistrationManagementPath,
+    Cached(TenantIdentifier { tenant }): Cached<TenantIdentifier>,
+    Cached(ProjectIdentifier { project }): Cached<ProjectIdentifier>,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+    AuthBearer(token): AuthBearer,
+) -> Result<StatusCode, OIDCError> {
+    authorize_registration_access(
+        &state.fhir_client,
+        &tenant,
+        &project,
+        client_id.clone(),
+        token,
+    )
+    .await?;
+
+    state
+        .fhir_client
+        .delete_instance(
+            Arc::new(ServerCTX::system(
+                tenant.clone(),
+                project.clone(),
+                state.fhir_client.clone(),
+            )),
+            ResourceType::ClientApplication,
+            client_id,
+        )
+        .await
+        .map_err(|_| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to delete client application.".to_string()),
+                None,
+            )
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(json: serde_json::Value) -> ClientMetadata {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_client_application_from_metadata() {
+        let client_app = client_application_from_metadata(
+            metadata(serde_json::json!({
+                "client_name": "Marketplace App",
+                "redirect_uris": ["https://app.example.com/callback"],
+                "scope": "openid launch/patient patient/*.rs"
+            })),
+            ClientApplication::default(),
+        )
+        .unwrap();
+
+        assert_eq!(client_app.name.value.as_deref(), Some("Marketplace App"));
+        assert!(client_app.secret.is_some());
+        assert_eq!(
+            metadata_from_client_application(&client_app).grant_types,
+            vec!["authorization_code".to_string()]
+        );
+
+        // Redirect URIs are required for the authorization code grant.
+        assert!(
+            client_application_from_metadata(
+                metadata(serde_json::json!({ "client_name": "No redirect" })),
+                ClientApplication::default(),
+            )
+            .is_err()
+        );
+
+        // private_key_jwt requires keys to verify assertions with.
+        assert!(
+            client_application_from_metadata(
+                metadata(serde_json::json!({
+                    "grant_types": ["client_credentials"],
+                    "token_endpoint_auth_method": "private_key_jwt"
+                })),
+                ClientApplication::default(),
+            )
+            .is_err()
+        );
+
+        let backend_service = client_application_from_metadata(
+            metadata(serde_json::json!({
+                "grant_types": ["client_credentials"],
+                "token_endpoint_auth_method": "private_key_jwt",
+                "jwks_uri": "https://app.example.com/.well-known/jwks.json"
+            })),
+            ClientApplication::default(),
+        )
+        .unwrap();
+        assert!(backend_service.secret.is_none());
+    }
+
+    #[test]
+    fn test_verify_open_registration() {
+        assert!(
+            verify_open_registration(&metadata(serde_json::json!({
+                "redirect_uris": ["https://app.example.com/callback"],
+                "scope": "openid launch/patient patient/*.rs"
+            })))
+            .is_ok()
+        );
+
+        assert!(
+            verify_open_registration(&metadata(serde_json::json!({
+                "grant_types": ["client_credentials"]
+            })))
+            .is_err()
+        );
+        assert!(
+            verify_open_registration(&metadata(serde_json::json!({
+                "scope": "openid system/*.cruds"
+            })))
+            .is_err()
+        );
+        assert!(
+            verify_open_registration(&metadata(serde_json::json!({
+                "jwks_uri": "http://localhost/internal"
+            })))
+            .is_err()
+        );
+    }
+}
@@ -43,6 +43,7 @@ pub struct ScopeForm {
     pub code_challenge_method: String,
     pub scope: haste_jwt::scopes::Scopes,
     pub redirect_uri: String,
+    pub launch: Option<String>,
     pub accept: Option<String>,
 }
 
@@ -134,7 +135,12 @@ pub async fn scope_post<
             + "&scope="
             + &String::from(scope_data.scope)
             + "&redirect_uri="
-            + &scope_data.redirect_uri;
+            + &scope_data.redirect_uri
+            + &scope_data
+                .launch
+                .as_ref()
+                .map(|launch| "&launch=".to_string() + launch)
+                .unwrap_or_default();
         let redirect = axum::response::Redirect::to(&authorization_route);
         Ok(redirect.into_response())
     } else {
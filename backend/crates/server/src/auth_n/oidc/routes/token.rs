@@ -1,11 +1,15 @@
 use crate::{
     auth_n::{
-        certificates::encoding_key,
+        certificates,
         oidc::{
+            client_assertion::{self, assertion_client_id, uses_private_key_jwt},
             code_verification,
             error::{OIDCError, OIDCErrorCode},
             extract::{body::ParsedBody, client_app::find_client_app},
-            routes::scope::verify_requested_scope_is_subset,
+            launch_context::LaunchContext,
+            routes::{
+                discovery::create_oidc_discovery_document, scope::verify_requested_scope_is_subset,
+            },
             schemas,
         },
     },
@@ -23,15 +27,15 @@ use haste_fhir_client::{
     url::{Parameter, ParsedParameter, ParsedParameters},
 };
 use haste_fhir_model::r4::generated::{
-    resources::{ClientApplication, ResourceType},
+    resources::{ClientApplication, Resource, ResourceType},
     terminology::ClientapplicationGrantType,
 };
 use haste_fhir_search::SearchEngine;
 use haste_fhir_terminology::FHIRTerminology;
 use haste_jwt::{
-    AuthorId, AuthorKind, ProjectId, TenantId, UserRole, VersionId,
+    AuthorId, AuthorKind, ProjectId, ResourceId, TenantId, UserRole, VersionId,
     claims::UserTokenClaims,
-    scopes::{OIDCScope, Scope, Scopes},
+    scopes::{OIDCScope, Scope, Scopes, SmartScope},
 };
 use haste_repository::{
     Repository,
@@ -39,16 +43,17 @@ use haste_repository::{
     types::{
         SupportedFHIRVersions,
         authorization_code::{
-            AuthorizationCodeKind, AuthorizationCodeSearchClaims, CreateAuthorizationCode,
+            AuthorizationCode, AuthorizationCodeKind, AuthorizationCodeSearchClaims,
+            CreateAuthorizationCode,
         },
         scope::{ClientId, CreateScope, ScopeSearchClaims, UserId},
         user::{User, UserRole as RepoUserRole},
     },
 };
-use jsonwebtoken::{Algorithm, Header};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{sync::Arc, time::Duration};
+use url::Url;
 
 #[derive(TypedPath)]
 #[typed_path("/token")]
@@ -70,6 +75,14 @@ pub struct TokenResponse {
     pub id_token: Option<String>,
     token_type: TokenType,
     expires_in: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patient: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encounter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_patient_banner: Option<bool>,
+    #[serde(rename = "fhirUser", skip_serializing_if = "Option::is_none")]
+    pub fhir_user: Option<String>,
 }
 
 struct TokenResponseArguments {
@@ -82,6 +95,19 @@ struct TokenResponseArguments {
     project: ProjectId,
     membership: Option<String>,
     access_policy_version_ids: Vec<VersionId>,
+    /// Authentication methods used when the user logged in, carried through refresh tokens.
+    amr: Option<Vec<String>>,
+    /// SMART launch context established at authorization, carried through refresh tokens.
+    launch: Option<LaunchContext>,
+    fhir_user: Option<String>,
+}
+
+/// Reads the authentication methods stored on an authorization code or refresh token.
+fn code_authentication_methods(code: &AuthorizationCode) -> Option<Vec<String>> {
+    code.meta
+        .as_ref()
+        .and_then(|meta| meta.get("amr"))
+        .and_then(|amr| serde_json::from_value::<Vec<String>>(amr.clone()).ok())
 }
 
 async fn create_token_response<Repo: Repository>(
@@ -91,24 +117,21 @@ async fn create_token_response<Repo: Repository>(
     grant_type_used: &schemas::token_body::OAuth2TokenBodyGrantType,
     args: TokenResponseArguments,
 ) -> Result<TokenResponse, OIDCError> {
-    let token = jsonwebtoken::encode(
-        &Header::new(Algorithm::RS256),
-        &UserTokenClaims {
-            sub: AuthorId::new(args.user_id.clone()),
-            exp: (chrono::Utc::now() + chrono::Duration::seconds(TOKEN_EXPIRATION as i64))
-                .timestamp() as usize,
-            aud: args.client_id.clone(),
-            scope: args.scopes.clone(),
-            tenant: args.tenant.clone(),
-            project: Some(args.project.clone()),
-            user_role: args.user_role,
-            user_id: AuthorId::new(args.user_id.clone()),
-            membership: args.membership.clone(),
-            resource_type: args.user_kind,
-            access_policy_version_ids: args.access_policy_version_ids,
-        },
-        encoding_key(),
-    )
+    let token = certificates::sign(&UserTokenClaims {
+        sub: AuthorId::new(args.user_id.clone()),
+        exp: (chrono::Utc::now() + chrono::Duration::seconds(TOKEN_EXPIRATION as i64)).timestamp()
+            as usize,
+        aud: args.client_id.clone(),
+        scope: args.scopes.clone(),
+        tenant: args.tenant.clone(),
+        project: Some(args.project.clone()),
+        user_role: args.user_role,
+        user_id: AuthorId::new(args.user_id.clone()),
+        membership: args.membership.clone(),
+        resource_type: args.user_kind,
+        access_policy_version_ids: args.access_policy_version_ids,
+        amr: args.amr.clone(),
+    })
     .map_err(|_| {
         OIDCError::new(
             OIDCErrorCode::ServerError,
@@ -123,6 +146,19 @@ async fn create_token_response<Repo: Repository>(
         expires_in: TOKEN_EXPIRATION,
         refresh_token: None,
         token_type: TokenType::Bearer,
+        patient: args
+            .launch
+            .as_ref()
+            .and_then(|launch| launch.patient.clone()),
+        encounter: args
+            .launch
+            .as_ref()
+            .and_then(|launch| launch.encounter.clone()),
+        need_patient_banner: args
+            .launch
+            .as_ref()
+            .map(|launch| launch.need_patient_banner),
+        fhir_user: args.fhir_user.clone(),
     };
 
     if args.scopes.contains_scope(&Scope::OIDC(OIDCScope::OpenId)) {
@@ -203,6 +239,8 @@ async fn create_token_response<Repo: Repository>(
                 redirect_uri: None,
                 meta: Some(sqlx::types::Json(json!({
                     "user_agent": user_agent.as_ref().map(|ua| ua.to_string()),
+                    "amr": args.amr,
+                    "launch": args.launch,
                 }))),
             },
         )
@@ -221,6 +259,78 @@ async fn create_token_response<Repo: Repository>(
     Ok(response)
 }
 
+/// Absolute URL of the FHIR resource the membership is linked to IE the Practitioner the user acts as.
+async fn find_fhir_user<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    state: &AppState<Repo, Search, Terminology>,
+    tenant: &TenantId,
+    project: &ProjectId,
+    scopes: &Scopes,
+    membership: Option<&String>,
+) -> Result<Option<String>, OIDCError> {
+    let Some(membership) = membership else {
+        return Ok(None);
+    };
+    if !scopes.contains_scope(&Scope::SMART(SmartScope::FHIRUser)) {
+        return Ok(None);
+    }
+
+    let membership = state
+        .repo
+        .read_latest(
+            tenant,
+            project,
+            &ResourceType::Membership,
+            &ResourceId::new(membership.clone()),
+        )
+        .await
+        .map_err(|_e| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Failed to retrieve membership.".to_string()),
+                None,
+            )
+        })?;
+
+    let Some(Resource::Membership(membership)) = membership else {
+        return Ok(None);
+    };
+    let Some(reference) = membership
+        .link
+        .as_ref()
+        .and_then(|link| link.reference.as_ref())
+        .and_then(|reference| reference.value.as_ref())
+    else {
+        return Ok(None);
+    };
+
+    let api_url = state
+        .config
+        .get(crate::ServerEnvironmentVariables::APIURI)
+        .ok()
+        .and_then(|api_url| Url::parse(&api_url).ok())
+        .ok_or_else(|| {
+            OIDCError::new(
+                OIDCErrorCode::ServerError,
+                Some("Invalid API_URL format".to_string()),
+                None,
+            )
+        })?;
+
+    Ok(api_url
+        .join(&format!(
+            "/w/{}/{}/api/v1/fhir/r4/{}",
+            tenant.as_ref(),
+            project.as_ref(),
+            reference
+        ))
+        .ok()
+        .map(|url| url.to_string()))
+}
+
 async fn get_approved_scopes<Repo: Repository>(
     repo: &Repo,
     tenant: &TenantId,
@@ -276,8 +386,34 @@ fn validate_client_grant_type(
     Ok(())
 }
 
-fn verify_client(
+/// Client id from the body, falling back to the sub of a client assertion.
+pub(crate) fn requested_client_id(
+    token_request_body: &schemas::token_body::OAuth2TokenBody,
+) -> Result<String, OIDCError> {
+    match (
+        &token_request_body.client_id,
+        &token_request_body.client_assertion,
+    ) {
+        (Some(client_id), _) => Ok(client_id.clone()),
+        (None, Some(assertion)) => assertion_client_id(assertion),
+        (None, None) => Err(OIDCError::new(
+            OIDCErrorCode::InvalidRequest,
+            Some("client_id is required.".to_string()),
+            None,
+        )),
+    }
+}
+
+async fn verify_client<
+    Repo: Repository + Send + Sync,
+    Search: SearchEngine + Send + Sync,
+    Terminology: FHIRTerminology + Send + Sync,
+>(
+    state: &AppState<Repo, Search, Terminology>,
+    tenant: &TenantId,
+    project: &ProjectId,
     client_app: &ClientApplication,
+    client_id: &str,
     token_request_body: &schemas::token_body::OAuth2TokenBody,
 ) -> Result<(), OIDCError> {
     // Verify the grant types align
@@ -302,7 +438,7 @@ fn verify_client(
         }
     }
 
-    if client_app.id.as_ref() != Some(&token_request_body.client_id) {
+    if client_app.id.as_deref() != Some(client_id) {
         return Err(OIDCError::new(
             OIDCErrorCode::AccessDenied,
             Some("Invalid credentials".to_string()),
@@ -310,6 +446,35 @@ fn verify_client(
         ));
     }
 
+    if let Some(assertion) = token_request_body.client_assertion.as_ref() {
+        let api_url_string = state
+            .config
+            .get(crate::ServerEnvironmentVariables::APIURI)
+            .unwrap_or_default();
+        let token_endpoint =
+            create_oidc_discovery_document(tenant, project, &api_url_string)?.token_endpoint;
+
+        return client_assertion::verify_client_assertion(
+            &*state.repo,
+            tenant,
+            project,
+            client_app,
+            &token_endpoint,
+            token_request_body.client_assertion_type.as_deref(),
+            assertion,
+        )
+        .await;
+    }
+
+    // Clients registered with keys must always authenticate with a signed assertion.
+    if uses_private_key_jwt(client_app) {
+        return Err(OIDCError::new(
+            OIDCErrorCode::InvalidClient,
+            Some("Client application must authenticate with a client assertion.".to_string()),
+            None,
+        ));
+    }
+
     if client_app
         .secret
         .as_ref()
@@ -372,6 +537,8 @@ async fn find_users_access_policy_version_ids<Search: SearchEngine>(
 pub enum ClientCredentialsMethod {
     BasicAuth,
     Body,
+    /// Signed JWT client assertion (SMART Backend Services).
+    ClientAssertion,
 }
 
 pub async fn client_credentials_to_token_response<
@@ -386,11 +553,11 @@ pub async fn client_credentials_to_token_response<
     token_body: &schemas::token_body::OAuth2TokenBody,
     method: ClientCredentialsMethod,
 ) -> Result<TokenResponse, OIDCError> {
-    let client_id = &token_body.client_id;
+    let client_id = &requested_client_id(token_body)?;
     let client_app =
         find_client_app(state, tenant.clone(), project.clone(), client_id.clone()).await?;
 
-    verify_client(&client_app, &token_body)?;
+    verify_client(state, tenant, project, &client_app, client_id, token_body).await?;
 
     // Allow basic auth if client app allows grant.
     if method == ClientCredentialsMethod::BasicAuth {
@@ -435,6 +602,9 @@ pub async fn client_credentials_to_token_response<
             tenant: tenant.clone(),
             project: project.clone(),
             membership: None,
+            amr: None,
+            launch: None,
+            fhir_user: None,
             access_policy_version_ids: find_users_access_policy_version_ids(
                 state.search.as_ref(),
                 &tenant,
@@ -470,14 +640,18 @@ pub async fn token<
                 &project,
                 &user_agent,
                 &token_body,
-                ClientCredentialsMethod::Body,
+                if token_body.client_assertion.is_some() {
+                    ClientCredentialsMethod::ClientAssertion
+                } else {
+                    ClientCredentialsMethod::Body
+                },
             )
             .await?;
 
             Ok(Json(response).into_response())
         }
         schemas::token_body::OAuth2TokenBodyGrantType::RefreshToken => {
-            let client_id = &token_body.client_id;
+            let client_id = &requested_client_id(&token_body)?;
             let refresh_token = &token_body.refresh_token.as_ref().ok_or_else(|| {
                 OIDCError::new(
                     OIDCErrorCode::InvalidRequest,
@@ -489,7 +663,15 @@ pub async fn token<
             let client_app =
                 find_client_app(&state, tenant.clone(), project.clone(), client_id.clone()).await?;
 
-            verify_client(&client_app, &token_body)?;
+            verify_client(
+                &state,
+                &tenant,
+                &project,
+                &client_app,
+                client_id,
+                &token_body,
+            )
+            .await?;
 
             let code = code_verification::retrieve_and_verify_code(
                 &*state.repo,
@@ -560,6 +742,16 @@ pub async fn token<
                         )
                     })?;
 
+            let amr = code_authentication_methods(&code);
+            let launch = LaunchContext::from_code(&code);
+            let fhir_user = find_fhir_user(
+                &state,
+                &tenant,
+                &project,
+                &approved_scopes,
+                code.membership.as_ref(),
+            )
+            .await?;
             let response = create_token_response(
                 &user_agent,
                 &*state.repo,
@@ -576,6 +768,9 @@ pub async fn token<
                     },
                     client_id: client_id.clone(),
                     scopes: approved_scopes.clone(),
+                    amr,
+                    launch,
+                    fhir_user,
                     tenant: tenant.clone(),
                     project: project.clone(),
                     access_policy_version_ids: match code.membership.as_ref() {
@@ -599,7 +794,7 @@ pub async fn token<
             Ok(Json(response).into_response())
         }
         schemas::token_body::OAuth2TokenBodyGrantType::AuthorizationCode => {
-            let client_id = &token_body.client_id;
+            let client_id = &requested_client_id(&token_body)?;
             let code = token_body.code.as_ref().ok_or_else(|| {
                 OIDCError::new(
                     OIDCErrorCode::InvalidRequest,
@@ -627,7 +822,15 @@ pub async fn token<
             let client_app =
                 find_client_app(&state, tenant.clone(), project.clone(), client_id.clone()).await?;
 
-            verify_client(&client_app, &token_body)?;
+            verify_client(
+                &state,
+                &tenant,
+                &project,
+                &client_app,
+                client_id,
+                &token_body,
+            )
+            .await?;
 
             let code = code_verification::retrieve_and_verify_code(
                 &*state.repo,
@@ -699,6 +902,16 @@ pub async fn token<
                         )
                     })?;
 
+            let amr = code_authentication_methods(&code);
+            let launch = LaunchContext::from_code(&code);
+            let fhir_user = find_fhir_user(
+                &state,
+                &tenant,
+                &project,
+                &approved_scopes,
+                code.membership.as_ref(),
+            )
+            .await?;
             let response = create_token_response(
                 &user_agent,
                 &*state.repo,
@@ -715,6 +928,9 @@ pub async fn token<
                     },
                     client_id: client_id.clone(),
                     scopes: approved_scopes.clone(),
+                    amr,
+                    launch,
+                    fhir_user,
                     tenant: tenant.clone(),
                     project: project.clone(),
                     access_policy_version_ids: match code.membership.as_ref() {
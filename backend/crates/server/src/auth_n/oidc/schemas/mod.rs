@@ -11,6 +11,7 @@ pub mod token_instrospection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth_n::oidc::routes::token::requested_client_id;
 
     #[test]
     fn test_token_body() {
@@ -37,9 +38,27 @@ mod tests {
                "client_secret": "test_secret"
             }
             "#,
-        );
+        )
+        .unwrap();
+
+        // client_id can only be omitted when a client assertion identifies the client.
+        assert!(requested_client_id(&missing_client_id).is_err());
+
+        // Backend services clients identify themselves through the client assertion.
+        let client_assertion = serde_json::from_str::<token_body::OAuth2TokenBody>(
+            r#"
+            {
+               "grant_type": "client_credentials",
+               "scope" : "system/*.rs",
+               "client_assertion_type": "urn:ietf:params:oauth:client-assertion-type:jwt-bearer",
+               "client_assertion": "header.payload.signature"
+            }
+            "#,
+        )
+        .unwrap();
 
-        assert!(!missing_client_id.is_ok());
+        assert!(client_assertion.client_id.is_none());
+        assert!(client_assertion.client_assertion.is_some());
 
         let body = serde_json::from_str::<token_body::OAuth2TokenBody>(
             r#"
@@ -0,0 +1,105 @@
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_jwt::TenantId;
+use haste_repository::types::user::User;
+use tower_sessions::Session;
+
+/// User who has passed the password check but has not yet completed MFA.
+static PENDING_USER_KEY: &str = "mfa_pending_user";
+static FAILED_ATTEMPTS_KEY: &str = "mfa_failed_attempts";
+
+fn pending_user_key(tenant: &TenantId) -> String {
+    format!("{}_{}", tenant.as_ref(), PENDING_USER_KEY)
+}
+
+fn failed_attempts_key(tenant: &TenantId) -> String {
+    format!("{}_{}", tenant.as_ref(), FAILED_ATTEMPTS_KEY)
+}
+
+pub async fn get_pending_user(
+    session: &Session,
+    tenant: &TenantId,
+) -> Result<Option<User>, OperationOutcomeError> {
+    let user = session
+        .get::<User>(&pending_user_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Session returned an error when retrieving pending MFA user.".to_string(),
+            )
+        })?;
+
+    Ok(user)
+}
+
+pub async fn set_pending_user(
+    session: &Session,
+    tenant: &TenantId,
+    user: &User,
+) -> Result<(), OperationOutcomeError> {
+    session
+        .insert(&pending_user_key(tenant), user)
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to set pending MFA user in session.".to_string(),
+            )
+        })
+}
+
+pub async fn clear_pending_user(
+    session: &Session,
+    tenant: &TenantId,
+) -> Result<(), OperationOutcomeError> {
+    session
+        .remove::<User>(&pending_user_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to clear pending MFA user from session.".to_string(),
+            )
+        })?;
+
+    session
+        .remove::<u32>(&failed_attempts_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to clear MFA attempts from session.".to_string(),
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Records a failed verification returning the number of failures for the pending login.
+pub async fn record_failed_attempt(
+    session: &Session,
+    tenant: &TenantId,
+) -> Result<u32, OperationOutcomeError> {
+    let key = failed_attempts_key(tenant);
+    let attempts = session
+        .get::<u32>(&key)
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Session returned an error when retrieving MFA attempts.".to_string(),
+            )
+        })?
+        .unwrap_or(0)
+        + 1;
+
+    session.insert(&key, attempts).await.map_err(|_e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to set MFA attempts in session.".to_string(),
+        )
+    })?;
+
+    Ok(attempts)
+}
@@ -1 +1,2 @@
+pub mod mfa;
 pub mod user;
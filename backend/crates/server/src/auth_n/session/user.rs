@@ -5,11 +5,16 @@ use haste_repository::types::user::User;
 use tower_sessions::Session;
 
 static USER_KEY: &str = "auth_user";
+static AUTHENTICATION_METHODS_KEY: &str = "auth_amr";
 
 fn user_key(tenant: &TenantId) -> String {
     format!("{}_{}", tenant.as_ref(), USER_KEY)
 }
 
+fn authentication_methods_key(tenant: &TenantId) -> String {
+    format!("{}_{}", tenant.as_ref(), AUTHENTICATION_METHODS_KEY)
+}
+
 pub async fn get_user(
     session: &Session,
     tenant: &TenantId,
@@ -29,6 +34,17 @@ pub async fn set_user(
     tenant: &TenantId,
     user: &User,
 ) -> Result<(), OperationOutcomeError> {
+    // Authentication methods belong to the previous login so are reset with the user.
+    session
+        .remove::<Vec<String>>(&authentication_methods_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to clear authentication methods from session.".to_string(),
+            )
+        })?;
+
     session.insert(&user_key(tenant), user).await.map_err(|_e| {
         OperationOutcomeError::fatal(
             IssueType::Exception(None),
@@ -48,5 +64,49 @@ pub async fn clear_user(session: &Session, tenant: &TenantId) -> Result<(), Oper
             )
         })?;
 
+    session
+        .remove::<Vec<String>>(&authentication_methods_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to clear authentication methods from session.".to_string(),
+            )
+        })?;
+
     Ok(())
 }
+
+/// Authentication methods (amr) the current user logged in with.
+pub async fn get_authentication_methods(
+    session: &Session,
+    tenant: &TenantId,
+) -> Result<Vec<String>, OperationOutcomeError> {
+    let methods = session
+        .get::<Vec<String>>(&authentication_methods_key(tenant))
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Session returned an error when retrieving authentication methods.".to_string(),
+            )
+        })?;
+
+    Ok(methods.unwrap_or_default())
+}
+
+pub async fn set_authentication_methods(
+    session: &Session,
+    tenant: &TenantId,
+    methods: &Vec<String>,
+) -> Result<(), OperationOutcomeError> {
+    session
+        .insert(&authentication_methods_key(tenant), methods)
+        .await
+        .map_err(|_e| {
+            OperationOutcomeError::fatal(
+                IssueType::Exception(None),
+                "Failed to set authentication methods in session.".to_string(),
+            )
+        })
+}
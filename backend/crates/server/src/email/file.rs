@@ -0,0 +1,55 @@
+use crate::{
+    ServerEnvironmentVariables,
+    email::{EmailError, EmailMessage, to_mime_message},
+};
+use haste_config::Config;
+use haste_fhir_operation_error::OperationOutcomeError;
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+use std::path::PathBuf;
+
+/// Writes each email as an .eml file to a local directory.
+/// Used to capture emails in development and test environments.
+pub struct FileTransport {
+    transport: AsyncFileTransport<Tokio1Executor>,
+}
+
+impl FileTransport {
+    pub fn new(directory: PathBuf) -> Self {
+        FileTransport {
+            transport: AsyncFileTransport::new(directory),
+        }
+    }
+
+    pub fn from_config(
+        config: &dyn Config<ServerEnvironmentVariables>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let directory = PathBuf::from(config.get(ServerEnvironmentVariables::EmailFileDirectory)?);
+        std::fs::create_dir_all(&directory).map_err(|e| {
+            tracing::error!("Failed to create email directory '{}'", e);
+            EmailError::InvalidConfiguration(String::from(
+                ServerEnvironmentVariables::EmailFileDirectory,
+            ))
+        })?;
+
+        Ok(FileTransport::new(directory))
+    }
+
+    pub async fn send(
+        &self,
+        from: &str,
+        message: &EmailMessage<'_>,
+    ) -> Result<(), OperationOutcomeError> {
+        let id = self
+            .transport
+            .send(to_mime_message(from, message)?)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to write email '{}'", e);
+                EmailError::SendFailed
+            })?;
+
+        tracing::info!("Email written with id: '{}'", id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+use crate::ServerEnvironmentVariables;
+use haste_config::Config;
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+
+mod file;
+mod send_grid;
+mod smtp;
+
+pub use file::FileTransport;
+pub use send_grid::SendGridTransport;
+pub use smtp::{SMTPSecurity, SMTPTransport};
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum EmailError {
+    #[fatal(code = "exception", diagnostic = "Email transport is not configured.")]
+    NotConfigured,
+    #[fatal(code = "invalid", diagnostic = "Unknown email transport '{arg0}'.")]
+    UnknownTransport(String),
+    #[fatal(code = "invalid", diagnostic = "Invalid email configuration '{arg0}'.")]
+    InvalidConfiguration(String),
+    #[error(code = "invalid", diagnostic = "Invalid email address '{arg0}'.")]
+    InvalidAddress(String),
+    #[fatal(code = "exception", diagnostic = "Failed to send email.")]
+    SendFailed,
+}
+
+pub struct EmailMessage<'a> {
+    pub to: &'a str,
+    pub subject: &'a str,
+    /// Rendered html body IE from the ui::email components.
+    pub html: &'a str,
+}
+
+/// Backend used to deliver emails, selected with EMAIL_TRANSPORT.
+pub enum EmailTransport {
+    SendGrid(SendGridTransport),
+    Smtp(SMTPTransport),
+    File(FileTransport),
+    /// No transport configured, sending will fail.
+    Disabled,
+}
+
+impl EmailTransport {
+    /// Builds the transport from the environment.
+    /// When EMAIL_TRANSPORT is not set SendGrid is used if SG_API_KEY is set for backwards compatibility.
+    pub fn from_config(
+        config: &dyn Config<ServerEnvironmentVariables>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let transport = config.get(ServerEnvironmentVariables::EmailTransport).ok();
+
+        match transport.as_deref() {
+            Some("sendgrid") => Ok(EmailTransport::SendGrid(SendGridTransport::from_config(
+                config,
+            )?)),
+            Some("smtp") => Ok(EmailTransport::Smtp(SMTPTransport::from_config(config)?)),
+            Some("file") => Ok(EmailTransport::File(FileTransport::from_config(config)?)),
+            Some(transport) => Err(EmailError::UnknownTransport(transport.to_string()).into()),
+            None => {
+                if config
+                    .get(ServerEnvironmentVariables::SendGridAPIKey)
+                    .is_ok()
+                {
+                    Ok(EmailTransport::SendGrid(SendGridTransport::from_config(
+                        config,
+                    )?))
+                } else {
+                    tracing::warn!("No email transport configured, emails will not be sent.");
+                    Ok(EmailTransport::Disabled)
+                }
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        from: &str,
+        message: &EmailMessage<'_>,
+    ) -> Result<(), OperationOutcomeError> {
+        match self {
+            EmailTransport::SendGrid(transport) => transport.send(from, message).await,
+            EmailTransport::Smtp(transport) => transport.send(from, message).await,
+            EmailTransport::File(transport) => transport.send(from, message).await,
+            EmailTransport::Disabled => Err(EmailError::NotConfigured.into()),
+        }
+    }
+}
+
+/// Sends emails from the configured EMAIL_FROM address.
+/// Used for password resets, signups and invitations.
+pub struct EmailService {
+    from_address: Option<String>,
+    transport: EmailTransport,
+}
+
+impl EmailService {
+    pub fn new(from_address: Option<String>, transport: EmailTransport) -> Self {
+        EmailService {
+            from_address,
+            transport,
+        }
+    }
+
+    pub fn from_config(
+        config: &dyn Config<ServerEnvironmentVariables>,
+    ) -> Result<Self, OperationOutcomeError> {
+        Ok(EmailService::new(
+            config
+                .get(ServerEnvironmentVariables::EmailFromAddress)
+                .ok(),
+            EmailTransport::from_config(config)?,
+        ))
+    }
+
+    pub async fn send(&self, message: &EmailMessage<'_>) -> Result<(), OperationOutcomeError> {
+        let from_address = self.from_address.as_ref().ok_or_else(|| {
+            EmailError::InvalidConfiguration(String::from(
+                ServerEnvironmentVariables::EmailFromAddress,
+            ))
+        })?;
+
+        self.transport.send(from_address, message).await
+    }
+}
+
+/// Builds a MIME message for the transports that deliver raw messages.
+fn to_mime_message(
+    from: &str,
+    message: &EmailMessage<'_>,
+) -> Result<lettre::Message, OperationOutcomeError> {
+    let from_mailbox = from
+        .parse::<lettre::message::Mailbox>()
+        .map_err(|_e| EmailError::InvalidAddress(from.to_string()))?;
+    let to_mailbox = message
+        .to
+        .parse::<lettre::message::Mailbox>()
+        .map_err(|_e| EmailError::InvalidAddress(message.to.to_string()))?;
+
+    let mime_message = lettre::Message::builder()
+        .from(from_mailbox)
+        .to(to_mailbox)
+        .subject(message.subject)
+        .header(lettre::message::header::ContentType::TEXT_HTML)
+        .body(message.html.to_string())
+        .map_err(|e| {
+            tracing::error!("Failed to build email '{}'", e);
+            EmailError::SendFailed
+        })?;
+
+    Ok(mime_message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_transport() {
+        let directory = std::env::temp_dir().join(format!(
+            "haste-email-test-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let service = EmailService::new(
+            Some("noreply@haste.health".to_string()),
+            EmailTransport::File(FileTransport::new(directory.clone())),
+        );
+
+        service
+            .send(&EmailMessage {
+                to: "user@example.com",
+                subject: "Password Reset",
+                html: "<a href=\"https://example.com\">Reset Password</a>",
+            })
+            .await
+            .unwrap();
+
+        let files = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+
+        let contents = std::fs::read_to_string(&files[0]).unwrap();
+        assert!(contents.contains("To: user@example.com"));
+        assert!(contents.contains("Subject: Password Reset"));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disabled_transport() {
+        let service = EmailService::new(
+            Some("noreply@haste.health".to_string()),
+            EmailTransport::Disabled,
+        );
+
+        assert!(
+            service
+                .send(&EmailMessage {
+                    to: "user@example.com",
+                    subject: "Password Reset",
+                    html: "",
+                })
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    ServerEnvironmentVariables,
+    email::{EmailError, EmailMessage},
+};
+use haste_config::Config;
+use haste_fhir_operation_error::OperationOutcomeError;
+use sendgrid::v3::{Content, Email, Message, Personalization, Sender};
+
+/// Delivers emails through the SendGrid v3 API.
+pub struct SendGridTransport {
+    sender: Sender,
+}
+
+impl SendGridTransport {
+    pub fn new(api_key: String) -> Self {
+        SendGridTransport {
+            sender: Sender::new(api_key, None),
+        }
+    }
+
+    pub fn from_config(
+        config: &dyn Config<ServerEnvironmentVariables>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let api_key = config.get(ServerEnvironmentVariables::SendGridAPIKey)?;
+        Ok(SendGridTransport::new(api_key))
+    }
+
+    pub async fn send(
+        &self,
+        from: &str,
+        message: &EmailMessage<'_>,
+    ) -> Result<(), OperationOutcomeError> {
+        let m = Message::new(Email::new(from))
+            .set_subject(message.subject)
+            .add_content(
+                Content::new()
+                    .set_content_type("text/html")
+                    .set_value(message.html),
+            )
+            .add_personalization(Personalization::new(Email::new(message.to)));
+
+        let resp = self.sender.send(&m).await.map_err(|e| {
+            tracing::error!("Failed to send email '{}'", e);
+            EmailError::SendFailed
+        })?;
+
+        tracing::info!("Email sent status: '{}'", resp.status());
+
+        Ok(())
+    }
+}
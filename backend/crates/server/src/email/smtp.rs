@@ -0,0 +1,124 @@
+use crate::{
+    ServerEnvironmentVariables,
+    email::{EmailError, EmailMessage, to_mime_message},
+};
+use haste_config::Config;
+use haste_fhir_operation_error::OperationOutcomeError;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    transport::smtp::authentication::Credentials,
+};
+
+/// Connection security set with SMTP_SECURITY.
+pub enum SMTPSecurity {
+    /// Upgrade a plaintext connection with STARTTLS (default port 587).
+    StartTLS,
+    /// Implicit TLS (default port 465).
+    ImplicitTLS,
+    /// Unencrypted, only for local relays and testing (default port 25).
+    None,
+}
+
+impl TryFrom<&str> for SMTPSecurity {
+    type Error = EmailError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "starttls" => Ok(SMTPSecurity::StartTLS),
+            "tls" => Ok(SMTPSecurity::ImplicitTLS),
+            "none" => Ok(SMTPSecurity::None),
+            _ => Err(EmailError::InvalidConfiguration(String::from(
+                ServerEnvironmentVariables::SMTPSecurity,
+            ))),
+        }
+    }
+}
+
+/// Delivers emails to an SMTP relay.
+pub struct SMTPTransport {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SMTPTransport {
+    pub fn new(
+        host: &str,
+        port: Option<u16>,
+        security: SMTPSecurity,
+        credentials: Option<(String, String)>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let mut builder = match security {
+            SMTPSecurity::StartTLS => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SMTPSecurity::ImplicitTLS => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            SMTPSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                host,
+            )),
+        }
+        .map_err(|e| {
+            tracing::error!("Failed to create SMTP transport '{}'", e);
+            EmailError::InvalidConfiguration(String::from(ServerEnvironmentVariables::SMTPHost))
+        })?;
+
+        if let Some(port) = port {
+            builder = builder.port(port);
+        }
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(SMTPTransport {
+            transport: builder.build(),
+        })
+    }
+
+    pub fn from_config(
+        config: &dyn Config<ServerEnvironmentVariables>,
+    ) -> Result<Self, OperationOutcomeError> {
+        let host = config.get(ServerEnvironmentVariables::SMTPHost)?;
+        let port = config
+            .get(ServerEnvironmentVariables::SMTPPort)
+            .ok()
+            .map(|port| {
+                port.parse::<u16>().map_err(|_e| {
+                    EmailError::InvalidConfiguration(String::from(
+                        ServerEnvironmentVariables::SMTPPort,
+                    ))
+                })
+            })
+            .transpose()?;
+        let security = SMTPSecurity::try_from(
+            config
+                .get(ServerEnvironmentVariables::SMTPSecurity)
+                .unwrap_or("starttls".to_string())
+                .as_str(),
+        )?;
+        let credentials = match (
+            config.get(ServerEnvironmentVariables::SMTPUsername),
+            config.get(ServerEnvironmentVariables::SMTPPassword),
+        ) {
+            (Ok(username), Ok(password)) => Some((username, password)),
+            _ => None,
+        };
+
+        SMTPTransport::new(&host, port, security, credentials)
+    }
+
+    pub async fn send(
+        &self,
+        from: &str,
+        message: &EmailMessage<'_>,
+    ) -> Result<(), OperationOutcomeError> {
+        let response = self
+            .transport
+            .send(to_mime_message(from, message)?)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to send email '{}'", e);
+                EmailError::SendFailed
+            })?;
+
+        tracing::info!("Email sent status: '{}'", response.code());
+
+        Ok(())
+    }
+}
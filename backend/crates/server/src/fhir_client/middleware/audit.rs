@@ -0,0 +1,793 @@
+use crate::{
+    fhir_client::{
+        ServerCTX,
+        middleware::{
+            ServerMiddlewareContext, ServerMiddlewareNext, ServerMiddlewareOutput,
+            ServerMiddlewareState,
+        },
+    },
+    services::AppState,
+};
+use haste_fhir_client::{
+    FHIRClient,
+    middleware::MiddlewareChain,
+    request::{
+        DeleteRequest, DeleteResponse, FHIRRequest, FHIRResponse, HistoryRequest,
+        InvocationRequest, InvokeResponse, SearchRequest, SearchResponse, UpdateRequest,
+    },
+    url::{ParsedParameter, ParsedParameters},
+};
+use haste_fhir_generated_ops::generated::HasteHealthExpunge;
+use haste_fhir_model::r4::{
+    datetime::Instant,
+    generated::{
+        resources::{
+            AuditEvent, AuditEventAgent, AuditEventEntity, AuditEventSource, Bundle, Project,
+            Resource, ResourceType,
+        },
+        terminology::{AuditEventAction, AuditEventOutcome, IssueType, SupportedFhirVersion},
+        types::{
+            CodeableConcept, Coding, FHIRBase64Binary, FHIRBoolean, FHIRCode, FHIRInstant,
+            FHIRString, FHIRUri, Reference,
+        },
+    },
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{AuthorId, AuthorKind, ProjectId, TenantId, UserRole, claims::UserTokenClaims};
+use haste_reflect::MetaValue;
+use haste_repository::{
+    Repository, admin::TenantAuthAdmin, fhir::FHIRRepository, types::project::CreateProject,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, OnceLock},
+};
+use tokio::sync::mpsc;
+
+/// Project audit events are written to in each tenant. Reserved so users can't create it.
+pub static AUDIT_PROJECT: &str = "audit";
+/// Requests wait for the writer once it falls this far behind so events are not dropped.
+static AUDIT_QUEUE_SIZE: usize = 10_000;
+/// Most events written in a single insert.
+static AUDIT_BATCH_SIZE: usize = 500;
+
+static AUDIT_EVENT_TYPE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/audit-event-type";
+static RESTFUL_INTERACTION_SYSTEM: &str = "http://hl7.org/fhir/restful-interaction";
+static DICOM_SYSTEM: &str = "http://dicom.nema.org/resources/ontology/DCM";
+static PARTICIPATION_TYPE_SYSTEM: &str =
+    "http://terminology.hl7.org/CodeSystem/v3-ParticipationType";
+static SECURITY_SOURCE_TYPE_SYSTEM: &str =
+    "http://terminology.hl7.org/CodeSystem/security-source-type";
+static ENTITY_TYPE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/audit-entity-type";
+static OBJECT_ROLE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/object-role";
+
+/// Top level fields that place a resource in a patients compartment.
+static PATIENT_REFERENCE_FIELDS: &[&str] = &["patient", "subject", "beneficiary"];
+
+pub struct AuditRecord {
+    pub tenant: TenantId,
+    pub event: AuditEvent,
+}
+
+static AUDIT_SENDER: OnceLock<mpsc::Sender<AuditRecord>> = OnceLock::new();
+
+/// Starts the background task that persists audit events.
+/// Events are only recorded once the writer is running.
+pub fn start_audit_writer<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    services: Arc<AppState<Repo, Search, Terminology>>,
+) {
+    let (sender, mut receiver) = mpsc::channel::<AuditRecord>(AUDIT_QUEUE_SIZE);
+    if AUDIT_SENDER.set(sender).is_err() {
+        tracing::warn!("Audit writer has already been started.");
+        return;
+    }
+
+    tokio::spawn(async move {
+        // Tenants whose audit project is known to exist.
+        let mut audit_projects = HashSet::<String>::new();
+        let mut records = Vec::with_capacity(AUDIT_BATCH_SIZE);
+        while receiver.recv_many(&mut records, AUDIT_BATCH_SIZE).await > 0 {
+            // Keyed by tenant id as TenantId isn't hashable.
+            let mut batches = HashMap::<String, (TenantId, Vec<Resource>)>::new();
+            for record in records.drain(..) {
+                batches
+                    .entry(record.tenant.as_ref().to_string())
+                    .or_insert_with(|| (record.tenant.clone(), vec![]))
+                    .1
+                    .push(Resource::AuditEvent(record.event));
+            }
+
+            for (tenant, mut events) in batches.into_values() {
+                if !audit_projects.contains(tenant.as_ref()) {
+                    if let Err(e) = ensure_audit_project(&services, &tenant).await {
+                        tracing::error!("Failed to create audit project: {:?}", e);
+                        continue;
+                    }
+                    audit_projects.insert(tenant.as_ref().to_string());
+                }
+
+                if let Err(e) = write_events(&services, &tenant, &mut events).await {
+                    tracing::error!("Failed to write {} audit events: {:?}", events.len(), e);
+                    // The audit project may have been removed so check it again on the next event.
+                    audit_projects.remove(tenant.as_ref());
+                }
+            }
+        }
+    });
+}
+
+/// Writes a tenants events to its audit project in a single insert.
+/// Events are built by the server so they go straight to the repository instead of through the
+/// client. They are indexed for search by the indexing worker.
+async fn write_events<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    services: &AppState<Repo, Search, Terminology>,
+    tenant: &TenantId,
+    events: &mut [Resource],
+) -> Result<(), OperationOutcomeError> {
+    let project = ProjectId::new(AUDIT_PROJECT.to_string());
+    let ctx = ServerCTX::system(
+        tenant.clone(),
+        project.clone(),
+        services.fhir_client.clone(),
+    );
+
+    services
+        .repo
+        .import(tenant, &project, &ctx.user, &ctx.fhir_version, events)
+        .await?;
+
+    Ok(())
+}
+
+async fn ensure_audit_project<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    services: &AppState<Repo, Search, Terminology>,
+    tenant: &TenantId,
+) -> Result<(), OperationOutcomeError> {
+    let existing = TenantAuthAdmin::<CreateProject, _, _, _, _>::read(
+        services.repo.as_ref(),
+        tenant,
+        &AUDIT_PROJECT.to_string(),
+    )
+    .await?;
+
+    match existing {
+        // Users can't create a project with the reserved id but it may predate the reservation.
+        Some(project) if !project.system_created => Err(OperationOutcomeError::error(
+            IssueType::Conflict(None),
+            format!(
+                "Project '{}' in tenant '{}' was not created by the system.",
+                AUDIT_PROJECT,
+                tenant.as_ref()
+            ),
+        )),
+        Some(_) => Ok(()),
+        None => {
+            services
+                .fhir_client
+                .create(
+                    Arc::new(ServerCTX::system(
+                        tenant.clone(),
+                        ProjectId::System,
+                        services.fhir_client.clone(),
+                    )),
+                    ResourceType::Project,
+                    Resource::Project(Project {
+                        id: Some(AUDIT_PROJECT.to_string()),
+                        name: Box::new(FHIRString {
+                            value: Some("Audit".to_string()),
+                            ..Default::default()
+                        }),
+                        fhirVersion: Box::new(SupportedFhirVersion::R4(None)),
+                        ..Default::default()
+                    }),
+                )
+                .await?;
+
+            Ok(())
+        }
+    }
+}
+
+fn string(value: &str) -> Box<FHIRString> {
+    Box::new(FHIRString {
+        value: Some(value.to_string()),
+        ..Default::default()
+    })
+}
+
+fn coding(system: &str, code: &str, display: &str) -> Coding {
+    Coding {
+        system: Some(Box::new(FHIRUri {
+            value: Some(system.to_string()),
+            ..Default::default()
+        })),
+        code: Some(Box::new(FHIRCode {
+            value: Some(code.to_string()),
+            ..Default::default()
+        })),
+        display: Some(string(display)),
+        ..Default::default()
+    }
+}
+
+fn reference(reference: &str) -> Box<Reference> {
+    Box::new(Reference {
+        reference: Some(string(reference)),
+        ..Default::default()
+    })
+}
+
+/// Restful interaction code (http://hl7.org/fhir/restful-interaction) and BALP action for a request.
+fn interaction(request: &FHIRRequest) -> (&'static str, AuditEventAction) {
+    match request {
+        FHIRRequest::Create(_) => ("create", AuditEventAction::C(None)),
+        FHIRRequest::Read(_) => ("read", AuditEventAction::R(None)),
+        FHIRRequest::VersionRead(_) => ("vread", AuditEventAction::R(None)),
+        FHIRRequest::Update(_) => ("update", AuditEventAction::U(None)),
+        FHIRRequest::Patch(_) => ("patch", AuditEventAction::U(None)),
+        FHIRRequest::Delete(_) => ("delete", AuditEventAction::D(None)),
+        FHIRRequest::Capabilities => ("capabilities", AuditEventAction::R(None)),
+        FHIRRequest::Search(SearchRequest::Type(_)) => ("search-type", AuditEventAction::E(None)),
+        FHIRRequest::Search(SearchRequest::System(_)) => {
+            ("search-system", AuditEventAction::E(None))
+        }
+        FHIRRequest::History(HistoryRequest::Instance(_)) => {
+            ("history-instance", AuditEventAction::R(None))
+        }
+        FHIRRequest::History(HistoryRequest::Type(_)) => {
+            ("history-type", AuditEventAction::R(None))
+        }
+        FHIRRequest::History(HistoryRequest::System(_)) => {
+            ("history-system", AuditEventAction::R(None))
+        }
+        // Expunge permanently removes data so it is recorded as a delete.
+        FHIRRequest::Invocation(_) if is_expunge(request) => {
+            ("operation", AuditEventAction::D(None))
+        }
+        FHIRRequest::Invocation(_) => ("operation", AuditEventAction::E(None)),
+        FHIRRequest::Batch(_) => ("batch", AuditEventAction::E(None)),
+        FHIRRequest::Transaction(_) => ("transaction", AuditEventAction::E(None)),
+    }
+}
+
+fn is_write(request: &FHIRRequest) -> bool {
+    matches!(
+        request,
+        FHIRRequest::Create(_)
+            | FHIRRequest::Update(_)
+            | FHIRRequest::Patch(_)
+            | FHIRRequest::Delete(_)
+            | FHIRRequest::Batch(_)
+            | FHIRRequest::Transaction(_)
+    ) || is_expunge(request)
+}
+
+fn is_expunge(request: &FHIRRequest) -> bool {
+    request_operation(request) == Some(HasteHealthExpunge::CODE)
+}
+
+/// Rebuilds the query string for search style requests.
+fn query_string(parameters: &ParsedParameters) -> String {
+    parameters
+        .parameters()
+        .iter()
+        .map(|parameter| {
+            let p = match parameter {
+                ParsedParameter::Result(p) | ParsedParameter::Resource(p) => p,
+            };
+            let mut name = p.name.clone();
+            if let Some(chains) = p.chains.as_ref() {
+                for chain in chains {
+                    name.push('.');
+                    name.push_str(chain);
+                }
+            }
+            if let Some(modifier) = p.modifier.as_ref() {
+                name.push(':');
+                name.push_str(modifier);
+            }
+            format!("{}={}", name, p.value.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn request_query(request: &FHIRRequest) -> Option<String> {
+    let parameters = match request {
+        FHIRRequest::Search(SearchRequest::Type(req)) => &req.parameters,
+        FHIRRequest::Search(SearchRequest::System(req)) => &req.parameters,
+        FHIRRequest::History(HistoryRequest::Instance(req)) => &req.parameters,
+        FHIRRequest::History(HistoryRequest::Type(req)) => &req.parameters,
+        FHIRRequest::History(HistoryRequest::System(req)) => &req.parameters,
+        FHIRRequest::Delete(DeleteRequest::Type(req)) => &req.parameters,
+        FHIRRequest::Delete(DeleteRequest::System(req)) => &req.parameters,
+        FHIRRequest::Update(UpdateRequest::Conditional(req)) => &req.parameters,
+        _ => return None,
+    };
+
+    Some(query_string(parameters))
+}
+
+/// Resource the request targets IE Patient/123 or Patient for type level requests.
+fn request_target(request: &FHIRRequest) -> Option<String> {
+    match request {
+        FHIRRequest::Read(req) => Some(format!("{}/{}", req.resource_type.as_ref(), req.id)),
+        FHIRRequest::VersionRead(req) => Some(format!(
+            "{}/{}/_history/{}",
+            req.resource_type.as_ref(),
+            req.id,
+            req.version_id.as_ref()
+        )),
+        FHIRRequest::Update(UpdateRequest::Instance(req)) => {
+            Some(format!("{}/{}", req.resource_type.as_ref(), req.id))
+        }
+        FHIRRequest::Patch(req) => Some(format!("{}/{}", req.resource_type.as_ref(), req.id)),
+        FHIRRequest::Delete(DeleteRequest::Instance(req)) => {
+            Some(format!("{}/{}", req.resource_type.as_ref(), req.id))
+        }
+        FHIRRequest::History(HistoryRequest::Instance(req)) => {
+            Some(format!("{}/{}", req.resource_type.as_ref(), req.id))
+        }
+        FHIRRequest::Invocation(InvocationRequest::Instance(req)) => {
+            Some(format!("{}/{}", req.resource_type.as_ref(), req.id))
+        }
+        _ => None,
+    }
+}
+
+fn request_operation(request: &FHIRRequest) -> Option<&str> {
+    match request {
+        FHIRRequest::Invocation(InvocationRequest::Instance(req)) => Some(req.operation.name()),
+        FHIRRequest::Invocation(InvocationRequest::Type(req)) => Some(req.operation.name()),
+        FHIRRequest::Invocation(InvocationRequest::System(req)) => Some(req.operation.name()),
+        _ => None,
+    }
+}
+
+fn resource_id(resource: &Resource) -> Option<String> {
+    resource
+        .get_field("id")
+        .and_then(|id| id.as_any().downcast_ref::<String>())
+        .map(|id| format!("{}/{}", resource.typename(), id))
+}
+
+/// Patients whose compartment the resource is in.
+fn resource_patients(resource: &Resource, patients: &mut Vec<String>) {
+    let mut add = |patient: String| {
+        if !patients.contains(&patient) {
+            patients.push(patient);
+        }
+    };
+
+    if let Resource::Patient(patient) = resource {
+        if let Some(id) = patient.id.as_ref() {
+            add(format!("Patient/{}", id));
+        }
+        return;
+    }
+
+    for field in PATIENT_REFERENCE_FIELDS {
+        if let Some(patient) = resource
+            .get_field(field)
+            .and_then(|value| value.as_any().downcast_ref::<Box<Reference>>())
+            .and_then(|r| r.reference.as_ref())
+            .and_then(|r| r.value.as_ref())
+            .filter(|r| r.starts_with("Patient/"))
+        {
+            add(patient.clone());
+        }
+    }
+}
+
+fn bundle_patients(bundle: &Bundle, patients: &mut Vec<String>) {
+    for resource in bundle
+        .entry
+        .iter()
+        .flatten()
+        .filter_map(|entry| entry.resource.as_ref())
+    {
+        resource_patients(resource, patients);
+    }
+}
+
+fn request_patients(request: &FHIRRequest, patients: &mut Vec<String>) {
+    match request {
+        FHIRRequest::Create(req) => resource_patients(&req.resource, patients),
+        FHIRRequest::Update(UpdateRequest::Instance(req)) => {
+            resource_patients(&req.resource, patients)
+        }
+        FHIRRequest::Update(UpdateRequest::Conditional(req)) => {
+            resource_patients(&req.resource, patients)
+        }
+        _ => {}
+    }
+}
+
+fn response_patients(response: &FHIRResponse, patients: &mut Vec<String>) {
+    match response {
+        FHIRResponse::Create(res) => resource_patients(&res.resource, patients),
+        FHIRResponse::Read(res) => {
+            if let Some(resource) = res.resource.as_ref() {
+                resource_patients(resource, patients)
+            }
+        }
+        FHIRResponse::VersionRead(res) => resource_patients(&res.resource, patients),
+        FHIRResponse::Update(res) => resource_patients(&res.resource, patients),
+        FHIRResponse::Patch(res) => resource_patients(&res.resource, patients),
+        FHIRResponse::Delete(DeleteResponse::Instance(res)) => {
+            resource_patients(&res.resource, patients)
+        }
+        FHIRResponse::Search(SearchResponse::Type(res)) => bundle_patients(&res.bundle, patients),
+        FHIRResponse::Search(SearchResponse::System(res)) => bundle_patients(&res.bundle, patients),
+        FHIRResponse::Invoke(InvokeResponse::Instance(res)) => {
+            resource_patients(&res.resource, patients)
+        }
+        _ => {}
+    }
+}
+
+fn response_target(response: &FHIRResponse) -> Option<String> {
+    match response {
+        FHIRResponse::Create(res) => resource_id(&res.resource),
+        _ => None,
+    }
+}
+
+fn user_role(role: &UserRole) -> &'static str {
+    match role {
+        UserRole::Owner => "owner",
+        UserRole::Admin => "admin",
+        UserRole::Member => "member",
+    }
+}
+
+fn user_agent(user: &UserTokenClaims, write: bool) -> AuditEventAgent {
+    let who = match (&user.resource_type, &user.user_id) {
+        (AuthorKind::Membership, AuthorId::User(id)) => Some(format!("User/{}", id.as_ref())),
+        (AuthorKind::ClientApplication, AuthorId::User(id)) => {
+            Some(format!("ClientApplication/{}", id.as_ref()))
+        }
+        _ => None,
+    };
+
+    let participation = if write {
+        coding(PARTICIPATION_TYPE_SYSTEM, "AUT", "author (originator)")
+    } else {
+        coding(PARTICIPATION_TYPE_SYSTEM, "IRCP", "information recipient")
+    };
+
+    AuditEventAgent {
+        type_: Some(Box::new(CodeableConcept {
+            coding: Some(vec![Box::new(participation)]),
+            ..Default::default()
+        })),
+        role: Some(vec![Box::new(CodeableConcept {
+            text: Some(string(user_role(&user.user_role))),
+            ..Default::default()
+        })]),
+        who: who.as_deref().map(reference),
+        altId: user.membership.as_deref().map(string),
+        requestor: Box::new(FHIRBoolean {
+            value: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn client_agent(user: &UserTokenClaims) -> AuditEventAgent {
+    AuditEventAgent {
+        type_: Some(Box::new(CodeableConcept {
+            coding: Some(vec![Box::new(coding(
+                DICOM_SYSTEM,
+                "110153",
+                "Source Role ID",
+            ))]),
+            ..Default::default()
+        })),
+        who: Some(reference(&format!("ClientApplication/{}", user.aud))),
+        requestor: Box::new(FHIRBoolean {
+            value: Some(false),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn entity(
+    what: Option<&str>,
+    entity_type: (&str, &str),
+    role: (&str, &str),
+    query: Option<&str>,
+    description: Option<&str>,
+) -> AuditEventEntity {
+    AuditEventEntity {
+        what: what.map(reference),
+        type_: Some(Box::new(coding(
+            ENTITY_TYPE_SYSTEM,
+            entity_type.0,
+            entity_type.1,
+        ))),
+        role: Some(Box::new(coding(OBJECT_ROLE_SYSTEM, role.0, role.1))),
+        query: query.map(|query| {
+            Box::new(FHIRBase64Binary {
+                value: Some(base64::Engine::encode(
+                    &base64::engine::general_purpose::STANDARD,
+                    query,
+                )),
+                ..Default::default()
+            })
+        }),
+        description: description.map(string),
+        ..Default::default()
+    }
+}
+
+struct PendingAudit {
+    subtype: &'static str,
+    action: AuditEventAction,
+    write: bool,
+    target: Option<String>,
+    query: Option<String>,
+    operation: Option<String>,
+    patients: Vec<String>,
+}
+
+impl PendingAudit {
+    fn new(request: &FHIRRequest) -> Self {
+        let (subtype, action) = interaction(request);
+        let mut patients = vec![];
+        request_patients(request, &mut patients);
+
+        PendingAudit {
+            subtype,
+            action,
+            write: is_write(request),
+            target: request_target(request),
+            query: request_query(request),
+            operation: request_operation(request).map(|o| match request {
+                FHIRRequest::Invocation(InvocationRequest::Type(req)) => {
+                    format!("{}/${}", req.resource_type.as_ref(), o)
+                }
+                _ => format!("${}", o),
+            }),
+            patients,
+        }
+    }
+
+    fn into_event(
+        mut self,
+        user: &UserTokenClaims,
+        project: &ProjectId,
+        result: Result<&FHIRResponse, &OperationOutcomeError>,
+    ) -> AuditEvent {
+        let (outcome, outcome_desc) = match result {
+            Ok(response) => {
+                response_patients(response, &mut self.patients);
+                if self.target.is_none() {
+                    self.target = response_target(response);
+                }
+                (AuditEventOutcome::V0(None), None)
+            }
+            Err(error) => {
+                let description = error
+                    .outcome()
+                    .issue
+                    .first()
+                    .and_then(|issue| issue.diagnostics.as_ref())
+                    .and_then(|d| d.value.clone());
+                if error.status().is_client_error() {
+                    (AuditEventOutcome::V4(None), description)
+                } else {
+                    (AuditEventOutcome::V8(None), description)
+                }
+            }
+        };
+
+        let mut entities = vec![];
+        if self.target.is_some() || self.query.is_some() || self.operation.is_some() {
+            let (role, description) = if self.query.is_some() {
+                (("24", "Query"), None)
+            } else {
+                (("4", "Domain Resource"), self.operation.clone())
+            };
+            entities.push(entity(
+                self.target.as_deref(),
+                ("2", "System Object"),
+                role,
+                self.query.as_deref(),
+                description.as_deref(),
+            ));
+        }
+        for patient in self.patients.iter() {
+            entities.push(entity(
+                Some(patient),
+                ("1", "Person"),
+                ("1", "Patient"),
+                None,
+                None,
+            ));
+        }
+
+        AuditEvent {
+            type_: Box::new(coding(AUDIT_EVENT_TYPE_SYSTEM, "rest", "Restful Operation")),
+            subtype: Some(vec![Box::new(coding(
+                RESTFUL_INTERACTION_SYSTEM,
+                self.subtype,
+                self.subtype,
+            ))]),
+            action: Some(Box::new(self.action)),
+            recorded: Box::new(FHIRInstant {
+                value: Some(Instant::Iso8601(chrono::Utc::now())),
+                ..Default::default()
+            }),
+            outcome: Some(Box::new(outcome)),
+            outcomeDesc: outcome_desc.as_deref().map(string),
+            agent: vec![user_agent(user, self.write), client_agent(user)],
+            source: AuditEventSource {
+                site: Some(string(project.as_ref())),
+                observer: Box::new(Reference {
+                    display: Some(string("haste-health")),
+                    ..Default::default()
+                }),
+                type_: Some(vec![Box::new(coding(
+                    SECURITY_SOURCE_TYPE_SYSTEM,
+                    "4",
+                    "Application Server",
+                ))]),
+                ..Default::default()
+            },
+            entity: if entities.is_empty() {
+                None
+            } else {
+                Some(entities)
+            },
+            ..Default::default()
+        }
+    }
+}
+
+async fn record(tenant: TenantId, event: AuditEvent) {
+    if let Some(sender) = AUDIT_SENDER.get() {
+        if let Err(e) = sender.send(AuditRecord { tenant, event }).await {
+            tracing::error!("Failed to queue audit event: {}", e);
+        }
+    }
+}
+
+/// Records an AuditEvent (IHE Basic Audit Log Patterns) for every request made by a user or client.
+/// Events are written asynchronously to the tenants audit project.
+pub struct Middleware {}
+impl Middleware {
+    pub fn new() -> Self {
+        Middleware {}
+    }
+}
+
+impl<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>
+    MiddlewareChain<
+        ServerMiddlewareState<Repo, Search, Terminology>,
+        Arc<ServerCTX<Repo, Search, Terminology>>,
+        FHIRRequest,
+        FHIRResponse,
+        OperationOutcomeError,
+    > for Middleware
+{
+    fn call(
+        &self,
+        state: ServerMiddlewareState<Repo, Search, Terminology>,
+        context: ServerMiddlewareContext<Repo, Search, Terminology>,
+        next: Option<Arc<ServerMiddlewareNext<Repo, Search, Terminology>>>,
+    ) -> ServerMiddlewareOutput<Repo, Search, Terminology> {
+        Box::pin(async move {
+            let Some(next) = next else {
+                return Err(OperationOutcomeError::fatal(
+                    IssueType::Exception(None),
+                    "No next middleware found".to_string(),
+                ));
+            };
+
+            // Internal requests are not audited (this includes writing the audit events).
+            if matches!(context.ctx.user.sub, AuthorId::System)
+                || matches!(context.request, FHIRRequest::Capabilities)
+            {
+                return next(state, context).await;
+            }
+
+            if context.ctx.project.as_ref() == AUDIT_PROJECT && is_write(&context.request) {
+                return Err(OperationOutcomeError::error(
+                    IssueType::Forbidden(None),
+                    "Audit events are read only.".to_string(),
+                ));
+            }
+
+            let pending = PendingAudit::new(&context.request);
+            let tenant = context.ctx.tenant.clone();
+            let project = context.ctx.project.clone();
+            let user = context.ctx.user.clone();
+
+            let result = next(state, context).await;
+
+            let event = match &result {
+                Ok(context) => match context.response.as_ref() {
+                    Some(response) => pending.into_event(&user, &project, Ok(response)),
+                    None => return result,
+                },
+                Err(error) => pending.into_event(&user, &project, Err(error)),
+            };
+            record(tenant, event).await;
+
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_client::request::{FHIRInvokeTypeRequest, FHIRReadRequest, Operation};
+    use haste_fhir_model::r4::generated::resources::{Observation, Parameters};
+
+    #[test]
+    fn test_patient_compartment() {
+        let observation = Resource::Observation(Observation {
+            id: Some("obs-1".to_string()),
+            subject: Some(reference("Patient/123")),
+            ..Default::default()
+        });
+
+        let mut patients = vec![];
+        resource_patients(&observation, &mut patients);
+        resource_patients(&observation, &mut patients);
+        assert_eq!(patients, vec!["Patient/123".to_string()]);
+        assert_eq!(
+            resource_id(&observation),
+            Some("Observation/obs-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_target() {
+        let request = FHIRRequest::Read(FHIRReadRequest {
+            resource_type: ResourceType::Patient,
+            id: "123".to_string(),
+        });
+        let pending = PendingAudit::new(&request);
+
+        assert_eq!(pending.subtype, "read");
+        assert_eq!(pending.target, Some("Patient/123".to_string()));
+        assert!(pending.query.is_none());
+    }
+
+    #[test]
+    fn test_expunge_is_audited_as_delete() {
+        let request = FHIRRequest::Invocation(InvocationRequest::Type(FHIRInvokeTypeRequest {
+            operation: Operation::new("$expunge").unwrap(),
+            resource_type: ResourceType::Patient,
+            parameters: Parameters::default(),
+        }));
+        let pending = PendingAudit::new(&request);
+
+        assert!(pending.write);
+        assert!(matches!(pending.action, AuditEventAction::D(_)));
+        assert_eq!(pending.operation, Some("Patient/$expunge".to_string()));
+    }
+}
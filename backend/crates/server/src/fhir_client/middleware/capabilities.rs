@@ -22,7 +22,7 @@ use haste_fhir_model::r4::generated::{
     terminology::{
         IssueType, ResourceTypes, RestfulCapabilityMode, TypeRestfulInteraction, VersioningPolicy,
     },
-    types::{FHIRBoolean, FHIRString},
+    types::{FHIRBoolean, FHIRCode, FHIRString},
 };
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_search::{SearchEngine, SearchOptions};
@@ -102,6 +102,7 @@ fn create_capability_rest_statement(
                 TypeRestfulInteraction::Read(None),
                 TypeRestfulInteraction::Vread(None),
                 TypeRestfulInteraction::Update(None),
+                TypeRestfulInteraction::Patch(None),
                 TypeRestfulInteraction::Delete(None),
                 TypeRestfulInteraction::SearchType(None),
                 TypeRestfulInteraction::Create(None),
@@ -240,6 +241,18 @@ async fn generate_capabilities<Repo: Repository, Search: SearchEngine>(
     let sps = sps?;
 
     Ok(CapabilityStatement {
+        // JSON Patch and FHIRPath Patch (Parameters) bodies.
+        patchFormat: Some(
+            ["application/json-patch+json", "application/fhir+json"]
+                .into_iter()
+                .map(|format| {
+                    Box::new(FHIRCode {
+                        value: Some(format.to_string()),
+                        ..Default::default()
+                    })
+                })
+                .collect(),
+        ),
         rest: Some(vec![CapabilityStatementRest {
             mode: Box::new(RestfulCapabilityMode::Server(None)),
             security: Some(CapabilityStatementRestSecurity {
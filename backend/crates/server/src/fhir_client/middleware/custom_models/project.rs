@@ -2,7 +2,7 @@ use crate::fhir_client::{
     ServerCTX,
     middleware::{
         ServerMiddlewareContext, ServerMiddlewareNext, ServerMiddlewareOutput,
-        ServerMiddlewareState,
+        ServerMiddlewareState, audit::AUDIT_PROJECT, referential_integrity,
     },
     utilities::request_to_resource_type,
 };
@@ -80,7 +80,20 @@ impl<
                                 }?;
 
                                 let name = project.name.clone();
+                                let require_mfa = project.requireMfa.clone();
+                                let auto_provenance = project.autoProvenance.clone();
+                                let open_client_registration =
+                                    project.openClientRegistration.clone();
+                                let referential_integrity = project.referentialIntegrity.clone();
                                 let id = project.id.clone().unwrap_or(generate_id(Some(8)));
+                                if id == AUDIT_PROJECT
+                                    && context.ctx.user.resource_type != AuthorKind::System
+                                {
+                                    return Err(OperationOutcomeError::error(
+                                        IssueType::Forbidden(None),
+                                        format!("Project id '{}' is reserved.", AUDIT_PROJECT),
+                                    ));
+                                }
 
                                 let project_model = TenantAuthAdmin::create(
                                     state.repo.as_ref(),
@@ -107,6 +120,11 @@ impl<
                                                 resource: Resource::Project(Project {
                                                     id: Some(id),
                                                     name: name,
+                                                    requireMfa: require_mfa,
+                                                    autoProvenance: auto_provenance,
+                                                    openClientRegistration:
+                                                        open_client_registration,
+                                                    referentialIntegrity: referential_integrity,
                                                     fhirVersion: match project_model.fhir_version {
                                                         SupportedFHIRVersions::R4 => {
                                                             Box::new(SupportedFhirVersion::R4(None))
@@ -195,6 +213,11 @@ impl<
                                 )
                                 .await?;
 
+                                referential_integrity::invalidate_setting(
+                                    &context.ctx.tenant,
+                                    &ProjectId::new(id),
+                                );
+
                                 Ok(res)
                             } else {
                                 Err(OperationOutcomeError::fatal(
@@ -221,12 +244,18 @@ impl<
                                         FHIRDeleteInstanceRequest {
                                             resource_type: ResourceType::Project,
                                             id: delete_request.id.clone(),
+                                            parameters: delete_request.parameters.clone(),
                                         },
                                     )),
                                 },
                             )
                             .await?;
 
+                            referential_integrity::invalidate_setting(
+                                &context.ctx.tenant,
+                                &ProjectId::new(delete_request.id.clone()),
+                            );
+
                             Ok(res)
                         }
 
@@ -6,11 +6,15 @@ use haste_fhir_client::{
 use haste_fhir_operation_error::OperationOutcomeError;
 use std::sync::Arc;
 
+pub mod audit;
 pub mod auth_z;
 pub mod capabilities;
 pub mod check_project;
 pub mod custom_models;
 pub mod operations;
+pub mod provenance;
+pub mod quota;
+pub mod referential_integrity;
 pub mod set_artifact_tenant;
 pub mod storage;
 pub mod transaction;
@@ -0,0 +1,162 @@
+use crate::fhir_client::{
+    SPECIAL_TYPES,
+    middleware::{operations::ServerOperationContext, quota::invalidate_usage},
+};
+use haste_fhir_client::request::InvocationRequest;
+use haste_fhir_generated_ops::generated::HasteHealthExpunge;
+use haste_fhir_model::r4::generated::{
+    resources::{OperationOutcome, OperationOutcomeIssue},
+    terminology::{IssueSeverity, IssueType},
+    types::FHIRString,
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, ResourceId, TenantId, UserRole};
+use haste_repository::Repository;
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum ExpungeError {
+    #[error(
+        code = "forbidden",
+        diagnostic = "Only tenant owners and admins can expunge resources."
+    )]
+    Forbidden,
+    #[error(
+        code = "not-supported",
+        diagnostic = "'{arg0}' resources cannot be expunged."
+    )]
+    UnsupportedType(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "Resources in the system project cannot be expunged."
+    )]
+    SystemProject,
+    #[error(
+        code = "invalid",
+        diagnostic = "Expunging every resource of a type or project can not be undone, set confirm to true to proceed."
+    )]
+    ConfirmationRequired,
+    #[error(
+        code = "not-supported",
+        diagnostic = "Expunge can not be used within a transaction."
+    )]
+    InTransaction,
+}
+
+pub fn expunge<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    HasteHealthExpunge::Input,
+    HasteHealthExpunge::Output,
+> {
+    OperationExecutor::new(
+        HasteHealthExpunge::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             project: ProjectId,
+             request: &InvocationRequest,
+             input: HasteHealthExpunge::Input| {
+                let (resource_type, id) = match request {
+                    InvocationRequest::Instance(req) => (
+                        Some(req.resource_type.clone()),
+                        Some(ResourceId::new(req.id.clone())),
+                    ),
+                    InvocationRequest::Type(req) => (Some(req.resource_type.clone()), None),
+                    InvocationRequest::System(_) => (None, None),
+                };
+
+                Box::pin(async move {
+                    if context.ctx.user.user_role == UserRole::Member {
+                        return Err(ExpungeError::Forbidden.into());
+                    }
+
+                    if project == ProjectId::System {
+                        return Err(ExpungeError::SystemProject.into());
+                    }
+
+                    if id.is_none() && input.confirm.and_then(|confirm| confirm.value) != Some(true)
+                    {
+                        return Err(ExpungeError::ConfirmationRequired.into());
+                    }
+
+                    if let Some(resource_type) = resource_type.as_ref()
+                        && SPECIAL_TYPES.contains(resource_type)
+                    {
+                        return Err(ExpungeError::UnsupportedType(
+                            resource_type.as_ref().to_string(),
+                        )
+                        .into());
+                    }
+
+                    // Index entries can not be rolled back alongside the history.
+                    if context.state.repo.in_transaction() {
+                        return Err(ExpungeError::InTransaction.into());
+                    }
+
+                    // The history is removed in a single statement first so the index is only
+                    // cleared for resources that no longer exist.
+                    let versions = context
+                        .state
+                        .repo
+                        .expunge(
+                            &tenant,
+                            &project,
+                            resource_type.as_ref(),
+                            id.as_ref(),
+                            &SPECIAL_TYPES,
+                        )
+                        .await?
+                        .len();
+                    let documents = context
+                        .state
+                        .search
+                        .remove(
+                            &context.ctx.fhir_version,
+                            &tenant,
+                            &project,
+                            resource_type.as_ref(),
+                            id.as_ref(),
+                            &SPECIAL_TYPES,
+                        )
+                        .await?;
+
+                    invalidate_usage(&tenant);
+
+                    tracing::info!(
+                        tenant = ?tenant,
+                        project = ?project,
+                        resource_type = ?resource_type,
+                        id = ?id,
+                        versions = versions,
+                        documents = documents,
+                        "$expunge"
+                    );
+
+                    Ok(HasteHealthExpunge::Output {
+                        return_: OperationOutcome {
+                            issue: vec![OperationOutcomeIssue {
+                                severity: Box::new(IssueSeverity::Information(None)),
+                                code: Box::new(IssueType::Informational(None)),
+                                diagnostics: Some(Box::new(FHIRString {
+                                    value: Some(format!(
+                                        "Expunged {} versions and {} search index entries.",
+                                        versions, documents
+                                    )),
+                                    ..Default::default()
+                                })),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        },
+                    })
+                })
+            },
+        ),
+    )
+}
@@ -0,0 +1,415 @@
+use crate::{
+    ServerEnvironmentVariables,
+    fhir_client::{
+        SPECIAL_TYPES,
+        middleware::{
+            operations::ServerOperationContext,
+            quota::{QuotaError, record_resources, tenant_usage},
+        },
+    },
+};
+use futures::TryStreamExt;
+use haste_config::Config;
+use haste_fhir_client::request::InvocationRequest;
+use haste_fhir_generated_ops::generated::HasteHealthImport;
+use haste_fhir_model::r4::generated::{
+    resources::{OperationOutcome, OperationOutcomeIssue, Resource, ResourceType},
+    terminology::{IssueSeverity, IssueType},
+    types::{FHIRId, FHIRString},
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_serialization_json::stream::NDJSONReader;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{AuthorId, ProjectId, TenantId, UserRole, VersionId, claims::UserTokenClaims};
+use haste_reflect::MetaValue;
+use haste_repository::{Repository, types::SupportedFHIRVersions, utilities::validate_id};
+use std::{collections::HashSet, time::Duration};
+use tokio::io::{AsyncBufRead, BufReader};
+use tokio_util::io::StreamReader;
+
+static DEFAULT_BATCH_SIZE: usize = 1000;
+/// Each row binds 10 parameters and Postgres allows at most 65535 per statement.
+static MAX_BATCH_SIZE: usize = 5000;
+/// Issues past this are counted but not listed.
+static MAX_ISSUES: usize = 1000;
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum ImportError {
+    #[error(
+        code = "forbidden",
+        diagnostic = "Only tenant owners and admins can import resources."
+    )]
+    Forbidden,
+    #[error(
+        code = "invalid",
+        diagnostic = "batch-size must be between 1 and {arg0}."
+    )]
+    InvalidBatchSize(usize),
+    #[error(
+        code = "not-supported",
+        diagnostic = "File imports are disabled as no import directory is configured."
+    )]
+    FileImportDisabled,
+    #[error(
+        code = "security",
+        diagnostic = "Source '{arg0}' is outside of the import directory."
+    )]
+    OutsideImportDirectory(String),
+    #[error(
+        code = "not-supported",
+        diagnostic = "URL imports are disabled as no allowed hosts are configured."
+    )]
+    UrlImportDisabled,
+    #[error(
+        code = "security",
+        diagnostic = "Source '{arg0}' is not on an allowed import host."
+    )]
+    HostNotAllowed(String),
+    #[error(
+        code = "not-found",
+        diagnostic = "Failed to open source '{arg0}': {arg1}"
+    )]
+    SourceUnavailable(String, String),
+}
+
+static CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+type SourceReader = Box<dyn AsyncBufRead + Send + Unpin>;
+
+/// Checks a URL source against the configured allowed hosts.
+fn verify_url_source(
+    config: &dyn Config<ServerEnvironmentVariables>,
+    source: &str,
+) -> Result<(), OperationOutcomeError> {
+    let allowed_hosts = config
+        .get(ServerEnvironmentVariables::ImportAllowedHosts)
+        .map_err(|_e| ImportError::UrlImportDisabled)?;
+    let url = url::Url::parse(source)
+        .map_err(|e| ImportError::SourceUnavailable(source.to_string(), e.to_string()))?;
+    let host = url.host_str().unwrap_or_default();
+
+    if !allowed_hosts
+        .split(',')
+        .map(str::trim)
+        .any(|allowed| !allowed.is_empty() && allowed.eq_ignore_ascii_case(host))
+    {
+        return Err(ImportError::HostNotAllowed(source.to_string()).into());
+    }
+
+    Ok(())
+}
+
+/// Opens an NDJSON source. URLs are streamed from allowed hosts and anything else is treated
+/// as a path that must resolve inside the configured import directory.
+async fn open_source(
+    config: &dyn Config<ServerEnvironmentVariables>,
+    source: &str,
+) -> Result<SourceReader, OperationOutcomeError> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        verify_url_source(config, source)?;
+
+        // Redirects are not followed so the allowed hosts can't be bypassed.
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| ImportError::SourceUnavailable(source.to_string(), e.to_string()))?;
+        let response = client
+            .get(source)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ImportError::SourceUnavailable(source.to_string(), e.to_string()))?;
+        let body = response.bytes_stream().map_err(std::io::Error::other);
+
+        return Ok(Box::new(StreamReader::new(Box::pin(body))));
+    }
+
+    let import_directory = config
+        .get(ServerEnvironmentVariables::ImportDirectory)
+        .map_err(|_e| ImportError::FileImportDisabled)?;
+    let import_directory = tokio::fs::canonicalize(&import_directory)
+        .await
+        .map_err(|e| ImportError::SourceUnavailable(import_directory.clone(), e.to_string()))?;
+
+    let path = tokio::fs::canonicalize(
+        import_directory.join(source.strip_prefix("file://").unwrap_or(source)),
+    )
+    .await
+    .map_err(|e| ImportError::SourceUnavailable(source.to_string(), e.to_string()))?;
+    if !path.starts_with(&import_directory) {
+        return Err(ImportError::OutsideImportDirectory(source.to_string()).into());
+    }
+
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| ImportError::SourceUnavailable(source.to_string(), e.to_string()))?;
+
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn issue(severity: IssueSeverity, code: IssueType, diagnostics: String) -> OperationOutcomeIssue {
+    OperationOutcomeIssue {
+        severity: Box::new(severity),
+        code: Box::new(code),
+        diagnostics: Some(Box::new(FHIRString {
+            value: Some(diagnostics),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+fn version_id(resource: &Resource) -> Option<&String> {
+    resource
+        .get_field("meta")
+        .and_then(|meta| meta.get_field("versionId"))
+        .and_then(|vid| vid.as_any().downcast_ref::<Box<FHIRId>>())
+        .and_then(|vid| vid.value.as_ref())
+}
+
+struct Importer<'a, Repo: Repository> {
+    repo: &'a Repo,
+    tenant: &'a TenantId,
+    project: &'a ProjectId,
+    user: &'a UserTokenClaims,
+    fhir_version: &'a SupportedFHIRVersions,
+    batch_size: usize,
+    /// Resources waiting to be written alongside where they were read from.
+    batch: Vec<(String, Resource)>,
+    /// Stored resource count, limit and tier name when the tenant has a resource quota.
+    quota: Option<(i64, i64, String)>,
+    imported: usize,
+    failed: usize,
+    issues: Vec<OperationOutcomeIssue>,
+}
+
+impl<'a, Repo: Repository> Importer<'a, Repo> {
+    fn fail(&mut self, code: IssueType, diagnostics: String) {
+        self.failed += 1;
+        if self.issues.len() < MAX_ISSUES {
+            self.issues
+                .push(issue(IssueSeverity::Error(None), code, diagnostics));
+        }
+    }
+
+    async fn push(
+        &mut self,
+        location: String,
+        resource: Resource,
+    ) -> Result<(), OperationOutcomeError> {
+        match ResourceType::try_from(resource.typename()) {
+            Ok(resource_type) if SPECIAL_TYPES.contains(&resource_type) => {
+                self.fail(
+                    IssueType::NotSupported(None),
+                    format!(
+                        "{}: '{}' resources cannot be imported.",
+                        location,
+                        resource.typename()
+                    ),
+                );
+            }
+            Ok(_) => {
+                if let Some(id) = resource
+                    .get_field("id")
+                    .and_then(|id| id.as_any().downcast_ref::<String>())
+                    && validate_id(id).is_err()
+                {
+                    self.fail(
+                        IssueType::Invalid(None),
+                        format!("{}: invalid id '{}'.", location, id),
+                    );
+                    return Ok(());
+                }
+
+                self.batch.push((location, resource));
+                if self.batch.len() >= self.batch_size {
+                    self.flush().await?;
+                }
+            }
+            Err(_) => {
+                self.fail(
+                    IssueType::Invalid(None),
+                    format!(
+                        "{}: unknown resource type '{}'.",
+                        location,
+                        resource.typename()
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<(), OperationOutcomeError> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+
+        if let Some((stored, limit, tier)) = &self.quota
+            && stored + self.batch.len() as i64 > *limit
+        {
+            return Err(QuotaError::ResourceQuota(*limit, tier.clone()).into());
+        }
+
+        let (locations, mut resources): (Vec<_>, Vec<_>) = self.batch.drain(..).unzip();
+        let stored = self
+            .repo
+            .import(
+                self.tenant,
+                self.project,
+                self.user,
+                self.fhir_version,
+                &mut resources,
+            )
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        // Every row is reported unless its version id was returned as stored.
+        for (location, resource) in locations.into_iter().zip(resources.iter()) {
+            let stored_row = version_id(resource)
+                .is_some_and(|version_id| stored.contains(&VersionId::new(version_id.clone())));
+            if !stored_row {
+                self.fail(
+                    IssueType::Exception(None),
+                    format!("{}: resource was not stored.", location),
+                );
+            }
+        }
+
+        self.imported += stored.len();
+        record_resources(self.tenant, stored.len() as i64);
+        if let Some((count, _, _)) = self.quota.as_mut() {
+            *count += stored.len() as i64;
+        }
+
+        tracing::info!(
+            tenant = ?self.tenant,
+            project = ?self.project,
+            imported = self.imported,
+            failed = self.failed,
+            "$import progress"
+        );
+
+        Ok(())
+    }
+
+    fn outcome(self) -> OperationOutcome {
+        let mut issues = vec![issue(
+            IssueSeverity::Information(None),
+            IssueType::Informational(None),
+            format!(
+                "Imported {} resources, {} failed.",
+                self.imported, self.failed
+            ),
+        )];
+        let omitted = self.failed.saturating_sub(self.issues.len());
+        issues.extend(self.issues);
+        if omitted > 0 {
+            issues.push(issue(
+                IssueSeverity::Warning(None),
+                IssueType::TooCostly(None),
+                format!("{} more errors omitted.", omitted),
+            ));
+        }
+
+        OperationOutcome {
+            issue: issues,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn import<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    HasteHealthImport::Input,
+    HasteHealthImport::Output,
+> {
+    OperationExecutor::new(
+        HasteHealthImport::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             project: ProjectId,
+             _request: &InvocationRequest,
+             input: HasteHealthImport::Input| {
+                Box::pin(async move {
+                    if context.ctx.user.user_role == UserRole::Member {
+                        return Err(ImportError::Forbidden.into());
+                    }
+
+                    let batch_size = match input.batch_size.and_then(|size| size.value) {
+                        Some(size) if size < 1 || size as usize > MAX_BATCH_SIZE => {
+                            return Err(ImportError::InvalidBatchSize(MAX_BATCH_SIZE).into());
+                        }
+                        Some(size) => size as usize,
+                        None => DEFAULT_BATCH_SIZE,
+                    };
+
+                    let quota = if tenant == TenantId::System
+                        || matches!(context.ctx.user.sub, AuthorId::System)
+                    {
+                        None
+                    } else {
+                        let usage = tenant_usage(&context.state.repo, &tenant).await?;
+                        usage
+                            .limits
+                            .max_resources
+                            .map(|limit| (usage.resources, limit, String::from(usage.tier)))
+                    };
+
+                    let mut importer = Importer {
+                        repo: context.state.repo.as_ref(),
+                        tenant: &tenant,
+                        project: &project,
+                        user: context.ctx.user.as_ref(),
+                        fhir_version: &context.ctx.fhir_version,
+                        batch_size,
+                        batch: Vec::with_capacity(batch_size),
+                        quota,
+                        imported: 0,
+                        failed: 0,
+                        issues: vec![],
+                    };
+
+                    for (i, resource) in input.resource.unwrap_or_default().into_iter().enumerate()
+                    {
+                        importer.push(format!("resource[{}]", i), resource).await?;
+                    }
+
+                    for source in input.source.unwrap_or_default() {
+                        let Some(source) = source.value else {
+                            continue;
+                        };
+                        let mut reader = NDJSONReader::new(
+                            open_source(context.state.config.as_ref(), &source).await?,
+                        );
+
+                        while let Some(result) = reader.next::<Resource>().await {
+                            let location = format!("Line {} of '{}'", reader.line_number(), source);
+                            match result {
+                                Ok(resource) => importer.push(location, resource).await?,
+                                Err(e) => importer
+                                    .fail(IssueType::Invalid(None), format!("{}: {}", location, e)),
+                            }
+                        }
+                    }
+
+                    importer.flush().await?;
+
+                    Ok(HasteHealthImport::Output {
+                        return_: importer.outcome(),
+                    })
+                })
+            },
+        ),
+    )
+}
@@ -3,8 +3,14 @@ mod approved_scopes;
 mod delete_approved_scope;
 mod delete_refresh_token;
 mod endpoint_meta;
+mod expunge;
 mod idp_info;
+mod import;
+mod observation_lastn;
+mod observation_stats;
 mod project_information;
+mod smart_launch;
+mod tenant_usage;
 mod valueset_expand;
 
 pub use active_refresh_tokens::*;
@@ -12,6 +18,12 @@ pub use approved_scopes::*;
 pub use delete_approved_scope::*;
 pub use delete_refresh_token::*;
 pub use endpoint_meta::*;
+pub use expunge::*;
 pub use idp_info::*;
+pub use import::*;
+pub use observation_lastn::*;
+pub use observation_stats::*;
 pub use project_information::*;
+pub use smart_launch::*;
+pub use tenant_usage::*;
 pub use valueset_expand::*;
@@ -0,0 +1,175 @@
+use crate::fhir_client::middleware::{operations::ServerOperationContext, storage::to_bundle};
+use haste_fhir_client::{
+    request::{FHIRSearchTypeRequest, InvocationRequest, SearchRequest},
+    url::{Parameter, ParsedParameter, ParsedParameters},
+};
+use haste_fhir_generated_ops::generated::ObservationLastn;
+use haste_fhir_model::r4::generated::{
+    resources::{ParametersParameter, ParametersParameterValueTypeChoice, ResourceType},
+    terminology::{BundleType, IssueType},
+    types::FHIRPositiveInt,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, TenantId};
+use haste_repository::{Repository, fhir::CachePolicy};
+
+static DEFAULT_MAX: usize = 1;
+
+/// $lastn accepts Observation search parameters alongside max.
+/// The generated input rejects unknown parameters so the search parameters are split out here.
+#[derive(Debug)]
+pub struct ObservationLastnInput {
+    pub max: Option<usize>,
+    search: Vec<ParametersParameter>,
+}
+
+fn parameter_value(parameter: &ParametersParameter) -> Option<&String> {
+    match parameter.value.as_ref()? {
+        ParametersParameterValueTypeChoice::String(value) => value.value.as_ref(),
+        ParametersParameterValueTypeChoice::Code(value) => value.value.as_ref(),
+        ParametersParameterValueTypeChoice::Uri(value) => value.value.as_ref(),
+        _ => None,
+    }
+}
+
+impl ObservationLastnInput {
+    /// Observation search parameters IE patient, category, code and date.
+    fn search_parameters(&self) -> Result<ParsedParameters, OperationOutcomeError> {
+        let parameters = self
+            .search
+            .iter()
+            .map(|parameter| {
+                let name = parameter.name.value.clone().unwrap_or_default();
+                let value = parameter_value(parameter).ok_or_else(|| {
+                    OperationOutcomeError::error(
+                        IssueType::Invalid(None),
+                        format!("Search parameter '{}' must be a string value.", name),
+                    )
+                })?;
+                if name.starts_with('_') {
+                    return Err(OperationOutcomeError::error(
+                        IssueType::NotSupported(None),
+                        format!("Parameter '{}' is not supported for $lastn.", name),
+                    ));
+                }
+
+                let (name, modifier) = match name.split_once(':') {
+                    Some((name, modifier)) => (name.to_string(), Some(modifier.to_string())),
+                    None => (name, None),
+                };
+
+                Ok(ParsedParameter::Resource(Parameter {
+                    name,
+                    modifier,
+                    value: value.split(',').map(|v| v.to_string()).collect(),
+                    chains: None,
+                }))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !parameters.iter().any(|p| match p {
+            ParsedParameter::Resource(p) => p.name == "patient" || p.name == "subject",
+            ParsedParameter::Result(_) => false,
+        }) {
+            return Err(OperationOutcomeError::error(
+                IssueType::Required(None),
+                "$lastn requires a 'patient' or 'subject' parameter.".to_string(),
+            ));
+        }
+
+        Ok(ParsedParameters::new(parameters))
+    }
+}
+
+impl TryFrom<Vec<ParametersParameter>> for ObservationLastnInput {
+    type Error = OperationOutcomeError;
+
+    fn try_from(parameters: Vec<ParametersParameter>) -> Result<Self, Self::Error> {
+        let (max, search): (Vec<_>, Vec<_>) = parameters
+            .into_iter()
+            .partition(|p| p.name.value.as_deref() == Some("max"));
+        let max = ObservationLastn::Input::try_from(max)?
+            .max
+            .and_then(|max| max.value)
+            .map(|max| max as usize);
+
+        Ok(ObservationLastnInput { max, search })
+    }
+}
+
+impl From<ObservationLastnInput> for Vec<ParametersParameter> {
+    fn from(input: ObservationLastnInput) -> Self {
+        let mut parameters: Vec<ParametersParameter> = ObservationLastn::Input {
+            max: input.max.map(|max| FHIRPositiveInt {
+                value: Some(max as u64),
+                ..Default::default()
+            }),
+        }
+        .into();
+        parameters.extend(input.search);
+        parameters
+    }
+}
+
+pub fn observation_lastn<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    ObservationLastnInput,
+    ObservationLastn::Output,
+> {
+    OperationExecutor::new(
+        ObservationLastn::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             project: ProjectId,
+             _request: &InvocationRequest,
+             input: ObservationLastnInput| {
+                Box::pin(async move {
+                    let search_request = SearchRequest::Type(FHIRSearchTypeRequest {
+                        resource_type: ResourceType::Observation,
+                        parameters: input.search_parameters()?,
+                    });
+
+                    let entries = context
+                        .state
+                        .search
+                        .observation_lastn(
+                            &context.ctx.fhir_version,
+                            &tenant,
+                            &project,
+                            &search_request,
+                            input.max.unwrap_or(DEFAULT_MAX),
+                        )
+                        .await?;
+
+                    let version_ids = entries.iter().map(|e| &e.version_id).collect::<Vec<_>>();
+                    let resources = context
+                        .state
+                        .repo
+                        .read_by_version_ids(
+                            &tenant,
+                            &project,
+                            version_ids.as_slice(),
+                            CachePolicy::NoCache,
+                        )
+                        .await?;
+
+                    Ok(ObservationLastn::Output {
+                        return_: to_bundle(
+                            BundleType::Searchset(None),
+                            Some(resources.len() as i64),
+                            resources,
+                        ),
+                    })
+                })
+            },
+        ),
+    )
+}
@@ -0,0 +1,468 @@
+use crate::fhir_client::middleware::operations::ServerOperationContext;
+use haste_fhir_client::{
+    request::{FHIRSearchTypeRequest, InvocationRequest, SearchRequest},
+    url::{Parameter, ParsedParameter, ParsedParameters},
+};
+use haste_fhir_generated_ops::generated::ObservationStats;
+use haste_fhir_model::r4::{
+    datetime::parse_datetime,
+    generated::{
+        resources::{
+            Observation, ObservationComponent, ObservationComponentValueTypeChoice,
+            ObservationEffectiveTypeChoice, Resource, ResourceType,
+        },
+        terminology::{IssueType, ObservationStatus},
+        types::{
+            CodeableConcept, Coding, FHIRCode, FHIRDateTime, FHIRDecimal, FHIRString, FHIRUri,
+            Period, Quantity, Reference,
+        },
+    },
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::{CodeStatistics, SearchEngine};
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, TenantId};
+use haste_repository::{Repository, fhir::CachePolicy};
+
+static STATISTICS_SYSTEM: &str = "http://hl7.org/fhir/observation-statistics";
+static DEFAULT_SOURCE_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy)]
+enum Statistic {
+    Average,
+    Maximum,
+    Minimum,
+    Count,
+    TotalCount,
+    Sum,
+}
+
+impl TryFrom<&str> for Statistic {
+    type Error = OperationOutcomeError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        match code {
+            "average" => Ok(Statistic::Average),
+            "maximum" => Ok(Statistic::Maximum),
+            "minimum" => Ok(Statistic::Minimum),
+            "count" => Ok(Statistic::Count),
+            "totalcount" => Ok(Statistic::TotalCount),
+            "sum" => Ok(Statistic::Sum),
+            code => Err(OperationOutcomeError::error(
+                IssueType::NotSupported(None),
+                format!("Statistic '{}' is not supported.", code),
+            )),
+        }
+    }
+}
+
+impl Statistic {
+    fn code(&self) -> &'static str {
+        match self {
+            Statistic::Average => "average",
+            Statistic::Maximum => "maximum",
+            Statistic::Minimum => "minimum",
+            Statistic::Count => "count",
+            Statistic::TotalCount => "totalcount",
+            Statistic::Sum => "sum",
+        }
+    }
+
+    fn value(&self, statistics: &CodeStatistics) -> Option<f64> {
+        match self {
+            Statistic::Average => statistics.average,
+            Statistic::Maximum => statistics.max,
+            Statistic::Minimum => statistics.min,
+            Statistic::Count => Some(statistics.count as f64),
+            Statistic::TotalCount => Some(statistics.total_count as f64),
+            Statistic::Sum => statistics.sum,
+        }
+    }
+
+    /// Counts are unitless, all other statistics share the unit of the values.
+    fn has_unit(&self) -> bool {
+        !matches!(self, Statistic::Count | Statistic::TotalCount)
+    }
+}
+
+fn resource_parameter(name: &str, value: Vec<String>) -> ParsedParameter {
+    ParsedParameter::Resource(Parameter {
+        name: name.to_string(),
+        value,
+        modifier: None,
+        chains: None,
+    })
+}
+
+fn token(system: Option<&String>, code: &str) -> String {
+    match system {
+        Some(system) => format!("{}|{}", system, code),
+        None => code.to_string(),
+    }
+}
+
+/// Converts the operation input into an Observation search over the subject, codes and period.
+fn search_parameters(
+    input: &ObservationStats::Input,
+) -> Result<ParsedParameters, OperationOutcomeError> {
+    let subject = input.subject.value.clone().ok_or_else(|| {
+        OperationOutcomeError::error(
+            IssueType::Required(None),
+            "$stats requires a 'subject' parameter.".to_string(),
+        )
+    })?;
+    let system = input.system.as_ref().and_then(|s| s.value.as_ref());
+
+    let codes = input
+        .code
+        .iter()
+        .flatten()
+        .filter_map(|code| code.value.as_ref().map(|code| token(system, code)))
+        .chain(input.coding.iter().flatten().filter_map(|coding| {
+            let code = coding.code.as_ref()?.value.as_ref()?;
+            Some(token(
+                coding.system.as_ref().and_then(|s| s.value.as_ref()),
+                code,
+            ))
+        }))
+        .collect::<Vec<_>>();
+
+    if codes.is_empty() {
+        return Err(OperationOutcomeError::error(
+            IssueType::Required(None),
+            "$stats requires at least one 'code' or 'coding' parameter.".to_string(),
+        ));
+    }
+
+    let mut parameters = vec![
+        resource_parameter("subject", vec![subject]),
+        resource_parameter("code", codes),
+    ];
+
+    if let Some(period) = input.period.as_ref() {
+        if let Some(start) = period.start.as_ref().and_then(|s| s.value.as_ref()) {
+            parameters.push(resource_parameter(
+                "date",
+                vec![format!("ge{}", start.to_string())],
+            ));
+        }
+        if let Some(end) = period.end.as_ref().and_then(|e| e.value.as_ref()) {
+            parameters.push(resource_parameter(
+                "date",
+                vec![format!("le{}", end.to_string())],
+            ));
+        }
+    } else if let Some(duration) = input.duration.as_ref().and_then(|d| d.value) {
+        // Duration is the number of hours prior to now to include.
+        let start = chrono::Utc::now() - chrono::Duration::seconds((duration * 3600.0) as i64);
+        parameters.push(resource_parameter(
+            "date",
+            vec![format!(
+                "ge{}",
+                start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            )],
+        ));
+    }
+
+    Ok(ParsedParameters::new(parameters))
+}
+
+fn to_datetime(value: Option<&String>) -> Option<Box<FHIRDateTime>> {
+    let value = parse_datetime(value?).ok()?;
+    Some(Box::new(FHIRDateTime {
+        value: Some(value),
+        ..Default::default()
+    }))
+}
+
+fn statistic_component(statistic: &Statistic, statistics: &CodeStatistics) -> ObservationComponent {
+    let value = statistic.value(statistics);
+
+    ObservationComponent {
+        code: Box::new(CodeableConcept {
+            coding: Some(vec![Box::new(Coding {
+                system: Some(Box::new(FHIRUri {
+                    value: Some(STATISTICS_SYSTEM.to_string()),
+                    ..Default::default()
+                })),
+                code: Some(Box::new(FHIRCode {
+                    value: Some(statistic.code().to_string()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })]),
+            ..Default::default()
+        }),
+        value: value.map(|value| {
+            ObservationComponentValueTypeChoice::Quantity(Box::new(Quantity {
+                value: Some(Box::new(FHIRDecimal {
+                    value: Some(value),
+                    ..Default::default()
+                })),
+                unit: statistics
+                    .unit_code
+                    .as_ref()
+                    .filter(|_| statistic.has_unit())
+                    .map(|code| {
+                        Box::new(FHIRString {
+                            value: Some(code.clone()),
+                            ..Default::default()
+                        })
+                    }),
+                system: statistics
+                    .unit_system
+                    .as_ref()
+                    .filter(|_| statistic.has_unit())
+                    .map(|system| {
+                        Box::new(FHIRUri {
+                            value: Some(system.clone()),
+                            ..Default::default()
+                        })
+                    }),
+                code: statistics
+                    .unit_code
+                    .as_ref()
+                    .filter(|_| statistic.has_unit())
+                    .map(|code| {
+                        Box::new(FHIRCode {
+                            value: Some(code.clone()),
+                            ..Default::default()
+                        })
+                    }),
+                ..Default::default()
+            }))
+        }),
+        ..Default::default()
+    }
+}
+
+/// Statistics are returned as an Observation per code with a component per statistic.
+fn statistics_observation(
+    input: &ObservationStats::Input,
+    statistics: &[Statistic],
+    code_statistics: &CodeStatistics,
+) -> Observation {
+    let period = input.period.clone().unwrap_or_else(|| Period {
+        start: to_datetime(code_statistics.earliest.as_ref()),
+        end: to_datetime(code_statistics.latest.as_ref()),
+        ..Default::default()
+    });
+
+    Observation {
+        status: Box::new(ObservationStatus::Final(None)),
+        code: Box::new(CodeableConcept {
+            coding: Some(vec![Box::new(Coding {
+                system: code_statistics.system.as_ref().map(|system| {
+                    Box::new(FHIRUri {
+                        value: Some(system.clone()),
+                        ..Default::default()
+                    })
+                }),
+                code: Some(Box::new(FHIRCode {
+                    value: Some(code_statistics.code.clone()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })]),
+            ..Default::default()
+        }),
+        subject: Some(Box::new(Reference {
+            reference: input.subject.value.as_ref().map(|subject| {
+                Box::new(FHIRString {
+                    value: Some(subject.clone()),
+                    ..Default::default()
+                })
+            }),
+            ..Default::default()
+        })),
+        effective: Some(ObservationEffectiveTypeChoice::Period(Box::new(period))),
+        component: Some(
+            statistics
+                .iter()
+                .map(|statistic| statistic_component(statistic, code_statistics))
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+pub fn observation_stats<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    ObservationStats::Input,
+    ObservationStats::Output,
+> {
+    OperationExecutor::new(
+        ObservationStats::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             project: ProjectId,
+             _request: &InvocationRequest,
+             input: ObservationStats::Input| {
+                Box::pin(async move {
+                    let statistics = input
+                        .statistic
+                        .iter()
+                        .map(|code| Statistic::try_from(code.value.as_deref().unwrap_or_default()))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    let include = input
+                        .include
+                        .as_ref()
+                        .and_then(|i| i.value)
+                        .unwrap_or(false);
+                    let source_limit = if include {
+                        Some(
+                            input
+                                .limit
+                                .as_ref()
+                                .and_then(|l| l.value)
+                                .map(|l| l as usize)
+                                .unwrap_or(DEFAULT_SOURCE_LIMIT),
+                        )
+                    } else {
+                        None
+                    };
+
+                    let search_request = SearchRequest::Type(FHIRSearchTypeRequest {
+                        resource_type: ResourceType::Observation,
+                        parameters: search_parameters(&input)?,
+                    });
+
+                    let code_statistics = context
+                        .state
+                        .search
+                        .observation_stats(
+                            &context.ctx.fhir_version,
+                            &tenant,
+                            &project,
+                            &search_request,
+                            source_limit,
+                        )
+                        .await?;
+
+                    let source = if include {
+                        let version_ids = code_statistics
+                            .iter()
+                            .flat_map(|s| s.entries.iter().map(|e| &e.version_id))
+                            .collect::<Vec<_>>();
+                        let resources = context
+                            .state
+                            .repo
+                            .read_by_version_ids(
+                                &tenant,
+                                &project,
+                                version_ids.as_slice(),
+                                CachePolicy::NoCache,
+                            )
+                            .await?;
+
+                        Some(
+                            resources
+                                .into_iter()
+                                .filter_map(|resource| match resource {
+                                    Resource::Observation(observation) => Some(observation),
+                                    _ => None,
+                                })
+                                .collect(),
+                        )
+                    } else {
+                        None
+                    };
+
+                    Ok(ObservationStats::Output {
+                        statistics: code_statistics
+                            .iter()
+                            .map(|s| statistics_observation(&input, &statistics, s))
+                            .collect(),
+                        source,
+                    })
+                })
+            },
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics_observation() {
+        let input = ObservationStats::Input {
+            subject: FHIRUri {
+                value: Some("Patient/123".to_string()),
+                ..Default::default()
+            },
+            code: Some(vec![FHIRString {
+                value: Some("8480-6".to_string()),
+                ..Default::default()
+            }]),
+            system: Some(FHIRUri {
+                value: Some("http://loinc.org".to_string()),
+                ..Default::default()
+            }),
+            coding: None,
+            duration: None,
+            period: None,
+            statistic: vec![],
+            include: None,
+            limit: None,
+        };
+
+        let parameters = search_parameters(&input).unwrap();
+        match parameters.get("code") {
+            Some(ParsedParameter::Resource(code)) => {
+                assert_eq!(code.value, vec!["http://loinc.org|8480-6".to_string()])
+            }
+            _ => panic!("Expected code parameter"),
+        }
+
+        let code_statistics = CodeStatistics {
+            system: Some("http://loinc.org".to_string()),
+            code: "8480-6".to_string(),
+            unit_system: Some("http://unitsofmeasure.org".to_string()),
+            unit_code: Some("mm[Hg]".to_string()),
+            total_count: 3,
+            count: 2,
+            min: Some(110.0),
+            max: Some(130.0),
+            average: Some(120.0),
+            sum: Some(240.0),
+            earliest: Some("2024-01-01T00:00:00.000Z".to_string()),
+            latest: Some("2024-02-01T00:00:00.000Z".to_string()),
+            entries: vec![],
+        };
+
+        let observation = statistics_observation(
+            &input,
+            &[Statistic::Average, Statistic::Count],
+            &code_statistics,
+        );
+        let components = observation.component.unwrap();
+        assert_eq!(components.len(), 2);
+
+        match components[0].value.as_ref() {
+            Some(ObservationComponentValueTypeChoice::Quantity(quantity)) => {
+                assert_eq!(quantity.value.as_ref().unwrap().value, Some(120.0));
+                assert_eq!(
+                    quantity.code.as_ref().unwrap().value,
+                    Some("mm[Hg]".to_string())
+                );
+            }
+            _ => panic!("Expected quantity"),
+        }
+        match components[1].value.as_ref() {
+            Some(ObservationComponentValueTypeChoice::Quantity(quantity)) => {
+                assert_eq!(quantity.value.as_ref().unwrap().value, Some(2.0));
+                assert!(quantity.code.is_none());
+            }
+            _ => panic!("Expected quantity"),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    auth_n::oidc::launch_context::{LaunchContext, create_launch_context},
+    fhir_client::middleware::operations::ServerOperationContext,
+};
+use haste_fhir_client::request::InvocationRequest;
+use haste_fhir_generated_ops::generated::SmartLaunch;
+use haste_fhir_model::r4::generated::{
+    resources::ResourceType, terminology::IssueType, types::FHIRString,
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{AuthorKind, ProjectId, ResourceId, TenantId};
+use haste_repository::Repository;
+
+async fn verify_exists<Repo: Repository>(
+    repo: &Repo,
+    tenant: &TenantId,
+    project: &ProjectId,
+    resource_type: ResourceType,
+    id: &str,
+) -> Result<(), OperationOutcomeError> {
+    let resource = repo
+        .read_latest(
+            tenant,
+            project,
+            &resource_type,
+            &ResourceId::new(id.to_string()),
+        )
+        .await?;
+
+    if resource.is_none() {
+        return Err(OperationOutcomeError::error(
+            IssueType::NotFound(None),
+            format!("{} '{}' not found.", resource_type.as_ref(), id),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn smart_launch<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    SmartLaunch::Input,
+    SmartLaunch::Output,
+> {
+    OperationExecutor::new(
+        SmartLaunch::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             project: ProjectId,
+             _request: &InvocationRequest,
+             input: SmartLaunch::Input| {
+                Box::pin(async move {
+                    // The launch is exchanged by the same user at the authorize endpoint.
+                    if context.ctx.user.resource_type != AuthorKind::Membership {
+                        return Err(OperationOutcomeError::error(
+                            IssueType::Forbidden(None),
+                            "Only users can create SMART launches.".to_string(),
+                        ));
+                    }
+
+                    let client_id = input.client_id.value.ok_or_else(|| {
+                        OperationOutcomeError::error(
+                            IssueType::Invalid(None),
+                            "Must provide client_id".to_string(),
+                        )
+                    })?;
+
+                    let patient = input.patient.and_then(|patient| patient.value);
+                    let encounter = input.encounter.and_then(|encounter| encounter.value);
+
+                    if let Some(patient) = patient.as_ref() {
+                        verify_exists(
+                            context.state.repo.as_ref(),
+                            &tenant,
+                            &project,
+                            ResourceType::Patient,
+                            patient,
+                        )
+                        .await?;
+                    }
+                    if let Some(encounter) = encounter.as_ref() {
+                        verify_exists(
+                            context.state.repo.as_ref(),
+                            &tenant,
+                            &project,
+                            ResourceType::Encounter,
+                            encounter,
+                        )
+                        .await?;
+                    }
+
+                    let launch = create_launch_context(
+                        context.state.repo.as_ref(),
+                        &tenant,
+                        &project,
+                        context.ctx.user.sub.as_ref(),
+                        &client_id,
+                        &LaunchContext {
+                            patient,
+                            encounter,
+                            need_patient_banner: input
+                                .need_patient_banner
+                                .and_then(|banner| banner.value)
+                                .unwrap_or(false),
+                        },
+                    )
+                    .await?;
+
+                    Ok(SmartLaunch::Output {
+                        launch: FHIRString {
+                            value: Some(launch),
+                            ..Default::default()
+                        },
+                    })
+                })
+            },
+        ),
+    )
+}
@@ -0,0 +1,72 @@
+use crate::fhir_client::middleware::{operations::ServerOperationContext, quota::tenant_usage};
+use haste_fhir_client::request::InvocationRequest;
+use haste_fhir_generated_ops::generated::TenantUsage;
+use haste_fhir_model::r4::generated::{
+    terminology::IssueType,
+    types::{FHIRCode, FHIRInteger},
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_ops::OperationExecutor;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, TenantId, UserRole};
+use haste_repository::Repository;
+
+fn integer(value: i64) -> FHIRInteger {
+    FHIRInteger {
+        value: Some(value),
+        ..Default::default()
+    }
+}
+
+pub fn tenant_usage_information<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>() -> OperationExecutor<
+    ServerOperationContext<Repo, Search, Terminology>,
+    TenantUsage::Input,
+    TenantUsage::Output,
+> {
+    OperationExecutor::new(
+        TenantUsage::CODE.to_string(),
+        Box::new(
+            |context: ServerOperationContext<Repo, Search, Terminology>,
+             tenant: TenantId,
+             _project: ProjectId,
+             _request: &InvocationRequest,
+             _input: TenantUsage::Input| {
+                Box::pin(async move {
+                    if context.ctx.user.user_role == UserRole::Member {
+                        return Err(OperationOutcomeError::error(
+                            IssueType::Forbidden(None),
+                            "Only tenant owners and admins can view usage.".to_string(),
+                        ));
+                    }
+
+                    let usage = tenant_usage(&context.state.repo, &tenant).await?;
+
+                    Ok(TenantUsage::Output {
+                        subscription: FHIRCode {
+                            value: Some(String::from(usage.tier)),
+                            ..Default::default()
+                        },
+                        requests_per_minute: integer(usage.requests_current_minute as i64),
+                        requests_per_minute_limit: usage
+                            .limits
+                            .requests_per_minute
+                            .map(|limit| integer(limit as i64)),
+                        resources: integer(usage.resources),
+                        resources_limit: usage.limits.max_resources.map(integer),
+                        projects: integer(usage.projects as i64),
+                        projects_limit: usage
+                            .limits
+                            .max_projects
+                            .map(|limit| integer(limit as i64)),
+                        max_count: usage.limits.max_count.map(|limit| integer(limit as i64)),
+                    })
+                })
+            },
+        ),
+    )
+}
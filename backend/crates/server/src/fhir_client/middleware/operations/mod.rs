@@ -55,7 +55,13 @@ impl<
             Box::new(custom_operations::delete_approved_scope()),
             Box::new(custom_operations::delete_refresh_token()),
             Box::new(custom_operations::endpoint_metadata()),
+            Box::new(custom_operations::expunge()),
             Box::new(custom_operations::idp_registration_info()),
+            Box::new(custom_operations::import()),
+            Box::new(custom_operations::observation_lastn()),
+            Box::new(custom_operations::observation_stats()),
+            Box::new(custom_operations::tenant_usage_information()),
+            Box::new(custom_operations::smart_launch()),
         ];
 
         Self(Arc::new(executors))
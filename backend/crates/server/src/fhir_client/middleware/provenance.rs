@@ -0,0 +1,317 @@
+use crate::fhir_client::{
+    ServerCTX,
+    middleware::{
+        ServerMiddlewareContext, ServerMiddlewareNext, ServerMiddlewareOutput,
+        ServerMiddlewareState,
+        transaction::{complete_transaction, setup_transaction_context},
+    },
+    utilities::request_to_resource_type,
+};
+use haste_fhir_client::{
+    middleware::MiddlewareChain,
+    request::{FHIRRequest, FHIRResponse},
+};
+use haste_fhir_model::r4::{
+    datetime::Instant,
+    generated::{
+        resources::{Provenance, ProvenanceAgent, Resource, ResourceType},
+        terminology::IssueType,
+        types::{
+            CodeableConcept, Coding, FHIRCode, FHIRInstant, FHIRString, FHIRUri, Meta, Reference,
+        },
+    },
+};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{AuthorId, AuthorKind, ProjectId, ResourceId, claims::UserTokenClaims};
+use haste_reflect::MetaValue;
+use haste_repository::{Repository, fhir::FHIRRepository};
+use std::sync::Arc;
+
+static PARTICIPANT_TYPE_SYSTEM: &str =
+    "http://terminology.hl7.org/CodeSystem/provenance-participant-type";
+static DATA_OPERATION_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-DataOperation";
+
+fn coding(system: &str, code: &str) -> Box<CodeableConcept> {
+    Box::new(CodeableConcept {
+        coding: Some(vec![Box::new(Coding {
+            system: Some(Box::new(FHIRUri {
+                value: Some(system.to_string()),
+                ..Default::default()
+            })),
+            code: Some(Box::new(FHIRCode {
+                value: Some(code.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })]),
+        ..Default::default()
+    })
+}
+
+fn reference(reference: String) -> Box<Reference> {
+    Box::new(Reference {
+        reference: Some(Box::new(FHIRString {
+            value: Some(reference),
+            ..Default::default()
+        })),
+        ..Default::default()
+    })
+}
+
+/// Provenance generated from the requesting user and client application.
+fn generate_provenance(user: &UserTokenClaims, request: &FHIRRequest) -> Provenance {
+    let mut agent = vec![];
+    let author = match (&user.resource_type, &user.user_id) {
+        (AuthorKind::Membership, AuthorId::User(id)) => Some(format!("User/{}", id.as_ref())),
+        (AuthorKind::ClientApplication, AuthorId::User(id)) => {
+            Some(format!("ClientApplication/{}", id.as_ref()))
+        }
+        _ => None,
+    };
+
+    if let Some(author) = author {
+        agent.push(ProvenanceAgent {
+            type_: Some(coding(PARTICIPANT_TYPE_SYSTEM, "author")),
+            who: reference(author),
+            ..Default::default()
+        });
+    }
+
+    agent.push(ProvenanceAgent {
+        type_: Some(coding(PARTICIPANT_TYPE_SYSTEM, "performer")),
+        who: reference(format!("ClientApplication/{}", user.aud)),
+        ..Default::default()
+    });
+
+    Provenance {
+        activity: Some(coding(
+            DATA_OPERATION_SYSTEM,
+            match request {
+                FHIRRequest::Create(_) => "CREATE",
+                _ => "UPDATE",
+            },
+        )),
+        agent,
+        ..Default::default()
+    }
+}
+
+/// Versioned reference IE Patient/123/_history/abc to the resource written.
+fn version_reference(resource: &Resource) -> Option<String> {
+    let id = resource
+        .get_field("id")
+        .and_then(|id| id.as_any().downcast_ref::<String>())?;
+    let version_id = resource
+        .get_field("meta")
+        .and_then(|meta| meta.as_any().downcast_ref::<Box<Meta>>())
+        .and_then(|meta| meta.versionId.as_ref())
+        .and_then(|version_id| version_id.value.as_ref())?;
+
+    Some(format!(
+        "{}/{}/_history/{}",
+        resource.typename(),
+        id,
+        version_id
+    ))
+}
+
+async fn auto_provenance<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    context: &ServerMiddlewareContext<Repo, Search, Terminology>,
+) -> Result<bool, OperationOutcomeError> {
+    let project = state
+        .repo
+        .read_latest(
+            &context.ctx.tenant,
+            &ProjectId::System,
+            &ResourceType::Project,
+            &ResourceId::new(context.ctx.project.as_ref().to_string()),
+        )
+        .await?;
+
+    Ok(match project {
+        Some(Resource::Project(project)) => project
+            .autoProvenance
+            .as_ref()
+            .and_then(|auto_provenance| auto_provenance.value)
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
+async fn store_provenance<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    ctx: &ServerCTX<Repo, Search, Terminology>,
+    mut provenance: Provenance,
+    response: Option<&FHIRResponse>,
+) -> Result<(), OperationOutcomeError> {
+    let resource = match response {
+        Some(FHIRResponse::Create(res)) => &res.resource,
+        Some(FHIRResponse::Update(res)) => &res.resource,
+        _ => return Ok(()),
+    };
+
+    let target = version_reference(resource).ok_or_else(|| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Written resource is missing an id or version id.".to_string(),
+        )
+    })?;
+
+    provenance.id = None;
+    provenance.target = vec![reference(target)];
+    if provenance.recorded.value.is_none() {
+        provenance.recorded = Box::new(FHIRInstant {
+            value: Some(Instant::Iso8601(chrono::Utc::now())),
+            ..Default::default()
+        });
+    }
+
+    FHIRRepository::create(
+        state.repo.as_ref(),
+        &ctx.tenant,
+        &ctx.project,
+        &ctx.user,
+        &ctx.fhir_version,
+        &mut Resource::Provenance(provenance),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Records a Provenance targeting each version written by a create or update.
+/// Provenance is taken from the X-Provenance header or, when the project has autoProvenance set,
+/// generated from the user claims. It is stored in the same transaction as the resource.
+pub struct Middleware {}
+impl Middleware {
+    pub fn new() -> Self {
+        Middleware {}
+    }
+}
+
+impl<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>
+    MiddlewareChain<
+        ServerMiddlewareState<Repo, Search, Terminology>,
+        Arc<ServerCTX<Repo, Search, Terminology>>,
+        FHIRRequest,
+        FHIRResponse,
+        OperationOutcomeError,
+    > for Middleware
+{
+    fn call(
+        &self,
+        state: ServerMiddlewareState<Repo, Search, Terminology>,
+        context: ServerMiddlewareContext<Repo, Search, Terminology>,
+        next: Option<Arc<ServerMiddlewareNext<Repo, Search, Terminology>>>,
+    ) -> ServerMiddlewareOutput<Repo, Search, Terminology> {
+        Box::pin(async move {
+            let Some(next) = next else {
+                return Err(OperationOutcomeError::fatal(
+                    IssueType::Exception(None),
+                    "No next middleware found".to_string(),
+                ));
+            };
+
+            // Provenance resources themselves are not tracked.
+            let is_write = match &context.request {
+                FHIRRequest::Create(_) | FHIRRequest::Update(_) => {
+                    request_to_resource_type(&context.request) != Some(&ResourceType::Provenance)
+                }
+                _ => false,
+            };
+            if !is_write {
+                return next(state, context).await;
+            }
+
+            let provenance = if let Some(provenance) = context.ctx.provenance.as_ref() {
+                Some(provenance.as_ref().clone())
+            } else if auto_provenance(&state, &context).await? {
+                Some(generate_provenance(&context.ctx.user, &context.request))
+            } else {
+                None
+            };
+
+            let Some(provenance) = provenance else {
+                return next(state, context).await;
+            };
+
+            let ctx = context.ctx.clone();
+
+            // Already in a transaction (IE a transaction bundle) so the caller commits.
+            if state.repo.in_transaction() {
+                let res = next(state.clone(), context).await?;
+                store_provenance(&state, &ctx, provenance, res.response.as_ref()).await?;
+                return Ok(res);
+            }
+
+            let repo_client;
+            // Place in block so transaction_state gets dropped.
+            let res = {
+                let transaction_state =
+                    setup_transaction_context(&context.request, state.clone()).await?;
+                repo_client = transaction_state.repo.clone();
+
+                match next(transaction_state.clone(), context).await {
+                    Ok(res) => store_provenance(
+                        &transaction_state,
+                        &ctx,
+                        provenance,
+                        res.response.as_ref(),
+                    )
+                    .await
+                    .map(|_| res),
+                    Err(e) => Err(e),
+                }
+            };
+
+            complete_transaction(repo_client, &res).await?;
+
+            res
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_model::r4::generated::{resources::Patient, types::FHIRId};
+
+    #[test]
+    fn test_version_reference() {
+        let patient = Resource::Patient(Patient {
+            id: Some("123".to_string()),
+            meta: Some(Box::new(Meta {
+                versionId: Some(Box::new(FHIRId {
+                    value: Some("abc".to_string()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            version_reference(&patient),
+            Some("Patient/123/_history/abc".to_string())
+        );
+        assert_eq!(
+            version_reference(&Resource::Patient(Patient::default())),
+            None
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    fhir_client::{
+        ServerCTX,
+        middleware::{
+            ServerMiddlewareContext, ServerMiddlewareNext, ServerMiddlewareOutput,
+            ServerMiddlewareState,
+        },
+    },
+    tenants::{SubscriptionTier, TierLimits},
+};
+use haste_fhir_client::{
+    middleware::MiddlewareChain,
+    request::{FHIRRequest, FHIRResponse, SearchRequest, UpdateRequest},
+    url::ParsedParameter,
+};
+use haste_fhir_model::r4::generated::{resources::ResourceType, terminology::IssueType};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{AuthorId, ResourceId, TenantId};
+use haste_reflect::MetaValue;
+use haste_repository::{
+    Repository,
+    admin::TenantAuthAdmin,
+    fhir::FHIRRepository,
+    types::{
+        project::{CreateProject, ProjectSearchClaims},
+        tenant::CreateTenant,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How long a tenants tier and stored counts are used before being re-read in the background.
+/// Counts are only read on the request path for a tenants first request. Creates are added to
+/// the cached counts so quotas can only be overrun by concurrent instances.
+static USAGE_TTL: Duration = Duration::from_secs(60);
+static RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum QuotaError {
+    #[error(
+        code = "throttled",
+        diagnostic = "Rate limit of {arg0} requests per minute exceeded for the '{arg1}' subscription tier."
+    )]
+    RateLimited(u64, String),
+    #[error(
+        code = "throttled",
+        diagnostic = "Resource quota of {arg0} exceeded for the '{arg1}' subscription tier."
+    )]
+    ResourceQuota(i64, String),
+    #[error(
+        code = "throttled",
+        diagnostic = "Project quota of {arg0} exceeded for the '{arg1}' subscription tier."
+    )]
+    ProjectQuota(usize, String),
+    #[error(
+        code = "invalid",
+        diagnostic = "_count of {arg0} exceeds the maximum of {arg1} for the '{arg2}' subscription tier."
+    )]
+    MaxCount(usize, usize, String),
+    #[error(code = "not-found", diagnostic = "Tenant '{arg0}' not found.")]
+    TenantNotFound(String),
+}
+
+/// Snapshot of a tenants usage against its subscription tier.
+#[derive(Debug, Clone)]
+pub struct TenantUsage {
+    pub tier: SubscriptionTier,
+    pub limits: TierLimits,
+    pub requests_current_minute: u64,
+    pub resources: i64,
+    pub projects: usize,
+}
+
+struct CachedUsage {
+    tier: SubscriptionTier,
+    resources: i64,
+    projects: usize,
+    fetched_at: Instant,
+    refreshing: bool,
+}
+
+struct RequestWindow {
+    started_at: Instant,
+    requests: u64,
+}
+
+/// Per tenant counters shared across every FHIRServerClient in the process.
+#[derive(Default)]
+struct Quotas {
+    windows: Mutex<HashMap<String, RequestWindow>>,
+    usage: Mutex<HashMap<String, CachedUsage>>,
+}
+
+static QUOTAS: LazyLock<Quotas> = LazyLock::new(Quotas::default);
+
+impl Quotas {
+    /// Fixed window counter, returns the number of requests made in the current window.
+    fn record_request(&self, tenant: &TenantId, now: Instant) -> u64 {
+        let mut windows = self.windows.lock().unwrap();
+        let window = windows
+            .entry(tenant.as_ref().to_string())
+            .or_insert(RequestWindow {
+                started_at: now,
+                requests: 0,
+            });
+
+        if now.duration_since(window.started_at) >= RATE_LIMIT_WINDOW {
+            window.started_at = now;
+            window.requests = 0;
+        }
+
+        window.requests += 1;
+        window.requests
+    }
+
+    fn current_requests(&self, tenant: &TenantId, now: Instant) -> u64 {
+        self.windows
+            .lock()
+            .unwrap()
+            .get(tenant.as_ref())
+            .filter(|w| now.duration_since(w.started_at) < RATE_LIMIT_WINDOW)
+            .map(|w| w.requests)
+            .unwrap_or(0)
+    }
+
+    /// Returns the cached usage and whether the caller should refresh it.
+    /// Only one refresh is handed out at a time.
+    fn cached_usage(&self, tenant: &TenantId) -> Option<((SubscriptionTier, i64, usize), bool)> {
+        let mut usage = self.usage.lock().unwrap();
+        let cached = usage.get_mut(tenant.as_ref())?;
+        let refresh = !cached.refreshing && cached.fetched_at.elapsed() >= USAGE_TTL;
+        if refresh {
+            cached.refreshing = true;
+        }
+
+        Some((
+            (cached.tier.clone(), cached.resources, cached.projects),
+            refresh,
+        ))
+    }
+
+    fn refresh_failed(&self, tenant: &TenantId) {
+        if let Some(usage) = self.usage.lock().unwrap().get_mut(tenant.as_ref()) {
+            usage.refreshing = false;
+        }
+    }
+
+    fn record_create(&self, tenant: &TenantId, resource_type: &ResourceType) {
+        if let Some(usage) = self.usage.lock().unwrap().get_mut(tenant.as_ref()) {
+            usage.resources += 1;
+            if resource_type == &ResourceType::Project {
+                usage.projects += 1;
+            }
+        }
+    }
+}
+
+/// Cached usage for the tenant. Stale usage is returned while it is re-read in the background
+/// so counting resources stays off the request path.
+async fn load_usage<Repo: Repository + Send + Sync + 'static>(
+    repo: &Arc<Repo>,
+    tenant: &TenantId,
+) -> Result<(SubscriptionTier, i64, usize), OperationOutcomeError> {
+    if let Some((usage, refresh)) = QUOTAS.cached_usage(tenant) {
+        // Transactions can not be shared with a background task.
+        if refresh && repo.in_transaction() {
+            QUOTAS.refresh_failed(tenant);
+        } else if refresh {
+            let repo = repo.clone();
+            let tenant = tenant.clone();
+            tokio::spawn(async move {
+                if let Err(e) = read_usage(repo.as_ref(), &tenant).await {
+                    tracing::error!("Failed to refresh tenant usage: {:?}", e);
+                    QUOTAS.refresh_failed(&tenant);
+                }
+            });
+        }
+
+        return Ok(usage);
+    }
+
+    read_usage(repo.as_ref(), tenant).await
+}
+
+async fn read_usage<Repo: Repository + Send + Sync + 'static>(
+    repo: &Repo,
+    tenant: &TenantId,
+) -> Result<(SubscriptionTier, i64, usize), OperationOutcomeError> {
+    let tenant_model = TenantAuthAdmin::<CreateTenant, _, _, _, _>::read(
+        repo,
+        &TenantId::System,
+        &tenant.as_ref().to_string(),
+    )
+    .await?
+    .ok_or_else(|| QuotaError::TenantNotFound(tenant.to_string()))?;
+    let tier = SubscriptionTier::try_from(tenant_model.subscription_tier.as_str())?;
+
+    let resources = repo.count_resources(tenant).await?;
+    let projects = TenantAuthAdmin::<CreateProject, _, _, _, _>::search(
+        repo,
+        tenant,
+        &ProjectSearchClaims {
+            id: None,
+            fhir_version: None,
+            system_created: Some(false),
+        },
+    )
+    .await?
+    .len();
+
+    QUOTAS.usage.lock().unwrap().insert(
+        tenant.as_ref().to_string(),
+        CachedUsage {
+            tier: tier.clone(),
+            resources,
+            projects,
+            fetched_at: Instant::now(),
+            refreshing: false,
+        },
+    );
+
+    Ok((tier, resources, projects))
+}
+
+/// Adds resources stored outside of create requests IE $import to the cached count.
+pub fn record_resources(tenant: &TenantId, count: i64) {
+    if let Some(usage) = QUOTAS.usage.lock().unwrap().get_mut(tenant.as_ref()) {
+        usage.resources += count;
+    }
+}
+
+/// Drops the cached usage so it is recounted IE after resources are expunged.
+pub fn invalidate_usage(tenant: &TenantId) {
+    QUOTAS.usage.lock().unwrap().remove(tenant.as_ref());
+}
+
+/// Current usage for a tenant IE for the usage operation.
+pub async fn tenant_usage<Repo: Repository + Send + Sync + 'static>(
+    repo: &Arc<Repo>,
+    tenant: &TenantId,
+) -> Result<TenantUsage, OperationOutcomeError> {
+    let (tier, resources, projects) = load_usage(repo, tenant).await?;
+
+    Ok(TenantUsage {
+        limits: tier.limits(),
+        tier,
+        requests_current_minute: QUOTAS.current_requests(tenant, Instant::now()),
+        resources,
+        projects,
+    })
+}
+
+fn requested_count(request: &FHIRRequest) -> Option<usize> {
+    let parameters = match request {
+        FHIRRequest::Search(SearchRequest::Type(search)) => &search.parameters,
+        FHIRRequest::Search(SearchRequest::System(search)) => &search.parameters,
+        _ => return None,
+    };
+
+    parameters.parameters().iter().find_map(|p| match p {
+        ParsedParameter::Result(p) if p.name == "_count" => {
+            p.value.first().and_then(|v| v.parse::<usize>().ok())
+        }
+        _ => None,
+    })
+}
+
+/// Whether the request can store a new resource. Instance updates create when the id doesn't
+/// exist and conditional updates create when nothing matches so they're treated as creates.
+async fn creates_resource<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    repo: &Arc<Repo>,
+    ctx: &ServerCTX<Repo, Search, Terminology>,
+    request: &FHIRRequest,
+) -> Result<bool, OperationOutcomeError> {
+    match request {
+        FHIRRequest::Create(_) | FHIRRequest::Update(UpdateRequest::Conditional(_)) => Ok(true),
+        FHIRRequest::Update(UpdateRequest::Instance(update_request)) => Ok(repo
+            .read_latest(
+                &ctx.tenant,
+                &ctx.project,
+                &update_request.resource_type,
+                &ResourceId::new(update_request.id.clone()),
+            )
+            .await?
+            .is_none()),
+        _ => Ok(false),
+    }
+}
+
+/// Enforces the tenants subscription tier limits.
+/// Requests made by the system (IE tenant creation) are not counted and nested requests
+/// (IE bundle entries) only count towards resource quotas.
+/// Updates that create a resource count towards resource quotas the same as creates.
+pub struct Middleware {}
+impl Middleware {
+    pub fn new() -> Self {
+        Middleware {}
+    }
+}
+
+impl<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>
+    MiddlewareChain<
+        ServerMiddlewareState<Repo, Search, Terminology>,
+        Arc<ServerCTX<Repo, Search, Terminology>>,
+        FHIRRequest,
+        FHIRResponse,
+        OperationOutcomeError,
+    > for Middleware
+{
+    fn call(
+        &self,
+        state: ServerMiddlewareState<Repo, Search, Terminology>,
+        context: ServerMiddlewareContext<Repo, Search, Terminology>,
+        next: Option<Arc<ServerMiddlewareNext<Repo, Search, Terminology>>>,
+    ) -> ServerMiddlewareOutput<Repo, Search, Terminology> {
+        Box::pin(async move {
+            let Some(next) = next else {
+                return Err(OperationOutcomeError::fatal(
+                    IssueType::Exception(None),
+                    "No next middleware found".to_string(),
+                ));
+            };
+
+            let tenant = context.ctx.tenant.clone();
+            if tenant == TenantId::System || matches!(context.ctx.user.sub, AuthorId::System) {
+                return next(state, context).await;
+            }
+
+            let (tier, resources, projects) = load_usage(&state.repo, &tenant).await?;
+            let limits = tier.limits();
+            let tier_name = String::from(tier);
+
+            if !context.ctx.nested {
+                let requests = QUOTAS.record_request(&tenant, Instant::now());
+                if let Some(requests_per_minute) = limits.requests_per_minute
+                    && requests > requests_per_minute
+                {
+                    return Err(QuotaError::RateLimited(requests_per_minute, tier_name).into());
+                }
+            }
+
+            if let Some(max_count) = limits.max_count
+                && let Some(count) = requested_count(&context.request)
+                && count > max_count
+            {
+                return Err(QuotaError::MaxCount(count, max_count, tier_name).into());
+            }
+
+            if let FHIRRequest::Create(create_request) = &context.request
+                && create_request.resource_type == ResourceType::Project
+                && let Some(max_projects) = limits.max_projects
+                && projects >= max_projects
+            {
+                return Err(QuotaError::ProjectQuota(max_projects, tier_name).into());
+            }
+
+            if let Some(max_resources) = limits.max_resources
+                && resources >= max_resources
+                && creates_resource(&state.repo, &context.ctx, &context.request).await?
+            {
+                return Err(QuotaError::ResourceQuota(max_resources, tier_name).into());
+            }
+
+            let context = next(state, context).await?;
+
+            // Updates to a new id respond with a create so they are counted as well.
+            if let Some(FHIRResponse::Create(create_response)) = context.response.as_ref()
+                && let Ok(resource_type) =
+                    ResourceType::try_from(create_response.resource.typename())
+            {
+                QUOTAS.record_create(&tenant, &resource_type);
+            }
+
+            Ok(context)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_window() {
+        let quotas = Quotas::default();
+        let tenant = TenantId::new("rate-limit-test".to_string());
+        let start = Instant::now();
+
+        assert_eq!(quotas.record_request(&tenant, start), 1);
+        assert_eq!(quotas.record_request(&tenant, start), 2);
+        assert_eq!(quotas.current_requests(&tenant, start), 2);
+
+        // New window resets the counter.
+        let next_window = start + RATE_LIMIT_WINDOW;
+        assert_eq!(quotas.current_requests(&tenant, next_window), 0);
+        assert_eq!(quotas.record_request(&tenant, next_window), 1);
+    }
+}
@@ -0,0 +1,532 @@
+use crate::fhir_client::{
+    ClientState, SPECIAL_TYPES, ServerCTX,
+    middleware::{
+        ServerMiddlewareContext, ServerMiddlewareNext, ServerMiddlewareOutput,
+        ServerMiddlewareState, transaction::complete_transaction,
+    },
+};
+use haste_fhir_client::{
+    middleware::MiddlewareChain,
+    request::{DeleteRequest, FHIRDeleteInstanceRequest, FHIRRequest, FHIRResponse},
+    url::{Parameter, ParsedParameter, ParsedParameters},
+};
+use haste_fhir_model::r4::generated::{
+    resources::{Resource, ResourceType},
+    terminology::IssueType,
+    types::Reference,
+};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::{ProjectId, ResourceId, TenantId, VersionId};
+use haste_reflect::MetaValue;
+use haste_repository::{Repository, fhir::FHIRRepository};
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How long a projects referentialIntegrity setting is used before the Project is re-read.
+static SETTING_TTL: Duration = Duration::from_secs(60);
+/// Whether referential integrity is enforced keyed by tenant and project.
+static SETTINGS: LazyLock<Mutex<HashMap<(String, String), (Instant, bool)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Inbound references past this are not checked and the delete is refused.
+static MAX_INBOUND_REFERENCES: usize = 1000;
+/// Number of referencing resources listed when a delete is refused.
+static LISTED_REFERENCES: usize = 5;
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum ReferentialIntegrityError {
+    #[error(
+        code = "not-found",
+        diagnostic = "Reference '{arg0}' does not resolve to an existing resource."
+    )]
+    UnresolvedReference(String),
+    #[error(
+        code = "conflict",
+        diagnostic = "'{arg0}' is referenced by {arg1} and can only be deleted with _cascade=delete."
+    )]
+    Referenced(String, String),
+    #[error(
+        code = "too-costly",
+        diagnostic = "'{arg0}' is referenced by more than {arg1} resources."
+    )]
+    TooManyReferences(String, usize),
+    #[error(
+        code = "not-supported",
+        diagnostic = "_cascade value '{arg0}' is not supported, only 'delete' is."
+    )]
+    UnsupportedCascade(String),
+}
+
+/// Literal relative reference IE Patient/123 or Patient/123/_history/1.
+#[derive(Debug, PartialEq)]
+struct RelativeReference {
+    resource_type: String,
+    id: String,
+    version_id: Option<String>,
+}
+
+impl RelativeReference {
+    /// Returns None for contained, absolute and logical references.
+    fn parse(reference: &Reference) -> Option<Self> {
+        let value = reference.reference.as_ref()?.value.as_ref()?;
+        if value.starts_with('#') || value.contains(':') {
+            return None;
+        }
+
+        match value.split('/').collect::<Vec<_>>().as_slice() {
+            [resource_type, id] => Some(RelativeReference {
+                resource_type: resource_type.to_string(),
+                id: id.to_string(),
+                version_id: None,
+            }),
+            [resource_type, id, "_history", version_id] => Some(RelativeReference {
+                resource_type: resource_type.to_string(),
+                id: id.to_string(),
+                version_id: Some(version_id.to_string()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RelativeReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.resource_type, self.id)?;
+        if let Some(version_id) = &self.version_id {
+            write!(f, "/_history/{}", version_id)?;
+        }
+        Ok(())
+    }
+}
+
+fn collect_references<'a>(value: &'a dyn MetaValue, references: &mut Vec<&'a Reference>) {
+    for node in value.flatten() {
+        if let Some(reference) = node.as_any().downcast_ref::<Reference>() {
+            references.push(reference);
+        }
+        for field in node.fields() {
+            if let Some(child) = node.get_field(field) {
+                collect_references(child, references);
+            }
+        }
+    }
+}
+
+/// Literal relative references in the resource.
+fn relative_references(resource: &Resource) -> Vec<RelativeReference> {
+    let mut references = vec![];
+    collect_references(resource, &mut references);
+
+    references
+        .into_iter()
+        .filter_map(RelativeReference::parse)
+        .collect()
+}
+
+fn is_cascade_delete(parameters: &ParsedParameters) -> Result<bool, OperationOutcomeError> {
+    match parameters.get("_cascade") {
+        Some(ParsedParameter::Result(Parameter { value, .. }))
+        | Some(ParsedParameter::Resource(Parameter { value, .. })) => match value.as_slice() {
+            [cascade] if cascade == "delete" => Ok(true),
+            _ => Err(ReferentialIntegrityError::UnsupportedCascade(value.join(",")).into()),
+        },
+        None => Ok(false),
+    }
+}
+
+async fn referential_integrity<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    ctx: &ServerCTX<Repo, Search, Terminology>,
+) -> Result<bool, OperationOutcomeError> {
+    let key = (
+        ctx.tenant.as_ref().to_string(),
+        ctx.project.as_ref().to_string(),
+    );
+    if let Some((fetched_at, enabled)) = SETTINGS.lock().unwrap().get(&key)
+        && fetched_at.elapsed() < SETTING_TTL
+    {
+        return Ok(*enabled);
+    }
+
+    let project = state
+        .repo
+        .read_latest(
+            &ctx.tenant,
+            &ProjectId::System,
+            &ResourceType::Project,
+            &ResourceId::new(ctx.project.as_ref().to_string()),
+        )
+        .await?;
+
+    let enabled = match project {
+        Some(Resource::Project(project)) => project
+            .referentialIntegrity
+            .as_ref()
+            .and_then(|referential_integrity| referential_integrity.value)
+            .unwrap_or(false),
+        _ => false,
+    };
+    SETTINGS
+        .lock()
+        .unwrap()
+        .insert(key, (Instant::now(), enabled));
+
+    Ok(enabled)
+}
+
+/// Drops the cached setting so the Project is re-read IE after the Project is updated.
+pub fn invalidate_setting(tenant: &TenantId, project: &ProjectId) {
+    SETTINGS
+        .lock()
+        .unwrap()
+        .remove(&(tenant.as_ref().to_string(), project.as_ref().to_string()));
+}
+
+/// Confirms every relative reference to a resource stored in the project resolves.
+/// References to tenant, auth and artifact types are not checked as they are stored elsewhere.
+async fn verify_references<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    ctx: &ServerCTX<Repo, Search, Terminology>,
+    resource: &Resource,
+) -> Result<(), OperationOutcomeError> {
+    for reference in relative_references(resource) {
+        let Ok(resource_type) = ResourceType::try_from(reference.resource_type.as_str()) else {
+            return Err(
+                ReferentialIntegrityError::UnresolvedReference(reference.to_string()).into(),
+            );
+        };
+        if SPECIAL_TYPES.contains(&resource_type) {
+            continue;
+        }
+
+        let resolved = if let Some(version_id) = reference.version_id.as_ref() {
+            state
+                .repo
+                .read_by_version_ids(
+                    &ctx.tenant,
+                    &ctx.project,
+                    &[&VersionId::new(version_id.clone())],
+                    haste_repository::fhir::CachePolicy::NoCache,
+                )
+                .await?
+                .first()
+                .and_then(|resource| resource.get_field("id"))
+                .and_then(|id| id.as_any().downcast_ref::<String>())
+                .is_some_and(|id| id == &reference.id)
+        } else {
+            state
+                .repo
+                .read_latest(
+                    &ctx.tenant,
+                    &ctx.project,
+                    &resource_type,
+                    &ResourceId::new(reference.id.clone()),
+                )
+                .await?
+                .is_some()
+        };
+
+        if !resolved {
+            return Err(
+                ReferentialIntegrityError::UnresolvedReference(reference.to_string()).into(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Current resources that reference `resource_type/id`.
+/// Best effort as the search index is updated asynchronously, resources written shortly before
+/// are missed. Hits are re-read so references that were since removed are skipped.
+async fn inbound_references<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    ctx: &ServerCTX<Repo, Search, Terminology>,
+    resource_type: &ResourceType,
+    id: &str,
+) -> Result<Vec<(ResourceType, String)>, OperationOutcomeError> {
+    let target = format!("{}/{}", resource_type.as_ref(), id);
+    let entries = state
+        .search
+        .inbound_references(
+            &ctx.fhir_version,
+            &ctx.tenant,
+            &ctx.project,
+            resource_type,
+            &ResourceId::new(id.to_string()),
+            MAX_INBOUND_REFERENCES + 1,
+        )
+        .await?;
+
+    if entries.len() > MAX_INBOUND_REFERENCES {
+        return Err(
+            ReferentialIntegrityError::TooManyReferences(target, MAX_INBOUND_REFERENCES).into(),
+        );
+    }
+
+    let mut references = vec![];
+    for entry in entries {
+        let Some(resource) = state
+            .repo
+            .read_latest(&ctx.tenant, &ctx.project, &entry.resource_type, &entry.id)
+            .await?
+        else {
+            continue;
+        };
+
+        if relative_references(&resource).iter().any(|reference| {
+            reference.resource_type == resource_type.as_ref() && reference.id == id
+        }) {
+            references.push((entry.resource_type, entry.id.as_ref().to_string()));
+        }
+    }
+
+    Ok(references)
+}
+
+/// Deletes the resources referencing the deleted resource. Each delete is made through the client
+/// within the current transaction so it is authorized and audited and cascades further in turn.
+async fn cascade_delete<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: &ServerMiddlewareState<Repo, Search, Terminology>,
+    ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    references: Vec<(ResourceType, String)>,
+    parameters: &ParsedParameters,
+) -> Result<(), OperationOutcomeError> {
+    let nested = Arc::new(ctx.nested());
+
+    for (resource_type, id) in references {
+        // Already deleted by an earlier cascade IE resources referencing each other.
+        if state
+            .repo
+            .read_latest(
+                &ctx.tenant,
+                &ctx.project,
+                &resource_type,
+                &ResourceId::new(id.clone()),
+            )
+            .await?
+            .is_none()
+        {
+            continue;
+        }
+
+        ctx.client
+            .request_with_state(
+                state.clone(),
+                nested.clone(),
+                FHIRRequest::Delete(DeleteRequest::Instance(FHIRDeleteInstanceRequest {
+                    resource_type,
+                    id,
+                    parameters: parameters.clone(),
+                })),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn enforce<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    state: ServerMiddlewareState<Repo, Search, Terminology>,
+    context: ServerMiddlewareContext<Repo, Search, Terminology>,
+    next: Arc<ServerMiddlewareNext<Repo, Search, Terminology>>,
+) -> Result<ServerMiddlewareContext<Repo, Search, Terminology>, OperationOutcomeError> {
+    let ctx = context.ctx.clone();
+
+    if let FHIRRequest::Delete(DeleteRequest::Instance(delete_request)) = &context.request {
+        let resource_type = delete_request.resource_type.clone();
+        let id = delete_request.id.clone();
+        let parameters = delete_request.parameters.clone();
+        let cascade = is_cascade_delete(&parameters)?;
+
+        let references = inbound_references(&state, &ctx, &resource_type, &id).await?;
+        if !cascade && !references.is_empty() {
+            let mut listed = references
+                .iter()
+                .take(LISTED_REFERENCES)
+                .map(|(resource_type, id)| format!("{}/{}", resource_type.as_ref(), id))
+                .collect::<Vec<_>>()
+                .join(", ");
+            if references.len() > LISTED_REFERENCES {
+                listed = format!(
+                    "{} and {} more",
+                    listed,
+                    references.len() - LISTED_REFERENCES
+                );
+            }
+
+            return Err(ReferentialIntegrityError::Referenced(
+                format!("{}/{}", resource_type.as_ref(), id),
+                listed,
+            )
+            .into());
+        }
+
+        // Delete first so references back to this resource are skipped by the cascade.
+        let res = next(state.clone(), context).await?;
+        cascade_delete(&state, ctx, references, &parameters).await?;
+
+        return Ok(res);
+    }
+
+    let res = next(state.clone(), context).await?;
+    let resource = match res.response.as_ref() {
+        Some(FHIRResponse::Create(res)) => &res.resource,
+        Some(FHIRResponse::Update(res)) => &res.resource,
+        Some(FHIRResponse::Patch(res)) => &res.resource,
+        _ => return Ok(res),
+    };
+    verify_references(&state, &ctx, resource).await?;
+
+    Ok(res)
+}
+
+/// Per project referential integrity, enabled with Project.referentialIntegrity.
+/// References in written resources are checked after storage within the same transaction, so patched
+/// resources are covered and the write is rolled back if a reference does not resolve.
+/// Deletes are refused while other resources reference the resource unless `_cascade=delete` is set.
+/// The setting is cached for up to a minute.
+pub struct Middleware {}
+impl Middleware {
+    pub fn new() -> Self {
+        Middleware {}
+    }
+}
+
+impl<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>
+    MiddlewareChain<
+        ServerMiddlewareState<Repo, Search, Terminology>,
+        Arc<ServerCTX<Repo, Search, Terminology>>,
+        FHIRRequest,
+        FHIRResponse,
+        OperationOutcomeError,
+    > for Middleware
+{
+    fn call(
+        &self,
+        state: ServerMiddlewareState<Repo, Search, Terminology>,
+        context: ServerMiddlewareContext<Repo, Search, Terminology>,
+        next: Option<Arc<ServerMiddlewareNext<Repo, Search, Terminology>>>,
+    ) -> ServerMiddlewareOutput<Repo, Search, Terminology> {
+        Box::pin(async move {
+            let Some(next) = next else {
+                return Err(OperationOutcomeError::fatal(
+                    IssueType::Exception(None),
+                    "No next middleware found".to_string(),
+                ));
+            };
+
+            let is_checked = matches!(
+                &context.request,
+                FHIRRequest::Create(_)
+                    | FHIRRequest::Update(_)
+                    | FHIRRequest::Patch(_)
+                    | FHIRRequest::Delete(DeleteRequest::Instance(_))
+            );
+            if !is_checked || !referential_integrity(&state, &context.ctx).await? {
+                return next(state, context).await;
+            }
+
+            // Already in a transaction (IE a transaction bundle or cascade) so the caller commits.
+            if state.repo.in_transaction() {
+                return enforce(state, context, next).await;
+            }
+
+            let repo_client;
+            // Place in block so transaction_state gets dropped.
+            let res = {
+                let transaction_state = Arc::new(ClientState {
+                    repo: Arc::new(state.repo.transaction(true).await?),
+                    search: state.search.clone(),
+                    terminology: state.terminology.clone(),
+                    config: state.config.clone(),
+                });
+                repo_client = transaction_state.repo.clone();
+
+                enforce(transaction_state, context, next).await
+            };
+
+            complete_transaction(repo_client, &res).await?;
+
+            res
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_model::r4::generated::{
+        resources::Observation,
+        types::{FHIRString, Reference},
+    };
+
+    fn reference(value: &str) -> Box<Reference> {
+        Box::new(Reference {
+            reference: Some(Box::new(FHIRString {
+                value: Some(value.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_relative_references() {
+        let observation = Resource::Observation(Observation {
+            subject: Some(reference("Patient/123")),
+            performer: Some(vec![
+                reference("Practitioner/abc/_history/2"),
+                reference("#contained"),
+                reference("http://example.com/fhir/Patient/456"),
+                reference("urn:uuid:0d7a9f2c"),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            relative_references(&observation),
+            vec![
+                RelativeReference {
+                    resource_type: "Patient".to_string(),
+                    id: "123".to_string(),
+                    version_id: None,
+                },
+                RelativeReference {
+                    resource_type: "Practitioner".to_string(),
+                    id: "abc".to_string(),
+                    version_id: Some("2".to_string()),
+                },
+            ]
+        );
+    }
+}
@@ -13,8 +13,8 @@ use haste_fhir_client::{
     request::{
         DeleteRequest, DeleteResponse, FHIRBatchResponse, FHIRCreateResponse,
         FHIRDeleteInstanceResponse, FHIRHistoryInstanceResponse, FHIRHistorySystemResponse,
-        FHIRHistoryTypeResponse, FHIRPatchResponse, FHIRReadResponse, FHIRRequest, FHIRResponse,
-        FHIRSearchSystemResponse, FHIRSearchTypeRequest, FHIRSearchTypeResponse,
+        FHIRHistoryTypeResponse, FHIRPatch, FHIRPatchResponse, FHIRReadResponse, FHIRRequest,
+        FHIRResponse, FHIRSearchSystemResponse, FHIRSearchTypeRequest, FHIRSearchTypeResponse,
         FHIRTransactionResponse, FHIRUpdateResponse, FHIRVersionReadResponse, HistoryRequest,
         HistoryResponse, SearchRequest, SearchResponse, UpdateRequest,
     },
@@ -28,14 +28,61 @@ use haste_fhir_model::r4::generated::{
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_search::SearchEngine;
 use haste_fhir_terminology::FHIRTerminology;
+use haste_fhirpath::FPEngine;
 use haste_jwt::ResourceId;
 use haste_reflect::MetaValue;
 use haste_repository::{Repository, fhir::FHIRRepository};
+use json_patch::Patch;
 use std::{
     io::{BufWriter, Write},
     sync::Arc,
 };
 
+/// Applies a JSON Patch against the JSON serialization of the resource.
+fn apply_json_patch(resource: &Resource, patch: &Patch) -> Result<Resource, OperationOutcomeError> {
+    let mut writer = BufWriter::new(Vec::new());
+    haste_fhir_serialization_json::to_writer(&mut writer, &resource).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to serialize resource for patching: ".to_string() + &e.to_string(),
+        )
+    })?;
+    writer.flush().map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to flush buffer: ".to_string() + &e.to_string(),
+        )
+    })?;
+
+    let content: Vec<u8> = writer.into_inner().map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to retrieve buffer content: ".to_string() + &e.to_string(),
+        )
+    })?;
+
+    let mut json: serde_json::Value = serde_json::from_reader(content.as_slice()).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to deserialize JSON for patching: ".to_string() + &e.to_string(),
+        )
+    })?;
+
+    json_patch::patch(&mut json, patch).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            format!("Failed to apply JSON patch: '{}'", e.to_string()),
+        )
+    })?;
+
+    haste_fhir_serialization_json::from_serde_value::<Resource>(&json).map_err(|e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            format!("Failed to deserialize patched resource '{}'.", e),
+        )
+    })
+}
+
 pub struct Middleware {}
 impl Middleware {
     pub fn new() -> Self {
@@ -508,7 +555,7 @@ impl<
 
                         Ok(process_transaction_bundle(
                             &transaction_client,
-                            context.ctx.clone(),
+                            Arc::new(context.ctx.nested()),
                             sorted_transaction,
                         )
                         .await?)
@@ -552,7 +599,7 @@ impl<
                     Ok(Some(FHIRResponse::Batch(FHIRBatchResponse {
                         resource: process_batch_bundle(
                             &batch_client,
-                            context.ctx.clone(),
+                            Arc::new(context.ctx.nested()),
                             batch_entries.unwrap_or_else(Vec::new),
                         )
                         .await?,
@@ -575,54 +622,24 @@ impl<
                         ));
                     };
 
-                    let mut writer = BufWriter::new(Vec::new());
-                    haste_fhir_serialization_json::to_writer(&mut writer, &resource).map_err(
-                        |e| {
-                            OperationOutcomeError::fatal(
-                                IssueType::Exception(None),
-                                "Failed to serialize resource for patching: ".to_string()
-                                    + &e.to_string(),
+                    let mut patched_resource = match &fhir_patch_request.patch {
+                        FHIRPatch::JSONPatch(patch) => apply_json_patch(&resource, patch)?,
+                        FHIRPatch::FHIRPathPatch(parameters) => {
+                            let mut patched_resource = resource.clone();
+                            haste_fhirpath::patch::apply_patch(
+                                &FPEngine::new(),
+                                &mut patched_resource,
+                                parameters.clone(),
                             )
-                        },
-                    )?;
-                    writer.flush().map_err(|e| {
-                        OperationOutcomeError::fatal(
-                            IssueType::Exception(None),
-                            "Failed to flush buffer: ".to_string() + &e.to_string(),
-                        )
-                    })?;
-
-                    let content: Vec<u8> = writer.into_inner().map_err(|e| {
-                        OperationOutcomeError::fatal(
-                            IssueType::Exception(None),
-                            "Failed to retrieve buffer content: ".to_string() + &e.to_string(),
-                        )
-                    })?;
-
-                    let mut json: serde_json::Value = serde_json::from_reader(content.as_slice())
-                        .map_err(|e| {
-                        OperationOutcomeError::fatal(
-                            IssueType::Exception(None),
-                            "Failed to deserialize JSON for patching: ".to_string()
-                                + &e.to_string(),
-                        )
-                    })?;
-
-                    json_patch::patch(&mut json, &fhir_patch_request.patch).map_err(|e| {
-                        OperationOutcomeError::fatal(
-                            IssueType::Exception(None),
-                            format!("Failed to apply JSON patch: '{}'", e.to_string()),
-                        )
-                    })?;
-
-                    let mut patched_resource =
-                        haste_fhir_serialization_json::from_serde_value::<Resource>(&json)
                             .map_err(|e| {
-                                OperationOutcomeError::fatal(
-                                    IssueType::Exception(None),
-                                    format!("Failed to deserialize patched resource '{}'.", e),
+                                OperationOutcomeError::error(
+                                    IssueType::Invalid(None),
+                                    format!("Failed to apply FHIRPath patch: '{}'", e),
                                 )
                             })?;
+                            patched_resource
+                        }
+                    };
 
                     if std::mem::discriminant(&resource)
                         != std::mem::discriminant(&patched_resource)
@@ -48,6 +48,30 @@ pub async fn setup_transaction_context<
     }
 }
 
+/// Commits the transaction when the request succeeded, otherwise rolls it back.
+pub async fn complete_transaction<Repo: Repository + Send + Sync + 'static, T>(
+    repo_client: Arc<Repo>,
+    res: &Result<T, OperationOutcomeError>,
+) -> Result<(), OperationOutcomeError> {
+    if !repo_client.in_transaction() {
+        return Ok(());
+    }
+
+    let repo_client = Arc::try_unwrap(repo_client).map_err(|_e| {
+        OperationOutcomeError::fatal(
+            IssueType::Exception(None),
+            "Failed to unwrap transaction client".to_string(),
+        )
+    })?;
+
+    if res.is_ok() {
+        repo_client.commit().await
+    } else {
+        info!("Rolling back transaction due to error");
+        repo_client.rollback().await
+    }
+}
+
 pub struct Middleware {}
 impl Middleware {
     pub fn new() -> Self {
@@ -91,28 +115,7 @@ impl<
                         res
                     };
 
-                    if res.is_ok() && repo_client.in_transaction() {
-                        Arc::try_unwrap(repo_client)
-                            .map_err(|_e| {
-                                OperationOutcomeError::fatal(
-                                    IssueType::Exception(None),
-                                    "Failed to unwrap transaction client".to_string(),
-                                )
-                            })?
-                            .commit()
-                            .await?;
-                    } else if res.is_err() && repo_client.in_transaction() {
-                        info!("Rolling back transaction due to error");
-                        Arc::try_unwrap(repo_client)
-                            .map_err(|_e| {
-                                OperationOutcomeError::fatal(
-                                    IssueType::Exception(None),
-                                    "Failed to unwrap transaction client".to_string(),
-                                )
-                            })?
-                            .rollback()
-                            .await?;
-                    }
+                    complete_transaction(repo_client, &res).await?;
 
                     res
                 }
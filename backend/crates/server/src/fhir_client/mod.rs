@@ -13,20 +13,23 @@ use haste_fhir_client::{
     FHIRClient,
     middleware::{Middleware, MiddlewareChain},
     request::{
-        FHIRBatchRequest, FHIRConditionalUpdateRequest, FHIRCreateRequest, FHIRReadRequest,
-        FHIRRequest, FHIRResponse, FHIRSearchTypeRequest, FHIRTransactionRequest,
-        FHIRUpdateInstanceRequest, SearchRequest, SearchResponse, UpdateRequest,
+        DeleteRequest, FHIRBatchRequest, FHIRConditionalUpdateRequest, FHIRCreateRequest,
+        FHIRDeleteInstanceRequest, FHIRHistoryInstanceRequest, FHIRInvokeInstanceRequest,
+        FHIRInvokeSystemRequest, FHIRInvokeTypeRequest, FHIRPatch, FHIRPatchRequest,
+        FHIRReadRequest, FHIRRequest, FHIRResponse, FHIRSearchTypeRequest, FHIRTransactionRequest,
+        FHIRUpdateInstanceRequest, FHIRVersionReadRequest, HistoryRequest, HistoryResponse,
+        InvocationRequest, InvokeResponse, Operation, SearchRequest, SearchResponse, UpdateRequest,
     },
     url::ParsedParameters,
 };
 use haste_fhir_model::r4::generated::resources::{
-    Bundle, CapabilityStatement, Parameters, Resource, ResourceType,
+    Bundle, CapabilityStatement, Parameters, Provenance, Resource, ResourceType,
 };
 use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
 use haste_fhir_search::SearchEngine;
 use haste_fhir_terminology::FHIRTerminology;
 use haste_jwt::{
-    AuthorId, AuthorKind, ProjectId, TenantId, UserRole,
+    AuthorId, AuthorKind, ProjectId, TenantId, UserRole, VersionId,
     scopes::{
         SMARTResourceScope, Scope, Scopes, SmartResourceScopeLevel, SmartResourceScopePermission,
         SmartResourceScopePermissions, SmartResourceScopeUser, SmartScope,
@@ -39,6 +42,9 @@ mod batch_transaction_processing;
 mod middleware;
 mod utilities;
 
+pub use middleware::audit::start_audit_writer;
+pub use utilities::request_to_resource_type;
+
 #[derive(OperationOutcomeError, Debug)]
 pub enum StorageError {
     #[error(
@@ -58,6 +64,8 @@ pub enum StorageError {
     NotFound(ResourceType, String),
     #[error(code = "invalid", diagnostic = "Invalid resource type.")]
     InvalidType,
+    #[error(code = "invalid", diagnostic = "Invalid operation name '{arg0}'.")]
+    InvalidOperation(String),
 }
 
 pub struct ServerCTX<
@@ -70,6 +78,11 @@ pub struct ServerCTX<
     pub fhir_version: SupportedFHIRVersions,
     pub user: Arc<haste_jwt::claims::UserTokenClaims>,
     pub client: Arc<FHIRServerClient<Repo, Search, Terminology>>,
+    /// Provenance supplied via the X-Provenance header, recorded against each version written.
+    pub provenance: Option<Arc<Provenance>>,
+    /// Set for requests made while processing another request IE bundle entries.
+    /// Nested requests are not counted against rate limits.
+    pub nested: bool,
 }
 
 impl<
@@ -91,9 +104,29 @@ impl<
             fhir_version,
             user,
             client,
+            provenance: None,
+            nested: false,
+        }
+    }
+
+    /// Context for requests made while processing a request under this context.
+    pub fn nested(&self) -> Self {
+        ServerCTX {
+            tenant: self.tenant.clone(),
+            project: self.project.clone(),
+            fhir_version: self.fhir_version.clone(),
+            user: self.user.clone(),
+            client: self.client.clone(),
+            provenance: self.provenance.clone(),
+            nested: true,
         }
     }
 
+    pub fn with_provenance(mut self, provenance: Option<Provenance>) -> Self {
+        self.provenance = provenance.map(Arc::new);
+        self
+    }
+
     pub fn system(
         tenant: TenantId,
         project: ProjectId,
@@ -127,8 +160,11 @@ impl<
                 resource_type: AuthorKind::System,
                 access_policy_version_ids: vec![],
                 membership: None,
+                amr: None,
             }),
             client,
+            provenance: None,
+            nested: false,
         }
     }
 }
@@ -253,7 +289,7 @@ static TENANT_AUTH_TYPES: &[ResourceType] = &[
 ];
 static PROJECT_AUTH_TYPES: &[ResourceType] = &[ResourceType::Membership];
 
-static SPECIAL_TYPES: LazyLock<Vec<ResourceType>> = LazyLock::new(|| {
+pub(crate) static SPECIAL_TYPES: LazyLock<Vec<ResourceType>> = LazyLock::new(|| {
     [
         &TENANT_AUTH_TYPES[..],
         &PROJECT_AUTH_TYPES[..],
@@ -329,7 +365,11 @@ impl<
                     }
                 }
             }),
-            middleware: Middleware::new(vec![Box::new(middleware::storage::Middleware::new())]),
+            middleware: Middleware::new(vec![
+                Box::new(middleware::referential_integrity::Middleware::new()),
+                Box::new(middleware::provenance::Middleware::new()),
+                Box::new(middleware::storage::Middleware::new()),
+            ]),
         };
 
         let operation_invocation_routes = Route {
@@ -424,6 +464,8 @@ impl<
                 config: config.config,
             }),
             middleware: Middleware::new(vec![
+                Box::new(middleware::audit::Middleware::new()),
+                Box::new(middleware::quota::Middleware::new()),
                 Box::new(middleware::auth_z::scope_check::SMARTScopeAccessMiddleware::new()),
                 Box::new(middleware::auth_z::access_control::AccessControlMiddleware::new()),
                 Box::new(route_middleware),
@@ -431,6 +473,21 @@ impl<
             ]),
         }
     }
+
+    /// Makes a request against the given state instead of the clients own
+    /// IE to run a request within a transaction opened by a middleware.
+    pub(crate) async fn request_with_state(
+        &self,
+        state: Arc<ClientState<Repo, Search, Terminology>>,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        request: FHIRRequest,
+    ) -> Result<FHIRResponse, OperationOutcomeError> {
+        let response = self.middleware.call(state, ctx, request).await?;
+
+        response
+            .response
+            .ok_or_else(|| StorageError::NoResponse.into())
+    }
 }
 
 impl<
@@ -586,12 +643,28 @@ impl<
 
     async fn patch(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _id: String,
-        _patches: json_patch::Patch,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        id: String,
+        patch: FHIRPatch,
     ) -> Result<Resource, OperationOutcomeError> {
-        todo!()
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::Patch(FHIRPatchRequest {
+                    resource_type,
+                    id,
+                    patch,
+                }),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::Patch(patch_response)) => Ok(patch_response.resource),
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn read(
@@ -617,21 +690,50 @@ impl<
 
     async fn vread(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _id: String,
-        _version_id: String,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        id: String,
+        version_id: String,
     ) -> Result<Option<Resource>, OperationOutcomeError> {
-        todo!()
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::VersionRead(FHIRVersionReadRequest {
+                    resource_type,
+                    id,
+                    version_id: VersionId::new(version_id),
+                }),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::VersionRead(vread_response)) => Ok(Some(vread_response.resource)),
+            None => Ok(None),
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn delete_instance(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _id: String,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        id: String,
     ) -> Result<(), OperationOutcomeError> {
-        todo!()
+        self.middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::Delete(DeleteRequest::Instance(FHIRDeleteInstanceRequest {
+                    resource_type,
+                    id,
+                    parameters: ParsedParameters::new(vec![]),
+                })),
+            )
+            .await?;
+
+        Ok(())
     }
 
     async fn delete_type(
@@ -670,42 +772,120 @@ impl<
 
     async fn history_instance(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _id: String,
-        _parameters: ParsedParameters,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        id: String,
+        parameters: ParsedParameters,
     ) -> Result<Bundle, OperationOutcomeError> {
-        todo!()
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::History(HistoryRequest::Instance(FHIRHistoryInstanceRequest {
+                    resource_type,
+                    id,
+                    parameters,
+                })),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::History(HistoryResponse::Instance(history_response))) => {
+                Ok(history_response.bundle)
+            }
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn invoke_instance(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _id: String,
-        _operation: String,
-        _parameters: Parameters,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        id: String,
+        operation: String,
+        parameters: Parameters,
     ) -> Result<Resource, OperationOutcomeError> {
-        todo!()
+        let operation = Operation::new(&operation)
+            .map_err(|_e| StorageError::InvalidOperation(operation.clone()))?;
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::Invocation(InvocationRequest::Instance(FHIRInvokeInstanceRequest {
+                    operation,
+                    resource_type,
+                    id,
+                    parameters,
+                })),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::Invoke(InvokeResponse::Instance(invoke_response))) => {
+                Ok(invoke_response.resource)
+            }
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn invoke_type(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _resource_type: ResourceType,
-        _operation: String,
-        _parameters: Parameters,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        resource_type: ResourceType,
+        operation: String,
+        parameters: Parameters,
     ) -> Result<Resource, OperationOutcomeError> {
-        todo!()
+        let operation = Operation::new(&operation)
+            .map_err(|_e| StorageError::InvalidOperation(operation.clone()))?;
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::Invocation(InvocationRequest::Type(FHIRInvokeTypeRequest {
+                    operation,
+                    resource_type,
+                    parameters,
+                })),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::Invoke(InvokeResponse::Type(invoke_response))) => {
+                Ok(invoke_response.resource)
+            }
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn invoke_system(
         &self,
-        _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
-        _operation: String,
-        _parameters: Parameters,
+        ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+        operation: String,
+        parameters: Parameters,
     ) -> Result<Resource, OperationOutcomeError> {
-        todo!()
+        let operation = Operation::new(&operation)
+            .map_err(|_e| StorageError::InvalidOperation(operation.clone()))?;
+        let res = self
+            .middleware
+            .call(
+                self.state.clone(),
+                ctx,
+                FHIRRequest::Invocation(InvocationRequest::System(FHIRInvokeSystemRequest {
+                    operation,
+                    parameters,
+                })),
+            )
+            .await?;
+
+        match res.response {
+            Some(FHIRResponse::Invoke(InvokeResponse::System(invoke_response))) => {
+                Ok(invoke_response.resource)
+            }
+            _ => panic!("Unexpected response type"),
+        }
     }
 
     async fn transaction(
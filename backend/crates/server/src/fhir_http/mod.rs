@@ -3,23 +3,27 @@ use haste_fhir_client::request::{
     DeleteRequest, FHIRBatchRequest, FHIRConditionalUpdateRequest, FHIRCreateRequest,
     FHIRDeleteInstanceRequest, FHIRDeleteSystemRequest, FHIRDeleteTypeRequest,
     FHIRHistoryInstanceRequest, FHIRHistorySystemRequest, FHIRHistoryTypeRequest,
-    FHIRInvokeInstanceRequest, FHIRInvokeSystemRequest, FHIRInvokeTypeRequest, FHIRPatchRequest,
-    FHIRReadRequest, FHIRRequest, FHIRSearchSystemRequest, FHIRSearchTypeRequest,
+    FHIRInvokeInstanceRequest, FHIRInvokeSystemRequest, FHIRInvokeTypeRequest, FHIRPatch,
+    FHIRPatchRequest, FHIRReadRequest, FHIRRequest, FHIRSearchSystemRequest, FHIRSearchTypeRequest,
     FHIRTransactionRequest, FHIRUpdateInstanceRequest, FHIRVersionReadRequest, HistoryRequest,
     InvocationRequest, Operation, OperationParseError, SearchRequest, UpdateRequest,
 };
 use haste_fhir_client::url::{ParseError, ParsedParameters};
 use haste_fhir_model::r4::generated::resources::{
-    Bundle, Parameters, Resource, ResourceType, ResourceTypeError,
+    Bundle, BundleEntry, Parameters, Resource, ResourceType, ResourceTypeError,
 };
 use haste_fhir_model::r4::generated::terminology::BundleType;
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_operation_error::derive::OperationOutcomeError;
 use haste_fhir_serialization_json::errors::DeserializeError;
+use haste_fhir_serialization_json::stream::JSONArrayFieldReader;
 use haste_jwt::VersionId;
 use haste_repository::types::SupportedFHIRVersions;
 use json_patch::Patch;
 use std::collections::HashMap;
+use tokio::io::AsyncBufRead;
+
+pub mod provenance;
 
 #[derive(Debug)]
 pub enum HTTPBody {
@@ -33,6 +37,7 @@ pub struct HTTPRequest {
     path: String,
     body: HTTPBody,
     query: HashMap<String, String>,
+    content_type: Option<String>,
 }
 impl HTTPRequest {
     pub fn new(
@@ -46,8 +51,14 @@ impl HTTPRequest {
             path,
             body,
             query,
+            content_type: None,
         }
     }
+
+    pub fn with_content_type(mut self, content_type: Option<String>) -> Self {
+        self.content_type = content_type;
+        self
+    }
 }
 
 #[derive(OperationOutcomeError, Debug)]
@@ -103,6 +114,35 @@ fn get_parameters(req: HTTPRequest) -> Result<Parameters, FHIRRequestParsingErro
     Ok(params)
 }
 
+/// JSON Patch bodies are sent as `application/json-patch+json` and FHIRPath Patch bodies as
+/// a Parameters resource. Bodies without a content type are treated as JSON Patch.
+fn get_patch(req: HTTPRequest) -> Result<FHIRPatch, FHIRRequestParsingError> {
+    let body = match req.body {
+        HTTPBody::Resource(Resource::Parameters(parameters)) => {
+            return Ok(FHIRPatch::FHIRPathPatch(parameters));
+        }
+        HTTPBody::Resource(_) => return Err(FHIRRequestParsingError::InvalidBody),
+        HTTPBody::String(body) => body,
+    };
+
+    let mime_type = req
+        .content_type
+        .as_deref()
+        .map(|content_type| content_type.split(';').next().unwrap_or_default().trim());
+
+    match mime_type {
+        None | Some("application/json-patch+json") | Some("application/json") => {
+            Ok(FHIRPatch::JSONPatch(serde_json::from_str::<Patch>(&body)?))
+        }
+        Some("application/fhir+json") => Ok(FHIRPatch::FHIRPathPatch(
+            haste_fhir_serialization_json::from_str::<Parameters>(&body)?,
+        )),
+        Some(content_type) => Err(FHIRRequestParsingError::Unsupported(format!(
+            "PATCH with content type {content_type}"
+        ))),
+    }
+}
+
 fn get_bundle(req: HTTPRequest) -> Result<Bundle, FHIRRequestParsingError> {
     let bundle = match req.body {
         HTTPBody::Resource(resource) => {
@@ -117,6 +157,28 @@ fn get_bundle(req: HTTPRequest) -> Result<Bundle, FHIRRequestParsingError> {
     Ok(bundle)
 }
 
+/// Reads a Bundle an entry at a time, so neither the raw body nor its JSON tree are held in
+/// memory as a whole.
+pub async fn read_bundle<R: AsyncBufRead + Unpin>(
+    reader: R,
+) -> Result<Bundle, FHIRRequestParsingError> {
+    let mut reader = JSONArrayFieldReader::new(reader, "entry");
+    let mut entries = vec![];
+    while let Some(entry) = reader.next::<BundleEntry>().await {
+        entries.push(entry?);
+    }
+
+    let fields = reader.finish().await?;
+    let mut bundle = haste_fhir_serialization_json::from_serde_value::<Bundle>(
+        &serde_json::Value::Object(fields),
+    )?;
+    if !entries.is_empty() {
+        bundle.entry = Some(entries);
+    }
+
+    Ok(bundle)
+}
+
 /*
 search-system	      ?	                                  GET	N/A	N/A	N/A	N/A
 
@@ -376,17 +438,13 @@ fn parse_request_2(
             Method::PATCH => Ok(FHIRRequest::Patch(FHIRPatchRequest {
                 resource_type: ResourceType::try_from(url_chunks[0].as_str())?,
                 id: url_chunks[1].to_string(),
-                patch: match req.body {
-                    HTTPBody::String(body) => serde_json::from_str::<Patch>(&body)?,
-                    _ => Err(FHIRRequestParsingError::Unsupported(
-                        "PATCH requests must have a JSON body".to_string(),
-                    ))?,
-                },
+                patch: get_patch(req)?,
             })),
             Method::DELETE => Ok(FHIRRequest::Delete(DeleteRequest::Instance(
                 FHIRDeleteInstanceRequest {
                     resource_type: ResourceType::try_from(url_chunks[0].as_str())?,
                     id: url_chunks[1].to_string(),
+                    parameters: ParsedParameters::try_from(&req.query)?,
                 },
             ))),
             _ => Err(FHIRRequestParsingError::Unsupported(
@@ -0,0 +1,87 @@
+use haste_fhir_client::request::FHIRRequest;
+use haste_fhir_model::r4::{datetime::Instant, generated::resources::Provenance};
+use haste_fhir_operation_error::{OperationOutcomeError, derive::OperationOutcomeError};
+use haste_fhir_serialization_json::errors::DeserializeError;
+
+pub static X_PROVENANCE_HEADER: &str = "x-provenance";
+
+#[derive(OperationOutcomeError, Debug)]
+pub enum ProvenanceHeaderError {
+    #[error(
+        code = "invalid",
+        diagnostic = "X-Provenance header must be a JSON object."
+    )]
+    InvalidJSON(#[from] serde_json::Error),
+    #[error(
+        code = "invalid",
+        diagnostic = "X-Provenance header must contain a Provenance resource."
+    )]
+    NotProvenance,
+    #[error(code = "invalid", diagnostic = "Invalid X-Provenance header: {arg0}")]
+    DeserializeError(#[from] DeserializeError),
+    #[error(
+        code = "invalid",
+        diagnostic = "X-Provenance header is only supported on create and update."
+    )]
+    UnsupportedInteraction,
+}
+
+/// Parses the Provenance resource from the X-Provenance header.
+/// Target and recorded are assigned by the server when the resource is written so may be omitted.
+pub fn parse_provenance_header(value: &str) -> Result<Provenance, OperationOutcomeError> {
+    let mut json =
+        serde_json::from_str::<serde_json::Value>(value).map_err(ProvenanceHeaderError::from)?;
+    let Some(object) = json.as_object_mut() else {
+        return Err(ProvenanceHeaderError::NotProvenance.into());
+    };
+
+    if object.get("resourceType").and_then(|v| v.as_str()) != Some("Provenance") {
+        return Err(ProvenanceHeaderError::NotProvenance.into());
+    }
+
+    object.insert(
+        "target".to_string(),
+        serde_json::json!([{ "reference": "#" }]),
+    );
+    if !object.contains_key("recorded") {
+        object.insert(
+            "recorded".to_string(),
+            serde_json::Value::String(Instant::Iso8601(chrono::Utc::now()).to_string()),
+        );
+    }
+
+    let provenance = haste_fhir_serialization_json::from_str::<Provenance>(&json.to_string())
+        .map_err(ProvenanceHeaderError::from)?;
+
+    Ok(provenance)
+}
+
+/// Provenance can only be recorded for interactions that write new versions.
+pub fn validate_provenance_request(request: &FHIRRequest) -> Result<(), OperationOutcomeError> {
+    match request {
+        FHIRRequest::Create(_)
+        | FHIRRequest::Update(_)
+        | FHIRRequest::Batch(_)
+        | FHIRRequest::Transaction(_) => Ok(()),
+        _ => Err(ProvenanceHeaderError::UnsupportedInteraction.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_provenance_header() {
+        let provenance = parse_provenance_header(
+            r#"{"resourceType":"Provenance","agent":[{"who":{"reference":"Device/pipeline"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(provenance.agent.len(), 1);
+        assert!(provenance.recorded.value.is_some());
+
+        assert!(parse_provenance_header(r#"{"resourceType":"Patient"}"#).is_err());
+        assert!(parse_provenance_header(r#"{"resourceType":"Provenance"}"#).is_err());
+        assert!(parse_provenance_header("not json").is_err());
+    }
+}
@@ -2,6 +2,7 @@ mod extract;
 mod fhir_http;
 
 pub mod auth_n;
+pub mod email;
 pub mod fhir_client;
 pub mod load_artifacts;
 mod mcp;
@@ -9,6 +10,7 @@ mod middleware;
 pub mod server;
 pub mod services;
 mod static_assets;
+mod telemetry;
 pub mod tenants;
 mod ui;
 
@@ -16,6 +18,8 @@ pub enum ServerEnvironmentVariables {
     AllowArtifactMutations,
     // Used for JWT
     CertificationDir,
+    JWTSigningAlgorithm,
+    JWTKeyRotationDays,
     // Main repo config
     DataBaseURL,
     // Search variable config.
@@ -27,16 +31,53 @@ pub enum ServerEnvironmentVariables {
     // Where to redirect for hardcoded admin app.
     AdminAppRedirectURI,
     // Email
-    SendGridAPIKey,
+    EmailTransport,
     EmailFromAddress,
+    SendGridAPIKey,
+    SMTPHost,
+    SMTPPort,
+    SMTPUsername,
+    SMTPPassword,
+    SMTPSecurity,
+    EmailFileDirectory,
     // Data Limits
     MaxRequestBodySize,
+    MaxBundleBodySize,
+    // Directory $import reads NDJSON files from, file imports are disabled when not set.
+    ImportDirectory,
+    // Comma separated hosts $import can fetch NDJSON from, URL imports are disabled when not set.
+    ImportAllowedHosts,
+    // Telemetry
+    OTLPEndpoint,
+    OTLPServiceName,
+    MetricsBearerToken,
+}
+
+impl ServerEnvironmentVariables {
+    /// Keys the server cannot start without.
+    pub fn required() -> Vec<Self> {
+        vec![
+            ServerEnvironmentVariables::CertificationDir,
+            ServerEnvironmentVariables::DataBaseURL,
+            ServerEnvironmentVariables::ElasticSearchURL,
+            ServerEnvironmentVariables::ElasticSearchUsername,
+            ServerEnvironmentVariables::ElasticSearchPassword,
+            ServerEnvironmentVariables::APIURI,
+        ]
+    }
+
+    /// Keys read on each use rather than at startup so changes apply without a restart.
+    pub fn reloadable() -> Vec<Self> {
+        vec![ServerEnvironmentVariables::MetricsBearerToken]
+    }
 }
 
 impl From<ServerEnvironmentVariables> for String {
     fn from(value: ServerEnvironmentVariables) -> Self {
         match value {
             ServerEnvironmentVariables::CertificationDir => "CERTIFICATION_DIR".to_string(),
+            ServerEnvironmentVariables::JWTSigningAlgorithm => "JWT_SIGNING_ALGORITHM".to_string(),
+            ServerEnvironmentVariables::JWTKeyRotationDays => "JWT_KEY_ROTATION_DAYS".to_string(),
             ServerEnvironmentVariables::AllowArtifactMutations => {
                 "ALLOW_ARTIFACT_MUTATIONS".to_string()
             }
@@ -50,9 +91,22 @@ impl From<ServerEnvironmentVariables> for String {
             }
             ServerEnvironmentVariables::APIURI => "API_URI".to_string(),
             ServerEnvironmentVariables::AdminAppRedirectURI => "ADMIN_APP_REDIRECT_URI".to_string(),
-            ServerEnvironmentVariables::SendGridAPIKey => "SG_API_KEY".to_string(),
+            ServerEnvironmentVariables::EmailTransport => "EMAIL_TRANSPORT".to_string(),
             ServerEnvironmentVariables::EmailFromAddress => "EMAIL_FROM".to_string(),
+            ServerEnvironmentVariables::SendGridAPIKey => "SG_API_KEY".to_string(),
+            ServerEnvironmentVariables::SMTPHost => "SMTP_HOST".to_string(),
+            ServerEnvironmentVariables::SMTPPort => "SMTP_PORT".to_string(),
+            ServerEnvironmentVariables::SMTPUsername => "SMTP_USERNAME".to_string(),
+            ServerEnvironmentVariables::SMTPPassword => "SMTP_PASSWORD".to_string(),
+            ServerEnvironmentVariables::SMTPSecurity => "SMTP_SECURITY".to_string(),
+            ServerEnvironmentVariables::EmailFileDirectory => "EMAIL_FILE_DIR".to_string(),
             ServerEnvironmentVariables::MaxRequestBodySize => "MAX_REQUEST_BODY_SIZE".to_string(),
+            ServerEnvironmentVariables::MaxBundleBodySize => "MAX_BUNDLE_BODY_SIZE".to_string(),
+            ServerEnvironmentVariables::ImportDirectory => "IMPORT_DIRECTORY".to_string(),
+            ServerEnvironmentVariables::ImportAllowedHosts => "IMPORT_ALLOWED_HOSTS".to_string(),
+            ServerEnvironmentVariables::OTLPEndpoint => "OTEL_EXPORTER_OTLP_ENDPOINT".to_string(),
+            ServerEnvironmentVariables::OTLPServiceName => "OTEL_SERVICE_NAME".to_string(),
+            ServerEnvironmentVariables::MetricsBearerToken => "METRICS_BEARER_TOKEN".to_string(),
         }
     }
 }
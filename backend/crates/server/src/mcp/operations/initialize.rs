@@ -4,7 +4,8 @@ use crate::{
         error::MCPError,
         request::InitializeRequest,
         schemas::schema_2025_11_25::{
-            Implementation, InitializeResult, ServerCapabilities, ServerCapabilitiesTools,
+            Implementation, InitializeResult, ServerCapabilities, ServerCapabilitiesPrompts,
+            ServerCapabilitiesResources, ServerCapabilitiesTools,
         },
     },
 };
@@ -26,8 +27,13 @@ pub async fn initialize<
             completions: serde_json::Map::new(),
             experimental: HashMap::new(),
             logging: serde_json::Map::new(),
-            prompts: None,
-            resources: None,
+            prompts: Some(ServerCapabilitiesPrompts {
+                list_changed: Some(false),
+            }),
+            resources: Some(ServerCapabilitiesResources {
+                list_changed: Some(false),
+                subscribe: Some(false),
+            }),
             tasks: None,
             tools: Some(ServerCapabilitiesTools {
                 list_changed: Some(false),
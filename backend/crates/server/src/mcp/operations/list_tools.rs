@@ -69,24 +69,70 @@ fn generate_resource_schema(
         })
         .unwrap_or_default();
 
+    let mut operation_enum = operations_supported
+        .iter()
+        .filter_map(|op| (*op).into())
+        .collect::<Vec<String>>();
+    // Operation invocations ($expand etc..) are not interactions so append separately.
+    operation_enum.push("invoke".to_string());
+
     let mut schema = json!({
         "properties": {
             "operation": {
                 "type": "string",
-                "enum": operations_supported.iter().filter_map(|op| {
-                    (*op).into()
-                }).collect::<Vec<String>>(),
+                "enum": operation_enum,
             }
         },
         "required": ["operation"],
         "type": "object"
     });
 
-    if operations_supported
-        .iter()
-        .find(|code| matches!(code, TypeRestfulInteraction::SearchType(_)))
-        .is_some()
-    {
+    let supports = |check: fn(&TypeRestfulInteraction) -> bool| {
+        operations_supported.iter().any(|code| check(*code))
+    };
+
+    schema["properties"]["id"] = json!({
+        "type": "string",
+        "description": "The logical id of the resource. Required for read, vread, update, patch, delete and history-instance. Optional for invoke to call an instance level operation.",
+    });
+
+    if supports(|code| matches!(code, TypeRestfulInteraction::Vread(_))) {
+        schema["properties"]["version_id"] = json!({
+            "type": "string",
+            "description": "The version id of the resource used for vread.",
+        });
+    }
+
+    if supports(|code| {
+        matches!(
+            code,
+            TypeRestfulInteraction::Create(_) | TypeRestfulInteraction::Update(_)
+        )
+    }) {
+        schema["properties"]["resource"] = json!({
+            "type": "object",
+            "description": "The FHIR resource as JSON used for create and update.",
+        });
+    }
+
+    if supports(|code| matches!(code, TypeRestfulInteraction::Patch(_))) {
+        schema["properties"]["patch"] = json!({
+            "type": "array",
+            "description": "JSON Patch (RFC 6902) operations to apply to the resource.",
+            "items": { "type": "object" },
+        });
+    }
+
+    schema["properties"]["operation_name"] = json!({
+        "type": "string",
+        "description": "The FHIR operation to invoke IE $expand.",
+    });
+    schema["properties"]["parameters"] = json!({
+        "type": "object",
+        "description": "A FHIR Parameters resource passed as input to the operation.",
+    });
+
+    if supports(|code| matches!(code, TypeRestfulInteraction::SearchType(_))) {
         let search_properties = search_tool_parameters(
             capability_search_params
                 .searchParam
@@ -1,7 +1,11 @@
 mod initialize;
 mod list_tools;
+mod prompts;
+mod resources;
 mod tools_call;
 
 pub use initialize::*;
 pub use list_tools::*;
+pub use prompts::*;
+pub use resources::*;
 pub use tools_call::*;
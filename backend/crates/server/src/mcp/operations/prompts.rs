@@ -0,0 +1,189 @@
+use crate::{
+    fhir_client::ServerCTX,
+    mcp::{
+        error::MCPError,
+        request::{GetPromptRequest, ListPromptsRequest},
+        schemas::schema_2025_11_25::{
+            ContentBlock, EmbeddedResource, EmbeddedResourceResource, GetPromptResult,
+            ListPromptsResult, Prompt, PromptArgument, PromptMessage, Role, TextContent,
+            TextResourceContents,
+        },
+    },
+};
+use haste_fhir_client::FHIRClient;
+use haste_fhir_model::r4::generated::{resources::ResourceType, terminology::IssueType};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_repository::Repository;
+use std::{collections::HashMap, sync::Arc};
+
+static SUMMARIZE_RESOURCE_PROMPT: &str = "summarize-resource";
+static SEARCH_RESOURCES_PROMPT: &str = "search-resources";
+
+fn prompt_argument(name: &str, description: &str) -> PromptArgument {
+    PromptArgument {
+        description: Some(description.to_string()),
+        name: name.to_string(),
+        required: Some(true),
+        title: None,
+    }
+}
+
+fn required_argument<'a>(
+    arguments: &'a HashMap<String, String>,
+    name: &str,
+) -> Result<&'a String, OperationOutcomeError> {
+    arguments.get(name).ok_or_else(|| {
+        OperationOutcomeError::error(
+            IssueType::Required(None),
+            format!("Missing required prompt argument '{}'", name),
+        )
+    })
+}
+
+fn text_message(text: String) -> PromptMessage {
+    PromptMessage {
+        content: ContentBlock::TextContent(TextContent {
+            annotations: None,
+            meta: None,
+            text,
+            type_: "text".to_string(),
+        }),
+        role: Role::User,
+    }
+}
+
+pub async fn list_prompts<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    _request: &ListPromptsRequest,
+) -> Result<ListPromptsResult, MCPError<serde_json::Value>> {
+    Ok(ListPromptsResult {
+        meta: None,
+        next_cursor: None,
+        prompts: vec![
+            Prompt {
+                arguments: vec![
+                    prompt_argument("resource_type", "The FHIR resource type IE Patient."),
+                    prompt_argument("id", "The logical id of the resource."),
+                ],
+                description: Some(
+                    "Summarize a FHIR resource in plain language for a clinician.".to_string(),
+                ),
+                icons: vec![],
+                meta: None,
+                name: SUMMARIZE_RESOURCE_PROMPT.to_string(),
+                title: Some("Summarize FHIR Resource".to_string()),
+            },
+            Prompt {
+                arguments: vec![
+                    prompt_argument("resource_type", "The FHIR resource type IE Observation."),
+                    prompt_argument(
+                        "criteria",
+                        "A natural language description of the resources to find.",
+                    ),
+                ],
+                description: Some(
+                    "Find FHIR resources matching a natural language description.".to_string(),
+                ),
+                icons: vec![],
+                meta: None,
+                name: SEARCH_RESOURCES_PROMPT.to_string(),
+                title: Some("Search FHIR Resources".to_string()),
+            },
+        ],
+    })
+}
+
+pub async fn get_prompt<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    request: &GetPromptRequest,
+) -> Result<GetPromptResult, MCPError<serde_json::Value>> {
+    let arguments = &request.params.arguments;
+    let name = request.params.name.as_str();
+
+    if name == SUMMARIZE_RESOURCE_PROMPT {
+        let resource_type = required_argument(arguments, "resource_type")?;
+        let id = required_argument(arguments, "id")?;
+
+        let parsed_resource_type =
+            ResourceType::try_from(resource_type.as_str()).map_err(|_| {
+                OperationOutcomeError::error(
+                    IssueType::Invalid(None),
+                    format!("Invalid resource type '{}'", resource_type),
+                )
+            })?;
+
+        // Read through the client so the same scope and access policy checks apply.
+        let resource = ctx
+            .client
+            .read(ctx.clone(), parsed_resource_type, id.clone())
+            .await?
+            .ok_or_else(|| {
+                OperationOutcomeError::error(
+                    IssueType::NotFound(None),
+                    format!("Resource '{}/{}' not found", resource_type, id),
+                )
+            })?;
+
+        let text = haste_fhir_serialization_json::to_string(&resource).map_err(|e| {
+            OperationOutcomeError::error(
+                IssueType::Processing(None),
+                format!("Failed to serialize resource: '{}'", e.to_string()),
+            )
+        })?;
+
+        Ok(GetPromptResult {
+            description: Some(format!("Summary of {}/{}", resource_type, id)),
+            messages: vec![
+                text_message(format!(
+                    "Summarize the following FHIR {} resource in plain language for a clinician. Highlight clinically relevant information and do not invent details that are not present.",
+                    resource_type
+                )),
+                PromptMessage {
+                    content: ContentBlock::EmbeddedResource(EmbeddedResource {
+                        annotations: None,
+                        meta: None,
+                        resource: EmbeddedResourceResource::TextResourceContents(
+                            TextResourceContents {
+                                meta: None,
+                                mime_type: Some("application/fhir+json".to_string()),
+                                text,
+                                uri: format!("fhir://{}/{}", resource_type, id),
+                            },
+                        ),
+                        type_: "resource".to_string(),
+                    }),
+                    role: Role::User,
+                },
+            ],
+            meta: None,
+        })
+    } else if name == SEARCH_RESOURCES_PROMPT {
+        let resource_type = required_argument(arguments, "resource_type")?;
+        let criteria = required_argument(arguments, "criteria")?;
+
+        Ok(GetPromptResult {
+            description: Some(format!("Search for {} resources", resource_type)),
+            messages: vec![text_message(format!(
+                "Use the '{}' tool with the 'search-type' operation to find resources matching: {}. Translate the criteria into the tool's search_parameters and summarize the results.",
+                resource_type, criteria
+            ))],
+            meta: None,
+        })
+    } else {
+        Err(OperationOutcomeError::error(
+            IssueType::NotFound(None),
+            format!("Prompt '{}' not found", name),
+        )
+        .into())
+    }
+}
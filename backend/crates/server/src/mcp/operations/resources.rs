@@ -0,0 +1,186 @@
+use crate::{
+    fhir_client::ServerCTX,
+    mcp::{
+        error::MCPError,
+        request::{ListResourceTemplatesRequest, ListResourcesRequest, ReadResourceRequest},
+        schemas::schema_2025_11_25::{
+            ListResourceTemplatesResult, ListResourcesResult, ReadResourceResult,
+            ReadResourceResultContentsItem, Resource, ResourceTemplate, TextResourceContents,
+        },
+    },
+};
+use haste_fhir_client::FHIRClient;
+use haste_fhir_model::r4::generated::{resources::ResourceType, terminology::IssueType};
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_repository::Repository;
+use std::sync::Arc;
+
+static FHIR_URI_SCHEME: &str = "fhir://";
+static FHIR_JSON_MIME_TYPE: &str = "application/fhir+json";
+
+/// Parsed form of the resource uris exposed by the MCP server.
+/// IE fhir://metadata, fhir://Patient/123 and fhir://Patient/123/_history/1
+enum FHIRResourceURI {
+    Metadata,
+    Instance(ResourceType, String),
+    Version(ResourceType, String, String),
+}
+
+fn invalid_uri(uri: &str) -> OperationOutcomeError {
+    OperationOutcomeError::error(
+        IssueType::Invalid(None),
+        format!("Invalid resource uri '{}'", uri),
+    )
+}
+
+fn parse_uri(uri: &str) -> Result<FHIRResourceURI, OperationOutcomeError> {
+    let path = uri
+        .strip_prefix(FHIR_URI_SCHEME)
+        .ok_or_else(|| invalid_uri(uri))?;
+    let chunks = path.split('/').collect::<Vec<_>>();
+
+    match chunks.as_slice() {
+        ["metadata"] => Ok(FHIRResourceURI::Metadata),
+        [resource_type, id] => Ok(FHIRResourceURI::Instance(
+            ResourceType::try_from(*resource_type).map_err(|_| invalid_uri(uri))?,
+            id.to_string(),
+        )),
+        [resource_type, id, "_history", version_id] => Ok(FHIRResourceURI::Version(
+            ResourceType::try_from(*resource_type).map_err(|_| invalid_uri(uri))?,
+            id.to_string(),
+            version_id.to_string(),
+        )),
+        _ => Err(invalid_uri(uri)),
+    }
+}
+
+pub async fn list_resources<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    _request: &ListResourcesRequest,
+) -> Result<ListResourcesResult, MCPError<serde_json::Value>> {
+    Ok(ListResourcesResult {
+        meta: None,
+        next_cursor: None,
+        resources: vec![Resource {
+            annotations: None,
+            description: Some(
+                "The FHIR CapabilityStatement describing the resources and interactions supported by this server."
+                    .to_string(),
+            ),
+            icons: vec![],
+            meta: None,
+            mime_type: Some(FHIR_JSON_MIME_TYPE.to_string()),
+            name: "metadata".to_string(),
+            size: None,
+            title: Some("FHIR Capability Statement".to_string()),
+            uri: format!("{}metadata", FHIR_URI_SCHEME),
+        }],
+    })
+}
+
+pub async fn list_resource_templates<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    _ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    _request: &ListResourceTemplatesRequest,
+) -> Result<ListResourceTemplatesResult, MCPError<serde_json::Value>> {
+    Ok(ListResourceTemplatesResult {
+        meta: None,
+        next_cursor: None,
+        resource_templates: vec![
+            ResourceTemplate {
+                annotations: None,
+                description: Some("Read the latest version of a FHIR resource.".to_string()),
+                icons: vec![],
+                meta: None,
+                mime_type: Some(FHIR_JSON_MIME_TYPE.to_string()),
+                name: "fhir-resource".to_string(),
+                title: Some("FHIR Resource".to_string()),
+                uri_template: format!("{}{{resourceType}}/{{id}}", FHIR_URI_SCHEME),
+            },
+            ResourceTemplate {
+                annotations: None,
+                description: Some("Read a specific version of a FHIR resource.".to_string()),
+                icons: vec![],
+                meta: None,
+                mime_type: Some(FHIR_JSON_MIME_TYPE.to_string()),
+                name: "fhir-resource-version".to_string(),
+                title: Some("FHIR Resource Version".to_string()),
+                uri_template: format!(
+                    "{}{{resourceType}}/{{id}}/_history/{{versionId}}",
+                    FHIR_URI_SCHEME
+                ),
+            },
+        ],
+    })
+}
+
+pub async fn read_resource<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
+    request: &ReadResourceRequest,
+) -> Result<ReadResourceResult, MCPError<serde_json::Value>> {
+    let uri = &request.params.uri;
+
+    let text = match parse_uri(uri)? {
+        FHIRResourceURI::Metadata => {
+            let capabilities = ctx.client.capabilities(ctx.clone()).await?;
+            haste_fhir_serialization_json::to_string(&capabilities)
+        }
+        FHIRResourceURI::Instance(resource_type, id) => {
+            let resource = ctx
+                .client
+                .read(ctx.clone(), resource_type, id)
+                .await?
+                .ok_or_else(|| {
+                    OperationOutcomeError::error(
+                        IssueType::NotFound(None),
+                        format!("Resource '{}' not found", uri),
+                    )
+                })?;
+            haste_fhir_serialization_json::to_string(&resource)
+        }
+        FHIRResourceURI::Version(resource_type, id, version_id) => {
+            let resource = ctx
+                .client
+                .vread(ctx.clone(), resource_type, id, version_id)
+                .await?
+                .ok_or_else(|| {
+                    OperationOutcomeError::error(
+                        IssueType::NotFound(None),
+                        format!("Resource '{}' not found", uri),
+                    )
+                })?;
+            haste_fhir_serialization_json::to_string(&resource)
+        }
+    }
+    .map_err(|e| {
+        OperationOutcomeError::error(
+            IssueType::Processing(None),
+            format!("Failed to serialize resource: '{}'", e.to_string()),
+        )
+    })?;
+
+    Ok(ReadResourceResult {
+        contents: vec![ReadResourceResultContentsItem::TextResourceContents(
+            TextResourceContents {
+                meta: None,
+                mime_type: Some(FHIR_JSON_MIME_TYPE.to_string()),
+                text,
+                uri: uri.clone(),
+            },
+        )],
+        meta: None,
+    })
+}
@@ -7,9 +7,13 @@ use crate::{
     },
 };
 use haste_fhir_client::{FHIRClient, url::ParsedParameters};
-use haste_fhir_model::r4::generated::{resources::ResourceType, terminology::IssueType};
+use haste_fhir_model::r4::generated::{
+    resources::{Parameters, Resource, ResourceType},
+    terminology::IssueType,
+};
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_search::SearchEngine;
+use haste_fhir_serialization_json::FHIRJSONSerializer;
 use haste_fhir_terminology::FHIRTerminology;
 use haste_repository::Repository;
 use std::{collections::HashMap, sync::Arc};
@@ -21,6 +25,84 @@ enum Arguments {
     FHIRSearch {
         search_parameters: Option<HashMap<String, String>>,
     },
+    #[serde(rename = "read")]
+    FHIRRead { id: String },
+    #[serde(rename = "vread")]
+    FHIRVersionRead { id: String, version_id: String },
+    #[serde(rename = "history-instance")]
+    FHIRHistoryInstance {
+        id: String,
+        search_parameters: Option<HashMap<String, String>>,
+    },
+    #[serde(rename = "create")]
+    FHIRCreate { resource: serde_json::Value },
+    #[serde(rename = "update")]
+    FHIRUpdate {
+        id: String,
+        resource: serde_json::Value,
+    },
+    #[serde(rename = "patch")]
+    FHIRPatch {
+        id: String,
+        patch: json_patch::Patch,
+    },
+    #[serde(rename = "delete")]
+    FHIRDelete { id: String },
+    /// Invoke a FHIR operation IE $expand at the type level or
+    /// at the instance level when an id is provided.
+    #[serde(rename = "invoke")]
+    FHIRInvoke {
+        id: Option<String>,
+        operation_name: String,
+        parameters: Option<serde_json::Value>,
+    },
+}
+
+fn invalid_argument(request: &CallToolRequest, message: &str) -> MCPError<serde_json::Value> {
+    MCPError {
+        id: request.id.clone(),
+        jsonrpc: "2.0".to_string(),
+        error: MCPErrorDetail {
+            code: 400,
+            message: message.to_string(),
+            data: None,
+        },
+    }
+}
+
+fn parse_resource(
+    request: &CallToolRequest,
+    resource_type: &ResourceType,
+    resource: &serde_json::Value,
+) -> Result<Resource, MCPError<serde_json::Value>> {
+    resource_type
+        .deserialize(&resource.to_string())
+        .map_err(|e| {
+            invalid_argument(
+                request,
+                &format!(
+                    "Failed to parse resource as '{}': '{}'",
+                    resource_type.as_ref(),
+                    e
+                ),
+            )
+        })
+}
+
+fn serialize<T: FHIRJSONSerializer>(value: &T) -> Result<String, OperationOutcomeError> {
+    haste_fhir_serialization_json::to_string(value).map_err(|e| {
+        OperationOutcomeError::error(
+            IssueType::Processing(None),
+            format!("Failed to serialize result: '{}'", e.to_string()),
+        )
+    })
+}
+
+fn not_found(resource_type: &ResourceType, id: &str) -> OperationOutcomeError {
+    OperationOutcomeError::error(
+        IssueType::NotFound(None),
+        format!("Resource '{}/{}' not found", resource_type.as_ref(), id),
+    )
 }
 
 pub async fn tools_call<
@@ -31,55 +113,131 @@ pub async fn tools_call<
     ctx: Arc<ServerCTX<Repo, Search, Terminology>>,
     request: CallToolRequest,
 ) -> Result<CallToolResult, MCPError<serde_json::Value>> {
-    let arguments = serde_json::from_value::<Arguments>(
-        request.params.arguments.unwrap_or_default(),
-    )
-    .map_err(|e| {
-        OperationOutcomeError::error(
-            IssueType::Invalid(None),
-            format!("Failed to parse tool arguments: '{}'", e.to_string()),
-        )
-    })?;
+    let arguments =
+        serde_json::from_value::<Arguments>(request.params.arguments.clone().unwrap_or_default())
+            .map_err(|e| {
+            OperationOutcomeError::error(
+                IssueType::Invalid(None),
+                format!("Failed to parse tool arguments: '{}'", e.to_string()),
+            )
+        })?;
+
+    let resource_type = ResourceType::try_from(request.params.name.as_str())
+        .map_err(|_| invalid_argument(&request, "Invalid resource type provided in arguments"))?;
 
     let content: String = match arguments {
         Arguments::FHIRSearch { search_parameters } => {
-            let resource_type =
-                ResourceType::try_from(request.params.name.as_str()).map_err(|_| MCPError {
-                    id: request.id.clone(),
-                    jsonrpc: "2.0".to_string(),
-                    error: MCPErrorDetail {
-                        code: 400,
-                        message: "Invalid resource type provided in arguments".to_string(),
-                        data: None,
-                    },
-                })?;
-
-            let parsed_parameters = ParsedParameters::try_from(
-                &search_parameters.unwrap_or_default(),
-            )
-            .map_err(|_| MCPError {
-                id: request.id.clone(),
-                jsonrpc: "2.0".to_string(),
-                error: MCPErrorDetail {
-                    code: 400,
-                    message: "Failed to parse search parameters".to_string(),
-                    data: None,
-                },
-            })?;
+            let parsed_parameters =
+                ParsedParameters::try_from(&search_parameters.unwrap_or_default())
+                    .map_err(|_| invalid_argument(&request, "Failed to parse search parameters"))?;
 
             let result = ctx
                 .client
                 .search_type(ctx.clone(), resource_type, parsed_parameters)
                 .await?;
 
-            let result = haste_fhir_serialization_json::to_string(&result).map_err(|e| {
-                OperationOutcomeError::error(
-                    IssueType::Processing(None),
-                    format!("Failed to serialize search result: '{}'", e.to_string()),
-                )
-            })?;
+            serialize(&result)?
+        }
+        Arguments::FHIRRead { id } => {
+            let result = ctx
+                .client
+                .read(ctx.clone(), resource_type.clone(), id.clone())
+                .await?
+                .ok_or_else(|| not_found(&resource_type, &id))?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRVersionRead { id, version_id } => {
+            let result = ctx
+                .client
+                .vread(ctx.clone(), resource_type.clone(), id.clone(), version_id)
+                .await?
+                .ok_or_else(|| not_found(&resource_type, &id))?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRHistoryInstance {
+            id,
+            search_parameters,
+        } => {
+            let parsed_parameters =
+                ParsedParameters::try_from(&search_parameters.unwrap_or_default())
+                    .map_err(|_| invalid_argument(&request, "Failed to parse search parameters"))?;
+
+            let result = ctx
+                .client
+                .history_instance(ctx.clone(), resource_type, id, parsed_parameters)
+                .await?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRCreate { resource } => {
+            let resource = parse_resource(&request, &resource_type, &resource)?;
+            let result = ctx
+                .client
+                .create(ctx.clone(), resource_type, resource)
+                .await?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRUpdate { id, resource } => {
+            let resource = parse_resource(&request, &resource_type, &resource)?;
+            let result = ctx
+                .client
+                .update(ctx.clone(), resource_type, id, resource)
+                .await?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRPatch { id, patch } => {
+            let result = ctx
+                .client
+                .patch(ctx.clone(), resource_type, id, patch.into())
+                .await?;
+
+            serialize(&result)?
+        }
+        Arguments::FHIRDelete { id } => {
+            ctx.client
+                .delete_instance(ctx.clone(), resource_type.clone(), id.clone())
+                .await?;
+
+            serde_json::json!({
+                "resourceType": resource_type.as_ref(),
+                "id": id,
+                "deleted": true,
+            })
+            .to_string()
+        }
+        Arguments::FHIRInvoke {
+            id,
+            operation_name,
+            parameters,
+        } => {
+            let parameters = match parameters {
+                Some(parameters) => {
+                    haste_fhir_serialization_json::from_serde_value::<Parameters>(&parameters)
+                        .map_err(|e| {
+                            invalid_argument(
+                                &request,
+                                &format!("Failed to parse operation parameters: '{}'", e),
+                            )
+                        })?
+                }
+                None => Parameters::default(),
+            };
+
+            let result = if let Some(id) = id {
+                ctx.client
+                    .invoke_instance(ctx.clone(), resource_type, id, operation_name, parameters)
+                    .await?
+            } else {
+                ctx.client
+                    .invoke_type(ctx.clone(), resource_type, operation_name, parameters)
+                    .await?
+            };
 
-            result
+            serialize(&result)?
         }
     };
 
@@ -88,7 +246,7 @@ pub async fn tools_call<
             |e| {
                 OperationOutcomeError::error(
                     IssueType::Processing(None),
-                    format!("Failed to parse search result JSON: '{}'", e.to_string()),
+                    format!("Failed to parse tool result JSON: '{}'", e.to_string()),
                 )
             },
         )?),
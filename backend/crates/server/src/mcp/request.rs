@@ -1,5 +1,6 @@
 use crate::mcp::schemas::schema_2025_11_25::{
-    CallToolRequestParams, InitializeRequestParams, RequestId,
+    CallToolRequestParams, GetPromptRequestParams, InitializeRequestParams, PaginatedRequestParams,
+    ReadResourceRequestParams, RequestId,
 };
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
@@ -26,6 +27,38 @@ pub struct CallToolRequest {
     pub params: CallToolRequestParams,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct ListResourcesRequest {
+    pub id: Option<RequestId>,
+    pub params: Option<PaginatedRequestParams>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct ListResourceTemplatesRequest {
+    pub id: Option<RequestId>,
+    pub params: Option<PaginatedRequestParams>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct ReadResourceRequest {
+    pub id: Option<RequestId>,
+    pub jsonrpc: ::std::string::String,
+    pub params: ReadResourceRequestParams,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct ListPromptsRequest {
+    pub id: Option<RequestId>,
+    pub params: Option<PaginatedRequestParams>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct GetPromptRequest {
+    pub id: Option<RequestId>,
+    pub jsonrpc: ::std::string::String,
+    pub params: GetPromptRequestParams,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(tag = "method")]
 pub enum MCPRequest {
@@ -40,4 +73,16 @@ pub enum MCPRequest {
     ListTools(ListToolsRequest),
     #[serde(rename = "tools/call")]
     ToolsCall(CallToolRequest),
+
+    #[serde(rename = "resources/list")]
+    ListResources(ListResourcesRequest),
+    #[serde(rename = "resources/templates/list")]
+    ListResourceTemplates(ListResourceTemplatesRequest),
+    #[serde(rename = "resources/read")]
+    ReadResource(ReadResourceRequest),
+
+    #[serde(rename = "prompts/list")]
+    ListPrompts(ListPromptsRequest),
+    #[serde(rename = "prompts/get")]
+    GetPrompt(GetPromptRequest),
 }
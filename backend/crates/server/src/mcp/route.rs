@@ -90,6 +90,53 @@ pub async fn mcp_handler<
             })
             .into_response())
         }
+        MCPRequest::ListResources(list_resources_request) => Ok(Json(JSONRPCResult {
+            id: list_resources_request.id.clone(),
+            result: ServerResult {
+                subtype_2: Some(operations::list_resources(ctx, &list_resources_request).await?),
+                ..ServerResult::default()
+            },
+            jsonrpc: "2.0".to_string(),
+        })
+        .into_response()),
+        MCPRequest::ListResourceTemplates(list_templates_request) => Ok(Json(JSONRPCResult {
+            id: list_templates_request.id.clone(),
+            result: ServerResult {
+                subtype_3: Some(
+                    operations::list_resource_templates(ctx, &list_templates_request).await?,
+                ),
+                ..ServerResult::default()
+            },
+            jsonrpc: "2.0".to_string(),
+        })
+        .into_response()),
+        MCPRequest::ReadResource(read_resource_request) => Ok(Json(JSONRPCResult {
+            id: read_resource_request.id.clone(),
+            result: ServerResult {
+                subtype_4: Some(operations::read_resource(ctx, &read_resource_request).await?),
+                ..ServerResult::default()
+            },
+            jsonrpc: "2.0".to_string(),
+        })
+        .into_response()),
+        MCPRequest::ListPrompts(list_prompts_request) => Ok(Json(JSONRPCResult {
+            id: list_prompts_request.id.clone(),
+            result: ServerResult {
+                subtype_5: Some(operations::list_prompts(ctx, &list_prompts_request).await?),
+                ..ServerResult::default()
+            },
+            jsonrpc: "2.0".to_string(),
+        })
+        .into_response()),
+        MCPRequest::GetPrompt(get_prompt_request) => Ok(Json(JSONRPCResult {
+            id: get_prompt_request.id.clone(),
+            result: ServerResult {
+                subtype_6: Some(operations::get_prompt(ctx, &get_prompt_request).await?),
+                ..ServerResult::default()
+            },
+            jsonrpc: "2.0".to_string(),
+        })
+        .into_response()),
         _ => Err(OperationOutcomeError::error(
             IssueType::NotSupported(None),
             "Request not implemented".to_string(),
@@ -1,23 +1,30 @@
 use crate::{
     auth_n,
-    fhir_client::ServerCTX,
-    fhir_http::{HTTPBody, HTTPRequest, http_request_to_fhir_request},
+    fhir_client::{ServerCTX, start_audit_writer},
+    fhir_http::{
+        HTTPBody, HTTPRequest, http_request_to_fhir_request,
+        provenance::{X_PROVENANCE_HEADER, parse_provenance_header, validate_provenance_request},
+        read_bundle,
+    },
     mcp,
     middleware::errors::{log_operationoutcome_errors, operation_outcome_error_handle},
     services::{AppState, ConfigError, create_services, get_pool},
     static_assets::{create_static_server, root_asset_route},
+    telemetry::{self, RequestLabels},
 };
 use axum::{
     Extension, Router, ServiceExt,
     body::Body,
     extract::{DefaultBodyLimit, OriginalUri, Path, State},
-    http::{Method, Uri},
+    http::{HeaderMap, Method, Uri, header::CONTENT_TYPE},
     middleware::from_fn,
     response::{IntoResponse, Response},
     routing::{any, get, post},
 };
-use haste_config::get_config;
+use futures::StreamExt;
+use haste_config::{ConfigType, LayeredConfigOptions, get_config};
 use haste_fhir_client::FHIRClient;
+use haste_fhir_model::r4::generated::resources::Resource;
 use haste_fhir_operation_error::OperationOutcomeError;
 use haste_fhir_search::SearchEngine;
 use haste_fhir_terminology::FHIRTerminology;
@@ -25,6 +32,8 @@ use haste_jwt::{ProjectId, TenantId, claims::UserTokenClaims};
 use haste_repository::{Repository, types::SupportedFHIRVersions};
 use serde::Deserialize;
 use std::{collections::HashMap, sync::Arc, time::Instant};
+use tokio::io::AsyncBufRead;
+use tokio_util::io::StreamReader;
 use tower::{Layer, ServiceBuilder};
 use tower_http::normalize_path::NormalizePath;
 use tower_http::{
@@ -62,9 +71,10 @@ async fn fhir_handler<
     claims: Arc<UserTokenClaims>,
     method: Method,
     uri: Uri,
+    headers: HeaderMap,
     path: FHIRHandlerPath,
     state: Arc<AppState<Repo, Search, Terminology>>,
-    body: String,
+    body: HTTPBody,
 ) -> Result<Response, OperationOutcomeError> {
     let start = Instant::now();
     let fhir_location = path.fhir_location.unwrap_or_default();
@@ -74,7 +84,7 @@ async fn fhir_handler<
         let http_req = HTTPRequest::new(
             method,
             fhir_location,
-            HTTPBody::String(body),
+            body,
             uri.query()
                 .map(|q| {
                     url::form_urlencoded::parse(q.as_bytes())
@@ -82,45 +92,110 @@ async fn fhir_handler<
                         .collect()
                 })
                 .unwrap_or_else(HashMap::new),
+        )
+        .with_content_type(
+            headers
+                .get(CONTENT_TYPE)
+                .and_then(|content_type| content_type.to_str().ok())
+                .map(|content_type| content_type.to_string()),
         );
 
         let fhir_request = http_request_to_fhir_request(SupportedFHIRVersions::R4, http_req)?;
 
-        let ctx = Arc::new(ServerCTX::new(
-            path.tenant,
-            path.project,
-            path.fhir_version,
-            claims.clone(),
-            state.fhir_client.clone(),
-        ));
+        let provenance = match headers.get(X_PROVENANCE_HEADER) {
+            Some(header) => {
+                validate_provenance_request(&fhir_request)?;
+                Some(parse_provenance_header(
+                    header.to_str().unwrap_or_default(),
+                )?)
+            }
+            None => None,
+        };
+
+        let ctx = Arc::new(
+            ServerCTX::new(
+                path.tenant,
+                path.project,
+                path.fhir_version,
+                claims.clone(),
+                state.fhir_client.clone(),
+            )
+            .with_provenance(provenance),
+        );
 
-        let response = state.fhir_client.request(ctx, fhir_request).await?;
+        let labels = RequestLabels::new(&ctx.tenant, &fhir_request);
+        let response = state.fhir_client.request(ctx, fhir_request).await;
 
         info!("Request processed in {:?}", start.elapsed());
 
-        let http_response = response.into_response();
-        Ok(http_response)
+        match response {
+            Ok(response) => {
+                let http_response = response.into_response();
+                labels.record(http_response.status(), start.elapsed());
+                Ok(http_response)
+            }
+            Err(e) => {
+                labels.record(e.status(), start.elapsed());
+                Err(e)
+            }
+        }
     }
     .instrument(span)
     .await
 }
 
+const DEFAULT_MAX_BUNDLE_BODY_SIZE: usize = 256 * 1024 * 1024;
+
+/// Reader over the request body that fails once more than `limit` bytes have been read.
+fn limited_body_reader(body: Body, limit: usize) -> impl AsyncBufRead + Unpin {
+    let mut read = 0;
+    StreamReader::new(Box::pin(body.into_data_stream().map(move |chunk| {
+        let chunk = chunk.map_err(std::io::Error::other)?;
+        read += chunk.len();
+        if read > limit {
+            Err(std::io::Error::other(format!(
+                "Request body exceeds the maximum size of {} bytes",
+                limit
+            )))
+        } else {
+            Ok(chunk)
+        }
+    })))
+}
+
 async fn fhir_root_handler<
     Repo: Repository + Send + Sync + 'static,
     Search: SearchEngine + Send + Sync + 'static,
     Terminology: FHIRTerminology + Send + Sync + 'static,
 >(
     method: Method,
+    headers: HeaderMap,
     Extension(user): Extension<Arc<UserTokenClaims>>,
     OriginalUri(uri): OriginalUri,
     Path(path): Path<FHIRRootHandlerPath>,
     State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
-    body: String,
+    body: Body,
 ) -> Result<Response, OperationOutcomeError> {
+    // Transaction and batch Bundles can be far larger than other requests, so they're
+    // deserialized as they arrive under their own size limit.
+    let body = if method == Method::POST {
+        let max_bundle_size = state
+            .config
+            .get(crate::ServerEnvironmentVariables::MaxBundleBodySize)
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_MAX_BUNDLE_BODY_SIZE);
+        let bundle = read_bundle(limited_body_reader(body, max_bundle_size)).await?;
+        HTTPBody::Resource(Resource::Bundle(bundle))
+    } else {
+        HTTPBody::String(String::new())
+    };
+
     fhir_handler(
         user,
         method,
         uri,
+        headers,
         FHIRHandlerPath {
             tenant: path.tenant,
             project: path.project,
@@ -139,20 +214,54 @@ async fn fhir_type_handler<
     Terminology: FHIRTerminology + Send + Sync + 'static,
 >(
     method: Method,
+    headers: HeaderMap,
     Extension(user): Extension<Arc<UserTokenClaims>>,
     OriginalUri(uri): OriginalUri,
     Path(path): Path<FHIRHandlerPath>,
     State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
     body: String,
 ) -> Result<Response, OperationOutcomeError> {
-    fhir_handler(user, method, uri, path, state, body).await
+    fhir_handler(
+        user,
+        method,
+        uri,
+        headers,
+        path,
+        state,
+        HTTPBody::String(body),
+    )
+    .await
 }
 
 pub async fn server() -> Result<NormalizePath<Router>, OperationOutcomeError> {
-    let config = get_config("environment".into());
+    let config = get_config(ConfigType::Layered(
+        LayeredConfigOptions::new()
+            .required(crate::ServerEnvironmentVariables::required())
+            .reloadable(crate::ServerEnvironmentVariables::reloadable()),
+    ))?;
     auth_n::certificates::create_certifications(&*config).unwrap();
-    let subscriber = tracing_subscriber::FmtSubscriber::new();
-    tracing::subscriber::set_global_default(subscriber).unwrap();
+    telemetry::init_tracing(&*config);
+    telemetry::init_metrics();
+
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(auth_n::certificates::ROTATION_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = auth_n::certificates::rotate_keys().await {
+                tracing::error!("Failed to rotate signing keys: {:?}", e);
+            }
+        }
+    });
+
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(auth_n::certificates::RELOAD_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = auth_n::certificates::reload_keys().await {
+                tracing::error!("Failed to reload signing keys: {:?}", e);
+            }
+        }
+    });
 
     let pool = get_pool(config.as_ref()).await;
     let session_store = PostgresStore::new(pool.clone());
@@ -164,6 +273,7 @@ pub async fn server() -> Result<NormalizePath<Router>, OperationOutcomeError> {
         .and_then(|s| s.parse::<usize>().ok())
         .unwrap_or(4 * 1024 * 1024);
     let shared_state = create_services(config).await?;
+    start_audit_writer(shared_state.clone());
 
     let fhir_router = Router::new()
         .route("/{fhir_version}", any(fhir_root_handler))
@@ -187,10 +297,16 @@ pub async fn server() -> Result<NormalizePath<Router>, OperationOutcomeError> {
                 )),
         );
 
-    let project_router = Router::new().merge(protected_resources_router).nest(
-        "/oidc",
-        auth_n::oidc::routes::create_router(shared_state.clone()),
-    );
+    let project_router = Router::new()
+        .merge(protected_resources_router)
+        .route(
+            "/fhir/{fhir_version}/.well-known/smart-configuration",
+            get(auth_n::oidc::routes::discovery::smart_configuration),
+        )
+        .nest(
+            "/oidc",
+            auth_n::oidc::routes::create_router(shared_state.clone()),
+        );
 
     let tenant_router = Router::new()
         .nest("/{project}/api/v1", project_router)
@@ -210,12 +326,17 @@ pub async fn server() -> Result<NormalizePath<Router>, OperationOutcomeError> {
             "/openid-configuration/w/{tenant}/{project}",
             get(auth_n::oidc::routes::discovery::openid_configuration),
         )
+        .route(
+            "/smart-configuration/w/{tenant}/{project}",
+            get(auth_n::oidc::routes::discovery::smart_configuration),
+        )
         .route(
             "/oauth-protected-resource/w/{tenant}/{project}/{*resource}",
             get(auth_n::oidc::routes::discovery::oauth_protected_resource),
         );
 
     let app = Router::new()
+        .route("/metrics", get(telemetry::metrics_handler))
         .nest("/.well-known", discovery_2_0_document_router)
         .nest(
             "/global",
@@ -1,5 +1,6 @@
 use crate::{
     ServerEnvironmentVariables,
+    email::EmailService,
     fhir_client::{FHIRServerClient, ServerClientConfig},
 };
 use haste_config::Config;
@@ -74,6 +75,7 @@ pub struct AppState<
     pub repo: Arc<Repo>,
     pub fhir_client: Arc<FHIRServerClient<Repo, Search, Terminology>>,
     pub config: Arc<dyn Config<ServerEnvironmentVariables>>,
+    pub email: Arc<EmailService>,
 }
 
 impl<
@@ -96,6 +98,7 @@ impl<
                     self.config.clone(),
                 ))),
                 config: self.config.clone(),
+                email: self.email.clone(),
             }
         })
     }
@@ -181,8 +184,11 @@ pub async fn create_services(
         )
     }));
 
+    let email = Arc::new(EmailService::from_config(config.as_ref())?);
+
     let shared_state = Arc::new(AppState {
         config,
+        email,
         repo: repo,
         terminology: terminology,
         search: search_engine,
@@ -0,0 +1,206 @@
+use crate::{
+    ServerEnvironmentVariables, fhir_client::request_to_resource_type, services::AppState,
+};
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    response::{IntoResponse, Response},
+};
+use haste_config::Config;
+use haste_fhir_client::request::{FHIRRequest, HistoryRequest, SearchRequest};
+use haste_fhir_search::SearchEngine;
+use haste_fhir_terminology::FHIRTerminology;
+use haste_jwt::TenantId;
+use haste_repository::Repository;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_sdk::{
+    Resource,
+    trace::{SdkTracer, SdkTracerProvider},
+};
+use std::{
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+use tracing_subscriber::{filter::LevelFilter, layer::SubscriberExt};
+
+static DEFAULT_SERVICE_NAME: &str = "haste-health";
+static LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+static UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+fn otlp_tracer(
+    config: &dyn Config<ServerEnvironmentVariables>,
+) -> Result<SdkTracer, opentelemetry_otlp::ExporterBuildError> {
+    // Endpoint, headers and protocol settings are read from the standard
+    // OTEL_EXPORTER_OTLP_* environment variables.
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()?;
+
+    let service_name = config
+        .get(ServerEnvironmentVariables::OTLPServiceName)
+        .unwrap_or_else(|_| DEFAULT_SERVICE_NAME.to_string());
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(service_name).build())
+        .build();
+    let tracer = provider.tracer(DEFAULT_SERVICE_NAME);
+    opentelemetry::global::set_tracer_provider(provider);
+
+    Ok(tracer)
+}
+
+/// Installs the global tracing subscriber.
+/// Spans are exported via OTLP when OTEL_EXPORTER_OTLP_ENDPOINT is set.
+pub fn init_tracing(config: &dyn Config<ServerEnvironmentVariables>) {
+    let otel_layer = if config.get(ServerEnvironmentVariables::OTLPEndpoint).is_ok() {
+        match otlp_tracer(config) {
+            Ok(tracer) => Some(tracing_opentelemetry::layer().with_tracer(tracer)),
+            Err(e) => {
+                eprintln!("Failed to create OTLP exporter, traces will not be exported: {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let subscriber = tracing_subscriber::registry()
+        .with(LevelFilter::INFO)
+        .with(tracing_subscriber::fmt::layer())
+        .with(otel_layer);
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+}
+
+/// Installs the Prometheus recorder backing the /metrics endpoint.
+pub fn init_metrics() {
+    let handle = PrometheusBuilder::new()
+        .set_buckets(LATENCY_BUCKETS)
+        .expect("Failed to set histogram buckets")
+        .install_recorder()
+        .expect("Failed to install Prometheus recorder");
+
+    let upkeep_handle = handle.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(UPKEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            upkeep_handle.run_upkeep();
+        }
+    });
+
+    let _ = PROMETHEUS_HANDLE.set(handle);
+}
+
+/// Interaction label for a request using the restful-interaction codes.
+pub fn request_interaction(request: &FHIRRequest) -> &'static str {
+    match request {
+        FHIRRequest::Create(_) => "create",
+        FHIRRequest::Read(_) => "read",
+        FHIRRequest::VersionRead(_) => "vread",
+        FHIRRequest::Update(_) => "update",
+        FHIRRequest::Patch(_) => "patch",
+        FHIRRequest::Delete(_) => "delete",
+        FHIRRequest::Capabilities => "capabilities",
+        FHIRRequest::Search(SearchRequest::Type(_)) => "search-type",
+        FHIRRequest::Search(SearchRequest::System(_)) => "search-system",
+        FHIRRequest::History(HistoryRequest::Instance(_)) => "history-instance",
+        FHIRRequest::History(HistoryRequest::Type(_)) => "history-type",
+        FHIRRequest::History(HistoryRequest::System(_)) => "history-system",
+        FHIRRequest::Invocation(_) => "operation",
+        FHIRRequest::Batch(_) => "batch",
+        FHIRRequest::Transaction(_) => "transaction",
+    }
+}
+
+/// Labels captured before the request is handed to the FHIR client.
+pub struct RequestLabels {
+    interaction: &'static str,
+    resource_type: String,
+    tenant: String,
+}
+
+impl RequestLabels {
+    pub fn new(tenant: &TenantId, request: &FHIRRequest) -> Self {
+        RequestLabels {
+            interaction: request_interaction(request),
+            resource_type: request_to_resource_type(request)
+                .map(|resource_type| resource_type.as_ref().to_string())
+                .unwrap_or_default(),
+            tenant: tenant.as_ref().to_string(),
+        }
+    }
+
+    pub fn record(self, status: StatusCode, elapsed: Duration) {
+        let labels = [
+            ("interaction", self.interaction.to_string()),
+            ("resource_type", self.resource_type),
+            ("tenant", self.tenant),
+        ];
+
+        let mut counter_labels = labels.to_vec();
+        counter_labels.push(("status", status.as_u16().to_string()));
+        metrics::counter!("fhir_requests_total", &counter_labels).increment(1);
+        metrics::histogram!("fhir_request_duration_seconds", &labels).record(elapsed.as_secs_f64());
+    }
+}
+
+/// Prometheus scrape endpoint.
+/// When METRICS_BEARER_TOKEN is set scrapes must present it as a bearer token.
+pub async fn metrics_handler<
+    Repo: Repository + Send + Sync + 'static,
+    Search: SearchEngine + Send + Sync + 'static,
+    Terminology: FHIRTerminology + Send + Sync + 'static,
+>(
+    headers: HeaderMap,
+    State(state): State<Arc<AppState<Repo, Search, Terminology>>>,
+) -> Response {
+    if let Ok(token) = state
+        .config
+        .get(ServerEnvironmentVariables::MetricsBearerToken)
+    {
+        let authorized = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            == Some(token.as_str());
+
+        if !authorized {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+    }
+
+    match PROMETHEUS_HANDLE.get() {
+        Some(handle) => handle.render().into_response(),
+        None => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use haste_fhir_client::request::FHIRReadRequest;
+    use haste_fhir_model::r4::generated::resources::ResourceType;
+
+    #[test]
+    fn test_request_labels() {
+        let request = FHIRRequest::Read(FHIRReadRequest {
+            resource_type: ResourceType::Patient,
+            id: "123".to_string(),
+        });
+        let labels = RequestLabels::new(&TenantId::new("tenant-1".to_string()), &request);
+
+        assert_eq!(labels.interaction, "read");
+        assert_eq!(labels.resource_type, "Patient");
+        assert_eq!(labels.tenant, "tenant-1");
+        assert_eq!(
+            request_interaction(&FHIRRequest::Capabilities),
+            "capabilities"
+        );
+    }
+}
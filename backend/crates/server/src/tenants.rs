@@ -3,6 +3,7 @@ use clap::ValueEnum;
 use haste_fhir_client::FHIRClient;
 use haste_fhir_model::r4::generated::{
     resources::{Project, Resource, ResourceType},
+    terminology::IssueType,
     types::FHIRString,
 };
 use haste_fhir_operation_error::OperationOutcomeError;
@@ -33,6 +34,66 @@ impl From<SubscriptionTier> for String {
     }
 }
 
+impl TryFrom<&str> for SubscriptionTier {
+    type Error = OperationOutcomeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "free" => Ok(SubscriptionTier::Free),
+            "professional" => Ok(SubscriptionTier::Professional),
+            "team" => Ok(SubscriptionTier::Team),
+            "unlimited" => Ok(SubscriptionTier::Unlimited),
+            _ => Err(OperationOutcomeError::fatal(
+                IssueType::Invalid(None),
+                format!("Unknown subscription tier '{}'", value),
+            )),
+        }
+    }
+}
+
+/// Limits applied to a tenant based on its subscription tier, None is unlimited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TierLimits {
+    pub requests_per_minute: Option<u64>,
+    /// Maximum number of stored resources across all projects.
+    pub max_resources: Option<i64>,
+    /// Maximum number of projects excluding the system project.
+    pub max_projects: Option<usize>,
+    /// Maximum `_count` for a search.
+    pub max_count: Option<usize>,
+}
+
+impl SubscriptionTier {
+    pub fn limits(&self) -> TierLimits {
+        match self {
+            SubscriptionTier::Free => TierLimits {
+                requests_per_minute: Some(60),
+                max_resources: Some(10_000),
+                max_projects: Some(1),
+                max_count: Some(20),
+            },
+            SubscriptionTier::Professional => TierLimits {
+                requests_per_minute: Some(600),
+                max_resources: Some(250_000),
+                max_projects: Some(5),
+                max_count: Some(50),
+            },
+            SubscriptionTier::Team => TierLimits {
+                requests_per_minute: Some(3_000),
+                max_resources: Some(2_500_000),
+                max_projects: Some(25),
+                max_count: Some(50),
+            },
+            SubscriptionTier::Unlimited => TierLimits {
+                requests_per_minute: None,
+                max_resources: None,
+                max_projects: None,
+                max_count: None,
+            },
+        }
+    }
+}
+
 pub async fn create_tenant<
     Repo: Repository + Send + Sync + 'static,
     Search: SearchEngine + Send + Sync + 'static,
@@ -0,0 +1,131 @@
+use crate::ui::components::{banner, page_html};
+use haste_jwt::{ProjectId, TenantId};
+use maud::{Markup, html};
+use std::borrow::Cow;
+
+fn project_name(project: &haste_fhir_model::r4::generated::resources::Project) -> Cow<'_, str> {
+    let project_id = project.id.clone().map(|id| ProjectId::new(id)).unwrap();
+    project
+        .name
+        .value
+        .as_ref()
+        .map(|s| Cow::Borrowed(s.as_str()))
+        .unwrap_or_else(|| Cow::Owned(project_id.as_ref().to_string()))
+}
+
+fn errors_html(errors: Option<Vec<String>>) -> Markup {
+    html! {
+        @if let Some(errors) = errors {
+            div class="mb-4" {
+                @for error in errors {
+                    div class="text-red-600 text-sm" { (error) }
+                }
+            }
+        }
+    }
+}
+
+pub fn mfa_verify_html(
+    tenant: &TenantId,
+    project: &haste_fhir_model::r4::generated::resources::Project,
+    mfa_route: &str,
+    errors: Option<Vec<String>>,
+) -> Markup {
+    let project_name = project_name(project);
+
+    page_html(html! {
+        (banner(tenant.as_ref(), Some(&project_name)))
+        div class="w-full bg-white rounded-lg shadow md:mt-0 xl:p-0 sm:max-w-md text-slate-700" {
+            div class="p-6 space-y-4 md:space-y-6 sm:p-8" {
+                (errors_html(errors))
+                h1 class="text-xl font-bold leading-tight tracking-tight text-slate-900 md:text-2xl " { "Two-factor authentication" }
+                form class="space-y-4 md:space-y-6" action=(mfa_route) method="POST" {
+                    div {
+                        label for="code" class="block mb-2 text-sm font-medium text-slate-600 " { "Enter the code from your authenticator app" }
+                        input type="text" id="code" inputmode="numeric" autocomplete="one-time-code" pattern="[0-9 ]*" class="bg-gray-50 border border-gray-300 text-slate-900 sm:text-sm rounded-lg focus:ring-orange-600 focus:border-orange-600 block w-full p-2.5 " placeholder="123456" required name="code" {}
+                    }
+                    button type="submit" class="w-full text-white bg-orange-500 hover:bg-orange-500 focus:ring-4 focus:outline-none focus:ring-orange-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center " { "Verify" }
+                }
+                details class="text-sm" {
+                    summary class="cursor-pointer font-medium text-orange-600 hover:underline" { "Use a recovery code" }
+                    form class="space-y-4 mt-4" action=(mfa_route) method="POST" {
+                        div {
+                            label for="recovery_code" class="block mb-2 text-sm font-medium text-slate-600 " { "Recovery code" }
+                            input type="text" id="recovery_code" autocomplete="off" class="bg-gray-50 border border-gray-300 text-slate-900 sm:text-sm rounded-lg focus:ring-orange-600 focus:border-orange-600 block w-full p-2.5 " placeholder="xxxxx-xxxxx" required name="recovery_code" {}
+                        }
+                        button type="submit" class="w-full text-white bg-slate-600 hover:bg-slate-700 focus:ring-4 focus:outline-none focus:ring-slate-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center " { "Use recovery code" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+pub fn mfa_enroll_html(
+    tenant: &TenantId,
+    project: &haste_fhir_model::r4::generated::resources::Project,
+    enroll_route: &str,
+    provisioning_uri: &str,
+    secret: &str,
+    errors: Option<Vec<String>>,
+) -> Markup {
+    let project_name = project_name(project);
+
+    page_html(html! {
+        (banner(tenant.as_ref(), Some(&project_name)))
+        div class="w-full bg-white rounded-lg shadow md:mt-0 xl:p-0 sm:max-w-md text-slate-700" {
+            div class="p-6 space-y-4 md:space-y-6 sm:p-8" {
+                (errors_html(errors))
+                h1 class="text-xl font-bold leading-tight tracking-tight text-slate-900 md:text-2xl " { "Set up two-factor authentication" }
+                p class="text-sm" {
+                    "Add an account to your authenticator app using the link below or by entering the key manually, then enter the generated code."
+                }
+                div class="space-y-2" {
+                    a href=(provisioning_uri) class="text-sm font-medium text-orange-600 hover:underline break-all" { "Open in authenticator app" }
+                    div {
+                        label for="provisioning_uri" class="block mb-2 text-sm font-medium text-slate-600" { "Provisioning URI" }
+                        input type="text" id="provisioning_uri" readonly class="bg-gray-50 border border-gray-300 text-slate-900 text-xs rounded-lg block w-full p-2.5 font-mono" value=(provisioning_uri) {}
+                    }
+                    div {
+                        span class="block mb-2 text-sm font-medium text-slate-600" { "Setup key" }
+                        code class="block bg-gray-50 border border-gray-300 text-slate-900 text-sm rounded-lg p-2.5 break-all" { (secret) }
+                    }
+                }
+                form class="space-y-4 md:space-y-6" action=(enroll_route) method="POST" {
+                    div {
+                        label for="code" class="block mb-2 text-sm font-medium text-slate-600 " { "Verification code" }
+                        input type="text" id="code" inputmode="numeric" autocomplete="one-time-code" pattern="[0-9 ]*" class="bg-gray-50 border border-gray-300 text-slate-900 sm:text-sm rounded-lg focus:ring-orange-600 focus:border-orange-600 block w-full p-2.5 " placeholder="123456" required name="code" {}
+                    }
+                    button type="submit" class="w-full text-white bg-orange-500 hover:bg-orange-500 focus:ring-4 focus:outline-none focus:ring-orange-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center " { "Enable" }
+                }
+            }
+        }
+    })
+}
+
+pub fn recovery_codes_html(
+    tenant: &TenantId,
+    project: &haste_fhir_model::r4::generated::resources::Project,
+    recovery_codes: &Vec<String>,
+    continue_route: &str,
+) -> Markup {
+    let project_name = project_name(project);
+
+    page_html(html! {
+        (banner(tenant.as_ref(), Some(&project_name)))
+        div class="w-full bg-white rounded-lg shadow md:mt-0 xl:p-0 sm:max-w-md text-slate-700" {
+            div class="p-6 space-y-4 md:space-y-6 sm:p-8" {
+                h1 class="text-xl font-bold leading-tight tracking-tight text-slate-900 md:text-2xl " { "Save your recovery codes" }
+                p class="text-sm" {
+                    "Each code can be used once to sign in if you lose access to your authenticator app. They will not be shown again."
+                }
+                ul class="grid grid-cols-2 gap-2 font-mono text-sm bg-gray-50 border border-gray-300 rounded-lg p-4" {
+                    @for code in recovery_codes {
+                        li { (code) }
+                    }
+                }
+                a href=(continue_route) class="block w-full text-white bg-orange-500 hover:bg-orange-500 focus:ring-4 focus:outline-none focus:ring-orange-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center " { "Continue" }
+            }
+        }
+    })
+}
@@ -2,5 +2,7 @@ pub mod email_form;
 pub mod error;
 pub mod login;
 pub mod message;
+pub mod mfa;
+pub mod patient_picker;
 pub mod scope_approval;
 pub mod tenant_select;
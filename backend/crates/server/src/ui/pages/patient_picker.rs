@@ -0,0 +1,100 @@
+use crate::{
+    auth_n::oidc::routes::route_string::oidc_route_string,
+    ui::components::{banner, client_app_header_html, page_html},
+};
+use haste_fhir_model::r4::generated::resources::{ClientApplication, Patient};
+use haste_jwt::{ProjectId, TenantId};
+use maud::{Markup, html};
+use std::{borrow::Cow, collections::HashMap};
+
+/// Parameters set by the picker itself that must not be carried over as hidden inputs.
+static PICKER_PARAMETERS: &[&str] = &["patient", "name"];
+
+fn patient_display_name(patient: &Patient) -> String {
+    let name = patient.name.as_ref().and_then(|names| names.first());
+    let text = name.and_then(|name| name.text.as_ref().and_then(|text| text.value.clone()));
+
+    text.or_else(|| {
+        let name = name?;
+        let parts = name
+            .given
+            .iter()
+            .flatten()
+            .chain(name.family.iter())
+            .filter_map(|part| part.value.clone())
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" "))
+        }
+    })
+    .unwrap_or_else(|| "Unnamed patient".to_string())
+}
+
+pub fn patient_picker_html(
+    tenant: &TenantId,
+    project: &haste_fhir_model::r4::generated::resources::Project,
+    client_application: &ClientApplication,
+    parameters: &HashMap<String, String>,
+    patients: &[Patient],
+    name_filter: Option<&str>,
+) -> Markup {
+    let project_id = project.id.clone().map(|id| ProjectId::new(id)).unwrap();
+    let project_name = project
+        .name
+        .value
+        .as_ref()
+        .map(|s| Cow::Borrowed(s.as_str()))
+        .unwrap_or_else(|| Cow::Owned(project_id.as_ref().to_string()));
+
+    let picker_route = oidc_route_string(tenant, &project_id, "auth/launch/patient");
+    let picker_route_str = picker_route
+        .to_str()
+        .expect("Could not create patient picker route");
+
+    let hidden_parameters = parameters
+        .iter()
+        .filter(|(name, _)| !PICKER_PARAMETERS.contains(&name.as_str()))
+        .collect::<Vec<_>>();
+
+    page_html(html! {
+        (banner(tenant.as_ref(), Some(&project_name)))
+        div class="w-full bg-white rounded-lg shadow md:mt-0 xl:p-0 sm:max-w-md text-slate-700" {
+            div class="p-6 space-y-4 md:space-y-6 sm:p-8" {
+                (client_app_header_html(client_application))
+                div {
+                    span class="text-sm text-slate-500" {
+                        "The above app is requesting access to a patient. Select the patient the app should launch with."
+                    }
+                }
+                form class="flex space-x-2" action=(picker_route_str) method="POST" {
+                    @for (name, value) in hidden_parameters.iter() {
+                        input readonly="" class="hidden" type="text" name=(name) value=(value) {}
+                    }
+                    input type="text" class="bg-gray-50 border border-gray-300 text-slate-900 sm:text-sm rounded-lg focus:ring-orange-600 focus:border-orange-600 block w-full p-2.5" placeholder="Search by name" name="name" value=(name_filter.unwrap_or("")) {}
+                    button type="submit" class="cursor-pointer text-slate-900 bg-gray-100 hover:bg-gray-200 focus:ring-4 focus:outline-none font-medium rounded-lg text-sm px-5 py-2.5 text-center" {
+                        "Search"
+                    }
+                }
+                div class="max-h-72 overflow-auto" {
+                    @if patients.is_empty() {
+                        span class="text-sm text-slate-500" { "No patients found." }
+                    }
+                    @for patient in patients.iter() {
+                        form action=(picker_route_str) method="POST" {
+                            @for (name, value) in hidden_parameters.iter() {
+                                input readonly="" class="hidden" type="text" name=(name) value=(value) {}
+                            }
+                            input readonly="" class="hidden" type="text" name="patient" value=(patient.id.as_deref().unwrap_or("")) {}
+                            button type="submit" class="cursor-pointer w-full text-left p-4 border border-gray-200 hover:bg-gray-50" {
+                                div class="font-medium" { (patient_display_name(patient)) }
+                                div class="text-xs text-slate-500" { (patient.id.as_deref().unwrap_or("")) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
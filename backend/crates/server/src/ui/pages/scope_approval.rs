@@ -69,6 +69,9 @@ pub fn scope_approval_html(
                             input readonly="" class="hidden" type="text" name="code_challenge_method" value=(authorization_info.code_challenge_method) {}
                             input readonly="" class="hidden" type="text" name="scope" value=(String::from(authorization_info.scope.clone())) {}
                             input readonly="" class="hidden" type="text" name="redirect_uri" value=(authorization_info.redirect_uri) {}
+                            @if let Some(launch) = &authorization_info.launch {
+                                input readonly="" class="hidden" type="text" name="launch" value=(launch) {}
+                            }
                             input readonly="" class="hidden" type="checkbox" name="accept" checked {}
                             button type="submit" class="cursor-pointer w-full text-white bg-orange-500 hover:bg-orange-500 focus:ring-4 focus:outline-none focus:ring-orange-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-orange-500 dark:hover:bg-orange-500 dark:focus:ring-orange-800" {
                                 "Allow"
@@ -83,6 +86,9 @@ pub fn scope_approval_html(
                             input readonly="" class="hidden" type="text" name="code_challenge_method" value=(authorization_info.code_challenge_method) {}
                             input readonly="" class="hidden" type="text" name="scope" value=(String::from(authorization_info.scope.clone())) {}
                             input readonly="" class="hidden" type="text" name="redirect_uri" value=(authorization_info.redirect_uri) {}
+                            @if let Some(launch) = &authorization_info.launch {
+                                input readonly="" class="hidden" type="text" name="launch" value=(launch) {}
+                            }
                             input readonly="" class="hidden" type="checkbox" name="accept" {}
                             button type="submit" class="cursor-pointer w-full text-slate-900 bg-gray-100 hover:bg-gray-200 focus:ring-4 focus:outline-none  font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:text-white dark:bg-gray-600 dark:hover:bg-gray-700 dark:focus:ring-gray-800" {
                                 "Deny"
@@ -1,19 +1,25 @@
 #![allow(unused)]
 use crate::CLIState;
 use clap::Subcommand;
+use futures::StreamExt;
 use haste_fhir_client::{
     FHIRClient,
     http::{FHIRHttpClient, FHIRHttpState},
+    pagination::{PaginatedRequest, PaginationOptions, paginate},
+    request::FHIRPatch,
+    retry::{RetryMiddleware, RetryPolicy},
     url::ParsedParameters,
 };
+use haste_fhir_generated_ops::generated::HasteHealthImport;
 use haste_fhir_model::r4::generated::{
-    resources::{Bundle, Resource, ResourceType},
+    resources::{Bundle, OperationOutcomeIssue, Parameters, Resource, ResourceType},
     terminology::IssueType,
+    types::{FHIRInteger, FHIRUri},
 };
 use haste_fhir_operation_error::OperationOutcomeError;
-use haste_fhir_serialization_json::FHIRJSONDeserializer;
+use haste_fhir_serialization_json::{FHIRJSONDeserializer, stream::NDJSONReader};
 use haste_server::auth_n::oidc::routes::discovery::WellKnownDiscoveryDocument;
-use std::sync::Arc;
+use std::{pin::pin, sync::Arc};
 use tokio::sync::Mutex;
 
 #[derive(Subcommand, Debug)]
@@ -63,26 +69,56 @@ pub enum ApiCommands {
 
     HistorySystem {
         parameters: Option<String>,
+        /// Follow bundle next links and print every resource as NDJSON.
+        #[arg(long)]
+        all: bool,
+        /// Stop after this many resources when used with --all.
+        #[arg(long)]
+        max_items: Option<usize>,
     },
 
     HistoryType {
         resource_type: String,
         parameters: Option<String>,
+        /// Follow bundle next links and print every resource as NDJSON.
+        #[arg(long)]
+        all: bool,
+        /// Stop after this many resources when used with --all.
+        #[arg(long)]
+        max_items: Option<usize>,
     },
 
     HistoryInstance {
         resource_type: String,
         id: String,
         parameters: Option<String>,
+        /// Follow bundle next links and print every resource as NDJSON.
+        #[arg(long)]
+        all: bool,
+        /// Stop after this many resources when used with --all.
+        #[arg(long)]
+        max_items: Option<usize>,
     },
 
     SearchType {
         resource_type: String,
         parameters: Option<String>,
+        /// Follow bundle next links and print every resource as NDJSON.
+        #[arg(long)]
+        all: bool,
+        /// Stop after this many resources when used with --all.
+        #[arg(long)]
+        max_items: Option<usize>,
     },
 
     SearchSystem {
         parameters: Option<String>,
+        /// Follow bundle next links and print every resource as NDJSON.
+        #[arg(long)]
+        all: bool,
+        /// Stop after this many resources when used with --all.
+        #[arg(long)]
+        max_items: Option<usize>,
     },
 
     InvokeSystem {
@@ -121,9 +157,24 @@ pub enum ApiCommands {
         id: String,
         operation_name: String,
     },
+
+    /// Bulk load NDJSON with $import, keeping resource ids and versions.
+    Import {
+        /// Local NDJSON files, uploaded in chunks.
+        files: Vec<String>,
+        /// Files under the servers import directory or URLs for the server to read directly.
+        #[arg(long)]
+        source: Vec<String>,
+        /// Resources uploaded per request.
+        #[arg(long)]
+        chunk_size: Option<usize>,
+        /// Resources the server writes per batch.
+        #[arg(long)]
+        batch_size: Option<i64>,
+    },
 }
 
-async fn config_to_fhir_http_state(
+pub(crate) async fn config_to_fhir_http_state(
     state: Arc<Mutex<CLIState>>,
 ) -> Result<FHIRHttpState, OperationOutcomeError> {
     let current_state = state.lock().await;
@@ -299,12 +350,114 @@ async fn read_from_file_or_stin<Type: FHIRJSONDeserializer>(
     }
 }
 
+async fn print_ndjson(
+    fhir_client: &FHIRHttpClient<()>,
+    request: PaginatedRequest,
+    parameters: ParsedParameters,
+    max_items: Option<usize>,
+) -> Result<(), OperationOutcomeError> {
+    let mut resources = pin!(paginate(
+        fhir_client,
+        (),
+        request,
+        parameters,
+        PaginationOptions { max_items },
+    ));
+
+    while let Some(resource) = resources.next().await {
+        println!(
+            "{}",
+            haste_fhir_serialization_json::to_string(&resource?)
+                .expect("Failed to serialize response")
+        );
+    }
+
+    Ok(())
+}
+
+static DEFAULT_IMPORT_CHUNK_SIZE: usize = 1000;
+
+/// Invokes $import, returning the issues from the resulting OperationOutcome.
+async fn invoke_import(
+    fhir_client: &FHIRHttpClient<()>,
+    input: HasteHealthImport::Input,
+) -> Result<Vec<OperationOutcomeIssue>, OperationOutcomeError> {
+    let Resource::Parameters(parameters) = Resource::from(input) else {
+        unreachable!("$import input is always Parameters");
+    };
+
+    match fhir_client
+        .invoke_system((), HasteHealthImport::CODE.to_string(), parameters)
+        .await?
+    {
+        Resource::OperationOutcome(outcome) => Ok(outcome.issue),
+        _ => Err(OperationOutcomeError::error(
+            IssueType::Exception(None),
+            "$import did not return an OperationOutcome".to_string(),
+        )),
+    }
+}
+
+fn issue_diagnostics(issue: &OperationOutcomeIssue) -> &str {
+    issue
+        .diagnostics
+        .as_ref()
+        .and_then(|d| d.value.as_deref())
+        .unwrap_or_default()
+}
+
+/// Uploads a chunk of resources read from local files, printing its errors against the
+/// file and line each resource came from.
+async fn import_chunk(
+    fhir_client: &FHIRHttpClient<()>,
+    chunk: Vec<(String, Resource)>,
+    batch_size: Option<i64>,
+) -> Result<(), OperationOutcomeError> {
+    let (locations, resources): (Vec<_>, Vec<_>) = chunk.into_iter().unzip();
+    let issues = invoke_import(
+        fhir_client,
+        HasteHealthImport::Input {
+            source: None,
+            resource: Some(resources),
+            batch_size: batch_size.map(|value| FHIRInteger {
+                value: Some(value),
+                ..Default::default()
+            }),
+        },
+    )
+    .await?;
+
+    for issue in issues.iter().skip(1) {
+        let diagnostics = issue_diagnostics(issue);
+        // Inline resources are reported as resource[index].
+        let location = diagnostics
+            .strip_prefix("resource[")
+            .and_then(|rest| rest.split_once("]: "))
+            .and_then(|(index, message)| {
+                Some((locations.get(index.parse::<usize>().ok()?)?, message))
+            });
+
+        match location {
+            Some((location, message)) => eprintln!("{}: {}", location, message),
+            None => eprintln!("{}", diagnostics),
+        }
+    }
+    if let Some(summary) = issues.first() {
+        eprintln!("{}", issue_diagnostics(summary));
+    }
+
+    Ok(())
+}
+
 pub async fn api_commands(
     state: Arc<Mutex<CLIState>>,
     command: &ApiCommands,
 ) -> Result<(), OperationOutcomeError> {
     let http_state = config_to_fhir_http_state(state).await?;
-    let fhir_client = Arc::new(FHIRHttpClient::<()>::new(http_state));
+    let fhir_client = Arc::new(FHIRHttpClient::<()>::with_middleware(
+        http_state,
+        vec![Box::new(RetryMiddleware::new(RetryPolicy::default()))],
+    ));
     match command {
         ApiCommands::Create {
             resource_type,
@@ -348,12 +501,18 @@ pub async fn api_commands(
                 )
             })?;
 
-            let patch = serde_json::from_str::<json_patch::Patch>(&file_content).map_err(|e| {
-                OperationOutcomeError::error(
-                    IssueType::Invalid(None),
-                    format!("Failed to parse patch JSON: {}", e),
-                )
-            })?;
+            // A JSON array is a JSON Patch, otherwise expect a FHIRPath Patch Parameters resource.
+            let patch: FHIRPatch = match serde_json::from_str::<json_patch::Patch>(&file_content) {
+                Ok(patch) => patch.into(),
+                Err(_) => haste_fhir_serialization_json::from_str::<Parameters>(&file_content)
+                    .map_err(|e| {
+                        OperationOutcomeError::error(
+                            IssueType::Invalid(None),
+                            format!("Failed to parse patch JSON: {}", e),
+                        )
+                    })?
+                    .into(),
+            };
 
             let resource_type = ResourceType::try_from(resource_type.as_str()).map_err(|e| {
                 OperationOutcomeError::error(
@@ -480,13 +639,24 @@ pub async fn api_commands(
 
             Ok(())
         }
-        ApiCommands::HistorySystem { parameters } => {
-            let result = fhir_client
-                .history_system(
-                    (),
-                    ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?,
+        ApiCommands::HistorySystem {
+            parameters,
+            all,
+            max_items,
+        } => {
+            let parameters =
+                ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?;
+            if *all {
+                return print_ndjson(
+                    fhir_client.as_ref(),
+                    PaginatedRequest::HistorySystem,
+                    parameters,
+                    *max_items,
                 )
-                .await?;
+                .await;
+            }
+
+            let result = fhir_client.history_system((), parameters).await?;
 
             println!(
                 "{}",
@@ -499,6 +669,8 @@ pub async fn api_commands(
         ApiCommands::HistoryType {
             resource_type,
             parameters,
+            all,
+            max_items,
         } => {
             let resource_type = ResourceType::try_from(resource_type.as_str()).map_err(|e| {
                 OperationOutcomeError::error(
@@ -510,12 +682,20 @@ pub async fn api_commands(
                 )
             })?;
 
-            let result = fhir_client
-                .history_type(
-                    (),
-                    resource_type,
-                    ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?,
+            let parameters =
+                ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?;
+            if *all {
+                return print_ndjson(
+                    fhir_client.as_ref(),
+                    PaginatedRequest::HistoryType(resource_type.clone()),
+                    parameters,
+                    *max_items,
                 )
+                .await;
+            }
+
+            let result = fhir_client
+                .history_type((), resource_type, parameters)
                 .await?;
 
             println!(
@@ -530,6 +710,8 @@ pub async fn api_commands(
             resource_type,
             id,
             parameters,
+            all,
+            max_items,
         } => {
             let resource_type = ResourceType::try_from(resource_type.as_str()).map_err(|e| {
                 OperationOutcomeError::error(
@@ -541,13 +723,20 @@ pub async fn api_commands(
                 )
             })?;
 
-            let result = fhir_client
-                .history_instance(
-                    (),
-                    resource_type,
-                    id.clone(),
-                    ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?,
+            let parameters =
+                ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?;
+            if *all {
+                return print_ndjson(
+                    fhir_client.as_ref(),
+                    PaginatedRequest::HistoryInstance(resource_type.clone(), id.clone()),
+                    parameters,
+                    *max_items,
                 )
+                .await;
+            }
+
+            let result = fhir_client
+                .history_instance((), resource_type, id.clone(), parameters)
                 .await?;
 
             println!(
@@ -561,6 +750,8 @@ pub async fn api_commands(
         ApiCommands::SearchType {
             resource_type,
             parameters,
+            all,
+            max_items,
         } => {
             let resource_type = ResourceType::try_from(resource_type.as_str()).map_err(|e| {
                 OperationOutcomeError::error(
@@ -572,12 +763,20 @@ pub async fn api_commands(
                 )
             })?;
 
-            let result = fhir_client
-                .search_type(
-                    (),
-                    resource_type,
-                    ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?,
+            let parameters =
+                ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?;
+            if *all {
+                return print_ndjson(
+                    fhir_client.as_ref(),
+                    PaginatedRequest::SearchType(resource_type.clone()),
+                    parameters,
+                    *max_items,
                 )
+                .await;
+            }
+
+            let result = fhir_client
+                .search_type((), resource_type, parameters)
                 .await?;
 
             println!(
@@ -588,13 +787,24 @@ pub async fn api_commands(
 
             Ok(())
         }
-        ApiCommands::SearchSystem { parameters } => {
-            let result = fhir_client
-                .search_system(
-                    (),
-                    ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?,
+        ApiCommands::SearchSystem {
+            parameters,
+            all,
+            max_items,
+        } => {
+            let parameters =
+                ParsedParameters::try_from(parameters.clone().unwrap_or_default().as_str())?;
+            if *all {
+                return print_ndjson(
+                    fhir_client.as_ref(),
+                    PaginatedRequest::SearchSystem,
+                    parameters,
+                    *max_items,
                 )
-                .await?;
+                .await;
+            }
+
+            let result = fhir_client.search_system((), parameters).await?;
 
             println!(
                 "{}",
@@ -766,6 +976,70 @@ pub async fn api_commands(
 
             println!("Resources deleted based on provided system-level parameters.");
 
+            Ok(())
+        }
+        ApiCommands::Import {
+            files,
+            source,
+            chunk_size,
+            batch_size,
+        } => {
+            let chunk_size = chunk_size.unwrap_or(DEFAULT_IMPORT_CHUNK_SIZE).max(1);
+
+            for file_path in files {
+                let file = tokio::fs::File::open(file_path).await.map_err(|e| {
+                    OperationOutcomeError::error(
+                        IssueType::Exception(None),
+                        format!("Failed to open '{}': {}", file_path, e),
+                    )
+                })?;
+                let mut reader = NDJSONReader::new(tokio::io::BufReader::new(file));
+                let mut chunk = Vec::with_capacity(chunk_size);
+
+                while let Some(result) = reader.next::<Resource>().await {
+                    let location = format!("{}:{}", file_path, reader.line_number());
+                    match result {
+                        Ok(resource) => chunk.push((location, resource)),
+                        Err(e) => eprintln!("{}: {}", location, e),
+                    }
+
+                    if chunk.len() >= chunk_size {
+                        import_chunk(&fhir_client, std::mem::take(&mut chunk), *batch_size).await?;
+                    }
+                }
+
+                if !chunk.is_empty() {
+                    import_chunk(&fhir_client, chunk, *batch_size).await?;
+                }
+            }
+
+            if !source.is_empty() {
+                let issues = invoke_import(
+                    &fhir_client,
+                    HasteHealthImport::Input {
+                        source: Some(
+                            source
+                                .iter()
+                                .map(|source| FHIRUri {
+                                    value: Some(source.clone()),
+                                    ..Default::default()
+                                })
+                                .collect(),
+                        ),
+                        resource: None,
+                        batch_size: batch_size.map(|value| FHIRInteger {
+                            value: Some(value),
+                            ..Default::default()
+                        }),
+                    },
+                )
+                .await?;
+
+                for issue in issues {
+                    eprintln!("{}", issue_diagnostics(&issue));
+                }
+            }
+
             Ok(())
         }
     }
@@ -30,6 +30,14 @@ pub enum CodeGen {
         #[arg(short, long)]
         output: Option<String>,
     },
+    /// Typed search query methods for the FHIR client.
+    SearchParameters {
+        #[arg(short, long)]
+        input: Vec<String>,
+        /// Output Rust file path
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 fn format_code(rust_code: String) -> String {
@@ -78,6 +86,27 @@ pub async fn codegen(command: &CodeGen) -> Result<(), OperationOutcomeError> {
 
             Ok(())
         }
+        CodeGen::SearchParameters { input, output } => {
+            let generated_search_parameters =
+                type_gen::search_parameters::generate_search_parameters_from_files(input)
+                    .map_err(|e| OperationOutcomeError::error(IssueType::Exception(None), e))?;
+
+            let formatted_code = format_code(generated_search_parameters);
+
+            match output {
+                Some(output_path) => {
+                    std::fs::write(output_path, formatted_code).map_err(|e| {
+                        OperationOutcomeError::error(IssueType::Exception(None), e.to_string())
+                    })?;
+                    println!("Generated search parameters written to: {}", output_path);
+                }
+                None => {
+                    println!("{}", formatted_code);
+                }
+            }
+
+            Ok(())
+        }
         CodeGen::Types {
             input,
             output,
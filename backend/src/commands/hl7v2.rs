@@ -0,0 +1,186 @@
+use crate::{CLIState, commands::api::config_to_fhir_http_state};
+use clap::Subcommand;
+use haste_fhir_client::{
+    FHIRClient,
+    http::{FHIRHttpClient, FHIRHttpState},
+};
+use haste_fhir_model::r4::generated::terminology::IssueType;
+use haste_fhir_operation_error::OperationOutcomeError;
+use haste_hl7v2::{
+    ack::{AcknowledgmentCode, acknowledgment},
+    conversion::Hl7V2Converter,
+    encoder::encode,
+    mllp::{MllpDecoder, frame},
+    parser::{Hl7V2Message, MessageHeader},
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+};
+
+#[derive(Subcommand, Debug)]
+pub enum Hl7V2Commands {
+    /// Listen for HL7 v2 messages over MLLP and submit them to the active profile's FHIR server.
+    Listen {
+        #[arg(long, default_value = "0.0.0.0")]
+        host: String,
+        #[arg(short, long, default_value_t = 2575)]
+        port: u16,
+    },
+}
+
+static ACK_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn ack_control_id() -> String {
+    format!(
+        "{}{}",
+        chrono::Utc::now().format("%Y%m%d%H%M%S"),
+        ACK_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+fn ack(original: Option<&MessageHeader>, code: AcknowledgmentCode, text: Option<&str>) -> String {
+    encode(&acknowledgment(
+        original,
+        code,
+        &ack_control_id(),
+        &chrono::Utc::now().format("%Y%m%d%H%M%S%z").to_string(),
+        text,
+    ))
+}
+
+/// Processes a single message returning the encoded ACK.
+/// Messages that fail to parse or convert are rejected (AR) and failures
+/// submitting the converted transaction are returned as application errors (AE).
+async fn process_message(
+    converter: &Hl7V2Converter,
+    client: &FHIRHttpClient<()>,
+    message: &str,
+) -> String {
+    let message = match Hl7V2Message::try_from(message) {
+        Ok(message) => message,
+        Err(e) => {
+            // Attempt to parse just the header so the ACK can reference the original message.
+            let header = message
+                .split(['\r', '\n'])
+                .next()
+                .and_then(|line| MessageHeader::try_from(line).ok());
+            return ack(
+                header.as_ref(),
+                AcknowledgmentCode::AR,
+                Some(&e.to_string()),
+            );
+        }
+    };
+
+    let bundle = match converter.convert(&message) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            return ack(
+                Some(&message.header),
+                AcknowledgmentCode::AR,
+                Some(&e.to_string()),
+            );
+        }
+    };
+
+    match client.transaction((), bundle).await {
+        Ok(_) => ack(Some(&message.header), AcknowledgmentCode::AA, None),
+        Err(e) => ack(
+            Some(&message.header),
+            AcknowledgmentCode::AE,
+            Some(&e.to_string()),
+        ),
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    converter: Arc<Hl7V2Converter>,
+    client: Arc<FHIRHttpClient<()>>,
+) -> Result<(), OperationOutcomeError> {
+    let mut decoder = MllpDecoder::default();
+    let mut buffer = [0u8; 8192];
+
+    loop {
+        let read = stream.read(&mut buffer).await.map_err(|e| {
+            OperationOutcomeError::error(
+                IssueType::Exception(None),
+                format!("Failed to read from connection: {}", e),
+            )
+        })?;
+
+        if read == 0 {
+            return Ok(());
+        }
+
+        decoder.push(&buffer[..read]);
+
+        while let Some(message) = decoder.next_message().transpose() {
+            let response = match message {
+                Ok(message) => process_message(&converter, &client, &message).await,
+                Err(e) => ack(None, AcknowledgmentCode::AR, Some(&e.to_string())),
+            };
+
+            stream.write_all(&frame(&response)).await.map_err(|e| {
+                OperationOutcomeError::error(
+                    IssueType::Exception(None),
+                    format!("Failed to write acknowledgment: {}", e),
+                )
+            })?;
+        }
+    }
+}
+
+async fn listen(
+    http_state: FHIRHttpState,
+    host: &str,
+    port: u16,
+) -> Result<(), OperationOutcomeError> {
+    let client = Arc::new(FHIRHttpClient::<()>::new(http_state));
+    let converter = Arc::new(Hl7V2Converter::default());
+
+    let listener = TcpListener::bind((host, port)).await.map_err(|e| {
+        OperationOutcomeError::error(
+            IssueType::Exception(None),
+            format!("Failed to bind MLLP listener on {}:{}: {}", host, port, e),
+        )
+    })?;
+
+    println!("Listening for MLLP connections on {}:{}", host, port);
+
+    loop {
+        let (stream, address) = listener.accept().await.map_err(|e| {
+            OperationOutcomeError::error(
+                IssueType::Exception(None),
+                format!("Failed to accept connection: {}", e),
+            )
+        })?;
+
+        let converter = converter.clone();
+        let client = client.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, converter, client).await {
+                eprintln!("MLLP connection from '{}' failed: {}", address, e);
+            }
+        });
+    }
+}
+
+pub async fn hl7v2(
+    state: Arc<Mutex<CLIState>>,
+    command: &Hl7V2Commands,
+) -> Result<(), OperationOutcomeError> {
+    match command {
+        Hl7V2Commands::Listen { host, port } => {
+            let http_state = config_to_fhir_http_state(state).await?;
+            listen(http_state, host, *port).await
+        }
+    }
+}
@@ -2,5 +2,6 @@ pub mod api;
 pub mod codegen;
 pub mod config;
 pub mod fhirpath;
+pub mod hl7v2;
 pub mod server;
 pub mod worker;
@@ -1,5 +1,5 @@
 use clap::Subcommand;
-use haste_config::{Config, get_config};
+use haste_config::{Config, ConfigType, LayeredConfigOptions, get_config};
 use haste_fhir_client::FHIRClient;
 use haste_fhir_model::r4::generated::{
     resources::{Resource, ResourceType, User},
@@ -107,7 +107,8 @@ async fn migrate_artifacts(
 }
 
 pub async fn server(command: &ServerCommands) -> Result<(), OperationOutcomeError> {
-    let config = get_config::<ServerEnvironmentVariables>("environment".into());
+    let config =
+        get_config::<ServerEnvironmentVariables>(ConfigType::Layered(LayeredConfigOptions::new()))?;
 
     match &command {
         ServerCommands::Start { port } => server::serve(port.unwrap_or(3000)).await,
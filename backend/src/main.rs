@@ -44,6 +44,10 @@ enum CLICommand {
         command: commands::config::ConfigCommands,
     },
     Worker {},
+    Hl7v2 {
+        #[command(subcommand)]
+        command: commands::hl7v2::Hl7V2Commands,
+    },
 }
 
 static CONFIG_LOCATION: LazyLock<PathBuf> = LazyLock::new(|| {
@@ -90,5 +94,6 @@ async fn main() -> Result<(), OperationOutcomeError> {
         CLICommand::Worker {} => commands::worker::worker().await,
         CLICommand::Config { command } => commands::config::config(&config, command).await,
         CLICommand::Api { command } => commands::api::api_commands(config, command).await,
+        CLICommand::Hl7v2 { command } => commands::hl7v2::hl7v2(config, command).await,
     }
 }